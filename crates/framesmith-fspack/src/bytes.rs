@@ -91,6 +91,188 @@ pub fn read_f32_le(data: &[u8], offset: usize) -> Option<f32> {
     Some(f32::from_bits(bits))
 }
 
+/// Borrow `len` bytes of `data` starting at `offset`.
+/// Returns `None` if `offset + len > data.len()`.
+#[inline]
+pub fn read_bytes(data: &[u8], offset: usize, len: usize) -> Option<&[u8]> {
+    data.get(offset..offset.checked_add(len)?)
+}
+
+/// Borrow a fixed-size record of `N` bytes of `data` starting at `offset`.
+/// Returns `None` if `offset + N > data.len()`.
+#[inline]
+pub fn read_array<const N: usize>(data: &[u8], offset: usize) -> Option<&[u8; N]> {
+    read_bytes(data, offset, N)?.try_into().ok()
+}
+
+/// Byte order of multi-byte fields in section payloads.
+///
+/// Packs are little-endian unless the header sets `PACK_FLAG_BIG_ENDIAN`.
+/// Without the `big-endian` feature every read is little-endian and the
+/// order check compiles away.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Endian {
+    #[inline]
+    fn is_big(self) -> bool {
+        cfg!(feature = "big-endian") && self == Endian::Big
+    }
+
+    /// Read a u16 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 2 > data.len()`.
+    #[inline]
+    pub fn read_u16(self, data: &[u8], offset: usize) -> Option<u16> {
+        let bytes = *read_array::<2>(data, offset)?;
+        Some(if self.is_big() {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    /// Read a u32 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 4 > data.len()`.
+    #[inline]
+    pub fn read_u32(self, data: &[u8], offset: usize) -> Option<u32> {
+        let bytes = *read_array::<4>(data, offset)?;
+        Some(if self.is_big() {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    /// Read a u64 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 8 > data.len()`.
+    #[inline]
+    pub fn read_u64(self, data: &[u8], offset: usize) -> Option<u64> {
+        let bytes = *read_array::<8>(data, offset)?;
+        Some(if self.is_big() {
+            u64::from_be_bytes(bytes)
+        } else {
+            u64::from_le_bytes(bytes)
+        })
+    }
+
+    /// Read an i16 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 2 > data.len()`.
+    #[inline]
+    pub fn read_i16(self, data: &[u8], offset: usize) -> Option<i16> {
+        Some(self.read_u16(data, offset)? as i16)
+    }
+
+    /// Read an i32 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 4 > data.len()`.
+    #[inline]
+    pub fn read_i32(self, data: &[u8], offset: usize) -> Option<i32> {
+        Some(self.read_u32(data, offset)? as i32)
+    }
+
+    /// Read an i64 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 8 > data.len()`.
+    #[inline]
+    pub fn read_i64(self, data: &[u8], offset: usize) -> Option<i64> {
+        Some(self.read_u64(data, offset)? as i64)
+    }
+
+    /// Read an f32 in this byte order from `data` at `offset`.
+    /// Returns `None` if `offset + 4 > data.len()`.
+    #[inline]
+    pub fn read_f32(self, data: &[u8], offset: usize) -> Option<f32> {
+        Some(f32::from_bits(self.read_u32(data, offset)?))
+    }
+}
+
+/// Sequential reader over a byte slice.
+///
+/// Each read advances the position past the value it returns. A read that
+/// would run past the end returns `None` and leaves the position unchanged.
+/// Multi-byte values are little-endian unless set with
+/// [`Cursor::with_endian`].
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+    endian: Endian,
+}
+
+impl<'a> Cursor<'a> {
+    /// Start reading `data` at `offset`.
+    #[inline]
+    pub fn new(data: &'a [u8], offset: usize) -> Self {
+        Self {
+            data,
+            pos: offset,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte values in `endian` order from here on.
+    #[inline]
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Offset of the next byte to read.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Bytes left between the position and the end of the data.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    /// Advance by `len` bytes without reading them.
+    /// Returns `None` if fewer than `len` bytes remain.
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    #[inline]
+    pub fn read_u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    #[inline]
+    pub fn read_u16(&mut self) -> Option<u16> {
+        let endian = self.endian;
+        self.take(2).and_then(|b| endian.read_u16(b, 0))
+    }
+
+    #[inline]
+    pub fn read_u32(&mut self) -> Option<u32> {
+        let endian = self.endian;
+        self.take(4).and_then(|b| endian.read_u32(b, 0))
+    }
+
+    /// Read a StrRef: offset(4) + len(2) + pad(2), returned as `(offset, len)`.
+    #[inline]
+    pub fn read_strref(&mut self) -> Option<(u32, u16)> {
+        let bytes = self.take(8)?;
+        Some((
+            self.endian.read_u32(bytes, 0)?,
+            self.endian.read_u16(bytes, 4)?,
+        ))
+    }
+
+    /// Borrow the next `len` bytes and advance past them.
+    #[inline]
+    pub fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = read_bytes(self.data, self.pos, len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,4 +324,76 @@ mod tests {
         let data = [0xFE, 0xFF]; // -2 in little-endian
         assert_eq!(read_i16_le(&data, 0), Some(-2));
     }
+
+    #[test]
+    fn read_bytes_in_bounds() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(read_bytes(&data, 1, 3), Some(&[2, 3, 4][..]));
+        assert_eq!(read_bytes(&data, 0, 5), Some(&data[..]));
+        assert_eq!(read_bytes(&data, 5, 0), Some(&[][..]));
+    }
+
+    #[test]
+    fn read_bytes_out_of_bounds() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(read_bytes(&data, 3, 3), None);
+        assert_eq!(read_bytes(&data, 6, 0), None);
+        assert_eq!(read_bytes(&data, usize::MAX, 2), None);
+    }
+
+    #[test]
+    fn read_array_in_bounds() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(read_array::<2>(&data, 3), Some(&[4, 5]));
+        assert_eq!(read_array::<5>(&data, 0), Some(&data));
+    }
+
+    #[test]
+    fn read_array_out_of_bounds() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(read_array::<2>(&data, 4), None);
+        assert_eq!(read_array::<6>(&data, 0), None);
+        assert_eq!(read_array::<1>(&data, usize::MAX), None);
+    }
+
+    #[test]
+    fn cursor_decodes_event_arg_record() {
+        // EventArg: key StrRef, tag u8 + pad(3), string value StrRef
+        let mut record = [0u8; 20];
+        record[0..4].copy_from_slice(&12u32.to_le_bytes());
+        record[4..6].copy_from_slice(&5u16.to_le_bytes());
+        record[8] = 3;
+        record[12..16].copy_from_slice(&40u32.to_le_bytes());
+        record[16..18].copy_from_slice(&7u16.to_le_bytes());
+
+        let mut cur = Cursor::new(&record, 0);
+        assert_eq!(cur.read_strref(), Some((12, 5)));
+        assert_eq!(cur.position(), 8);
+        assert_eq!(cur.read_u8(), Some(3));
+        assert_eq!(cur.skip(3), Some(()));
+        assert_eq!(cur.read_u32(), Some(40));
+        assert_eq!(cur.read_u16(), Some(7));
+        assert_eq!(cur.position(), 18);
+        assert_eq!(cur.remaining(), 2);
+    }
+
+    #[test]
+    fn cursor_over_read_returns_none_and_keeps_position() {
+        let data = [1, 0, 2, 0, 0];
+        let mut cur = Cursor::new(&data, 2);
+        assert_eq!(cur.read_u32(), None);
+        assert_eq!(cur.read_strref(), None);
+        assert_eq!(cur.skip(4), None);
+        assert_eq!(cur.position(), 2);
+
+        assert_eq!(cur.read_u16(), Some(2));
+        assert_eq!(cur.read_u16(), None);
+        assert_eq!(cur.read_u8(), Some(0));
+        assert_eq!(cur.read_u8(), None);
+        assert_eq!(cur.remaining(), 0);
+
+        let mut past_end = Cursor::new(&data, usize::MAX);
+        assert_eq!(past_end.read_u8(), None);
+        assert_eq!(past_end.remaining(), 0);
+    }
 }
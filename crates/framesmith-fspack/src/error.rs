@@ -9,4 +9,13 @@ pub enum Error {
     InvalidMagic,
     /// An offset or length field points outside the data.
     OutOfBounds,
+    /// A string reference is out of bounds of the string table or not valid UTF-8.
+    InvalidString {
+        /// Kind of the section holding the reference.
+        section: u32,
+        /// Index of the record holding the reference within that section.
+        index: usize,
+    },
+    /// The pack sets `PACK_FLAG_BIG_ENDIAN` and the `big-endian` feature is off.
+    BigEndian,
 }
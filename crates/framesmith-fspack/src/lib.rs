@@ -1,6 +1,6 @@
 #![no_std]
 
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
 pub mod bytes;
@@ -8,23 +8,32 @@ pub mod error;
 pub mod fixed;
 pub mod view;
 
+pub use bytes::Endian;
 pub use error::Error;
 pub use fixed::{Q12_4, Q24_8, Q8_8};
 pub use view::{
+    CancelCondition,
     CancelFlags,
+    CancelTagRule,
+    CancelTagRuleView,
+    CancelTagRulesView,
     CancelsView,
     CharacterPropView,
     CharacterPropsView,
+    EventArgValue,
     EventArgView,
     EventArgsView,
     EventEmitView,
     EventEmitsView,
+    HitCancelView,
+    HitCancelsView,
     HitWindowView,
     HitWindowsView,
     HurtWindowView,
     HurtWindowsView,
     KeyframesKeysView,
     MeshKeysView,
+    MetadataView,
     MoveNotifiesView,
     MoveNotifyView,
     MoveResourceCostView,
@@ -33,22 +42,54 @@ pub use view::{
     MoveResourceDeltasView,
     MoveResourcePreconditionView,
     MoveResourcePreconditionsView,
+    PackFlags,
+    PackStats,
     PackView,
+    PropValue,
     PushWindowView,
     PushWindowsView,
+    ResolvedEvent,
+    ResolvedEventArg,
+    ResolvedNotify,
     ResourceDefView,
     ResourceDefsView,
+    ResourceTrigger,
     SchemaCharacterPropView,
     SchemaCharacterPropsView,
     SchemaView,
     ShapeView,
     ShapesView,
+    StateConditionView,
+    StateConditionsView,
     StateExtrasRecordView,
     StateExtrasView,
+    StateKnockbackView,
+    StateKnockbacksView,
+    StateMeterGainView,
+    StateMeterGainsView,
+    StateMovementView,
+    StateMovementsView,
+    StateOnUseView,
+    StateOnUsesView,
+    StatePushbackView,
+    StatePushbacksView,
+    StateSummary,
+    StateSuperFreezesView,
+    StateType,
     StateView,
+    StateWindows,
     StatesView,
+    SuperFreezeData,
     // Constants
     CHARACTER_PROP_SIZE,
+    CHARGE_DIR_BACK,
+    CHARGE_DIR_DOWN,
+    CHARGE_DIR_FORWARD,
+    CHARGE_DIR_UP,
+    CONDITION_KIND_AIRBORNE,
+    CONDITION_KIND_CHARGE,
+    CONDITION_KIND_DISTANCE,
+    CONDITION_KIND_GROUNDED,
     EVENT_ARG_SIZE,
     EVENT_ARG_TAG_BOOL,
     EVENT_ARG_TAG_F32,
@@ -56,16 +97,34 @@ pub use view::{
     EVENT_ARG_TAG_STRING,
     EVENT_EMIT_SIZE,
     HEADER_SIZE,
+    HIT_CANCEL_SIZE,
+    HIT_FLAG_GROUND_BOUNCE,
+    HIT_FLAG_THROW,
+    HIT_FLAG_WALL_BOUNCE,
     HIT_WINDOW_SIZE,
+    HURT_FLAG_ARMOR,
+    HURT_FLAG_FULL_INVULN,
+    HURT_FLAG_PROJECTILE_INVULN,
+    HURT_FLAG_STRIKE_INVULN,
+    HURT_FLAG_THROW_INVULN,
     HURT_WINDOW_SIZE,
     KEY_NONE,
     MAGIC,
     MAX_SECTIONS,
+    METADATA_KEY_BUILD_TIMESTAMP,
+    METADATA_KEY_CHARACTER_ID,
+    METADATA_KEY_EXPORTER_VERSION,
+    MOVEMENT_FLAG_AIRBORNE,
     MOVE_NOTIFY_SIZE,
     MOVE_RESOURCE_COST_SIZE,
     MOVE_RESOURCE_DELTA_SIZE,
     MOVE_RESOURCE_PRECONDITION_SIZE,
     OPT_U16_NONE,
+    PACK_FLAG_BIG_ENDIAN,
+    PACK_FLAG_SORTED_INPUTS,
+    PROP_TYPE_BOOL,
+    PROP_TYPE_Q24_8,
+    PROP_TYPE_STR,
     RESOURCE_DEF_SIZE,
     RESOURCE_DELTA_TRIGGER_ON_BLOCK,
     RESOURCE_DELTA_TRIGGER_ON_HIT,
@@ -81,6 +140,7 @@ pub use view::{
     SECTION_HURT_WINDOWS,
     SECTION_KEYFRAMES_KEYS,
     SECTION_MESH_KEYS,
+    SECTION_METADATA,
     SECTION_MOVE_NOTIFIES,
     SECTION_MOVE_RESOURCE_COSTS,
     SECTION_MOVE_RESOURCE_DELTAS,
@@ -90,30 +150,1677 @@ pub use view::{
     SECTION_SCHEMA,
     SECTION_SHAPES,
     SECTION_STATES,
+    SECTION_STATE_CONDITIONS,
     SECTION_STATE_EXTRAS,
+    SECTION_STATE_HIT_CANCELS,
+    SECTION_STATE_KNOCKBACK,
+    SECTION_STATE_METER_GAIN,
+    SECTION_STATE_MOVEMENT,
+    SECTION_STATE_ON_USE,
     SECTION_STATE_PROPS,
+    SECTION_STATE_PUSHBACK,
+    SECTION_STATE_SUPER_FREEZE,
     SECTION_STRING_TABLE,
     SHAPE_KIND_AABB,
     SHAPE_KIND_CAPSULE,
     SHAPE_KIND_CIRCLE,
     SHAPE_KIND_RECT,
     SHAPE_SIZE,
+    STATE_CONDITIONS_INDEX_ENTRY_SIZE,
+    STATE_CONDITION_SIZE,
     STATE_EXTRAS_SIZE,
+    STATE_FLAG_AIRBORNE,
+    STATE_FLAG_ALLOW_FROM_STUN,
+    STATE_HIT_CANCELS_INDEX_ENTRY_SIZE,
+    STATE_KNOCKBACK_SIZE,
+    STATE_METER_GAIN_SIZE,
+    STATE_MOVEMENT_SIZE,
+    STATE_ON_USE_SIZE,
     STATE_PROPS_INDEX_ENTRY_SIZE,
+    STATE_PUSHBACK_SIZE,
     STATE_RECORD_SIZE,
+    STATE_SUPER_FREEZE_SIZE,
     STRREF_SIZE,
+    SUPER_FREEZE_FLAG_FLASH,
 };
 
+#[cfg(feature = "alloc")]
+pub use view::PackBuf;
+#[cfg(any(feature = "alloc", test))]
+pub use view::PackBuilder;
+
+#[cfg(feature = "big-endian")]
+pub use view::{to_big_endian, to_little_endian};
+
+#[cfg(feature = "bytemuck")]
+pub use view::{ShapeRaw, StateRecordRaw};
+
+#[cfg(feature = "serde")]
+pub use view::{DecodedHitWindow, DecodedHurtWindow, DecodedPack, DecodedShape, DecodedState};
+
 #[cfg(test)]
 extern crate std;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::view::{PUSH_WINDOW_SIZE, SECTION_STATE_TAGS};
+    use std::vec::Vec;
+
+    /// Highest `SECTION_*` kind; bump it when a section is added.
+    const LAST_SECTION_KIND: u32 = SECTION_STATE_PUSHBACK;
+
+    /// Assemble a pack from `(kind, bytes)` sections with [`PackBuilder`].
+    fn build_pack(sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut builder = PackBuilder::new();
+        for (kind, bytes) in sections {
+            builder.section(*kind, bytes);
+        }
+        builder.finish()
+    }
+
+    /// Window records of `size` bytes with the given (start, end) frames.
+    fn windows(size: usize, frames: &[(u8, u8)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for &(start, end) in frames {
+            let mut rec = std::vec![0u8; size];
+            rec[0] = start;
+            rec[1] = end;
+            out.extend_from_slice(&rec);
+        }
+        out
+    }
+
+    /// Two states: state 0 owns hit window 0; state 1 owns hit windows 1-2,
+    /// all three hurt windows, and the single push window.
+    fn multi_window_pack() -> Vec<u8> {
+        let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        states[26..28].copy_from_slice(&1u16.to_le_bytes());
+        let s1 = &mut states[STATE_RECORD_SIZE..];
+        s1[22..26].copy_from_slice(&(HIT_WINDOW_SIZE as u32).to_le_bytes());
+        s1[26..28].copy_from_slice(&2u16.to_le_bytes());
+        s1[30..32].copy_from_slice(&3u16.to_le_bytes());
+        s1[34..36].copy_from_slice(&1u16.to_le_bytes());
+
+        build_pack(&[
+            (SECTION_STATES, states),
+            (
+                SECTION_HIT_WINDOWS,
+                windows(HIT_WINDOW_SIZE, &[(1, 2), (5, 7), (9, 10)]),
+            ),
+            (
+                SECTION_HURT_WINDOWS,
+                windows(HURT_WINDOW_SIZE, &[(0, 4), (5, 8), (9, 20)]),
+            ),
+            (SECTION_PUSH_WINDOWS, windows(PUSH_WINDOW_SIZE, &[(0, 20)])),
+        ])
+    }
 
     #[test]
     fn parse_empty_fails_too_short() {
         let result = PackView::parse(&[]);
         assert!(matches!(result, Err(Error::TooShort)));
     }
+
+    #[test]
+    fn total_len_and_body_cover_section_data() {
+        let mut bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"abc".to_vec()),
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+        ]);
+        let declared = bytes.len();
+        bytes.extend_from_slice(&[0xAA; 5]); // trailing bytes outside the pack
+
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.total_len(), declared);
+
+        let body = pack.body();
+        // The states section starts on the next 4-byte boundary after "abc".
+        assert_eq!(body.len(), 3 + 1 + STATE_RECORD_SIZE);
+        assert_eq!(
+            body.len(),
+            pack.total_len() - HEADER_SIZE - pack.section_count() * SECTION_HEADER_SIZE
+        );
+        assert_eq!(&body[..3], b"abc");
+    }
+
+    #[test]
+    fn get_section_at_walks_table_order() {
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+            (SECTION_STRING_TABLE, b"abc".to_vec()),
+            (SECTION_SHAPES, std::vec![1u8; SHAPE_SIZE]),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.section_count(), 3);
+
+        let walked: Vec<(u32, usize)> = (0..pack.section_count())
+            .map(|i| {
+                pack.get_section_at(i)
+                    .map(|(kind, data)| (kind, data.len()))
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(
+            walked,
+            std::vec![
+                (SECTION_STATES, STATE_RECORD_SIZE),
+                (SECTION_STRING_TABLE, 3),
+                (SECTION_SHAPES, SHAPE_SIZE),
+            ]
+        );
+        assert_eq!(pack.get_section_at(1).unwrap().1, b"abc");
+        assert!(pack.get_section_at(3).is_none());
+    }
+
+    #[test]
+    fn content_hash_follows_pack_bytes() {
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"abc".to_vec()),
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+        ]);
+        let copy = bytes.clone();
+        let hash = PackView::parse(&bytes).unwrap().content_hash();
+        assert_eq!(PackView::parse(&copy).unwrap().content_hash(), hash);
+
+        let mut changed = bytes.clone();
+        let last = changed.len() - 1;
+        changed[last] ^= 1;
+        assert_ne!(PackView::parse(&changed).unwrap().content_hash(), hash);
+
+        // Trailing bytes outside the pack don't count.
+        let mut padded = bytes.clone();
+        padded.extend_from_slice(&[0xAA; 5]);
+        assert_eq!(PackView::parse(&padded).unwrap().content_hash(), hash);
+
+        // Pinned so the hash stays stable across releases.
+        let empty = build_pack(&[]);
+        let empty = PackView::parse(&empty).unwrap();
+        assert_eq!(empty.content_hash(), 0x4858_90a3_dc77_4413);
+    }
+
+    #[test]
+    fn body_of_header_only_pack_is_empty() {
+        let bytes = build_pack(&[]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.total_len(), HEADER_SIZE);
+        assert!(pack.body().is_empty());
+    }
+
+    #[test]
+    fn state_type_byte_roundtrip() {
+        for raw in 0..=u8::MAX {
+            assert_eq!(StateType::from_u8(raw).to_u8(), raw);
+        }
+        assert_eq!(StateType::from_u8(8), StateType::System);
+        assert_eq!(StateType::from_u8(255), StateType::Unknown(255));
+    }
+
+    #[test]
+    fn resource_trigger_byte_roundtrip() {
+        for raw in 0..=u8::MAX {
+            assert_eq!(ResourceTrigger::from_u8(raw).to_u8(), raw);
+        }
+        assert_eq!(
+            ResourceTrigger::from_u8(RESOURCE_DELTA_TRIGGER_ON_HIT),
+            ResourceTrigger::OnHit
+        );
+        assert_eq!(ResourceTrigger::from_u8(9), ResourceTrigger::Unknown(9));
+    }
+
+    #[test]
+    fn state_meter_gain_reads_parallel_records() {
+        let mut gains = std::vec![0u8; 2 * STATE_METER_GAIN_SIZE];
+        gains[4..6].copy_from_slice(&100u16.to_le_bytes());
+        gains[6..8].copy_from_slice(&20u16.to_le_bytes());
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 2 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_METER_GAIN, gains),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let gains = pack.state_meter_gains().unwrap();
+        assert_eq!(gains.len(), 2);
+        let g = gains.get(1).unwrap();
+        assert_eq!((g.meter_gain_hit(), g.meter_gain_whiff()), (100, 20));
+        assert!(gains.get(2).is_none());
+    }
+
+    #[test]
+    fn pack_without_meter_gain_keeps_36_byte_states() {
+        // Packs written before meter gain existed have no STATE_METER_GAIN
+        // section; their state records must still parse at 36 bytes.
+        let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        states[36..38].copy_from_slice(&7u16.to_le_bytes());
+        states[50..52].copy_from_slice(&12u16.to_le_bytes());
+        let bytes = build_pack(&[(SECTION_STATES, states)]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let states = pack.states().unwrap();
+        assert_eq!(states.len(), 2);
+        let s = states.get(1).unwrap();
+        assert_eq!((s.state_id(), s.total()), (7, 12));
+        assert!(pack.state_meter_gains().is_none());
+    }
+
+    #[test]
+    fn state_knockback_reads_signed_parallel_records() {
+        let mut knockbacks = std::vec![0u8; 2 * STATE_KNOCKBACK_SIZE];
+        knockbacks[4..6].copy_from_slice(&(-12i16).to_le_bytes());
+        knockbacks[6..8].copy_from_slice(&(-30i16).to_le_bytes());
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 2 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_KNOCKBACK, knockbacks),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let knockbacks = pack.state_knockbacks().unwrap();
+        assert_eq!(knockbacks.len(), 2);
+        let k = knockbacks.get(0).unwrap();
+        assert_eq!((k.x(), k.y()), (0, 0));
+        let k = knockbacks.get(1).unwrap();
+        assert_eq!((k.x(), k.y()), (-12, -30));
+        assert!(knockbacks.get(2).is_none());
+    }
+
+    #[test]
+    fn state_pushback_reads_q12_4_parallel_records() {
+        let mut pushbacks = std::vec![0u8; 2 * STATE_PUSHBACK_SIZE];
+        pushbacks[4..6].copy_from_slice(&(3i16 << 4).to_le_bytes());
+        pushbacks[6..8].copy_from_slice(&(-(5i16 << 4) - 8).to_le_bytes());
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 2 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_PUSHBACK, pushbacks),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let pushbacks = pack.state_pushbacks().unwrap();
+        assert_eq!(pushbacks.len(), 2);
+        let p = pushbacks.get(0).unwrap();
+        assert_eq!((p.hit_pushback_px(), p.block_pushback_px()), (0, 0));
+        let p = pushbacks.get(1).unwrap();
+        assert_eq!((p.hit_pushback_raw(), p.block_pushback_raw()), (48, -88));
+        assert_eq!((p.hit_pushback_px(), p.block_pushback_px()), (3, -6));
+        assert!(pushbacks.get(2).is_none());
+    }
+
+    #[test]
+    fn state_windows_match_raw_accessors() {
+        let bytes = multi_window_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = pack.states().unwrap().get(1).unwrap();
+        let windows = pack.state_windows(1).unwrap();
+
+        assert_eq!(
+            windows.hit_windows().count(),
+            state.hit_windows_len() as usize
+        );
+        assert_eq!(
+            windows.hurt_windows().count(),
+            state.hurt_windows_len() as usize
+        );
+        assert_eq!(
+            windows.push_windows().count(),
+            state.push_windows_len() as usize
+        );
+
+        let raw_hit = pack
+            .hit_windows()
+            .unwrap()
+            .get_at(state.hit_windows_off(), 0)
+            .unwrap();
+        let first_hit = windows.hit_windows().next().unwrap();
+        assert_eq!(
+            (first_hit.start_frame(), first_hit.end_frame()),
+            (raw_hit.start_frame(), raw_hit.end_frame())
+        );
+        assert_eq!((first_hit.start_frame(), first_hit.end_frame()), (5, 7));
+
+        let first_hurt = windows.hurt_windows().next().unwrap();
+        assert_eq!((first_hurt.start_frame(), first_hurt.end_frame()), (0, 4));
+        let first_push = windows.push_windows().next().unwrap();
+        assert_eq!((first_push.start_frame(), first_push.end_frame()), (0, 20));
+    }
+
+    #[test]
+    fn state_windows_empty_ranges_and_bounds() {
+        let bytes = multi_window_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let state0 = pack.state_windows(0).unwrap();
+        assert_eq!(state0.hit_windows().count(), 1);
+        assert_eq!(state0.hurt_windows().count(), 0);
+        assert_eq!(state0.push_windows().count(), 0);
+
+        assert!(pack.state_windows(2).is_none());
+    }
+
+    #[test]
+    fn window_shapes_iterate_in_order() {
+        // Shape 0 is unrelated; both windows own shapes 1 and 2.
+        let mut shapes = Vec::new();
+        for x in [100i16, 1, 2] {
+            let mut rec = std::vec![0u8; SHAPE_SIZE];
+            rec[2..4].copy_from_slice(&(x << 4).to_le_bytes());
+            shapes.extend_from_slice(&rec);
+        }
+        let mut hit = windows(HIT_WINDOW_SIZE, &[(0, 1)]);
+        hit[12..16].copy_from_slice(&(SHAPE_SIZE as u32).to_le_bytes());
+        hit[16..18].copy_from_slice(&2u16.to_le_bytes());
+        let mut hurt = windows(HURT_WINDOW_SIZE, &[(0, 1)]);
+        hurt[4..8].copy_from_slice(&(SHAPE_SIZE as u32).to_le_bytes());
+        hurt[8..10].copy_from_slice(&2u16.to_le_bytes());
+
+        let bytes = build_pack(&[
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_HURT_WINDOWS, hurt),
+            (SECTION_SHAPES, shapes),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let hw = pack.hit_windows().unwrap().get(0).unwrap();
+        let xs: Vec<i32> = hw.shapes(&pack).map(|s| s.x_px()).collect();
+        assert_eq!(xs, std::vec![1, 2]);
+
+        let hrt = pack.hurt_windows().unwrap().get(0).unwrap();
+        let xs: Vec<i32> = hrt.shapes(&pack).map(|s| s.x_px()).collect();
+        assert_eq!(xs, std::vec![1, 2]);
+    }
+
+    #[test]
+    fn window_shapes_empty_without_shapes_section() {
+        let mut hit = windows(HIT_WINDOW_SIZE, &[(0, 1)]);
+        hit[16..18].copy_from_slice(&2u16.to_le_bytes());
+        let bytes = build_pack(&[(SECTION_HIT_WINDOWS, hit)]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let hw = pack.hit_windows().unwrap().get(0).unwrap();
+        assert_eq!(hw.shapes(&pack).count(), 0);
+    }
+
+    #[test]
+    fn contains_section_and_section_len() {
+        let states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"abc".to_vec()),
+            (SECTION_STATES, states),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert!(pack.contains_section(SECTION_STATES));
+        assert!(pack.contains_section(SECTION_STRING_TABLE));
+        assert!(!pack.contains_section(SECTION_SHAPES));
+        assert_eq!(
+            pack.section_len(SECTION_STATES),
+            Some(2 * STATE_RECORD_SIZE)
+        );
+        assert_eq!(pack.section_len(SECTION_STRING_TABLE), Some(3));
+        assert_eq!(pack.section_len(SECTION_SHAPES), None);
+    }
+
+    #[test]
+    fn stats_match_view_lengths() {
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 3 * STATE_RECORD_SIZE]),
+            (
+                SECTION_HIT_WINDOWS,
+                windows(HIT_WINDOW_SIZE, &[(1, 2), (5, 7)]),
+            ),
+            (SECTION_HURT_WINDOWS, windows(HURT_WINDOW_SIZE, &[(0, 4)])),
+            // A trailing partial record is not counted
+            (SECTION_SHAPES, std::vec![0u8; 4 * SHAPE_SIZE + 5]),
+            (SECTION_CANCELS_U16, std::vec![0u8; 6]),
+            (SECTION_EVENT_EMITS, std::vec![0u8; 2 * EVENT_EMIT_SIZE]),
+            (SECTION_EVENT_ARGS, std::vec![0u8; EVENT_ARG_SIZE]),
+            (SECTION_MESH_KEYS, [strref(0, 0), strref(0, 0)].concat()),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let stats = pack.stats();
+        let len = |n: Option<usize>| n.unwrap_or(0);
+
+        assert_eq!(stats.states, len(pack.states().map(|v| v.len())));
+        assert_eq!(stats.hit_windows, len(pack.hit_windows().map(|v| v.len())));
+        assert_eq!(
+            stats.hurt_windows,
+            len(pack.hurt_windows().map(|v| v.len()))
+        );
+        assert_eq!(stats.shapes, len(pack.shapes().map(|v| v.len())));
+        assert_eq!(stats.cancels, len(pack.cancels().map(|v| v.len())));
+        assert_eq!(stats.event_emits, len(pack.event_emits().map(|v| v.len())));
+        assert_eq!(stats.event_args, len(pack.event_args().map(|v| v.len())));
+        assert_eq!(stats.mesh_keys, len(pack.mesh_keys().map(|v| v.len())));
+        assert_eq!(
+            stats,
+            PackStats {
+                states: 3,
+                hit_windows: 2,
+                hurt_windows: 1,
+                shapes: 4,
+                cancels: 3,
+                event_emits: 2,
+                event_args: 1,
+                mesh_keys: 2,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn super_freeze_reads_records_and_skips_zero_frames() {
+        // State 0 has no freeze; state 1 freezes 30 frames, 1.5x zoom, half dark, flash.
+        let mut data = std::vec![0u8; 2 * STATE_SUPER_FREEZE_SIZE];
+        data[6] = 30;
+        data[7] = SUPER_FREEZE_FLAG_FLASH;
+        data[8..10].copy_from_slice(&384i16.to_le_bytes());
+        data[10] = 128;
+        let bytes = build_pack(&[(SECTION_STATE_SUPER_FREEZE, data)]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert_eq!(pack.state_super_freezes().map(|v| v.len()), Some(2));
+        assert_eq!(pack.super_freeze(0), None);
+        assert_eq!(
+            pack.super_freeze(1),
+            Some(SuperFreezeData {
+                frames: 30,
+                zoom: Q8_8::from_raw(384),
+                darken: 128,
+                flash: true,
+            })
+        );
+        assert_eq!(pack.super_freeze(2), None);
+    }
+
+    /// StrRef record: offset(4) + len(2) + pad(2).
+    fn strref(off: u32, len: u16) -> Vec<u8> {
+        let mut out = off.to_le_bytes().to_vec();
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&[0, 0]);
+        out
+    }
+
+    #[test]
+    fn validate_strings_accepts_valid_references() {
+        let mut tags = strref(0, 4);
+        tags.extend(strref(4, 3));
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"meshbody".to_vec()),
+            (SECTION_MESH_KEYS, strref(0, 4)),
+            (SECTION_STATE_TAGS, tags),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.validate_strings(), Ok(()));
+    }
+
+    #[test]
+    fn state_tags_distinguish_untagged_states_from_untagged_packs() {
+        use crate::view::SECTION_STATE_TAG_RANGES;
+
+        // State 0 has tags "air" and "low"; state 1 has none.
+        let mut tags = strref(0, 3);
+        tags.extend(strref(3, 3));
+        let mut ranges = 0u32.to_le_bytes().to_vec();
+        ranges.extend_from_slice(&2u16.to_le_bytes());
+        ranges.extend_from_slice(&[0, 0]);
+        ranges.extend_from_slice(&16u32.to_le_bytes());
+        ranges.extend_from_slice(&[0; 4]);
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"airlow".to_vec()),
+            (SECTION_STATES, std::vec![0u8; 2 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_TAG_RANGES, ranges),
+            (SECTION_STATE_TAGS, tags),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let tags: Vec<&str> = pack.state_tags(0).unwrap().collect();
+        assert_eq!(tags, ["air", "low"]);
+        assert_eq!(pack.state_tags(1).unwrap().count(), 0);
+        assert_eq!(pack.state_tags(2).unwrap().count(), 0);
+
+        let bytes = build_pack(&[(SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE])]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert!(pack.state_tags(0).is_none());
+    }
+
+    #[test]
+    fn validate_strings_flags_invalid_utf8() {
+        // "ok" then a lone continuation byte and an invalid lead byte.
+        let mut tags = strref(0, 2);
+        tags.extend(strref(2, 2));
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"ok\x80\xFF".to_vec()),
+            (SECTION_STATE_TAGS, tags),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert_eq!(pack.string(2, 2), None);
+        assert_eq!(
+            pack.validate_strings(),
+            Err(Error::InvalidString {
+                section: SECTION_STATE_TAGS,
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn validate_strings_flags_out_of_bounds_references() {
+        let mut resource = strref(1, 8);
+        resource.extend_from_slice(&[0; 4]); // start, max
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"meter".to_vec()),
+            (SECTION_RESOURCE_DEFS, resource),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert_eq!(
+            pack.validate_strings(),
+            Err(Error::InvalidString {
+                section: SECTION_RESOURCE_DEFS,
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn state_summaries_resolve_inputs_and_frame_data() {
+        let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        for (rec, (startup, active, recovery, damage, guard)) in states
+            .chunks_exact_mut(STATE_RECORD_SIZE)
+            .zip([(5u8, 2u8, 8u8, 300u16, 1u8), (13, 4, 26, 1200, 0)])
+        {
+            rec[8] = guard;
+            rec[10] = startup;
+            rec[11] = active;
+            rec[12] = recovery;
+            let total = u16::from(startup) + u16::from(active) + u16::from(recovery);
+            rec[14..16].copy_from_slice(&total.to_le_bytes());
+            rec[16..18].copy_from_slice(&damage.to_le_bytes());
+        }
+        // Inputs "5L" @0 and "236P" @2
+        let mut extras = std::vec![0u8; 2 * STATE_EXTRAS_SIZE];
+        extras[60..62].copy_from_slice(&2u16.to_le_bytes());
+        extras[STATE_EXTRAS_SIZE + 56..STATE_EXTRAS_SIZE + 60].copy_from_slice(&2u32.to_le_bytes());
+        extras[STATE_EXTRAS_SIZE + 60..STATE_EXTRAS_SIZE + 62].copy_from_slice(&4u16.to_le_bytes());
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"5L236P".to_vec()),
+            (SECTION_STATES, states),
+            (SECTION_STATE_EXTRAS, extras),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let summaries: Vec<StateSummary> = pack.state_summaries().collect();
+        assert_eq!(
+            summaries,
+            std::vec![
+                StateSummary {
+                    index: 0,
+                    input: Some("5L"),
+                    startup: 5,
+                    active: 2,
+                    recovery: 8,
+                    total: 15,
+                    damage: 300,
+                    guard: 1,
+                },
+                StateSummary {
+                    index: 1,
+                    input: Some("236P"),
+                    startup: 13,
+                    active: 4,
+                    recovery: 26,
+                    total: 43,
+                    damage: 1200,
+                    guard: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn state_summaries_without_extras_or_states() {
+        let bytes = build_pack(&[(SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE])]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let summaries: Vec<StateSummary> = pack.state_summaries().collect();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].input, None);
+
+        let empty = build_pack(&[]);
+        let pack = PackView::parse(&empty).unwrap();
+        assert_eq!(pack.state_summaries().count(), 0);
+    }
+
+    #[test]
+    fn hit_window_throw_flag() {
+        let mut hit = windows(HIT_WINDOW_SIZE, &[(0, 1), (2, 3)]);
+        hit[HIT_WINDOW_SIZE + 3] = HIT_FLAG_THROW;
+        let bytes = build_pack(&[(SECTION_HIT_WINDOWS, hit)]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let hit_windows = pack.hit_windows().unwrap();
+        assert!(!hit_windows.get(0).unwrap().is_throw());
+        assert!(hit_windows.get(1).unwrap().is_throw());
+    }
+
+    #[test]
+    fn notifies_for_state_resolves_events_and_args() {
+        // "vfx.swing_trail" @0, "bone" @15, "hand_r" @19
+        let strings = b"vfx.swing_trailbonehand_r".to_vec();
+
+        // State 0 has no notifies; state 1 has one at offset 0.
+        let mut extras = std::vec![0u8; 2 * STATE_EXTRAS_SIZE];
+        extras[STATE_EXTRAS_SIZE + 28..STATE_EXTRAS_SIZE + 30].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut notify = std::vec![0u8; MOVE_NOTIFY_SIZE];
+        notify[0..2].copy_from_slice(&7u16.to_le_bytes()); // frame
+        notify[8..10].copy_from_slice(&1u16.to_le_bytes()); // one emit at offset 0
+
+        let mut emit = std::vec![0u8; EVENT_EMIT_SIZE];
+        emit[4..6].copy_from_slice(&15u16.to_le_bytes()); // id "vfx.swing_trail"
+        emit[12..14].copy_from_slice(&1u16.to_le_bytes()); // one arg at offset 0
+
+        let mut arg = std::vec![0u8; EVENT_ARG_SIZE];
+        arg[0..4].copy_from_slice(&15u32.to_le_bytes()); // key "bone"
+        arg[4..6].copy_from_slice(&4u16.to_le_bytes());
+        arg[8] = EVENT_ARG_TAG_STRING;
+        arg[12..16].copy_from_slice(&19u32.to_le_bytes()); // value "hand_r"
+        arg[16..18].copy_from_slice(&6u16.to_le_bytes());
+
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, strings),
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_MOVE_NOTIFIES, notify),
+            (SECTION_EVENT_EMITS, emit),
+            (SECTION_EVENT_ARGS, arg),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert_eq!(pack.notifies_for_state(0).count(), 0);
+        assert_eq!(pack.notifies_for_state(9).count(), 0);
+
+        let notifies: Vec<_> = pack.notifies_for_state(1).collect();
+        assert_eq!(notifies.len(), 1);
+        assert_eq!(notifies[0].frame(), 7);
+
+        let events: Vec<_> = notifies[0].events().collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id(), "vfx.swing_trail");
+        assert_eq!(
+            events[0].args().collect::<Vec<_>>(),
+            std::vec![ResolvedEventArg {
+                key: "bone",
+                value: EventArgValue::Str("hand_r"),
+            }]
+        );
+        assert_eq!(events[0].arg("bone"), Some(EventArgValue::Str("hand_r")));
+        assert_eq!(events[0].arg("scale"), None);
+    }
+
+    #[test]
+    fn cancels_for_state_resolves_target_ids() {
+        // State 0 has no routes; state 1 routes to states 4 and 9 at byte offset 2.
+        let mut extras = std::vec![0u8; 2 * STATE_EXTRAS_SIZE];
+        let rec = &mut extras[STATE_EXTRAS_SIZE..];
+        rec[64..68].copy_from_slice(&2u32.to_le_bytes());
+        rec[68..70].copy_from_slice(&2u16.to_le_bytes());
+
+        let mut cancels = Vec::new();
+        for target in [1u16, 4, 9] {
+            cancels.extend_from_slice(&target.to_le_bytes());
+        }
+
+        let bytes = build_pack(&[
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_CANCELS_U16, cancels),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert_eq!(pack.cancels_for_state(0).count(), 0);
+        assert_eq!(pack.cancels_for_state(5).count(), 0);
+        assert_eq!(
+            pack.cancels_for_state(1).collect::<Vec<_>>(),
+            std::vec![4, 9]
+        );
+    }
+
+    #[test]
+    fn cancels_get_range_yields_targets_in_bulk() {
+        let mut cancels = Vec::new();
+        for target in [3u16, 1, 4, 1, 5] {
+            cancels.extend_from_slice(&target.to_le_bytes());
+        }
+        let bytes = build_pack(&[(SECTION_CANCELS_U16, cancels)]);
+        let view = PackView::parse(&bytes).unwrap().cancels().unwrap();
+
+        assert_eq!(
+            view.get_range(0, 5).collect::<Vec<_>>(),
+            std::vec![3, 1, 4, 1, 5]
+        );
+        assert_eq!(view.get_range(4, 2).collect::<Vec<_>>(), std::vec![4, 1]);
+        // Ranges past the end of the section are cut short.
+        assert_eq!(view.get_range(6, 4).collect::<Vec<_>>(), std::vec![1, 5]);
+        assert_eq!(view.get_range(u32::MAX, u16::MAX).count(), 0);
+    }
+
+    /// Append a 12-byte character property record.
+    fn char_prop(out: &mut Vec<u8>, name: (u32, u16), value_type: u8, value: [u8; 4]) {
+        out.extend_from_slice(&name.0.to_le_bytes());
+        out.extend_from_slice(&name.1.to_le_bytes());
+        out.extend_from_slice(&[value_type, 0]);
+        out.extend_from_slice(&value);
+    }
+
+    #[test]
+    fn character_props_entries_decode_typed_values() {
+        // "walk_speed" @0, "can_air_dash" @10, "archetype" @22, "rushdown" @31
+        let strings = b"walk_speedcan_air_dasharchetyperushdown".to_vec();
+        let mut props = Vec::new();
+        char_prop(
+            &mut props,
+            (0, 10),
+            PROP_TYPE_Q24_8,
+            (4 * 256 + 128i32).to_le_bytes(),
+        );
+        char_prop(&mut props, (10, 12), PROP_TYPE_BOOL, 1u32.to_le_bytes());
+        char_prop(&mut props, (22, 9), PROP_TYPE_STR, [31, 0, 8, 0]);
+        char_prop(&mut props, (0, 10), 9, [0; 4]); // unknown type is skipped
+
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, strings),
+            (SECTION_CHARACTER_PROPS, props),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let entries: Vec<_> = pack
+            .character_props()
+            .unwrap()
+            .entries(pack.string_pool())
+            .collect();
+
+        assert_eq!(
+            entries,
+            std::vec![
+                (
+                    "walk_speed",
+                    PropValue::Number(Q24_8::from_raw(4 * 256 + 128))
+                ),
+                ("can_air_dash", PropValue::Bool(true)),
+                ("archetype", PropValue::Str("rushdown")),
+            ]
+        );
+    }
+
+    #[test]
+    fn schema_character_props_entries_use_schema_names() {
+        // "health" @0, "walk_speed" @6
+        let strings = b"healthwalk_speed".to_vec();
+        let mut schema = std::vec![0u8; SCHEMA_HEADER_SIZE];
+        schema[0..2].copy_from_slice(&2u16.to_le_bytes());
+        for (off, len) in [(0u32, 6u16), (6, 10)] {
+            schema.extend_from_slice(&off.to_le_bytes());
+            schema.extend_from_slice(&len.to_le_bytes());
+            schema.extend_from_slice(&[0, 0]);
+        }
+
+        let mut props = Vec::new();
+        for (id, value_type, value) in [
+            (1u16, PROP_TYPE_Q24_8, (4i32 * 256).to_le_bytes()),
+            (0, PROP_TYPE_Q24_8, (10000i32 * 256).to_le_bytes()),
+            (5, PROP_TYPE_BOOL, 1i32.to_le_bytes()), // unknown schema ID is skipped
+        ] {
+            props.extend_from_slice(&id.to_le_bytes());
+            props.extend_from_slice(&[value_type, 0]);
+            props.extend_from_slice(&value);
+        }
+
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, strings),
+            (SECTION_SCHEMA, schema),
+            (SECTION_CHARACTER_PROPS, props),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let entries: Vec<_> = pack
+            .schema_character_props()
+            .unwrap()
+            .entries(pack.schema().unwrap(), pack.string_pool())
+            .collect();
+
+        assert_eq!(
+            entries,
+            std::vec![
+                ("walk_speed", PropValue::Number(Q24_8::from_int(4))),
+                ("health", PropValue::Number(Q24_8::from_int(10000))),
+            ]
+        );
+    }
+
+    #[test]
+    fn state_hit_cancels_reads_per_hit_ranges() {
+        // State 0: none. State 1: hit on frames 5-7 -> 2 targets, hit on 15-17 -> 1 target.
+        let index_size = 2 * STATE_HIT_CANCELS_INDEX_ENTRY_SIZE;
+        let mut section = std::vec![0u8; index_size];
+        section[8..12].copy_from_slice(&(index_size as u32).to_le_bytes());
+        section[12..14].copy_from_slice(&2u16.to_le_bytes());
+        for (start, end, len, off) in [(5u8, 7u8, 2u16, 0u32), (15, 17, 1, 4)] {
+            section.extend_from_slice(&[start, end]);
+            section.extend_from_slice(&len.to_le_bytes());
+            section.extend_from_slice(&off.to_le_bytes());
+        }
+        let bytes = build_pack(&[(SECTION_STATE_HIT_CANCELS, section)]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert!(pack.state_hit_cancels(0).is_none());
+        assert!(pack.state_hit_cancels(2).is_none());
+
+        let hits = pack.state_hit_cancels(1).unwrap();
+        assert_eq!(hits.len(), 2);
+        let second = hits.get(1).unwrap();
+        assert_eq!((second.start_frame(), second.end_frame()), (15, 17));
+        assert_eq!(second.cancels(), (4, 1));
+        assert_eq!(hits.iter().map(|h| h.cancels().1).sum::<u16>(), 3);
+    }
+
+    /// One state (id 7, 3/2/5 frames) with one 50-damage hit window on
+    /// frames 3-4 holding a 30x40 AABB at (10, -20).
+    #[cfg(feature = "alloc")]
+    fn one_state_pack() -> std::vec::Vec<u8> {
+        let mut states = std::vec![0u8; STATE_RECORD_SIZE];
+        states[0..2].copy_from_slice(&7u16.to_le_bytes()); // state_id
+        states[10] = 3; // startup
+        states[11] = 2; // active
+        states[12] = 5; // recovery
+        states[14..16].copy_from_slice(&10u16.to_le_bytes()); // total
+        states[26..28].copy_from_slice(&1u16.to_le_bytes()); // one hit window
+
+        let mut hit = windows(HIT_WINDOW_SIZE, &[(3, 4)]);
+        hit[4..6].copy_from_slice(&50u16.to_le_bytes()); // damage
+        hit[16..18].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+        // AABB at (10, -20), 30x40 px (Q12.4)
+        let mut shape = std::vec![0u8; SHAPE_SIZE];
+        shape[2..4].copy_from_slice(&(10i16 << 4).to_le_bytes());
+        shape[4..6].copy_from_slice(&(-20i16 << 4).to_le_bytes());
+        shape[6..8].copy_from_slice(&(30u16 << 4).to_le_bytes());
+        shape[8..10].copy_from_slice(&(40u16 << 4).to_le_bytes());
+
+        build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_SHAPES, shape),
+        ])
+    }
+
+    #[test]
+    fn pack_builder_states_roundtrip() {
+        let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        states[0..2].copy_from_slice(&7u16.to_le_bytes()); // state_id
+        states[10] = 3; // startup
+        states[STATE_RECORD_SIZE..STATE_RECORD_SIZE + 2].copy_from_slice(&9u16.to_le_bytes());
+
+        let mut builder = PackBuilder::new();
+        builder
+            .section(SECTION_STRING_TABLE, b"odd")
+            .section(SECTION_STATES, &states);
+        assert_eq!(builder.section_count(), 2);
+        let bytes = builder.finish();
+
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.total_len(), bytes.len());
+        assert_eq!(pack.string(0, 3), Some("odd"));
+        // Sections start on 4-byte boundaries, past the 3-byte string table.
+        assert_eq!(pack.get_section(SECTION_STATES), Some(&states[..]));
+        let offset =
+            pack.get_section(SECTION_STATES).unwrap().as_ptr() as usize - bytes.as_ptr() as usize;
+        assert_eq!(offset % 4, 0);
+
+        let view = pack.states().unwrap();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.get(0).unwrap().state_id(), 7);
+        assert_eq!(view.get(0).unwrap().startup(), 3);
+        assert_eq!(view.get(1).unwrap().state_id(), 9);
+    }
+
+    #[test]
+    fn pack_builder_without_sections_is_a_valid_pack() {
+        let bytes = PackBuilder::new().finish();
+        assert_eq!(bytes.len(), HEADER_SIZE);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.section_count(), 0);
+        assert!(pack.states().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn debug_dump_lists_state_frames_and_shapes() {
+        let bytes = one_state_pack();
+        let dump = PackView::parse(&bytes).unwrap().debug_dump();
+
+        assert!(dump.contains("state[0]: id=7"), "{dump}");
+        assert!(
+            dump.contains("startup=3 active=2 recovery=5 total=10"),
+            "{dump}"
+        );
+        assert!(dump.contains("hit[0]: frames=3-4 damage=50"), "{dump}");
+        assert!(
+            dump.contains("shape[0]: aabb x=10 y=-20 w=30 h=40"),
+            "{dump}"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn decode_resolves_state_windows_and_shapes() {
+        let bytes = one_state_pack();
+        let decoded = PackView::parse(&bytes).unwrap().decode();
+
+        assert_eq!(decoded.states.len(), 1);
+        let state = &decoded.states[0];
+        assert_eq!(state.input, None);
+        assert_eq!(state.state_id, 7);
+        assert_eq!((state.startup, state.active, state.recovery), (3, 2, 5));
+        assert_eq!(state.total, 10);
+        assert!(state.hurt_windows.is_empty());
+
+        assert_eq!(state.hit_windows.len(), 1);
+        let hit = &state.hit_windows[0];
+        assert_eq!((hit.start_frame, hit.end_frame), (3, 4));
+        assert_eq!(hit.damage, 50);
+        assert_eq!(
+            hit.shapes,
+            std::vec![DecodedShape::Aabb {
+                x: 10,
+                y: -20,
+                w: 30,
+                h: 40
+            }]
+        );
+
+        let json = serde_json::to_value(&decoded).unwrap();
+        assert_eq!(
+            json["states"][0]["hit_windows"][0]["shapes"][0]["kind"],
+            "aabb"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pack_buf_views_share_validated_sections() {
+        let bytes = multi_window_pack();
+        let buf = PackBuf::parse_owned(bytes.clone()).unwrap();
+
+        let parsed = PackView::parse(&bytes).unwrap();
+        for _ in 0..3 {
+            let view = buf.view();
+            assert_eq!(view.section_count(), parsed.section_count());
+            assert_eq!(view.states().unwrap().len(), 2);
+            assert_eq!(
+                view.state_windows(1).unwrap().hurt_windows().count(),
+                parsed.state_windows(1).unwrap().hurt_windows().count()
+            );
+            // Views borrow the owned bytes rather than a copy.
+            assert_eq!(
+                view.get_section(SECTION_STATES).unwrap().as_ptr(),
+                buf.as_bytes()[HEADER_SIZE + 4 * SECTION_HEADER_SIZE..].as_ptr()
+            );
+        }
+        assert_eq!(buf.into_bytes(), bytes);
+    }
+
+    #[test]
+    fn flags_expose_header_bits() {
+        let mut bytes = build_pack(&[(SECTION_STRING_TABLE, b"abc".to_vec())]);
+        assert_eq!(
+            PackView::parse(&bytes).unwrap().flags(),
+            PackFlags::default()
+        );
+
+        let bits = PACK_FLAG_SORTED_INPUTS | 0x8000_0000;
+        bytes[4..8].copy_from_slice(&bits.to_le_bytes());
+        let flags = PackView::parse(&bytes).unwrap().flags();
+
+        assert!(flags.sorted_inputs());
+        assert_eq!(flags.bits(), bits);
+        assert!(!PackFlags::from_bits(0x8000_0000).sorted_inputs());
+    }
+
+    fn metadata_entry(key: &str, value: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&(key.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        out.extend_from_slice(key.as_bytes());
+        out.extend_from_slice(value);
+        out
+    }
+
+    #[test]
+    fn metadata_reads_known_keys_and_skips_unknown_ones() {
+        let mut section = metadata_entry("future_key", b"ignored");
+        section.extend(metadata_entry(METADATA_KEY_EXPORTER_VERSION, b"0.4.1"));
+        section.extend(metadata_entry("bad_utf8", &[0xFF]));
+        section.extend(metadata_entry(METADATA_KEY_CHARACTER_ID, b"glitch"));
+        let bytes = build_pack(&[(SECTION_METADATA, section.clone())]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let meta = pack.metadata().unwrap();
+        assert_eq!(meta.character_id(), Some("glitch"));
+        assert_eq!(meta.exporter_version(), Some("0.4.1"));
+        assert_eq!(meta.build_timestamp(), None);
+        assert_eq!(meta.entries().count(), 3);
+
+        // A truncated trailing entry ends iteration without losing earlier ones.
+        section.extend_from_slice(&[4, 0, 9, 0, b'k']);
+        let meta = MetadataView::new(&section);
+        assert_eq!(meta.entries().count(), 3);
+        assert_eq!(meta.character_id(), Some("glitch"));
+
+        let bytes = build_pack(&[(SECTION_STRING_TABLE, b"abc".to_vec())]);
+        assert!(PackView::parse(&bytes).unwrap().metadata().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn metadata_section_can_be_stripped() {
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+            (
+                SECTION_METADATA,
+                metadata_entry(METADATA_KEY_CHARACTER_ID, b"glitch"),
+            ),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let mut builder = PackBuilder::new();
+        for (kind, data) in (0..pack.section_count()).filter_map(|i| pack.get_section_at(i)) {
+            if kind != SECTION_METADATA {
+                builder.section(kind, data);
+            }
+        }
+        let stripped = builder.finish();
+        let stripped = PackView::parse(&stripped).unwrap();
+
+        assert!(stripped.metadata().is_none());
+        assert_eq!(stripped.states().unwrap().len(), 1);
+    }
+
+    /// Deterministic xorshift64 so fuzz failures reproduce.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn bytes(&mut self, len: usize) -> Vec<u8> {
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+    }
+
+    /// Up to seven sections of random kind and contents.
+    ///
+    /// Kinds cover every `SECTION_*` constant, 1..=[`LAST_SECTION_KIND`].
+    fn random_sections(rng: &mut Rng) -> Vec<(u32, Vec<u8>)> {
+        (0..rng.next() % 8)
+            .map(|_| {
+                let kind = (rng.next() % u64::from(LAST_SECTION_KIND) + 1) as u32;
+                let len = (rng.next() % 160) as usize;
+                (kind, rng.bytes(len))
+            })
+            .collect()
+    }
+
+    /// Call every accessor on `pack`, walking each record it yields.
+    fn exercise<'a>(pack: &'a PackView<'a>) {
+        let strings = pack.string_pool();
+        let _ = (
+            pack.total_len(),
+            pack.body(),
+            pack.string(u32::MAX, u16::MAX),
+            pack.flags(),
+        );
+        let _ = pack.content_hash();
+        let _ = (
+            pack.contains_section(SECTION_STATES),
+            pack.section_len(SECTION_SHAPES),
+        );
+        let _ = pack.stats();
+        let _ = pack.validate_strings();
+        let _ = pack.state_summaries().count();
+        let _ = pack
+            .metadata()
+            .map(|m| (m.entries().count(), m.character_id()));
+        for i in 0..=pack.section_count() {
+            let _ = pack.get_section_at(i);
+        }
+        if let Some(v) = pack.mesh_keys() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.keyframes_keys() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.resource_defs() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.state_movements() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|m| m.is_moving());
+            }
+        }
+        if let Some(v) = pack.state_on_uses() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|u| (u.enters_state(), u.gain_meter()));
+            }
+        }
+        if let Some(v) = pack.state_super_freezes() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.state_meter_gains() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|g| (g.meter_gain_hit(), g.meter_gain_whiff()));
+            }
+        }
+        if let Some(v) = pack.state_knockbacks() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|k| (k.x(), k.y()));
+            }
+        }
+        if let Some(v) = pack.state_pushbacks() {
+            for i in 0..=v.len() {
+                let _ = v
+                    .get(i)
+                    .map(|p| (p.hit_pushback_raw(), p.block_pushback_raw()));
+            }
+        }
+        if let Some(v) = pack.event_emits() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.event_args() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|a| (a.value_i64(), a.value_f32()));
+            }
+        }
+        if let Some(v) = pack.move_resource_costs() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.move_resource_preconditions() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|p| (p.min(), p.max()));
+            }
+        }
+        if let Some(v) = pack.move_resource_deltas() {
+            for i in 0..=v.len() {
+                let _ = v.get(i).map(|d| (d.delta(), d.trigger_enum()));
+            }
+        }
+        if let Some(v) = pack.cancels() {
+            v.iter().for_each(drop);
+            v.get_range(u32::MAX, u16::MAX).for_each(drop);
+            v.get_range(1, u16::MAX).for_each(drop);
+        }
+        if let Some(v) = pack.shapes() {
+            for shape in (0..=v.len()).filter_map(|i| v.get(i)) {
+                let _ = (shape.as_aabb(), shape.as_circle(), shape.as_capsule());
+            }
+        }
+        if let Some(v) = pack.state_tag_ranges() {
+            for i in 0..=v.len() {
+                let _ = v.get(i);
+            }
+        }
+        if let Some(v) = pack.cancel_tag_rules() {
+            v.resolved().for_each(drop);
+        }
+        let _ = pack.has_cancel_deny(0, 0);
+        if let Some(v) = pack.character_props() {
+            v.entries(strings).for_each(drop);
+        }
+        if let (Some(schema), Some(v)) = (pack.schema(), pack.schema_character_props()) {
+            v.entries(schema, strings).for_each(drop);
+            for id in 0..=schema.tag_count() as u16 {
+                let _ = schema.char_prop_name(id);
+                let _ = (schema.state_prop_name(id), schema.tag_name(id));
+            }
+        }
+        let _ = pack.find_state_by_input("5L");
+        let state_count = pack.states().map_or(0, |s| s.len());
+        for idx in 0..=state_count {
+            if let Some(windows) = pack.state_windows(idx) {
+                let _ = (windows.state().total(), windows.state().kara_window());
+                windows
+                    .hit_windows()
+                    .for_each(|w| w.shapes(pack).for_each(drop));
+                windows
+                    .hurt_windows()
+                    .for_each(|w| w.shapes(pack).for_each(drop));
+                windows.push_windows().for_each(drop);
+            }
+            pack.notifies_for_state(idx)
+                .for_each(|n| n.events().for_each(|e| e.args().for_each(drop)));
+            pack.cancels_for_state(idx).for_each(drop);
+            if let Some(tags) = pack.state_tags(idx) {
+                tags.for_each(drop);
+            }
+            let _ = (pack.state_props_raw(idx), pack.has_state_props(idx));
+            if let Some(v) = pack.state_conditions(idx) {
+                for i in 0..=v.len() {
+                    let _ = v.get(i);
+                }
+            }
+            if let Some(v) = pack.state_hit_cancels(idx) {
+                v.iter().for_each(drop);
+            }
+        }
+    }
+
+    #[test]
+    fn accessors_never_panic_on_random_bytes() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..2000 {
+            let len = (rng.next() % 256) as usize;
+            let mut bytes = rng.bytes(len);
+            if bytes.len() >= HEADER_SIZE && rng.next() & 1 == 0 {
+                bytes[..4].copy_from_slice(&MAGIC);
+            }
+            if let Ok(pack) = PackView::parse(&bytes) {
+                exercise(&pack);
+            }
+        }
+    }
+
+    #[test]
+    fn accessors_never_panic_on_random_sections() {
+        let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+        for _ in 0..2000 {
+            let sections = random_sections(&mut rng);
+            let bytes = build_pack(&sections);
+            let pack = PackView::parse(&bytes).expect("build_pack output parses");
+            exercise(&pack);
+        }
+    }
+
+    #[test]
+    fn out_of_range_lookups_return_none() {
+        let bytes = build_pack(&[(SECTION_STRING_TABLE, b"hello".to_vec())]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(pack.string(0, 5), Some("hello"));
+        assert_eq!(pack.string(u32::MAX, u16::MAX), None);
+        assert_eq!(pack.string(1, 5), None);
+        assert!(pack.get_section_at(1).is_none());
+        assert!(pack.get_section_at(usize::MAX).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pack_buf_rejects_invalid_bytes() {
+        assert!(matches!(
+            PackBuf::parse_owned(std::vec![0u8; 4]),
+            Err(Error::TooShort)
+        ));
+        assert!(matches!(
+            PackBuf::parse_owned(std::vec![0u8; HEADER_SIZE]),
+            Err(Error::InvalidMagic)
+        ));
+    }
+
+    #[cfg(all(feature = "big-endian", feature = "alloc"))]
+    #[test]
+    fn big_endian_packs_convert_back_to_identical_bytes() {
+        let original = one_state_pack();
+        let mut bytes = original.clone();
+        to_big_endian(&mut bytes).unwrap();
+
+        let pack = PackView::parse(&bytes).unwrap();
+        assert!(pack.flags().big_endian());
+        assert_eq!(pack.endian(), Endian::Big);
+        let states = pack.get_section(SECTION_STATES).unwrap();
+        assert_eq!(states[0..2], 7u16.to_be_bytes()); // state_id
+        assert_eq!(states[14..16], 10u16.to_be_bytes()); // total
+        let state = pack.states().unwrap().get(0).unwrap();
+        assert_eq!((state.state_id(), state.total()), (7, 10));
+
+        // Converting to the order a pack is already in changes nothing.
+        let snapshot = bytes.clone();
+        to_big_endian(&mut bytes).unwrap();
+        assert_eq!(bytes, snapshot);
+
+        to_little_endian(&mut bytes).unwrap();
+        assert_eq!(bytes, original);
+    }
+
+    #[cfg(all(feature = "big-endian", feature = "alloc"))]
+    #[test]
+    fn big_endian_pack_buf_reads_the_same_values() {
+        let mut bytes = one_state_pack();
+        let expected = PackView::parse(&bytes).unwrap().debug_dump();
+
+        to_big_endian(&mut bytes).unwrap();
+        let buf = PackBuf::parse_owned(bytes).unwrap();
+        assert!(!buf.view().flags().big_endian());
+        assert_eq!(buf.view().debug_dump(), expected);
+    }
+
+    #[cfg(feature = "big-endian")]
+    #[test]
+    fn byte_order_roundtrips_random_sections() {
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+        for _ in 0..2000 {
+            let sections = random_sections(&mut rng);
+            let original = build_pack(&sections);
+            let mut bytes = original.clone();
+            to_big_endian(&mut bytes).unwrap();
+            to_little_endian(&mut bytes).unwrap();
+            assert_eq!(bytes, original);
+        }
+    }
+
+    #[cfg(feature = "big-endian")]
+    #[test]
+    fn big_endian_views_read_random_sections_like_little_endian() {
+        let mut rng = Rng(0x6A09_E667_F3BC_C908);
+        for _ in 0..2000 {
+            let sections = random_sections(&mut rng);
+            let little = build_pack(&sections);
+            let mut big = little.clone();
+            to_big_endian(&mut big).unwrap();
+
+            let little = PackView::parse(&little).unwrap();
+            let big = PackView::parse(&big).unwrap();
+            assert_eq!(big.endian(), Endian::Big);
+            assert_eq!(field_values(&big), field_values(&little));
+        }
+    }
+
+    /// Every value the views decode from `pack`, rendered for comparison.
+    #[cfg(feature = "big-endian")]
+    fn field_values(pack: &PackView<'_>) -> Vec<std::string::String> {
+        use std::format;
+
+        let strings = pack.string_pool();
+        let mut out = Vec::new();
+        out.push(format!("{:?}", pack.validate_strings()));
+        if let Some(m) = pack.metadata() {
+            out.extend(m.entries().map(|e| format!("{e:?}")));
+        }
+        if let Some(v) = pack.mesh_keys() {
+            out.extend((0..v.len()).map(|i| format!("{:?}", v.get(i))));
+        }
+        if let Some(v) = pack.states() {
+            for s in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (
+                        s.state_id(),
+                        s.mesh_key(),
+                        s.keyframes_key(),
+                        s.flags(),
+                        s.total()
+                    )
+                ));
+                out.push(format!(
+                    "{:?}",
+                    (
+                        s.damage(),
+                        s.hit_windows_off(),
+                        s.hurt_windows_off(),
+                        s.push_windows_off()
+                    )
+                ));
+            }
+        }
+        if let Some(v) = pack.hit_windows() {
+            for w in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (
+                        w.damage(),
+                        w.chip_damage(),
+                        w.shapes_off(),
+                        w.shapes_len(),
+                        w.cancels_off()
+                    )
+                ));
+            }
+        }
+        if let Some(v) = pack.hurt_windows() {
+            for w in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!("{:?}", (w.hurt_flags(), w.flags(), w.shapes_off())));
+            }
+        }
+        if let Some(v) = pack.shapes() {
+            for s in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (s.a_raw(), s.b_raw(), s.c_raw(), s.d_raw(), s.e_raw())
+                ));
+            }
+        }
+        if let Some(v) = pack.cancels() {
+            out.extend(v.iter().map(|c| format!("{c:?}")));
+        }
+        if let Some(v) = pack.resource_defs() {
+            for d in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (d.name_off(), d.regen_per_frame(), d.start(), d.max())
+                ));
+            }
+        }
+        if let Some(v) = pack.state_extras() {
+            for x in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (x.on_use_emits(), x.notifies(), x.input(), x.cancels())
+                ));
+            }
+        }
+        if let Some(v) = pack.event_args() {
+            for a in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (a.key_off(), a.value_i64(), a.value_string())
+                ));
+                out.push(format!("{:?}", a.value_f32().map(f32::to_bits)));
+            }
+        }
+        if let Some(v) = pack.move_resource_preconditions() {
+            for p in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!("{:?}", (p.name_off(), p.min(), p.max())));
+            }
+        }
+        if let Some(v) = pack.move_resource_deltas() {
+            out.extend(
+                (0..v.len())
+                    .filter_map(|i| v.get(i))
+                    .map(|d| format!("{}", d.delta())),
+            );
+        }
+        if let Some(v) = pack.state_movements() {
+            for m in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (m.velocity_x(), m.velocity_y(), m.acceleration_x())
+                ));
+            }
+        }
+        if let Some(v) = pack.state_on_uses() {
+            for u in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!("{:?}", (u.enters_state(), u.gain_meter())));
+            }
+        }
+        if let Some(v) = pack.state_super_freezes() {
+            out.extend((0..v.len()).map(|i| format!("{:?}", v.get(i))));
+        }
+        if let Some(v) = pack.state_meter_gains() {
+            for g in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!("{:?}", (g.meter_gain_hit(), g.meter_gain_whiff())));
+            }
+        }
+        if let Some(v) = pack.state_knockbacks() {
+            for k in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!("{:?}", (k.x(), k.y())));
+            }
+        }
+        if let Some(v) = pack.state_pushbacks() {
+            for p in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!(
+                    "{:?}",
+                    (p.hit_pushback_raw(), p.block_pushback_raw())
+                ));
+            }
+        }
+        // With a schema, CHARACTER_PROPS holds 8-byte records instead.
+        if let (false, Some(v)) = (pack.has_schema(), pack.character_props()) {
+            for p in (0..v.len()).filter_map(|i| v.get(i)) {
+                out.push(format!("{:?}", (p.name(), p.value_raw(), p.value(strings))));
+            }
+        }
+        if let Some(schema) = pack.schema() {
+            out.push(format!(
+                "{:?}",
+                (
+                    schema.char_prop_count(),
+                    schema.state_prop_count(),
+                    schema.tag_count()
+                )
+            ));
+            if let Some(v) = pack.schema_character_props() {
+                for p in (0..v.len()).filter_map(|i| v.get(i)) {
+                    out.push(format!(
+                        "{:?}",
+                        (p.schema_id(), p.value_raw(), p.value(strings))
+                    ));
+                }
+            }
+        }
+        if let Some(v) = pack.cancel_tag_rules() {
+            out.extend(v.resolved().map(|r| format!("{r:?}")));
+        }
+        let state_count = pack.states().map_or(0, |s| s.len());
+        for idx in 0..state_count {
+            out.extend(pack.cancels_for_state(idx).map(|c| format!("{c:?}")));
+            out.push(format!(
+                "{:?}",
+                (
+                    pack.state_props_raw(idx),
+                    pack.has_cancel_deny(idx as u16, 1)
+                )
+            ));
+            if let Some(tags) = pack.state_tags(idx) {
+                out.extend(tags.map(|t| format!("{t:?}")));
+            }
+            if let Some(v) = pack.state_conditions(idx) {
+                for c in (0..v.len()).filter_map(|i| v.get(i)) {
+                    out.push(format!("{:?}", (c.kind(), c.arg(), c.min(), c.max())));
+                }
+            }
+            if let Some(v) = pack.state_hit_cancels(idx) {
+                for c in v.iter() {
+                    out.push(format!(
+                        "{:?}",
+                        (c.start_frame(), c.end_frame(), c.cancels())
+                    ));
+                }
+            }
+            pack.notifies_for_state(idx).for_each(|n| {
+                out.push(format!("{}", n.frame()));
+                n.events()
+                    .for_each(|e| out.extend(e.args().map(|a| format!("{a:?}"))));
+            });
+        }
+        out
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn raw_records_match_field_decoding() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        let bytes = build_pack(&[
+            (SECTION_STATES, rng.bytes(4 * STATE_RECORD_SIZE)),
+            (SECTION_SHAPES, rng.bytes(3 * SHAPE_SIZE)),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let states = pack.states().unwrap();
+        let raw = pack.states_raw().unwrap();
+        assert_eq!(raw.len(), 4);
+        for (i, r) in raw.iter().enumerate() {
+            let s = states.get(i).unwrap();
+            assert_eq!(
+                (r.state_id, r.mesh_key, r.keyframes_key),
+                (s.state_id(), s.mesh_key(), s.keyframes_key())
+            );
+            assert_eq!(
+                (r.state_type, r.trigger, r.guard, r.flags),
+                (s.state_type(), s.trigger(), s.guard(), s.flags())
+            );
+            assert_eq!(
+                (r.startup, r.active, r.recovery, r.total, r.damage),
+                (s.startup(), s.active(), s.recovery(), s.total(), s.damage())
+            );
+            assert_eq!(
+                (r.hitstun, r.blockstun, r.hitstop),
+                (s.hitstun(), s.blockstun(), s.hitstop())
+            );
+            let kara = (r.kara_frames > 0)
+                .then(|| (r.kara_start, r.kara_start.saturating_add(r.kara_frames - 1)));
+            assert_eq!(kara, s.kara_window());
+            assert_eq!(
+                (r.hit_windows_off, r.hit_windows_len),
+                (s.hit_windows_off(), s.hit_windows_len())
+            );
+            assert_eq!(
+                (
+                    r.hurt_windows_off,
+                    r.hurt_windows_len,
+                    r.push_windows_off,
+                    r.push_windows_len
+                ),
+                (
+                    s.hurt_windows_off(),
+                    s.hurt_windows_len(),
+                    s.push_windows_off(),
+                    s.push_windows_len()
+                )
+            );
+        }
+
+        let shapes = pack.shapes().unwrap();
+        let raw = pack.shapes_raw().unwrap();
+        assert_eq!(raw.len(), 3);
+        for (i, r) in raw.iter().enumerate() {
+            let s = shapes.get(i).unwrap();
+            assert_eq!(
+                (r.kind, r.flags, r.a, r.b, r.c, r.d, r.e),
+                (
+                    s.kind(),
+                    s.flags(),
+                    s.a_raw(),
+                    s.b_raw(),
+                    s.c_raw(),
+                    s.d_raw(),
+                    s.e_raw()
+                )
+            );
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn raw_records_reject_partial_sections() {
+        let bytes = build_pack(&[(SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE + 1])]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert!(pack.states_raw().is_none());
+        assert!(pack.shapes_raw().is_none());
+    }
 }
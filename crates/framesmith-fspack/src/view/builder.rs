@@ -0,0 +1,83 @@
+//! Minimal FSPK writer for tests and tools (requires the `alloc` feature
+//! outside this crate's own tests).
+
+use alloc::vec::Vec;
+
+use super::{HEADER_SIZE, MAGIC, SECTION_HEADER_SIZE};
+
+/// Alignment applied to every section's start offset.
+const SECTION_ALIGN: usize = 4;
+
+/// Assembles raw sections into a valid FSPK pack.
+///
+/// The builder only lays out the header and section table; section bytes are
+/// written as given, so callers are responsible for their record layouts.
+/// Sections appear in the order they were added, each starting on a 4-byte
+/// boundary.
+///
+/// ```
+/// use framesmith_fspack::{PackBuilder, PackView, SECTION_STATES, STATE_RECORD_SIZE};
+///
+/// let bytes = PackBuilder::new()
+///     .section(SECTION_STATES, &[0u8; STATE_RECORD_SIZE])
+///     .finish();
+/// let pack = PackView::parse(&bytes).unwrap();
+/// assert_eq!(pack.states().unwrap().len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PackBuilder {
+    sections: Vec<(u32, Vec<u8>)>,
+}
+
+impl PackBuilder {
+    /// Create a builder with no sections.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a section of the given `SECTION_*` kind.
+    ///
+    /// Kinds are not deduplicated; [`PackView::get_section`](super::PackView::get_section)
+    /// returns the first section of a kind.
+    pub fn section(&mut self, kind: u32, bytes: &[u8]) -> &mut Self {
+        self.sections.push((kind, bytes.to_vec()));
+        self
+    }
+
+    /// Number of sections added so far.
+    pub fn section_count(&self) -> usize {
+        self.sections.len()
+    }
+
+    /// Write the header, section table and section data.
+    ///
+    /// Packs with more than [`MAX_SECTIONS`](super::MAX_SECTIONS) sections or
+    /// over 4 GiB are written as-is but won't parse.
+    pub fn finish(&self) -> Vec<u8> {
+        let table_end = HEADER_SIZE + self.sections.len() * SECTION_HEADER_SIZE;
+        let mut offsets = Vec::with_capacity(self.sections.len());
+        let mut end = table_end;
+        for (_, bytes) in &self.sections {
+            let offset = end.next_multiple_of(SECTION_ALIGN);
+            offsets.push(offset);
+            end = offset + bytes.len();
+        }
+
+        let mut out = Vec::with_capacity(end);
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&0u32.to_le_bytes()); // flags
+        out.extend_from_slice(&(end as u32).to_le_bytes());
+        out.extend_from_slice(&(self.sections.len() as u32).to_le_bytes());
+        for ((kind, bytes), &offset) in self.sections.iter().zip(&offsets) {
+            out.extend_from_slice(&kind.to_le_bytes());
+            out.extend_from_slice(&(offset as u32).to_le_bytes());
+            out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            out.extend_from_slice(&(SECTION_ALIGN as u32).to_le_bytes());
+        }
+        for ((_, bytes), &offset) in self.sections.iter().zip(&offsets) {
+            out.resize(offset, 0);
+            out.extend_from_slice(bytes);
+        }
+        out
+    }
+}
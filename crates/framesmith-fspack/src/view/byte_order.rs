@@ -0,0 +1,243 @@
+//! Big-endian pack conversion (requires the `big-endian` feature).
+//!
+//! A big-endian pack keeps the header and section table little-endian, sets
+//! [`PACK_FLAG_BIG_ENDIAN`], and stores every multi-byte field inside the known
+//! sections big-endian. Views read either order in place; converting swaps
+//! those fields in place using each section's record layout; string bytes,
+//! single-byte fields and unknown sections are left as they are.
+
+use crate::bytes::read_u16_le;
+use crate::error::Error;
+
+use super::{
+    PackView, EVENT_ARG_TAG_F32, EVENT_ARG_TAG_I64, EVENT_ARG_TAG_STRING, HEADER_FLAGS_OFF,
+    PACK_FLAG_BIG_ENDIAN, PROP_TYPE_Q24_8, PROP_TYPE_STR, SCHEMA_HEADER_SIZE, SECTION_CANCELS_U16,
+    SECTION_CANCEL_DENIES, SECTION_CANCEL_TAG_RULES, SECTION_CHARACTER_PROPS, SECTION_EVENT_ARGS,
+    SECTION_EVENT_EMITS, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS, SECTION_KEYFRAMES_KEYS,
+    SECTION_MESH_KEYS, SECTION_METADATA, SECTION_MOVE_NOTIFIES, SECTION_MOVE_RESOURCE_COSTS,
+    SECTION_MOVE_RESOURCE_DELTAS, SECTION_MOVE_RESOURCE_PRECONDITIONS, SECTION_PUSH_WINDOWS,
+    SECTION_RESOURCE_DEFS, SECTION_SCHEMA, SECTION_SHAPES, SECTION_STATES,
+    SECTION_STATE_CONDITIONS, SECTION_STATE_EXTRAS, SECTION_STATE_HIT_CANCELS,
+    SECTION_STATE_KNOCKBACK, SECTION_STATE_METER_GAIN, SECTION_STATE_MOVEMENT,
+    SECTION_STATE_ON_USE, SECTION_STATE_PROPS, SECTION_STATE_PUSHBACK, SECTION_STATE_SUPER_FREEZE,
+    SECTION_STATE_TAGS, SECTION_STATE_TAG_RANGES, STATE_RECORD_SIZE,
+};
+
+// Record layouts as field widths in bytes, in order. Padding is listed as
+// single bytes so it is never swapped.
+
+/// StrRef: off(4) + len(2) + pad(2). Also the (offset, count) index entries.
+const STRREF: &[u8] = &[4, 2, 1, 1];
+const STATE_RECORD: &[u8] = &[
+    2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 1, 1, 4, 2, 2, 2, 2, 2,
+];
+const HIT_WINDOW: &[u8] = &[1, 1, 1, 1, 2, 2, 1, 1, 1, 1, 4, 2, 4, 2];
+const HURT_WINDOW: &[u8] = &[1, 1, 2, 4, 2, 2];
+const SHAPE: &[u8] = &[1, 1, 2, 2, 2, 2, 2];
+const U16: &[u8] = &[2];
+const RESOURCE_DEF: &[u8] = &[4, 2, 2, 2, 2];
+const STATE_EXTRAS: &[u8] = &[
+    4, 2, 1, 1, 4, 2, 1, 1, 4, 2, 1, 1, 4, 2, 1, 1, 4, 2, 1, 1, 4, 2, 1, 1, 4, 2, 1, 1, 4, 2, 1, 1,
+    4, 2, 1, 1,
+];
+const EVENT_EMIT: &[u8] = &[4, 2, 1, 1, 4, 2, 1, 1];
+const MOVE_NOTIFY: &[u8] = &[2, 1, 1, 4, 2, 1, 1];
+const RESOURCE_COST: &[u8] = &[4, 2, 1, 1, 2, 1, 1];
+const RESOURCE_PRECONDITION: &[u8] = &[4, 2, 1, 1, 2, 2];
+const RESOURCE_DELTA: &[u8] = &[4, 2, 1, 1, 4, 1, 1, 1, 1];
+const CANCEL_TAG_RULE: &[u8] = &[4, 2, 1, 1, 4, 2, 1, 1, 1, 1, 1, 1, 4];
+const CANCEL_DENY: &[u8] = &[2, 2];
+const STATE_MOVEMENT: &[u8] = &[1, 1, 1, 1, 2, 2, 2, 2];
+const STATE_ON_USE: &[u8] = &[2, 2];
+const STATE_SUPER_FREEZE: &[u8] = &[1, 1, 2, 1, 1];
+const STATE_METER_GAIN: &[u8] = &[2, 2];
+const STATE_KNOCKBACK: &[u8] = &[2, 2];
+const STATE_PUSHBACK: &[u8] = &[2, 2];
+const STATE_CONDITION: &[u8] = &[1, 1, 1, 1, 2, 2];
+const HIT_CANCEL: &[u8] = &[1, 1, 2, 4];
+const SCHEMA_HEADER: &[u8] = &[2, 2, 2, 1, 1];
+
+/// Width of a property value (CharacterProp12 / SchemaProp8) by value type.
+fn prop_value_layout(value_type: u8) -> &'static [u8] {
+    match value_type {
+        PROP_TYPE_Q24_8 => &[4],
+        PROP_TYPE_STR => &[2, 2],
+        // Bools are read from the first byte only
+        _ => &[1, 1, 1, 1],
+    }
+}
+
+/// Rewrite a little-endian pack as big-endian in place.
+///
+/// Does nothing if the pack is already big-endian.
+///
+/// # Errors
+/// Returns the same layout errors as [`PackView::parse`].
+pub fn to_big_endian(bytes: &mut [u8]) -> Result<(), Error> {
+    convert(bytes, true)
+}
+
+/// Rewrite a big-endian pack as little-endian in place, e.g. before casting
+/// sections with the `bytemuck` accessors.
+///
+/// Does nothing if the pack is already little-endian.
+///
+/// # Errors
+/// Returns the same layout errors as [`PackView::parse`].
+pub fn to_little_endian(bytes: &mut [u8]) -> Result<(), Error> {
+    convert(bytes, false)
+}
+
+fn convert(bytes: &mut [u8], to_big: bool) -> Result<(), Error> {
+    let (sections, section_count, flags) = {
+        let view = PackView::parse_layout(bytes)?;
+        (view.sections, view.section_count, view.flags().bits())
+    };
+    if (flags & PACK_FLAG_BIG_ENDIAN != 0) == to_big {
+        return Ok(());
+    }
+
+    let sections = &sections[..section_count];
+    let section_len = |kind: u32| sections.iter().find(|s| s.kind == kind).map(|s| s.len);
+    let state_count = section_len(SECTION_STATES).map_or(0, |len| len as usize / STATE_RECORD_SIZE);
+    let has_schema = section_len(SECTION_SCHEMA).is_some();
+
+    for section in sections {
+        let start = section.offset as usize;
+        let data = &mut bytes[start..start + section.len as usize];
+        swap_section(section.kind, data, state_count, has_schema, to_big);
+    }
+
+    let flags = flags ^ PACK_FLAG_BIG_ENDIAN;
+    bytes[HEADER_FLAGS_OFF..HEADER_FLAGS_OFF + 4].copy_from_slice(&flags.to_le_bytes());
+    Ok(())
+}
+
+fn swap_section(kind: u32, data: &mut [u8], state_count: usize, has_schema: bool, to_big: bool) {
+    let layout = match kind {
+        SECTION_MESH_KEYS | SECTION_KEYFRAMES_KEYS | SECTION_STATE_TAGS => STRREF,
+        SECTION_STATES => STATE_RECORD,
+        SECTION_HIT_WINDOWS => HIT_WINDOW,
+        SECTION_HURT_WINDOWS | SECTION_PUSH_WINDOWS => HURT_WINDOW,
+        SECTION_SHAPES => SHAPE,
+        SECTION_CANCELS_U16 => U16,
+        SECTION_RESOURCE_DEFS => RESOURCE_DEF,
+        SECTION_STATE_EXTRAS => STATE_EXTRAS,
+        SECTION_EVENT_EMITS => EVENT_EMIT,
+        SECTION_MOVE_NOTIFIES => MOVE_NOTIFY,
+        SECTION_MOVE_RESOURCE_COSTS => RESOURCE_COST,
+        SECTION_MOVE_RESOURCE_PRECONDITIONS => RESOURCE_PRECONDITION,
+        SECTION_MOVE_RESOURCE_DELTAS => RESOURCE_DELTA,
+        SECTION_STATE_TAG_RANGES => STRREF,
+        SECTION_CANCEL_TAG_RULES => CANCEL_TAG_RULE,
+        SECTION_CANCEL_DENIES => CANCEL_DENY,
+        SECTION_STATE_MOVEMENT => STATE_MOVEMENT,
+        SECTION_STATE_ON_USE => STATE_ON_USE,
+        SECTION_STATE_SUPER_FREEZE => STATE_SUPER_FREEZE,
+        SECTION_STATE_METER_GAIN => STATE_METER_GAIN,
+        SECTION_STATE_KNOCKBACK => STATE_KNOCKBACK,
+        SECTION_STATE_PUSHBACK => STATE_PUSHBACK,
+        SECTION_EVENT_ARGS => return swap_event_args(data),
+        SECTION_CHARACTER_PROPS => return swap_props(data, has_schema),
+        SECTION_STATE_PROPS => {
+            let records = swap_index(data, state_count);
+            return swap_props(records, has_schema);
+        }
+        SECTION_STATE_CONDITIONS => {
+            return swap_records(swap_index(data, state_count), STATE_CONDITION);
+        }
+        SECTION_STATE_HIT_CANCELS => {
+            return swap_records(swap_index(data, state_count), HIT_CANCEL);
+        }
+        SECTION_SCHEMA => {
+            let (header, names) = data.split_at_mut(data.len().min(SCHEMA_HEADER_SIZE));
+            swap_fields(header, SCHEMA_HEADER);
+            return swap_records(names, STRREF);
+        }
+        SECTION_METADATA => return swap_metadata(data, to_big),
+        _ => return,
+    };
+    swap_records(data, layout);
+}
+
+/// Swap every whole record of `data`; a trailing partial record is left alone.
+fn swap_records(data: &mut [u8], layout: &[u8]) {
+    let size: usize = layout.iter().map(|&w| usize::from(w)).sum();
+    for record in data.chunks_exact_mut(size) {
+        swap_fields(record, layout);
+    }
+}
+
+/// Reverse the bytes of each field of `record` in turn.
+fn swap_fields(record: &mut [u8], layout: &[u8]) {
+    let mut pos = 0;
+    for &width in layout {
+        let end = pos + usize::from(width);
+        if let Some(field) = record.get_mut(pos..end) {
+            field.reverse();
+        }
+        pos = end;
+    }
+}
+
+/// Swap the leading per-state index of an indexed section and return the
+/// record area after it.
+fn swap_index(data: &mut [u8], state_count: usize) -> &mut [u8] {
+    let index_len = (state_count * STRREF.len() * 2).min(data.len());
+    let (index, records) = data.split_at_mut(index_len);
+    swap_records(index, STRREF);
+    records
+}
+
+/// EventArg20: key StrRef, tag, reserved, then an 8-byte value whose layout
+/// depends on the tag.
+fn swap_event_args(data: &mut [u8]) {
+    for record in data.chunks_exact_mut(20) {
+        swap_fields(&mut record[..8], STRREF);
+        let value: &[u8] = match record[8] {
+            EVENT_ARG_TAG_I64 => &[8],
+            EVENT_ARG_TAG_F32 => &[4],
+            EVENT_ARG_TAG_STRING => STRREF,
+            // Bools are nonzero tests on the whole value
+            _ => &[8],
+        };
+        swap_fields(&mut record[12..], value);
+    }
+}
+
+/// CharacterProp12 (name StrRef, type, pad, value) or, with a schema,
+/// SchemaProp8 (schema id, type, pad, value).
+fn swap_props(data: &mut [u8], has_schema: bool) {
+    let (size, head, type_at): (usize, &[u8], usize) = if has_schema {
+        (8, &[2, 1, 1], 2)
+    } else {
+        (12, &[4, 2, 1, 1], 6)
+    };
+    let value_at = size - 4;
+    for record in data.chunks_exact_mut(size) {
+        swap_fields(&mut record[..value_at], head);
+        let layout = prop_value_layout(record[type_at]);
+        swap_fields(&mut record[value_at..], layout);
+    }
+}
+
+/// Metadata entries: key_len(2) + value_len(2) + inline UTF-8 bytes.
+fn swap_metadata(data: &mut [u8], to_big: bool) {
+    let mut pos = 0;
+    while pos + 4 <= data.len() {
+        // Swap first, then read the lengths back in the target order.
+        swap_fields(&mut data[pos..pos + 4], &[2, 2]);
+        let (Some(key_len), Some(value_len)) =
+            (read_len(data, pos, to_big), read_len(data, pos + 2, to_big))
+        else {
+            return;
+        };
+        pos += 4 + usize::from(key_len) + usize::from(value_len);
+    }
+}
+
+/// Read a length already swapped to the target order.
+fn read_len(data: &[u8], pos: usize, big: bool) -> Option<u16> {
+    let raw = read_u16_le(data, pos)?;
+    Some(if big { raw.swap_bytes() } else { raw })
+}
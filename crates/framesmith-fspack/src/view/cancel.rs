@@ -1,6 +1,6 @@
 //! Cancel target and tag rule views.
 
-use crate::bytes::{read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_bytes, read_u8, Endian};
 
 /// StateTagRange record size: off(4) + count(2) + pad(2) = 8 bytes
 pub const STATE_TAG_RANGE_SIZE: usize = 8;
@@ -11,18 +11,33 @@ pub const CANCEL_TAG_RULE_SIZE: usize = 24;
 /// CancelDeny record size (4 bytes: from u16, to u16)
 pub const CANCEL_DENY_SIZE: usize = 4;
 
+/// HitCancel record size: start(1) + end(1) + len(2) + off(4) = 8 bytes
+pub const HIT_CANCEL_SIZE: usize = 8;
+
+/// StateHitCancels index entry size: offset(4) + count(2) + pad(2) = 8 bytes
+pub const STATE_HIT_CANCELS_INDEX_ENTRY_SIZE: usize = 8;
+
 /// Zero-copy view over cancel targets (CANCELS_U16 section).
 ///
 /// Each entry is a u16 move ID representing a cancel target.
 #[derive(Clone, Copy)]
 pub struct CancelsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> CancelsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the total number of cancel target entries.
@@ -43,7 +58,7 @@ impl<'a> CancelsView<'a> {
         if off + 2 > self.data.len() {
             return None;
         }
-        read_u16_le(self.data, off)
+        self.endian.read_u16(self.data, off)
     }
 
     /// Get a cancel target at a byte offset + index.
@@ -57,13 +72,111 @@ impl<'a> CancelsView<'a> {
         if base + 2 > self.data.len() {
             return None;
         }
-        read_u16_le(self.data, base)
+        self.endian.read_u16(self.data, base)
     }
 
     /// Returns an iterator over all cancel target move IDs.
     pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
         (0..self.len()).filter_map(move |i| self.get(i))
     }
+
+    /// Iterate `count` targets starting at a byte offset, as stored in an
+    /// `(offset, count)` range such as `StateExtrasView::cancels`.
+    ///
+    /// Walks the range as one slice instead of bounds-checking each index.
+    /// Stops early if the range runs past the end of the section.
+    pub fn get_range(&self, offset_bytes: u32, count: u16) -> impl Iterator<Item = u16> + 'a {
+        let start = offset_bytes as usize;
+        let len = usize::from(count) * 2;
+        let end = start.saturating_add(len).min(self.data.len());
+        self.data
+            .get(start..end)
+            .unwrap_or(&[])
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    }
+}
+
+/// Zero-copy view over one state's per-hit cancel records.
+#[derive(Clone, Copy)]
+pub struct HitCancelsView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> HitCancelsView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of hits with cancel records.
+    pub fn len(&self) -> usize {
+        self.data.len() / HIT_CANCEL_SIZE
+    }
+
+    /// Returns true if there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a hit's cancel record by index.
+    pub fn get(&self, index: usize) -> Option<HitCancelView<'a>> {
+        let off = index.checked_mul(HIT_CANCEL_SIZE)?;
+        let end = off.checked_add(HIT_CANCEL_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(HitCancelView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+
+    /// Returns an iterator over the records in frame order.
+    pub fn iter(&self) -> impl Iterator<Item = HitCancelView<'a>> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+}
+
+/// Zero-copy view over a single HitCancel8 record.
+///
+/// Layout:
+/// - 0: start_frame (u8, first active frame of the hit)
+/// - 1: end_frame (u8, last active frame of the hit)
+/// - 2-3: cancel target count (u16)
+/// - 4-7: byte offset into CANCELS_U16 (u32)
+#[derive(Clone, Copy)]
+pub struct HitCancelView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> HitCancelView<'a> {
+    /// First active frame of the hit.
+    pub fn start_frame(&self) -> u8 {
+        read_u8(self.data, 0).unwrap_or(0)
+    }
+
+    /// Last active frame of the hit.
+    pub fn end_frame(&self) -> u8 {
+        read_u8(self.data, 1).unwrap_or(0)
+    }
+
+    /// Range of cancel targets in CANCELS_U16 as (byte offset, count).
+    pub fn cancels(&self) -> (u32, u16) {
+        let len = self.endian.read_u16(self.data, 2).unwrap_or(0);
+        let off = self.endian.read_u32(self.data, 4).unwrap_or(0);
+        (off, len)
+    }
 }
 
 /// Zero-copy view over STATE_TAG_RANGES section.
@@ -73,25 +186,31 @@ impl<'a> CancelsView<'a> {
 #[derive(Clone, Copy)]
 pub struct StateTagRangesView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> StateTagRangesView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Get the tag range (offset, count) for a state by index.
     ///
     /// Returns `None` if the index is out of bounds.
     pub fn get(&self, index: usize) -> Option<(u32, u16)> {
-        let offset = index * STATE_TAG_RANGE_SIZE;
-        if offset + STATE_TAG_RANGE_SIZE > self.data.len() {
-            return None;
-        }
-        let slice = &self.data[offset..offset + STATE_TAG_RANGE_SIZE];
-        let off = read_u32_le(slice, 0)?;
-        let count = read_u16_le(slice, 4)?;
+        let offset = index.checked_mul(STATE_TAG_RANGE_SIZE)?;
+        let slice = read_bytes(self.data, offset, STATE_TAG_RANGE_SIZE)?;
+        let off = self.endian.read_u32(slice, 0)?;
+        let count = self.endian.read_u16(slice, 4)?;
         Some((off, count))
     }
 
@@ -106,6 +225,54 @@ impl<'a> StateTagRangesView<'a> {
     }
 }
 
+/// Cancel condition bitfield decoded from a cancel tag rule.
+///
+/// Bits: 0=hit, 1=block, 2=whiff. Mirrors the authoring-side `on` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CancelCondition(pub u8);
+
+impl CancelCondition {
+    pub const HIT: Self = Self(0b001);
+    pub const BLOCK: Self = Self(0b010);
+    pub const WHIFF: Self = Self(0b100);
+    pub const ALWAYS: Self = Self(0b111);
+
+    /// Returns true if the hit bit is set.
+    pub fn on_hit(&self) -> bool {
+        self.0 & Self::HIT.0 != 0
+    }
+
+    /// Returns true if the block bit is set.
+    pub fn on_block(&self) -> bool {
+        self.0 & Self::BLOCK.0 != 0
+    }
+
+    /// Returns true if the whiff bit is set.
+    pub fn on_whiff(&self) -> bool {
+        self.0 & Self::WHIFF.0 != 0
+    }
+
+    /// Check if this condition matches the given hit/block state.
+    pub fn matches(&self, hit_confirmed: bool, block_confirmed: bool) -> bool {
+        (hit_confirmed && self.on_hit())
+            || (block_confirmed && self.on_block())
+            || (!hit_confirmed && !block_confirmed && self.on_whiff())
+    }
+}
+
+/// A cancel tag rule with its tag names resolved through the string table.
+///
+/// Field names follow the authoring format (`from`, `to`, `on`,
+/// `after_frame`, `before_frame`). A wildcard tag resolves to `"any"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CancelTagRule<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub on: CancelCondition,
+    pub after_frame: u8,
+    pub before_frame: u8,
+}
+
 /// View into a single cancel tag rule.
 ///
 /// Binary layout (24 bytes):
@@ -124,8 +291,8 @@ pub struct CancelTagRuleView<'a> {
 impl<'a> CancelTagRuleView<'a> {
     /// Get the source tag. Returns None if "any" (sentinel 0xFFFFFFFF).
     pub fn from_tag(&self) -> Option<&'a str> {
-        let off = read_u32_le(self.data, 0)?;
-        let len = read_u16_le(self.data, 4)?;
+        let off = self.pack.endian().read_u32(self.data, 0)?;
+        let len = self.pack.endian().read_u16(self.data, 4)?;
         if off == 0xFFFFFFFF {
             return None;
         } // "any"
@@ -134,8 +301,8 @@ impl<'a> CancelTagRuleView<'a> {
 
     /// Get the target tag. Returns None if "any".
     pub fn to_tag(&self) -> Option<&'a str> {
-        let off = read_u32_le(self.data, 8)?;
-        let len = read_u16_le(self.data, 12)?;
+        let off = self.pack.endian().read_u32(self.data, 8)?;
+        let len = self.pack.endian().read_u16(self.data, 12)?;
         if off == 0xFFFFFFFF {
             return None;
         }
@@ -164,6 +331,28 @@ impl<'a> CancelTagRuleView<'a> {
     pub fn flags(&self) -> u8 {
         read_u8(self.data, 19).unwrap_or(0)
     }
+
+    /// Resolve this rule's tag strings.
+    ///
+    /// Returns `None` if a non-wildcard tag doesn't resolve in the string table.
+    pub fn resolve(&self) -> Option<CancelTagRule<'a>> {
+        Some(CancelTagRule {
+            from: self.resolve_tag(0)?,
+            to: self.resolve_tag(8)?,
+            on: CancelCondition(self.condition()),
+            after_frame: self.min_frame(),
+            before_frame: self.max_frame(),
+        })
+    }
+
+    fn resolve_tag(&self, base: usize) -> Option<&'a str> {
+        let off = self.pack.endian().read_u32(self.data, base)?;
+        if off == 0xFFFFFFFF {
+            return Some("any");
+        }
+        let len = self.pack.endian().read_u16(self.data, base + 4)?;
+        self.pack.string(off, len)
+    }
 }
 
 /// View into cancel tag rules section.
@@ -181,12 +370,9 @@ impl<'a> CancelTagRulesView<'a> {
 
     /// Get a cancel tag rule by index.
     pub fn get(&self, index: usize) -> Option<CancelTagRuleView<'a>> {
-        let offset = index * CANCEL_TAG_RULE_SIZE;
-        if offset + CANCEL_TAG_RULE_SIZE > self.data.len() {
-            return None;
-        }
+        let offset = index.checked_mul(CANCEL_TAG_RULE_SIZE)?;
         Some(CancelTagRuleView {
-            data: &self.data[offset..offset + CANCEL_TAG_RULE_SIZE],
+            data: read_bytes(self.data, offset, CANCEL_TAG_RULE_SIZE)?,
             pack: self.pack,
         })
     }
@@ -205,4 +391,11 @@ impl<'a> CancelTagRulesView<'a> {
     pub fn iter(&self) -> impl Iterator<Item = CancelTagRuleView<'a>> + '_ {
         (0..self.len()).filter_map(move |i| self.get(i))
     }
+
+    /// Returns an iterator over all cancel tag rules with tag names resolved.
+    ///
+    /// Rules whose tags don't resolve in the string table are skipped.
+    pub fn resolved(&self) -> impl Iterator<Item = CancelTagRule<'a>> + '_ {
+        self.iter().filter_map(|rule| rule.resolve())
+    }
 }
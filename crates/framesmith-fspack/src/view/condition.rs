@@ -0,0 +1,118 @@
+//! Per-state non-resource precondition views.
+
+use crate::bytes::{read_u8, Endian};
+
+use super::OPT_U16_NONE;
+
+/// StateCondition record size (8 bytes)
+pub const STATE_CONDITION_SIZE: usize = 8;
+
+/// StateConditions index entry size: offset(4) + count(2) + pad(2) = 8 bytes
+pub const STATE_CONDITIONS_INDEX_ENTRY_SIZE: usize = 8;
+
+/// Condition kind: distance to the opponent in pixels (`min`/`max`).
+pub const CONDITION_KIND_DISTANCE: u8 = 1;
+
+/// Condition kind: frames a direction has been held (`arg` = CHARGE_DIR_*, `min`).
+pub const CONDITION_KIND_CHARGE: u8 = 2;
+
+/// Condition kind: the character is on the ground (no arguments).
+pub const CONDITION_KIND_GROUNDED: u8 = 3;
+
+/// Condition kind: the character is in the air (no arguments).
+pub const CONDITION_KIND_AIRBORNE: u8 = 4;
+
+/// Charge direction: down (numpad 1, 2, 3).
+pub const CHARGE_DIR_DOWN: u8 = 0;
+
+/// Charge direction: back, relative to facing (numpad 1, 4, 7).
+pub const CHARGE_DIR_BACK: u8 = 1;
+
+/// Charge direction: up (numpad 7, 8, 9).
+pub const CHARGE_DIR_UP: u8 = 2;
+
+/// Charge direction: forward, relative to facing (numpad 3, 6, 9).
+pub const CHARGE_DIR_FORWARD: u8 = 3;
+
+/// Zero-copy view over one state's condition records.
+#[derive(Clone, Copy)]
+pub struct StateConditionsView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateConditionsView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of conditions.
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_CONDITION_SIZE
+    }
+
+    /// Returns true if there are no conditions.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a condition by index.
+    pub fn get(&self, index: usize) -> Option<StateConditionView<'a>> {
+        let off = index.checked_mul(STATE_CONDITION_SIZE)?;
+        let end = off.checked_add(STATE_CONDITION_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(StateConditionView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+}
+
+/// Zero-copy view over a single StateCondition8 record.
+///
+/// Layout:
+/// - 0: kind (u8, CONDITION_KIND_*)
+/// - 1: arg (u8, kind-specific)
+/// - 2-3: reserved (u16)
+/// - 4-5: min (u16, 0xFFFF = none)
+/// - 6-7: max (u16, 0xFFFF = none)
+#[derive(Clone, Copy)]
+pub struct StateConditionView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateConditionView<'a> {
+    /// Condition kind (CONDITION_KIND_*).
+    pub fn kind(&self) -> u8 {
+        read_u8(self.data, 0).unwrap_or(0)
+    }
+
+    /// Kind-specific argument byte.
+    pub fn arg(&self) -> u8 {
+        read_u8(self.data, 1).unwrap_or(0)
+    }
+
+    /// Lower bound, if any.
+    pub fn min(&self) -> Option<u16> {
+        let v = self.endian.read_u16(self.data, 4).unwrap_or(OPT_U16_NONE);
+        (v != OPT_U16_NONE).then_some(v)
+    }
+
+    /// Upper bound, if any.
+    pub fn max(&self) -> Option<u16> {
+        let v = self.endian.read_u16(self.data, 6).unwrap_or(OPT_U16_NONE);
+        (v != OPT_U16_NONE).then_some(v)
+    }
+}
@@ -0,0 +1,107 @@
+//! Human-readable pack dump (requires the `alloc` feature).
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::{PackView, ShapeView};
+
+impl<'a> PackView<'a> {
+    /// Render the pack's states, their frame data, and window shapes as a tree.
+    ///
+    /// Intended for logging while debugging exports; the format is not stable.
+    pub fn debug_dump(&self) -> String {
+        let mut out = String::new();
+        let state_count = self.states().map_or(0, |s| s.len());
+        let _ = writeln!(
+            out,
+            "pack: {} bytes, {} sections, {} states",
+            self.total_len(),
+            self.section_count(),
+            state_count
+        );
+
+        for index in 0..state_count {
+            let Some(windows) = self.state_windows(index) else {
+                continue;
+            };
+            let state = windows.state();
+            let _ = writeln!(
+                out,
+                "state[{}]: id={} type={} startup={} active={} recovery={} total={} damage={}",
+                index,
+                state.state_id(),
+                state.state_type(),
+                state.startup(),
+                state.active(),
+                state.recovery(),
+                state.total(),
+                state.damage()
+            );
+
+            for (i, hw) in windows.hit_windows().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "  hit[{}]: frames={}-{} damage={} hitstun={} blockstun={}",
+                    i,
+                    hw.start_frame(),
+                    hw.end_frame(),
+                    hw.damage(),
+                    hw.hitstun(),
+                    hw.blockstun()
+                );
+                write_shapes(&mut out, hw.shapes(self));
+            }
+            for (i, hw) in windows.hurt_windows().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "  hurt[{}]: frames={}-{} flags={:#04x}",
+                    i,
+                    hw.start_frame(),
+                    hw.end_frame(),
+                    hw.flags()
+                );
+                write_shapes(&mut out, hw.shapes(self));
+            }
+            for (i, pw) in windows.push_windows().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "  push[{}]: frames={}-{}",
+                    i,
+                    pw.start_frame(),
+                    pw.end_frame()
+                );
+                write_shapes(&mut out, pw.shapes(self));
+            }
+        }
+        out
+    }
+}
+
+fn write_shapes<'a>(out: &mut String, shapes: impl Iterator<Item = ShapeView<'a>>) {
+    for (j, shape) in shapes.enumerate() {
+        let _ = write!(out, "    shape[{}]: ", j);
+        write_shape(out, &shape);
+    }
+}
+
+fn write_shape(out: &mut String, shape: &ShapeView<'_>) {
+    let _ = if let Some((x, y, w, h)) = shape.as_aabb() {
+        writeln!(out, "aabb x={} y={} w={} h={}", x, y, w, h)
+    } else if let Some((x, y, r)) = shape.as_circle() {
+        writeln!(out, "circle x={} y={} r={}", x, y, r)
+    } else if let Some((x1, y1, x2, y2, r)) = shape.as_capsule() {
+        writeln!(
+            out,
+            "capsule x1={} y1={} x2={} y2={} r={}",
+            x1, y1, x2, y2, r
+        )
+    } else {
+        writeln!(
+            out,
+            "kind={} x={} y={}",
+            shape.kind(),
+            shape.x_px(),
+            shape.y_px()
+        )
+    };
+}
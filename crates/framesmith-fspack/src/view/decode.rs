@@ -0,0 +1,230 @@
+//! Owned, serializable decoding of a whole pack (requires the `serde` feature).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::Serialize;
+
+use super::{PackView, ShapeView, StateView, SHAPE_KIND_RECT};
+
+/// Fully decoded pack: every state with its windows and shapes resolved.
+///
+/// This is the inverse of the exporter, intended for round-trip tests and
+/// editor tooling that wants to diff a pack against its source data.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DecodedPack {
+    pub states: Vec<DecodedState>,
+}
+
+/// A decoded state record.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DecodedState {
+    /// Input notation from STATE_EXTRAS, if the pack has one.
+    pub input: Option<String>,
+    pub state_id: u16,
+    pub state_type: u8,
+    pub trigger: u8,
+    pub guard: u8,
+    pub flags: u8,
+    pub startup: u8,
+    pub active: u8,
+    pub recovery: u8,
+    pub total: u16,
+    pub damage: u16,
+    pub hitstun: u8,
+    pub blockstun: u8,
+    pub hitstop: u8,
+    pub hit_windows: Vec<DecodedHitWindow>,
+    pub hurt_windows: Vec<DecodedHurtWindow>,
+    pub push_windows: Vec<DecodedHurtWindow>,
+}
+
+/// A decoded hit window.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DecodedHitWindow {
+    pub start_frame: u8,
+    pub end_frame: u8,
+    pub guard: u8,
+    pub flags: u8,
+    pub damage: u16,
+    pub chip_damage: u16,
+    pub hitstun: u8,
+    pub blockstun: u8,
+    pub hitstop: u8,
+    pub hit_pushback: i32,
+    pub block_pushback: i32,
+    pub shapes: Vec<DecodedShape>,
+}
+
+/// A decoded hurt or push window.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DecodedHurtWindow {
+    pub start_frame: u8,
+    pub end_frame: u8,
+    pub flags: u8,
+    pub shapes: Vec<DecodedShape>,
+}
+
+/// A decoded shape in whole pixels.
+///
+/// Rotated rects keep their raw Q8.8 angle; unknown kinds keep the raw fields.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DecodedShape {
+    Aabb {
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+    },
+    Rect {
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+        angle_raw: i16,
+    },
+    Circle {
+        x: i32,
+        y: i32,
+        r: u32,
+    },
+    Capsule {
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        r: u32,
+    },
+    Unknown {
+        raw_kind: u8,
+        a: i16,
+        b: i16,
+        c: i16,
+        d: i16,
+        e: i16,
+    },
+}
+
+impl DecodedShape {
+    fn from_view(shape: &ShapeView<'_>) -> Self {
+        if let Some((x, y, w, h)) = shape.as_aabb() {
+            Self::Aabb { x, y, w, h }
+        } else if let Some((x, y, r)) = shape.as_circle() {
+            Self::Circle { x, y, r }
+        } else if let Some((x1, y1, x2, y2, r)) = shape.as_capsule() {
+            Self::Capsule { x1, y1, x2, y2, r }
+        } else if shape.kind() == SHAPE_KIND_RECT {
+            Self::Rect {
+                x: shape.x_px(),
+                y: shape.y_px(),
+                w: shape.width_px(),
+                h: shape.height_px(),
+                angle_raw: shape.e_raw(),
+            }
+        } else {
+            Self::Unknown {
+                raw_kind: shape.kind(),
+                a: shape.a_raw(),
+                b: shape.b_raw(),
+                c: shape.c_raw(),
+                d: shape.d_raw(),
+                e: shape.e_raw(),
+            }
+        }
+    }
+}
+
+impl<'a> PackView<'a> {
+    /// Decode every state, window, and shape into owned, serializable structs.
+    ///
+    /// States whose windows can't be resolved decode with empty window lists.
+    pub fn decode(&self) -> DecodedPack {
+        let state_count = self.states().map_or(0, |s| s.len());
+        let states = (0..state_count)
+            .filter_map(|index| Some(self.decode_state(index, self.states()?.get(index)?)))
+            .collect();
+        DecodedPack { states }
+    }
+
+    fn decode_state(&self, index: usize, state: StateView<'a>) -> DecodedState {
+        let input = self
+            .state_extras()
+            .and_then(|extras| extras.get(index))
+            .and_then(|ex| {
+                let (off, len) = ex.input();
+                self.string(off, len)
+            })
+            .map(String::from);
+
+        let mut decoded = DecodedState {
+            input,
+            state_id: state.state_id(),
+            state_type: state.state_type(),
+            trigger: state.trigger(),
+            guard: state.guard(),
+            flags: state.flags(),
+            startup: state.startup(),
+            active: state.active(),
+            recovery: state.recovery(),
+            total: state.total(),
+            damage: state.damage(),
+            hitstun: state.hitstun(),
+            blockstun: state.blockstun(),
+            hitstop: state.hitstop(),
+            hit_windows: Vec::new(),
+            hurt_windows: Vec::new(),
+            push_windows: Vec::new(),
+        };
+
+        let Some(windows) = self.state_windows(index) else {
+            return decoded;
+        };
+        let pushback = self.state_pushbacks().and_then(|p| p.get(index));
+        decoded.hit_windows = windows
+            .hit_windows()
+            .map(|hw| DecodedHitWindow {
+                start_frame: hw.start_frame(),
+                end_frame: hw.end_frame(),
+                guard: hw.guard(),
+                flags: hw.flags(),
+                damage: hw.damage(),
+                chip_damage: hw.chip_damage(),
+                hitstun: hw.hitstun(),
+                blockstun: hw.blockstun(),
+                hitstop: hw.hitstop(),
+                hit_pushback: pushback.map_or(0, |p| p.hit_pushback_px()),
+                block_pushback: pushback.map_or(0, |p| p.block_pushback_px()),
+                shapes: hw
+                    .shapes(self)
+                    .map(|s| DecodedShape::from_view(&s))
+                    .collect(),
+            })
+            .collect();
+        decoded.hurt_windows = windows
+            .hurt_windows()
+            .map(|hw| DecodedHurtWindow {
+                start_frame: hw.start_frame(),
+                end_frame: hw.end_frame(),
+                flags: hw.flags(),
+                shapes: hw
+                    .shapes(self)
+                    .map(|s| DecodedShape::from_view(&s))
+                    .collect(),
+            })
+            .collect();
+        decoded.push_windows = windows
+            .push_windows()
+            .map(|pw| DecodedHurtWindow {
+                start_frame: pw.start_frame(),
+                end_frame: pw.end_frame(),
+                flags: pw.flags(),
+                shapes: pw
+                    .shapes(self)
+                    .map(|s| DecodedShape::from_view(&s))
+                    .collect(),
+            })
+            .collect();
+        decoded
+    }
+}
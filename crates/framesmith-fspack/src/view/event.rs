@@ -1,6 +1,6 @@
 //! Event emission and argument views.
 
-use crate::bytes::{read_f32_le, read_i64_le, read_u16_le, read_u32_le, read_u64_le, read_u8};
+use crate::bytes::{read_u8, Cursor, Endian};
 
 /// EventEmit record size
 pub const EVENT_EMIT_SIZE: usize = 16;
@@ -16,9 +16,9 @@ pub const EVENT_ARG_TAG_STRING: u8 = 3;
 
 /// Helper to read a (u32 offset, u16 length) pair.
 #[inline]
-fn read_range(data: &[u8], base: usize) -> Option<(u32, u16)> {
-    let off = read_u32_le(data, base)?;
-    let len = read_u16_le(data, base + 4)?;
+fn read_range(data: &[u8], base: usize, endian: Endian) -> Option<(u32, u16)> {
+    let off = endian.read_u32(data, base)?;
+    let len = endian.read_u16(data, base + 4)?;
     Some((off, len))
 }
 
@@ -26,12 +26,21 @@ fn read_range(data: &[u8], base: usize) -> Option<(u32, u16)> {
 #[derive(Clone, Copy)]
 pub struct EventEmitsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> EventEmitsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -50,6 +59,7 @@ impl<'a> EventEmitsView<'a> {
         }
         Some(EventEmitView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 
@@ -62,6 +72,7 @@ impl<'a> EventEmitsView<'a> {
         }
         Some(EventEmitView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -69,19 +80,20 @@ impl<'a> EventEmitsView<'a> {
 #[derive(Clone, Copy)]
 pub struct EventEmitView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> EventEmitView<'a> {
     pub fn id_off(&self) -> u32 {
-        read_u32_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u32(self.data, 0).unwrap_or(0)
     }
 
     pub fn id_len(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
     }
 
     pub fn args(&self) -> (u32, u16) {
-        read_range(self.data, 8).unwrap_or((0, 0))
+        read_range(self.data, 8, self.endian).unwrap_or((0, 0))
     }
 }
 
@@ -89,12 +101,21 @@ impl<'a> EventEmitView<'a> {
 #[derive(Clone, Copy)]
 pub struct EventArgsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> EventArgsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -113,6 +134,7 @@ impl<'a> EventArgsView<'a> {
         }
         Some(EventArgView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 
@@ -125,6 +147,7 @@ impl<'a> EventArgsView<'a> {
         }
         Some(EventArgView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -132,15 +155,16 @@ impl<'a> EventArgsView<'a> {
 #[derive(Clone, Copy)]
 pub struct EventArgView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> EventArgView<'a> {
     pub fn key_off(&self) -> u32 {
-        read_u32_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u32(self.data, 0).unwrap_or(0)
     }
 
     pub fn key_len(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
     }
 
     pub fn tag(&self) -> u8 {
@@ -151,29 +175,29 @@ impl<'a> EventArgView<'a> {
         if self.tag() != EVENT_ARG_TAG_BOOL {
             return None;
         }
-        Some(read_u64_le(self.data, 12)? != 0)
+        Some(self.endian.read_u64(self.data, 12)? != 0)
     }
 
     pub fn value_i64(&self) -> Option<i64> {
         if self.tag() != EVENT_ARG_TAG_I64 {
             return None;
         }
-        Some(read_i64_le(self.data, 12)?)
+        self.endian.read_i64(self.data, 12)
     }
 
     pub fn value_f32(&self) -> Option<f32> {
         if self.tag() != EVENT_ARG_TAG_F32 {
             return None;
         }
-        read_f32_le(self.data, 12)
+        self.endian.read_f32(self.data, 12)
     }
 
     pub fn value_string(&self) -> Option<(u32, u16)> {
         if self.tag() != EVENT_ARG_TAG_STRING {
             return None;
         }
-        let off = read_u32_le(self.data, 12)?;
-        let len = read_u16_le(self.data, 16)?;
-        Some((off, len))
+        Cursor::new(self.data, 12)
+            .with_endian(self.endian)
+            .read_strref()
     }
 }
@@ -1,14 +1,25 @@
 //! Hitbox window and shape views.
 
-use crate::bytes::{read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_u8, Endian};
 use crate::fixed::{Q12_4, Q8_8};
 
+use super::PackView;
+
 /// HitWindow record size (24 bytes)
 pub const HIT_WINDOW_SIZE: usize = 24;
 
 /// Shape record size (12 bytes)
 pub const SHAPE_SIZE: usize = 12;
 
+/// Hit window flag: the window is a throw (unblockable, grounded targets only).
+pub const HIT_FLAG_THROW: u8 = 0x01;
+
+/// Hit window flag: the hit bounces the defender off the wall.
+pub const HIT_FLAG_WALL_BOUNCE: u8 = 0x02;
+
+/// Hit window flag: the hit bounces the defender off the ground.
+pub const HIT_FLAG_GROUND_BOUNCE: u8 = 0x04;
+
 // Shape Type Constants
 /// Shape type: axis-aligned bounding box
 pub const SHAPE_KIND_AABB: u8 = 0;
@@ -28,12 +39,21 @@ pub const SHAPE_KIND_CAPSULE: u8 = 3;
 #[derive(Clone, Copy)]
 pub struct HitWindowsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> HitWindowsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the total number of hit windows.
@@ -55,6 +75,7 @@ impl<'a> HitWindowsView<'a> {
         }
         Some(HitWindowView {
             data: &self.data[off..end],
+            endian: self.endian,
         })
     }
 
@@ -70,33 +91,35 @@ impl<'a> HitWindowsView<'a> {
         }
         Some(HitWindowView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
 
-/// Zero-copy view over a single HitWindow24 record (24 bytes minimum).
+/// Zero-copy view over a single HitWindow24 record (24 bytes).
 ///
 /// Layout:
 /// - 0: start_f (u8)
 /// - 1: end_f (u8)
 /// - 2: guard (u8)
-/// - 3: _reserved (u8)
+/// - 3: flags (u8, HIT_FLAG_*)
 /// - 4-5: dmg (u16)
 /// - 6-7: chip (u16)
 /// - 8: hitstun (u8)
 /// - 9: blockstun (u8)
 /// - 10: hitstop (u8)
-/// - 11: _reserved (u8)
+/// - 11: launch (i8, knockback y in pixels; negative is up)
 /// - 12-15: shapes_off (u32)
 /// - 16-17: shapes_len (u16)
 /// - 18-21: cancels_off (u32)
 /// - 22-23: cancels_len (u16)
-/// Optional extended fields (backwards-compatible):
-/// - 24-25: hit_pushback (i16, Q12.4 fixed-point)
-/// - 26-27: block_pushback (i16, Q12.4 fixed-point)
+///
+/// Pushback is per state, in the STATE_PUSHBACK section
+/// ([`PackView::state_pushbacks`]).
 #[derive(Clone, Copy)]
 pub struct HitWindowView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> HitWindowView<'a> {
@@ -115,14 +138,34 @@ impl<'a> HitWindowView<'a> {
         read_u8(self.data, 2).unwrap_or(0)
     }
 
+    /// Hit window flags (`HIT_FLAG_*` bits).
+    pub fn flags(&self) -> u8 {
+        read_u8(self.data, 3).unwrap_or(0)
+    }
+
+    /// Returns true if this hit window is a throw.
+    pub fn is_throw(&self) -> bool {
+        self.flags() & HIT_FLAG_THROW != 0
+    }
+
+    /// Returns true if this hit bounces the defender off the wall.
+    pub fn wall_bounce(&self) -> bool {
+        self.flags() & HIT_FLAG_WALL_BOUNCE != 0
+    }
+
+    /// Returns true if this hit bounces the defender off the ground.
+    pub fn ground_bounce(&self) -> bool {
+        self.flags() & HIT_FLAG_GROUND_BOUNCE != 0
+    }
+
     /// Damage value for this hit window.
     pub fn damage(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
     }
 
     /// Chip damage for this hit window (0 = none).
     pub fn chip_damage(&self) -> u16 {
-        read_u16_le(self.data, 6).unwrap_or(0)
+        self.endian.read_u16(self.data, 6).unwrap_or(0)
     }
 
     /// Hitstun frames for this hit window.
@@ -140,64 +183,38 @@ impl<'a> HitWindowView<'a> {
         read_u8(self.data, 10).unwrap_or(0)
     }
 
+    /// Vertical launch in pixels from knockback `y` (negative is up, 0 = no launch).
+    pub fn launch(&self) -> i8 {
+        read_u8(self.data, 11).unwrap_or(0) as i8
+    }
+
     /// Byte offset into SHAPES section.
     pub fn shapes_off(&self) -> u32 {
-        read_u32_le(self.data, 12).unwrap_or(0)
+        self.endian.read_u32(self.data, 12).unwrap_or(0)
     }
 
     /// Number of shapes in this hit window.
     pub fn shapes_len(&self) -> u16 {
-        read_u16_le(self.data, 16).unwrap_or(0)
+        self.endian.read_u16(self.data, 16).unwrap_or(0)
+    }
+
+    /// Iterate this window's shapes from the pack's SHAPES section in order.
+    ///
+    /// Yields nothing if the pack has no SHAPES section.
+    pub fn shapes(&self, pack: &PackView<'a>) -> impl Iterator<Item = ShapeView<'a>> + 'a {
+        let shapes = pack.shapes();
+        let off = self.shapes_off();
+        (0..self.shapes_len() as usize).filter_map(move |i| shapes?.get_at(off, i))
     }
 
     /// Byte offset into CANCELS_U16 section.
     pub fn cancels_off(&self) -> u32 {
-        read_u32_le(self.data, 18).unwrap_or(0)
+        self.endian.read_u32(self.data, 18).unwrap_or(0)
     }
 
     /// Number of cancel targets for this hit window.
     pub fn cancels_len(&self) -> u16 {
-        read_u16_le(self.data, 22).unwrap_or(0)
-    }
-
-    /// Hit pushback (Q12.4 fixed-point). Returns 0 if not present.
-    pub fn hit_pushback_raw(&self) -> i16 {
-        if self.data.len() >= 26 {
-            read_u16_le(self.data, 24).unwrap_or(0) as i16
-        } else {
-            0
-        }
-    }
-
-    /// Block pushback (Q12.4 fixed-point). Returns 0 if not present.
-    pub fn block_pushback_raw(&self) -> i16 {
-        if self.data.len() >= 28 {
-            read_u16_le(self.data, 26).unwrap_or(0) as i16
-        } else {
-            0
-        }
-    }
-
-    /// Hit pushback in pixels.
-    pub fn hit_pushback_px(&self) -> i32 {
-        (self.hit_pushback_raw() as i32) >> 4
-    }
-
-    /// Block pushback in pixels.
-    pub fn block_pushback_px(&self) -> i32 {
-        (self.block_pushback_raw() as i32) >> 4
-    }
-
-    /// Hit pushback as Q12.4 fixed-point.
-    #[inline]
-    pub fn hit_pushback_fixed(&self) -> Q12_4 {
-        Q12_4::from_raw(self.hit_pushback_raw())
-    }
-
-    /// Block pushback as Q12.4 fixed-point.
-    #[inline]
-    pub fn block_pushback_fixed(&self) -> Q12_4 {
-        Q12_4::from_raw(self.block_pushback_raw())
+        self.endian.read_u16(self.data, 22).unwrap_or(0)
     }
 }
 
@@ -207,12 +224,21 @@ impl<'a> HitWindowView<'a> {
 #[derive(Clone, Copy)]
 pub struct ShapesView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> ShapesView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the total number of shapes.
@@ -234,6 +260,7 @@ impl<'a> ShapesView<'a> {
         }
         Some(ShapeView {
             data: &self.data[off..end],
+            endian: self.endian,
         })
     }
 
@@ -248,6 +275,7 @@ impl<'a> ShapesView<'a> {
         }
         Some(ShapeView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -267,6 +295,7 @@ impl<'a> ShapesView<'a> {
 #[derive(Clone, Copy)]
 pub struct ShapeView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> ShapeView<'a> {
@@ -284,14 +313,14 @@ impl<'a> ShapeView<'a> {
     /// For AABB/rect/circle: x coordinate.
     /// For capsule: x1 coordinate.
     pub fn a_raw(&self) -> i16 {
-        read_u16_le(self.data, 2).unwrap_or(0) as i16
+        self.endian.read_u16(self.data, 2).unwrap_or(0) as i16
     }
 
     /// Raw field b (Q12.4 fixed-point).
     /// For AABB/rect/circle: y coordinate.
     /// For capsule: y1 coordinate.
     pub fn b_raw(&self) -> i16 {
-        read_u16_le(self.data, 4).unwrap_or(0) as i16
+        self.endian.read_u16(self.data, 4).unwrap_or(0) as i16
     }
 
     /// Raw field c (Q12.4 fixed-point).
@@ -299,7 +328,7 @@ impl<'a> ShapeView<'a> {
     /// For circle: radius.
     /// For capsule: x2 coordinate.
     pub fn c_raw(&self) -> i16 {
-        read_u16_le(self.data, 6).unwrap_or(0) as i16
+        self.endian.read_u16(self.data, 6).unwrap_or(0) as i16
     }
 
     /// Raw field d (Q12.4 fixed-point).
@@ -307,14 +336,14 @@ impl<'a> ShapeView<'a> {
     /// For circle: unused.
     /// For capsule: y2 coordinate.
     pub fn d_raw(&self) -> i16 {
-        read_u16_le(self.data, 8).unwrap_or(0) as i16
+        self.endian.read_u16(self.data, 8).unwrap_or(0) as i16
     }
 
     /// Raw field e (Q8.8 fixed-point).
     /// For rect: rotation angle.
     /// For capsule: radius.
     pub fn e_raw(&self) -> i16 {
-        read_u16_le(self.data, 10).unwrap_or(0) as i16
+        self.endian.read_u16(self.data, 10).unwrap_or(0) as i16
     }
 
     /// Convert Q12.4 fixed-point to integer pixels (rounding down).
@@ -400,4 +429,35 @@ impl<'a> ShapeView<'a> {
     pub fn radius_fixed(&self) -> Q8_8 {
         Q8_8::from_raw(self.e_raw())
     }
+
+    /// Decode an AABB as `(x, y, w, h)` in pixels.
+    ///
+    /// Returns `None` unless `kind()` is AABB (rotated rects are excluded).
+    pub fn as_aabb(&self) -> Option<(i32, i32, u32, u32)> {
+        (self.kind() == SHAPE_KIND_AABB)
+            .then(|| (self.x_px(), self.y_px(), self.width_px(), self.height_px()))
+    }
+
+    /// Decode a circle as `(x, y, r)` in pixels.
+    ///
+    /// Returns `None` unless `kind()` is circle.
+    pub fn as_circle(&self) -> Option<(i32, i32, u32)> {
+        (self.kind() == SHAPE_KIND_CIRCLE).then(|| (self.x_px(), self.y_px(), self.radius_px()))
+    }
+
+    /// Decode a capsule as `(x1, y1, x2, y2, r)` in pixels.
+    ///
+    /// Returns `None` unless `kind()` is capsule. The radius comes from the
+    /// Q8.8 `e` field, not from `c`/`d`, which hold the second endpoint.
+    pub fn as_capsule(&self) -> Option<(i32, i32, i32, i32, u32)> {
+        (self.kind() == SHAPE_KIND_CAPSULE).then(|| {
+            (
+                self.x_fixed().to_int(),
+                self.y_fixed().to_int(),
+                self.x2_fixed().to_int(),
+                self.y2_fixed().to_int(),
+                self.radius_fixed().to_int().max(0) as u32,
+            )
+        })
+    }
 }
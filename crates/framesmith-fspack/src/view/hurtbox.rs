@@ -1,6 +1,8 @@
 //! Hurtbox and pushbox window views.
 
-use crate::bytes::{read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_u8, Endian};
+
+use super::{PackView, ShapeView};
 
 /// HurtWindow record size (12 bytes)
 pub const HURT_WINDOW_SIZE: usize = 12;
@@ -8,18 +10,42 @@ pub const HURT_WINDOW_SIZE: usize = 12;
 /// PushWindow record size (12 bytes) - same layout as HurtWindow
 pub const PUSH_WINDOW_SIZE: usize = 12;
 
+/// Hurt window flag: ignores strike hits.
+pub const HURT_FLAG_STRIKE_INVULN: u8 = 0x01;
+
+/// Hurt window flag: ignores throws.
+pub const HURT_FLAG_THROW_INVULN: u8 = 0x02;
+
+/// Hurt window flag: ignores projectiles.
+pub const HURT_FLAG_PROJECTILE_INVULN: u8 = 0x04;
+
+/// Hurt window flag: ignores every kind of hit.
+pub const HURT_FLAG_FULL_INVULN: u8 = 0x08;
+
+/// Hurt window flag: takes hits without entering hitstun.
+pub const HURT_FLAG_ARMOR: u8 = 0x10;
+
 /// Zero-copy view over hurt windows section.
 ///
 /// Each entry is a HurtWindow12 (12 bytes).
 #[derive(Clone, Copy)]
 pub struct HurtWindowsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> HurtWindowsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the total number of hurt windows.
@@ -41,6 +67,7 @@ impl<'a> HurtWindowsView<'a> {
         }
         Some(HurtWindowView {
             data: &self.data[off..end],
+            endian: self.endian,
         })
     }
 
@@ -53,6 +80,7 @@ impl<'a> HurtWindowsView<'a> {
         }
         Some(HurtWindowView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -69,6 +97,7 @@ impl<'a> HurtWindowsView<'a> {
 #[derive(Clone, Copy)]
 pub struct HurtWindowView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> HurtWindowView<'a> {
@@ -84,17 +113,33 @@ impl<'a> HurtWindowView<'a> {
 
     /// Hurtbox flags (invincibility, armor, etc.).
     pub fn hurt_flags(&self) -> u16 {
-        read_u16_le(self.data, 2).unwrap_or(0)
+        self.endian.read_u16(self.data, 2).unwrap_or(0)
+    }
+
+    /// Hurtbox flag byte (`HURT_FLAG_*` bits).
+    ///
+    /// This is the low byte of `hurt_flags`; the high byte is reserved.
+    pub fn flags(&self) -> u8 {
+        self.hurt_flags() as u8
     }
 
     /// Byte offset into SHAPES section.
     pub fn shapes_off(&self) -> u32 {
-        read_u32_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u32(self.data, 4).unwrap_or(0)
     }
 
     /// Number of shapes in this hurt window.
     pub fn shapes_len(&self) -> u16 {
-        read_u16_le(self.data, 8).unwrap_or(0)
+        self.endian.read_u16(self.data, 8).unwrap_or(0)
+    }
+
+    /// Iterate this window's shapes from the pack's SHAPES section in order.
+    ///
+    /// Yields nothing if the pack has no SHAPES section.
+    pub fn shapes(&self, pack: &PackView<'a>) -> impl Iterator<Item = ShapeView<'a>> + 'a {
+        let shapes = pack.shapes();
+        let off = self.shapes_off();
+        (0..self.shapes_len() as usize).filter_map(move |i| shapes?.get_at(off, i))
     }
 }
 
@@ -116,12 +161,21 @@ pub type PushWindowView<'a> = HurtWindowView<'a>;
 #[derive(Clone, Copy)]
 pub struct PushWindowsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> PushWindowsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the total number of push windows.
@@ -143,6 +197,7 @@ impl<'a> PushWindowsView<'a> {
         }
         Some(HurtWindowView {
             data: &self.data[off..end],
+            endian: self.endian,
         })
     }
 
@@ -157,6 +212,7 @@ impl<'a> PushWindowsView<'a> {
         }
         Some(HurtWindowView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
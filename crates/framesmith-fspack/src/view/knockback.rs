@@ -0,0 +1,76 @@
+//! Per-state knockback views.
+
+use crate::bytes::Endian;
+
+/// StateKnockback record size (4 bytes)
+pub const STATE_KNOCKBACK_SIZE: usize = 4;
+
+/// Zero-copy view over the state knockback section.
+///
+/// Each entry is a StateKnockback4 (4 bytes), parallel to STATES.
+#[derive(Clone, Copy)]
+pub struct StateKnockbacksView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateKnockbacksView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of knockback records (one per state).
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_KNOCKBACK_SIZE
+    }
+
+    /// Returns true if there are no knockback records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the knockback record for the given state index.
+    pub fn get(&self, index: usize) -> Option<StateKnockbackView<'a>> {
+        let off = index.checked_mul(STATE_KNOCKBACK_SIZE)?;
+        let end = off.checked_add(STATE_KNOCKBACK_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(StateKnockbackView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+}
+
+/// Zero-copy view over a single state knockback record (4 bytes).
+///
+/// Layout:
+/// - 0-1: x (i16, pixels; positive pushes away from the attacker)
+/// - 2-3: y (i16, pixels; negative is up)
+#[derive(Clone, Copy)]
+pub struct StateKnockbackView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateKnockbackView<'a> {
+    /// Horizontal knockback, relative to the attacker's facing.
+    pub fn x(&self) -> i16 {
+        self.endian.read_u16(self.data, 0).unwrap_or(0) as i16
+    }
+
+    /// Vertical knockback (negative is up).
+    pub fn y(&self) -> i16 {
+        self.endian.read_u16(self.data, 2).unwrap_or(0) as i16
+    }
+}
@@ -0,0 +1,77 @@
+//! Pack metadata view (source character, exporter version, build info).
+//!
+//! Metadata is diagnostic only: runtimes never read it, and the section keeps
+//! its strings inline so dropping it from a pack leaves nothing behind.
+
+use crate::bytes::{Cursor, Endian};
+
+/// Key naming the character the pack was exported from.
+pub const METADATA_KEY_CHARACTER_ID: &str = "character_id";
+
+/// Key naming the exporter version that wrote the pack.
+pub const METADATA_KEY_EXPORTER_VERSION: &str = "exporter_version";
+
+/// Key holding the build timestamp, when the tool that wrote the pack records one.
+pub const METADATA_KEY_BUILD_TIMESTAMP: &str = "build_timestamp";
+
+/// Zero-copy view over the metadata section.
+///
+/// Layout: entries back to back, each
+/// key_len(2) + value_len(2) + key bytes + value bytes, UTF-8 and unaligned.
+#[derive(Clone, Copy)]
+pub struct MetadataView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> MetadataView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Iterate `(key, value)` pairs in section order.
+    ///
+    /// Stops at the first truncated entry; entries that aren't valid UTF-8
+    /// are skipped.
+    pub fn entries(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        let mut cursor = Cursor::new(self.data, 0).with_endian(self.endian);
+        core::iter::from_fn(move || loop {
+            let key_len = cursor.read_u16()? as usize;
+            let value_len = cursor.read_u16()? as usize;
+            let key = cursor.take(key_len)?;
+            let value = cursor.take(value_len)?;
+            if let (Ok(key), Ok(value)) = (core::str::from_utf8(key), core::str::from_utf8(value)) {
+                return Some((key, value));
+            }
+        })
+    }
+
+    /// Value of the first entry with `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.entries().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Id of the character the pack was exported from.
+    pub fn character_id(&self) -> Option<&'a str> {
+        self.get(METADATA_KEY_CHARACTER_ID)
+    }
+
+    /// Version of the exporter that wrote the pack.
+    pub fn exporter_version(&self) -> Option<&'a str> {
+        self.get(METADATA_KEY_EXPORTER_VERSION)
+    }
+
+    /// Build timestamp, in whatever format the writing tool recorded it.
+    pub fn build_timestamp(&self) -> Option<&'a str> {
+        self.get(METADATA_KEY_BUILD_TIMESTAMP)
+    }
+}
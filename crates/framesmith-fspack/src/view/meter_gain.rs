@@ -0,0 +1,76 @@
+//! Per-state meter gain views.
+
+use crate::bytes::Endian;
+
+/// StateMeterGain record size (4 bytes)
+pub const STATE_METER_GAIN_SIZE: usize = 4;
+
+/// Zero-copy view over the state meter gain section.
+///
+/// Each entry is a StateMeterGain4 (4 bytes), parallel to STATES.
+#[derive(Clone, Copy)]
+pub struct StateMeterGainsView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateMeterGainsView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of meter gain records (one per state).
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_METER_GAIN_SIZE
+    }
+
+    /// Returns true if there are no meter gain records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the meter gain record for the given state index.
+    pub fn get(&self, index: usize) -> Option<StateMeterGainView<'a>> {
+        let off = index.checked_mul(STATE_METER_GAIN_SIZE)?;
+        let end = off.checked_add(STATE_METER_GAIN_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(StateMeterGainView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+}
+
+/// Zero-copy view over a single state meter gain record (4 bytes).
+///
+/// Layout:
+/// - 0-1: meter_gain_hit (u16)
+/// - 2-3: meter_gain_whiff (u16)
+#[derive(Clone, Copy)]
+pub struct StateMeterGainView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateMeterGainView<'a> {
+    /// Meter gained each time the state hits.
+    pub fn meter_gain_hit(&self) -> u16 {
+        self.endian.read_u16(self.data, 0).unwrap_or(0)
+    }
+
+    /// Meter gained when the state ends without connecting.
+    pub fn meter_gain_whiff(&self) -> u16 {
+        self.endian.read_u16(self.data, 2).unwrap_or(0)
+    }
+}
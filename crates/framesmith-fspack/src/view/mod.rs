@@ -1,27 +1,71 @@
 //! Zero-copy view into an FSPK pack.
 
-use crate::bytes::{read_u16_le, read_u32_le};
+use crate::bytes::{read_bytes, read_u32_le, Cursor, Endian};
 use crate::error::Error;
 
 // Declare submodules
+#[cfg(any(feature = "alloc", test))]
+mod builder;
+#[cfg(feature = "big-endian")]
+mod byte_order;
 mod cancel;
+mod condition;
+#[cfg(feature = "alloc")]
+mod debug;
+#[cfg(feature = "serde")]
+mod decode;
 mod event;
 mod hitbox;
 mod hurtbox;
+mod knockback;
+mod metadata;
+mod meter_gain;
+mod movement;
+mod notify;
+mod on_use;
+#[cfg(feature = "alloc")]
+mod owned;
 mod property;
+mod pushback;
+#[cfg(feature = "bytemuck")]
+mod raw;
 mod resource;
 mod schema;
 mod state;
+mod stats;
+mod super_freeze;
+mod windows;
 
 // Re-export everything from submodules
+#[cfg(any(feature = "alloc", test))]
+pub use builder::*;
+#[cfg(feature = "big-endian")]
+pub use byte_order::*;
 pub use cancel::*;
+pub use condition::*;
+#[cfg(feature = "serde")]
+pub use decode::*;
 pub use event::*;
 pub use hitbox::*;
 pub use hurtbox::*;
+pub use knockback::*;
+pub use metadata::*;
+pub use meter_gain::*;
+pub use movement::*;
+pub use notify::*;
+pub use on_use::*;
+#[cfg(feature = "alloc")]
+pub use owned::*;
 pub use property::*;
+pub use pushback::*;
+#[cfg(feature = "bytemuck")]
+pub use raw::*;
 pub use resource::*;
 pub use schema::*;
 pub use state::*;
+pub use stats::*;
+pub use super_freeze::*;
+pub use windows::*;
 
 /// Magic bytes identifying an FSPK file.
 pub const MAGIC: [u8; 4] = [b'F', b'S', b'P', b'K'];
@@ -36,12 +80,56 @@ pub const HEADER_FLAGS_OFF: usize = 4;
 pub const HEADER_TOTAL_LEN_OFF: usize = 8;
 pub const HEADER_SECTION_COUNT_OFF: usize = 12;
 
+/// Header flag: states are ordered by input, then state id.
+pub const PACK_FLAG_SORTED_INPUTS: u32 = 1 << 0;
+
+/// Header flag: multi-byte fields in section payloads are big-endian.
+///
+/// The header and section table stay little-endian so any reader can see
+/// this bit. With the `big-endian` feature, views read such packs in place;
+/// without it [`PackView::parse`] rejects them.
+pub const PACK_FLAG_BIG_ENDIAN: u32 = 1 << 1;
+
+/// Typed view of the header `flags` word.
+///
+/// Unknown bits are preserved in [`PackFlags::bits`] so newer packs still
+/// parse; readers only act on the bits they know.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PackFlags(u32);
+
+impl PackFlags {
+    /// Wrap a raw flags word.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// The raw flags word.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Whether every bit of `flag` (a `PACK_FLAG_*` constant) is set.
+    pub const fn contains(self, flag: u32) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// States are ordered by input, then state id ([`PACK_FLAG_SORTED_INPUTS`]).
+    pub const fn sorted_inputs(self) -> bool {
+        self.contains(PACK_FLAG_SORTED_INPUTS)
+    }
+
+    /// Section payloads are big-endian ([`PACK_FLAG_BIG_ENDIAN`]).
+    pub const fn big_endian(self) -> bool {
+        self.contains(PACK_FLAG_BIG_ENDIAN)
+    }
+}
+
 /// Size of each section header in bytes.
 /// Layout: kind(4) + offset(4) + len(4) + align(4)
 pub const SECTION_HEADER_SIZE: usize = 16;
 
 /// Maximum number of sections supported.
-pub const MAX_SECTIONS: usize = 24; // Increased for tag and cancel rule sections
+pub const MAX_SECTIONS: usize = 40;
 
 // =============================================================================
 // Section Kind Constants
@@ -122,6 +210,37 @@ pub const SECTION_STATE_PROPS: u32 = 23;
 /// When present, property records use 8-byte schema-based format instead of 12-byte.
 pub const SECTION_SCHEMA: u32 = 24;
 
+/// Section containing per-state movement (StateMovement12, parallel to STATES)
+pub const SECTION_STATE_MOVEMENT: u32 = 25;
+
+/// Section containing per-state non-resource preconditions.
+/// Layout: index (8 bytes per state: offset u32 + count u16 + pad u16) followed by StateCondition8 records.
+pub const SECTION_STATE_CONDITIONS: u32 = 26;
+
+/// Section containing per-hit cancel routes for multi-hit states.
+/// Layout: index (8 bytes per state: offset u32 + count u16 + pad u16) followed by HitCancel8 records.
+/// Record target ranges point into CANCELS_U16.
+pub const SECTION_STATE_HIT_CANCELS: u32 = 27;
+
+/// Section containing pack metadata as inline key-value strings (see [`MetadataView`]).
+/// Not read by the runtime; safe to strip.
+pub const SECTION_METADATA: u32 = 28;
+
+/// Array of StateOnUse4 structs (parallel to STATES)
+pub const SECTION_STATE_ON_USE: u32 = 29;
+
+/// Array of StateSuperFreeze6 structs (parallel to STATES)
+pub const SECTION_STATE_SUPER_FREEZE: u32 = 30;
+
+/// Array of StateMeterGain4 structs (parallel to STATES)
+pub const SECTION_STATE_METER_GAIN: u32 = 31;
+
+/// Array of StateKnockback4 structs (parallel to STATES)
+pub const SECTION_STATE_KNOCKBACK: u32 = 32;
+
+/// Array of StatePushback4 structs (parallel to STATES)
+pub const SECTION_STATE_PUSHBACK: u32 = 33;
+
 /// StatePropsIndex entry size: offset(4) + len(2) + pad(2) = 8 bytes
 pub const STATE_PROPS_INDEX_ENTRY_SIZE: usize = 8;
 
@@ -141,6 +260,7 @@ struct SectionInfo {
 /// allocating memory. All data is read directly from the underlying byte slice.
 pub struct PackView<'a> {
     data: &'a [u8],
+    endian: Endian,
     sections: [SectionInfo; MAX_SECTIONS],
     section_count: usize,
 }
@@ -156,7 +276,22 @@ impl<'a> PackView<'a> {
     /// - The data is too short to contain a valid header (`TooShort`)
     /// - The magic bytes are incorrect (`InvalidMagic`)
     /// - Section headers or data are out of bounds (`OutOfBounds`)
+    /// - Section payloads are big-endian and the `big-endian` feature is off
+    ///   (`BigEndian`)
     pub fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut view = Self::parse_layout(bytes)?;
+        if view.flags().big_endian() {
+            if !cfg!(feature = "big-endian") {
+                return Err(Error::BigEndian);
+            }
+            view.endian = Endian::Big;
+        }
+        Ok(view)
+    }
+
+    /// Validate the header and section table without looking at the byte
+    /// order of section payloads.
+    pub(crate) fn parse_layout(bytes: &'a [u8]) -> Result<Self, Error> {
         // Check minimum length for header
         if bytes.len() < HEADER_SIZE {
             return Err(Error::TooShort);
@@ -167,10 +302,7 @@ impl<'a> PackView<'a> {
             return Err(Error::InvalidMagic);
         }
 
-        // Read header fields
-        // flags at offset 4 (u32) - currently unused
-        let _flags = read_u32_le(bytes, HEADER_FLAGS_OFF).ok_or(Error::TooShort)?;
-
+        // Read header fields (flags are read on demand by `flags()`)
         let total_len = read_u32_le(bytes, HEADER_TOTAL_LEN_OFF).ok_or(Error::TooShort)? as usize;
         let section_count =
             read_u32_le(bytes, HEADER_SECTION_COUNT_OFF).ok_or(Error::TooShort)? as usize;
@@ -205,7 +337,7 @@ impl<'a> PackView<'a> {
 
         // Parse section headers
         let mut sections = [SectionInfo::default(); MAX_SECTIONS];
-        for i in 0..section_count {
+        for (i, section) in sections.iter_mut().enumerate().take(section_count) {
             let header_offset = HEADER_SIZE + i * SECTION_HEADER_SIZE;
 
             let kind = read_u32_le(bytes, header_offset).ok_or(Error::OutOfBounds)?;
@@ -221,7 +353,7 @@ impl<'a> PackView<'a> {
                 return Err(Error::OutOfBounds);
             }
 
-            sections[i] = SectionInfo {
+            *section = SectionInfo {
                 kind,
                 offset,
                 len,
@@ -233,28 +365,112 @@ impl<'a> PackView<'a> {
             data: bytes,
             sections,
             section_count,
+            endian: Endian::Little,
         })
     }
 
     /// Get the data for a section with the given kind.
     ///
-    /// Returns `None` if no section with that kind exists.
+    /// Multi-byte fields are in [`PackView::endian`] order. Returns `None` if
+    /// no section with that kind exists.
     pub fn get_section(&self, kind: u32) -> Option<&'a [u8]> {
         for i in 0..self.section_count {
             if self.sections[i].kind == kind {
                 let offset = self.sections[i].offset as usize;
                 let len = self.sections[i].len as usize;
-                return Some(&self.data[offset..offset + len]);
+                // Bounds were validated in `parse`; stay panic-free regardless.
+                return read_bytes(self.data, offset, len);
             }
         }
         None
     }
 
+    /// Get the kind and data of the section at `index` in table order.
+    ///
+    /// Returns `None` if `index >= section_count()`. Together with
+    /// [`PackView::section_count`] this walks every section as written.
+    pub fn get_section_at(&self, index: usize) -> Option<(u32, &'a [u8])> {
+        if index >= self.section_count {
+            return None;
+        }
+        let section = self.sections.get(index)?;
+        let data = read_bytes(self.data, section.offset as usize, section.len as usize)?;
+        Some((section.kind, data))
+    }
+
+    /// Check whether the pack has a section with the given kind.
+    ///
+    /// Only reads the section table; cheaper than a typed accessor.
+    pub fn contains_section(&self, kind: u32) -> bool {
+        self.sections
+            .iter()
+            .take(self.section_count)
+            .any(|s| s.kind == kind)
+    }
+
+    /// Byte length of the first section with the given kind.
+    ///
+    /// Returns `None` if no section with that kind exists.
+    pub fn section_len(&self, kind: u32) -> Option<usize> {
+        self.sections
+            .iter()
+            .take(self.section_count)
+            .find(|s| s.kind == kind)
+            .map(|s| s.len as usize)
+    }
+
     /// Returns the number of sections in the pack.
     pub fn section_count(&self) -> usize {
         self.section_count
     }
 
+    /// Returns the header flags.
+    pub fn flags(&self) -> PackFlags {
+        PackFlags::from_bits(read_u32_le(self.data, HEADER_FLAGS_OFF).unwrap_or(0))
+    }
+
+    /// Byte order of multi-byte fields in section payloads.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Returns the pack length declared in the header.
+    ///
+    /// May be shorter than the slice passed to [`PackView::parse`] if the
+    /// buffer has trailing bytes.
+    pub fn total_len(&self) -> usize {
+        // Validated against the buffer length in `parse`.
+        read_u32_le(self.data, HEADER_TOTAL_LEN_OFF).unwrap_or(0) as usize
+    }
+
+    /// Returns the section data region: everything after the header and
+    /// section table, up to `total_len()`.
+    pub fn body(&self) -> &'a [u8] {
+        let start = self
+            .section_count
+            .checked_mul(SECTION_HEADER_SIZE)
+            .and_then(|table| table.checked_add(HEADER_SIZE));
+        start
+            .and_then(|start| self.data.get(start..self.total_len()))
+            .unwrap_or(&[])
+    }
+
+    /// 64-bit FNV-1a hash of the pack bytes (header, section table and body).
+    ///
+    /// Stable across runs and platforms for identical bytes, so callers can
+    /// cache work per pack and skip it when the hash is unchanged. Bytes past
+    /// `total_len()` are not included. Not collision resistant against
+    /// crafted input.
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let bytes = self.data.get(..self.total_len()).unwrap_or(self.data);
+        bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &b| {
+            (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+        })
+    }
+
     /// Get a string from the string table by offset and length.
     ///
     /// Returns `None` if:
@@ -263,12 +479,7 @@ impl<'a> PackView<'a> {
     /// - The bytes are not valid UTF-8
     pub fn string(&self, off: u32, len: u16) -> Option<&'a str> {
         let table = self.get_section(SECTION_STRING_TABLE)?;
-        let start = off as usize;
-        let end = start.checked_add(len as usize)?;
-        if end > table.len() {
-            return None;
-        }
-        core::str::from_utf8(&table[start..end]).ok()
+        core::str::from_utf8(read_bytes(table, off as usize, len as usize)?).ok()
     }
 
     /// Get the raw string pool bytes for direct access.
@@ -278,12 +489,74 @@ impl<'a> PackView<'a> {
         self.get_section(SECTION_STRING_TABLE).unwrap_or(&[])
     }
 
+    /// Check that every string reference resolves to valid UTF-8 in the string table.
+    ///
+    /// Covers mesh keys, keyframes keys, state inputs, state tags, resource
+    /// names and event ids. [`string`](Self::string) only checks when called and
+    /// returns `None`, so run this after loading to report a corrupt string at
+    /// its source.
+    ///
+    /// # Errors
+    /// [`Error::InvalidString`] for the first bad reference found.
+    pub fn validate_strings(&self) -> Result<(), Error> {
+        let check = |section: u32, index: usize, (off, len): (u32, u16)| match self.string(off, len)
+        {
+            Some(_) => Ok(()),
+            None => Err(Error::InvalidString { section, index }),
+        };
+
+        if let Some(keys) = self.mesh_keys() {
+            for i in 0..keys.len() {
+                check(SECTION_MESH_KEYS, i, keys.get(i).unwrap_or_default())?;
+            }
+        }
+        if let Some(keys) = self.keyframes_keys() {
+            for i in 0..keys.len() {
+                check(SECTION_KEYFRAMES_KEYS, i, keys.get(i).unwrap_or_default())?;
+            }
+        }
+        if let Some(extras) = self.state_extras() {
+            for i in 0..extras.len() {
+                let input = extras.get(i).map(|ex| ex.input()).unwrap_or_default();
+                check(SECTION_STATE_EXTRAS, i, input)?;
+            }
+        }
+        if let Some(tags) = self.get_section(SECTION_STATE_TAGS) {
+            for (i, tag) in tags.chunks_exact(STRREF_SIZE).enumerate() {
+                let strref = Cursor::new(tag, 0)
+                    .with_endian(self.endian)
+                    .read_strref()
+                    .unwrap_or_default();
+                check(SECTION_STATE_TAGS, i, strref)?;
+            }
+        }
+        if let Some(defs) = self.resource_defs() {
+            for i in 0..defs.len() {
+                let name = defs
+                    .get(i)
+                    .map(|d| (d.name_off(), d.name_len()))
+                    .unwrap_or_default();
+                check(SECTION_RESOURCE_DEFS, i, name)?;
+            }
+        }
+        if let Some(emits) = self.event_emits() {
+            for i in 0..emits.len() {
+                let id = emits
+                    .get(i)
+                    .map(|e| (e.id_off(), e.id_len()))
+                    .unwrap_or_default();
+                check(SECTION_EVENT_EMITS, i, id)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Get mesh keys section as a typed view.
     ///
     /// Returns `None` if no mesh keys section exists.
     pub fn mesh_keys(&self) -> Option<MeshKeysView<'a>> {
         let data = self.get_section(SECTION_MESH_KEYS)?;
-        Some(MeshKeysView::new(data))
+        Some(MeshKeysView::new(data).with_endian(self.endian))
     }
 
     /// Get keyframes keys section as a typed view.
@@ -291,7 +564,7 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no keyframes keys section exists.
     pub fn keyframes_keys(&self) -> Option<KeyframesKeysView<'a>> {
         let data = self.get_section(SECTION_KEYFRAMES_KEYS)?;
-        Some(KeyframesKeysView::new(data))
+        Some(KeyframesKeysView::new(data).with_endian(self.endian))
     }
 
     /// Get states section as a typed view.
@@ -299,19 +572,72 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no states section exists.
     pub fn states(&self) -> Option<StatesView<'a>> {
         let data = self.get_section(SECTION_STATES)?;
-        Some(StatesView::new(data))
+        Some(StatesView::new(data).with_endian(self.endian))
     }
 
     /// Get resource definitions as a typed view.
     pub fn resource_defs(&self) -> Option<ResourceDefsView<'a>> {
         let data = self.get_section(SECTION_RESOURCE_DEFS)?;
-        Some(ResourceDefsView::new(data))
+        Some(ResourceDefsView::new(data).with_endian(self.endian))
     }
 
     /// Get per-state extras as a typed view.
     pub fn state_extras(&self) -> Option<StateExtrasView<'a>> {
         let data = self.get_section(SECTION_STATE_EXTRAS)?;
-        Some(StateExtrasView::new(data))
+        Some(StateExtrasView::new(data).with_endian(self.endian))
+    }
+
+    /// Get per-state movement records as a typed view.
+    ///
+    /// Returns `None` if no state in the pack moves.
+    pub fn state_movements(&self) -> Option<StateMovementsView<'a>> {
+        let data = self.get_section(SECTION_STATE_MOVEMENT)?;
+        Some(StateMovementsView::new(data).with_endian(self.endian))
+    }
+
+    /// Get per-state on-use effects as a typed view.
+    ///
+    /// Returns `None` if no state in the pack has on-use effects.
+    pub fn state_on_uses(&self) -> Option<StateOnUsesView<'a>> {
+        let data = self.get_section(SECTION_STATE_ON_USE)?;
+        Some(StateOnUsesView::new(data).with_endian(self.endian))
+    }
+
+    /// Get per-state super freezes as a typed view.
+    ///
+    /// Returns `None` if no state in the pack has a super freeze.
+    pub fn state_super_freezes(&self) -> Option<StateSuperFreezesView<'a>> {
+        let data = self.get_section(SECTION_STATE_SUPER_FREEZE)?;
+        Some(StateSuperFreezesView::new(data).with_endian(self.endian))
+    }
+
+    /// Get per-state meter gain as a typed view.
+    ///
+    /// Returns `None` if no state in the pack gains meter.
+    pub fn state_meter_gains(&self) -> Option<StateMeterGainsView<'a>> {
+        let data = self.get_section(SECTION_STATE_METER_GAIN)?;
+        Some(StateMeterGainsView::new(data).with_endian(self.endian))
+    }
+
+    /// Get per-state `on_hit` knockback as a typed view.
+    ///
+    /// Returns `None` if no state in the pack has a knockback.
+    pub fn state_knockbacks(&self) -> Option<StateKnockbacksView<'a>> {
+        let data = self.get_section(SECTION_STATE_KNOCKBACK)?;
+        Some(StateKnockbacksView::new(data).with_endian(self.endian))
+    }
+
+    /// Get per-state hit and block pushback as a typed view.
+    ///
+    /// Returns `None` if no state in the pack has pushback.
+    pub fn state_pushbacks(&self) -> Option<StatePushbacksView<'a>> {
+        let data = self.get_section(SECTION_STATE_PUSHBACK)?;
+        Some(StatePushbacksView::new(data).with_endian(self.endian))
+    }
+
+    /// Super freeze of the state at `index`, if it has one.
+    pub fn super_freeze(&self, index: usize) -> Option<SuperFreezeData> {
+        self.state_super_freezes()?.get(index)
     }
 
     /// Find a state by input notation (e.g., "5L", "236P").
@@ -334,40 +660,87 @@ impl<'a> PackView<'a> {
         None
     }
 
+    /// Summarize every state's input and core frame data, in state order.
+    ///
+    /// Yields nothing if the pack has no STATES section.
+    pub fn state_summaries(&self) -> impl Iterator<Item = StateSummary<'a>> + '_ {
+        let states = self.states();
+        let extras = self.state_extras();
+        (0..states.map_or(0, |s| s.len())).filter_map(move |index| {
+            let state = states?.get(index)?;
+            let input = extras.and_then(|ex| ex.get(index)).and_then(|ex| {
+                let (off, len) = ex.input();
+                self.string(off, len)
+            });
+            Some(StateSummary {
+                index,
+                input,
+                startup: state.startup(),
+                active: state.active(),
+                recovery: state.recovery(),
+                total: state.total(),
+                damage: state.damage(),
+                guard: state.guard(),
+            })
+        })
+    }
+
     /// Get event emits as a typed view.
     pub fn event_emits(&self) -> Option<EventEmitsView<'a>> {
         let data = self.get_section(SECTION_EVENT_EMITS)?;
-        Some(EventEmitsView::new(data))
+        Some(EventEmitsView::new(data).with_endian(self.endian))
     }
 
     /// Get event args as a typed view.
     pub fn event_args(&self) -> Option<EventArgsView<'a>> {
         let data = self.get_section(SECTION_EVENT_ARGS)?;
-        Some(EventArgsView::new(data))
+        Some(EventArgsView::new(data).with_endian(self.endian))
     }
 
     /// Get move notifies as a typed view.
     pub fn move_notifies(&self) -> Option<MoveNotifiesView<'a>> {
         let data = self.get_section(SECTION_MOVE_NOTIFIES)?;
-        Some(MoveNotifiesView::new(data))
+        Some(MoveNotifiesView::new(data).with_endian(self.endian))
+    }
+
+    /// Iterate a state's move notifies with their events and args resolved.
+    ///
+    /// Yields nothing if the state has no notifies or the extras, notifies, or
+    /// event sections are missing.
+    pub fn notifies_for_state(
+        &self,
+        state_idx: usize,
+    ) -> impl Iterator<Item = ResolvedNotify<'a>> + 'a {
+        let range = self
+            .state_extras()
+            .and_then(|extras| extras.get(state_idx))
+            .map_or((0, 0), |ex| ex.notifies());
+        let notifies = self.move_notifies();
+        let emits = self.event_emits();
+        let args = self.event_args();
+        let strings = self.string_pool();
+        (0..range.1 as usize).filter_map(move |i| {
+            let notify = notifies?.get_at(range.0, i)?;
+            Some(ResolvedNotify::new(notify, emits, args, strings))
+        })
     }
 
     /// Get move resource costs as a typed view.
     pub fn move_resource_costs(&self) -> Option<MoveResourceCostsView<'a>> {
         let data = self.get_section(SECTION_MOVE_RESOURCE_COSTS)?;
-        Some(MoveResourceCostsView::new(data))
+        Some(MoveResourceCostsView::new(data).with_endian(self.endian))
     }
 
     /// Get move resource preconditions as a typed view.
     pub fn move_resource_preconditions(&self) -> Option<MoveResourcePreconditionsView<'a>> {
         let data = self.get_section(SECTION_MOVE_RESOURCE_PRECONDITIONS)?;
-        Some(MoveResourcePreconditionsView::new(data))
+        Some(MoveResourcePreconditionsView::new(data).with_endian(self.endian))
     }
 
     /// Get move resource deltas as a typed view.
     pub fn move_resource_deltas(&self) -> Option<MoveResourceDeltasView<'a>> {
         let data = self.get_section(SECTION_MOVE_RESOURCE_DELTAS)?;
-        Some(MoveResourceDeltasView::new(data))
+        Some(MoveResourceDeltasView::new(data).with_endian(self.endian))
     }
 
     /// Get cancel targets as a typed view.
@@ -375,7 +748,21 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no cancels section exists.
     pub fn cancels(&self) -> Option<CancelsView<'a>> {
         let data = self.get_section(SECTION_CANCELS_U16)?;
-        Some(CancelsView::new(data))
+        Some(CancelsView::new(data).with_endian(self.endian))
+    }
+
+    /// Iterate a state's explicit cancel routes as target state IDs.
+    ///
+    /// Resolves the state's STATE_EXTRAS cancel range against CANCELS_U16.
+    /// Yields nothing if the state has no routes or either section is missing.
+    pub fn cancels_for_state(&self, state_idx: usize) -> impl Iterator<Item = u16> + 'a {
+        let range = self
+            .state_extras()
+            .and_then(|extras| extras.get(state_idx))
+            .map_or((0, 0), |ex| ex.cancels());
+        self.cancels()
+            .into_iter()
+            .flat_map(move |cancels| cancels.get_range(range.0, range.1))
     }
 
     /// Get hit windows section as a typed view.
@@ -383,7 +770,7 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no hit windows section exists.
     pub fn hit_windows(&self) -> Option<HitWindowsView<'a>> {
         let data = self.get_section(SECTION_HIT_WINDOWS)?;
-        Some(HitWindowsView::new(data))
+        Some(HitWindowsView::new(data).with_endian(self.endian))
     }
 
     /// Get hurt windows section as a typed view.
@@ -391,7 +778,7 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no hurt windows section exists.
     pub fn hurt_windows(&self) -> Option<HurtWindowsView<'a>> {
         let data = self.get_section(SECTION_HURT_WINDOWS)?;
-        Some(HurtWindowsView::new(data))
+        Some(HurtWindowsView::new(data).with_endian(self.endian))
     }
 
     /// Get push windows section as a typed view.
@@ -399,7 +786,20 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no push windows section exists.
     pub fn push_windows(&self) -> Option<PushWindowsView<'a>> {
         let data = self.get_section(SECTION_PUSH_WINDOWS)?;
-        Some(PushWindowsView::new(data))
+        Some(PushWindowsView::new(data).with_endian(self.endian))
+    }
+
+    /// Get the hit, hurt, and push windows of a state.
+    ///
+    /// Returns `None` if the state index is out of bounds.
+    pub fn state_windows(&self, state_idx: usize) -> Option<StateWindows<'a>> {
+        let state = self.states()?.get(state_idx)?;
+        Some(StateWindows::new(
+            state,
+            self.hit_windows(),
+            self.hurt_windows(),
+            self.push_windows(),
+        ))
     }
 
     /// Get shapes section as a typed view.
@@ -407,7 +807,7 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no shapes section exists.
     pub fn shapes(&self) -> Option<ShapesView<'a>> {
         let data = self.get_section(SECTION_SHAPES)?;
-        Some(ShapesView::new(data))
+        Some(ShapesView::new(data).with_endian(self.endian))
     }
 
     /// Get the state tag ranges section as a typed view.
@@ -415,33 +815,35 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no STATE_TAG_RANGES section exists.
     pub fn state_tag_ranges(&self) -> Option<StateTagRangesView<'a>> {
         let data = self.get_section(SECTION_STATE_TAG_RANGES)?;
-        Some(StateTagRangesView::new(data))
+        Some(StateTagRangesView::new(data).with_endian(self.endian))
     }
 
     /// Get tags for a state/move by its index.
     ///
-    /// Returns an iterator over the tag strings for the given state,
-    /// or `None` if tag sections don't exist or the index is invalid.
+    /// Returns an iterator over the tag strings for the given state, or
+    /// `None` only if the pack has neither STATE_TAG_RANGES nor STATE_TAGS.
+    /// In a tagged pack, a state without tags (or past the end of the
+    /// ranges) yields an empty iterator.
     pub fn state_tags(&self, state_idx: usize) -> Option<impl Iterator<Item = &'a str> + 'a> {
-        let ranges = self.state_tag_ranges()?;
-        let (off, count) = ranges.get(state_idx)?;
-        let tags_section = self.get_section(SECTION_STATE_TAGS)?;
-        let string_table = self.get_section(SECTION_STRING_TABLE)?;
+        let ranges = self.state_tag_ranges();
+        let tags_section = self.get_section(SECTION_STATE_TAGS);
+        if ranges.is_none() && tags_section.is_none() {
+            return None;
+        }
+        let (off, count) = ranges.and_then(|r| r.get(state_idx)).unwrap_or((0, 0));
+        let tags_section = tags_section.unwrap_or(&[]);
+        let string_table = self.get_section(SECTION_STRING_TABLE).unwrap_or(&[]);
+        let endian = self.endian;
 
         Some((0..count).filter_map(move |i| {
-            let tag_offset = (off as usize) + (i as usize) * STRREF_SIZE;
-            if tag_offset + STRREF_SIZE > tags_section.len() {
-                return None;
-            }
-            let str_off = read_u32_le(tags_section, tag_offset)?;
-            let str_len = read_u16_le(tags_section, tag_offset + 4)?;
+            let tag_offset = (i as usize)
+                .checked_mul(STRREF_SIZE)
+                .and_then(|rel| rel.checked_add(off as usize))?;
+            let str_off = endian.read_u32(tags_section, tag_offset)?;
+            let str_len = endian.read_u16(tags_section, tag_offset.checked_add(4)?)?;
             // Resolve string from string table
-            let start = str_off as usize;
-            let end = start.checked_add(str_len as usize)?;
-            if end > string_table.len() {
-                return None;
-            }
-            core::str::from_utf8(&string_table[start..end]).ok()
+            let bytes = read_bytes(string_table, str_off as usize, str_len as usize)?;
+            core::str::from_utf8(bytes).ok()
         }))
     }
 
@@ -470,8 +872,8 @@ impl<'a> PackView<'a> {
         let count = denies.len() / CANCEL_DENY_SIZE;
         for i in 0..count {
             let off = i * CANCEL_DENY_SIZE;
-            let deny_from = read_u16_le(denies, off).unwrap_or(0xFFFF);
-            let deny_to = read_u16_le(denies, off + 2).unwrap_or(0xFFFF);
+            let deny_from = self.endian.read_u16(denies, off).unwrap_or(0xFFFF);
+            let deny_to = self.endian.read_u16(denies, off + 2).unwrap_or(0xFFFF);
             if deny_from == from && deny_to == to {
                 return true;
             }
@@ -484,7 +886,7 @@ impl<'a> PackView<'a> {
     /// Returns `None` if no CHARACTER_PROPS section exists.
     pub fn character_props(&self) -> Option<CharacterPropsView<'a>> {
         let data = self.get_section(SECTION_CHARACTER_PROPS)?;
-        Some(CharacterPropsView::new(data))
+        Some(CharacterPropsView::new(data).with_endian(self.endian))
     }
 
     /// Get raw property record bytes for a state.
@@ -494,7 +896,8 @@ impl<'a> PackView<'a> {
     /// - The state index is out of bounds
     /// - The state has no properties (len == 0)
     ///
-    /// The returned bytes contain fixed 12-byte property records.
+    /// The returned bytes contain fixed 12-byte property records, with
+    /// multi-byte fields in [`PackView::endian`] order.
     /// Each record: name_off(u32) + name_len(u16) + type(u8) + pad(u8) + value(4 bytes).
     /// Use the string pool to look up property names from (name_off, name_len).
     pub fn state_props_raw(&self, state_idx: usize) -> Option<&'a [u8]> {
@@ -505,21 +908,63 @@ impl<'a> PackView<'a> {
         let index_entry_off = state_idx.checked_mul(STATE_PROPS_INDEX_ENTRY_SIZE)?;
 
         // Read the index entry
-        let off = read_u32_le(section, index_entry_off)? as usize;
-        let len = read_u16_le(section, index_entry_off + 4)? as usize;
+        let off = self.endian.read_u32(section, index_entry_off)? as usize;
+        let len = self
+            .endian
+            .read_u16(section, index_entry_off.checked_add(4)?)? as usize;
 
         // Empty properties (len == 0) means no data
         if len == 0 {
             return None;
         }
 
-        // Bounds check
-        let end = off.checked_add(len)?;
-        if end > section.len() {
+        read_bytes(section, off, len)
+    }
+
+    /// Get a state's non-resource preconditions (distance, ...).
+    ///
+    /// Returns `None` if the pack has no conditions section or the state has none.
+    pub fn state_conditions(&self, state_idx: usize) -> Option<StateConditionsView<'a>> {
+        let section = self.get_section(SECTION_STATE_CONDITIONS)?;
+        let index_entry_off = state_idx.checked_mul(STATE_CONDITIONS_INDEX_ENTRY_SIZE)?;
+
+        let off = self.endian.read_u32(section, index_entry_off)? as usize;
+        let count = self
+            .endian
+            .read_u16(section, index_entry_off.checked_add(4)?)? as usize;
+        if count == 0 {
             return None;
         }
 
-        Some(&section[off..end])
+        let len = count.checked_mul(STATE_CONDITION_SIZE)?;
+        Some(StateConditionsView::new(read_bytes(section, off, len)?).with_endian(self.endian))
+    }
+
+    /// Get a state's per-hit cancel records, one per hit in frame order.
+    ///
+    /// Returns `None` if the pack has no hit cancels section or the state has none.
+    pub fn state_hit_cancels(&self, state_idx: usize) -> Option<HitCancelsView<'a>> {
+        let section = self.get_section(SECTION_STATE_HIT_CANCELS)?;
+        let index_entry_off = state_idx.checked_mul(STATE_HIT_CANCELS_INDEX_ENTRY_SIZE)?;
+
+        let off = self.endian.read_u32(section, index_entry_off)? as usize;
+        let count = self
+            .endian
+            .read_u16(section, index_entry_off.checked_add(4)?)? as usize;
+        if count == 0 {
+            return None;
+        }
+
+        let len = count.checked_mul(HIT_CANCEL_SIZE)?;
+        Some(HitCancelsView::new(read_bytes(section, off, len)?).with_endian(self.endian))
+    }
+
+    /// Get the pack metadata (source character, exporter version, ...).
+    ///
+    /// Returns `None` if the pack has no metadata section.
+    pub fn metadata(&self) -> Option<MetadataView<'a>> {
+        let data = self.get_section(SECTION_METADATA)?;
+        Some(MetadataView::new(data).with_endian(self.endian))
     }
 
     /// Check if a state has properties.
@@ -529,7 +974,7 @@ impl<'a> PackView<'a> {
 
     /// Check if this pack has a schema section.
     pub fn has_schema(&self) -> bool {
-        self.get_section(SECTION_SCHEMA).is_some()
+        self.contains_section(SECTION_SCHEMA)
     }
 
     /// Get the schema section as a typed view.
@@ -538,7 +983,7 @@ impl<'a> PackView<'a> {
     pub fn schema(&self) -> Option<SchemaView<'a>> {
         let data = self.get_section(SECTION_SCHEMA)?;
         let string_pool = self.string_pool();
-        SchemaView::new(data, string_pool)
+        SchemaView::new_with_endian(data, string_pool, self.endian)
     }
 
     /// Get schema-based character properties (8-byte records).
@@ -551,6 +996,6 @@ impl<'a> PackView<'a> {
             return None;
         }
         let data = self.get_section(SECTION_CHARACTER_PROPS)?;
-        Some(SchemaCharacterPropsView::new(data))
+        Some(SchemaCharacterPropsView::new(data).with_endian(self.endian))
     }
 }
@@ -0,0 +1,128 @@
+//! Per-state movement views.
+
+use crate::bytes::{read_u8, Endian};
+
+/// StateMovement record size (12 bytes)
+pub const STATE_MOVEMENT_SIZE: usize = 12;
+
+/// Movement flag: the state is airborne while moving.
+pub const MOVEMENT_FLAG_AIRBORNE: u8 = 0x01;
+
+/// Zero-copy view over the state movement section.
+///
+/// Each entry is a StateMovement12 (12 bytes), parallel to STATES.
+#[derive(Clone, Copy)]
+pub struct StateMovementsView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateMovementsView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of movement records (one per state).
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_MOVEMENT_SIZE
+    }
+
+    /// Returns true if there are no movement records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the movement record for the given state index.
+    pub fn get(&self, index: usize) -> Option<StateMovementView<'a>> {
+        let off = index.checked_mul(STATE_MOVEMENT_SIZE)?;
+        let end = off.checked_add(STATE_MOVEMENT_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(StateMovementView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+}
+
+/// Zero-copy view over a single state movement record (12 bytes).
+///
+/// Velocity and acceleration are Q8.8 pixels per frame (per frame squared),
+/// in character-local space (+x is forward).
+///
+/// Layout:
+/// - 0: start_frame (u8)
+/// - 1: end_frame (u8, inclusive)
+/// - 2: flags (u8, MOVEMENT_FLAG_*)
+/// - 3: reserved (u8)
+/// - 4-5: velocity_x (i16, Q8.8)
+/// - 6-7: velocity_y (i16, Q8.8)
+/// - 8-9: acceleration_x (i16, Q8.8)
+/// - 10-11: acceleration_y (i16, Q8.8)
+///
+/// States without movement have an all-zero record.
+#[derive(Clone, Copy)]
+pub struct StateMovementView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateMovementView<'a> {
+    /// First frame of the movement window.
+    pub fn start_frame(&self) -> u8 {
+        read_u8(self.data, 0).unwrap_or(0)
+    }
+
+    /// Last frame of the movement window (inclusive).
+    pub fn end_frame(&self) -> u8 {
+        read_u8(self.data, 1).unwrap_or(0)
+    }
+
+    /// Movement flags (MOVEMENT_FLAG_*).
+    pub fn flags(&self) -> u8 {
+        read_u8(self.data, 2).unwrap_or(0)
+    }
+
+    /// Returns true if the state is airborne while moving.
+    pub fn is_airborne(&self) -> bool {
+        self.flags() & MOVEMENT_FLAG_AIRBORNE != 0
+    }
+
+    /// Initial X velocity (raw Q8.8).
+    pub fn velocity_x(&self) -> i16 {
+        self.endian.read_i16(self.data, 4).unwrap_or(0)
+    }
+
+    /// Initial Y velocity (raw Q8.8).
+    pub fn velocity_y(&self) -> i16 {
+        self.endian.read_i16(self.data, 6).unwrap_or(0)
+    }
+
+    /// X acceleration (raw Q8.8).
+    pub fn acceleration_x(&self) -> i16 {
+        self.endian.read_i16(self.data, 8).unwrap_or(0)
+    }
+
+    /// Y acceleration (raw Q8.8).
+    pub fn acceleration_y(&self) -> i16 {
+        self.endian.read_i16(self.data, 10).unwrap_or(0)
+    }
+
+    /// Returns true if this record moves the character at all.
+    pub fn is_moving(&self) -> bool {
+        self.velocity_x() != 0
+            || self.velocity_y() != 0
+            || self.acceleration_x() != 0
+            || self.acceleration_y() != 0
+    }
+}
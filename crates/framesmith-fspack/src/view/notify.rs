@@ -0,0 +1,129 @@
+//! Move notifies resolved through their event emits and args.
+
+use crate::bytes::read_bytes;
+
+use super::{EventArgView, EventArgsView, EventEmitsView, MoveNotifyView};
+use super::{EVENT_ARG_TAG_BOOL, EVENT_ARG_TAG_F32, EVENT_ARG_TAG_I64, EVENT_ARG_TAG_STRING};
+
+fn resolve_str(strings: &[u8], off: u32, len: u16) -> Option<&str> {
+    core::str::from_utf8(read_bytes(strings, off as usize, len as usize)?).ok()
+}
+
+/// A decoded event argument value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EventArgValue<'a> {
+    Bool(bool),
+    I64(i64),
+    F32(f32),
+    Str(&'a str),
+}
+
+/// An event argument with its key and value resolved.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResolvedEventArg<'a> {
+    pub key: &'a str,
+    pub value: EventArgValue<'a>,
+}
+
+impl<'a> ResolvedEventArg<'a> {
+    /// Decode an arg record; `None` if its key, tag, or string value doesn't resolve.
+    fn resolve(arg: EventArgView<'a>, strings: &'a [u8]) -> Option<Self> {
+        let key = resolve_str(strings, arg.key_off(), arg.key_len())?;
+        let value = match arg.tag() {
+            EVENT_ARG_TAG_BOOL => EventArgValue::Bool(arg.value_bool()?),
+            EVENT_ARG_TAG_I64 => EventArgValue::I64(arg.value_i64()?),
+            EVENT_ARG_TAG_F32 => EventArgValue::F32(arg.value_f32()?),
+            EVENT_ARG_TAG_STRING => {
+                let (off, len) = arg.value_string()?;
+                EventArgValue::Str(resolve_str(strings, off, len)?)
+            }
+            _ => return None,
+        };
+        Some(Self { key, value })
+    }
+}
+
+/// An event emit with its id resolved; args are decoded as they're iterated.
+#[derive(Clone, Copy)]
+pub struct ResolvedEvent<'a> {
+    id: &'a str,
+    args_range: (u32, u16),
+    args: Option<EventArgsView<'a>>,
+    strings: &'a [u8],
+}
+
+impl<'a> ResolvedEvent<'a> {
+    /// The event id, e.g. `vfx.swing_trail`.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// Iterate the event's arguments in authored order.
+    ///
+    /// Args whose key or value doesn't resolve are skipped.
+    pub fn args(&self) -> impl Iterator<Item = ResolvedEventArg<'a>> + 'a {
+        let (off, len) = self.args_range;
+        let args = self.args;
+        let strings = self.strings;
+        (0..len as usize)
+            .filter_map(move |i| ResolvedEventArg::resolve(args?.get_at(off, i)?, strings))
+    }
+
+    /// Look up an argument value by key.
+    pub fn arg(&self, key: &str) -> Option<EventArgValue<'a>> {
+        self.args().find(|arg| arg.key == key).map(|arg| arg.value)
+    }
+}
+
+/// A move notify: the frame it fires on and its resolved events.
+///
+/// Built by [`PackView::notifies_for_state`](super::PackView::notifies_for_state).
+#[derive(Clone, Copy)]
+pub struct ResolvedNotify<'a> {
+    frame: u16,
+    emits_range: (u32, u16),
+    emits: Option<EventEmitsView<'a>>,
+    args: Option<EventArgsView<'a>>,
+    strings: &'a [u8],
+}
+
+impl<'a> ResolvedNotify<'a> {
+    pub(super) fn new(
+        notify: MoveNotifyView<'a>,
+        emits: Option<EventEmitsView<'a>>,
+        args: Option<EventArgsView<'a>>,
+        strings: &'a [u8],
+    ) -> Self {
+        Self {
+            frame: notify.frame(),
+            emits_range: notify.emits(),
+            emits,
+            args,
+            strings,
+        }
+    }
+
+    /// Frame the notify fires on.
+    pub fn frame(&self) -> u16 {
+        self.frame
+    }
+
+    /// Iterate the notify's events in authored order.
+    ///
+    /// Events whose id doesn't resolve in the string table are skipped.
+    pub fn events(&self) -> impl Iterator<Item = ResolvedEvent<'a>> + 'a {
+        let (off, len) = self.emits_range;
+        let emits = self.emits;
+        let args = self.args;
+        let strings = self.strings;
+        (0..len as usize).filter_map(move |i| {
+            let emit = emits?.get_at(off, i)?;
+            Some(ResolvedEvent {
+                id: resolve_str(strings, emit.id_off(), emit.id_len())?,
+                args_range: emit.args(),
+                args,
+                strings,
+            })
+        })
+    }
+}
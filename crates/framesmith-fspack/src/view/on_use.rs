@@ -0,0 +1,83 @@
+//! Per-state on-use effect views.
+
+use crate::bytes::Endian;
+
+use super::OPT_U16_NONE;
+
+/// StateOnUse record size (4 bytes)
+pub const STATE_ON_USE_SIZE: usize = 4;
+
+/// Zero-copy view over the state on-use section.
+///
+/// Each entry is a StateOnUse4 (4 bytes), parallel to STATES.
+#[derive(Clone, Copy)]
+pub struct StateOnUsesView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateOnUsesView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of on-use records (one per state).
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_ON_USE_SIZE
+    }
+
+    /// Returns true if there are no on-use records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the on-use record for the given state index.
+    pub fn get(&self, index: usize) -> Option<StateOnUseView<'a>> {
+        let off = index.checked_mul(STATE_ON_USE_SIZE)?;
+        let end = off.checked_add(STATE_ON_USE_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(StateOnUseView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+}
+
+/// Zero-copy view over a single state on-use record (4 bytes).
+///
+/// Layout:
+/// - 0-1: enters_state (u16, state index, `OPT_U16_NONE` = none)
+/// - 2-3: gain_meter (u16)
+///
+/// States without on-use effects have `enters_state = OPT_U16_NONE` and
+/// `gain_meter = 0`.
+#[derive(Clone, Copy)]
+pub struct StateOnUseView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateOnUseView<'a> {
+    /// State the character moves into once this state starts, if any.
+    pub fn enters_state(&self) -> Option<u16> {
+        self.endian
+            .read_u16(self.data, 0)
+            .filter(|&idx| idx != OPT_U16_NONE)
+    }
+
+    /// Meter granted when this state starts.
+    pub fn gain_meter(&self) -> u16 {
+        self.endian.read_u16(self.data, 2).unwrap_or(0)
+    }
+}
@@ -0,0 +1,65 @@
+//! Owned, pre-validated pack storage (requires the `alloc` feature).
+
+use alloc::vec::Vec;
+
+use super::{PackView, SectionInfo, MAX_SECTIONS};
+use crate::bytes::Endian;
+use crate::error::Error;
+
+/// An FSPK pack that owns its bytes and was validated once at construction.
+///
+/// [`PackBuf::view`] hands out a [`PackView`] by copying the already parsed
+/// section table, so long-lived holders (e.g. a training session) avoid
+/// re-parsing the header every frame.
+#[derive(Clone)]
+pub struct PackBuf {
+    bytes: Vec<u8>,
+    sections: [SectionInfo; MAX_SECTIONS],
+    section_count: usize,
+}
+
+impl PackBuf {
+    /// Validate `bytes` as an FSPK pack and take ownership of them.
+    ///
+    /// With the `big-endian` feature, a big-endian pack is converted to
+    /// little-endian in place first, so [`PackBuf::as_bytes`] returns the
+    /// converted bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PackView::parse`].
+    #[cfg_attr(not(feature = "big-endian"), allow(unused_mut))]
+    pub fn parse_owned(mut bytes: Vec<u8>) -> Result<Self, Error> {
+        #[cfg(feature = "big-endian")]
+        super::to_little_endian(&mut bytes)?;
+        let (sections, section_count) = {
+            let view = PackView::parse(&bytes)?;
+            (view.sections, view.section_count)
+        };
+        Ok(Self {
+            bytes,
+            sections,
+            section_count,
+        })
+    }
+
+    /// Borrow a view of the pack without re-validating it.
+    pub fn view(&self) -> PackView<'_> {
+        PackView {
+            data: &self.bytes,
+            sections: self.sections,
+            section_count: self.section_count,
+            endian: Endian::Little,
+        }
+    }
+
+    /// The raw pack bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Give the raw pack bytes back.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
@@ -1,11 +1,74 @@
 //! Character and state property views.
 
-use crate::bytes::{read_i32_le, read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_bytes, read_u8, Endian};
+use crate::fixed::Q24_8;
 
 /// Character property record size (12 bytes)
 /// Layout: name_off(4) + name_len(2) + value_type(1) + pad(1) + value(4)
 pub const CHARACTER_PROP_SIZE: usize = 12;
 
+/// Property value type: Q24.8 signed fixed-point number.
+pub const PROP_TYPE_Q24_8: u8 = 0;
+
+/// Property value type: boolean (nonzero = true).
+pub const PROP_TYPE_BOOL: u8 = 1;
+
+/// Property value type: string reference (u16 offset, u16 length).
+pub const PROP_TYPE_STR: u8 = 2;
+
+/// A property value decoded per its `PROP_TYPE_*` tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PropValue<'a> {
+    /// `PROP_TYPE_Q24_8`
+    Number(Q24_8),
+    /// `PROP_TYPE_BOOL`
+    Bool(bool),
+    /// `PROP_TYPE_STR`, resolved against the string table.
+    Str(&'a str),
+}
+
+impl<'a> PropValue<'a> {
+    /// Decode a 4-byte property value for the given type tag.
+    ///
+    /// Returns `None` for an unknown tag or a string reference that falls
+    /// outside `strings` or isn't valid UTF-8.
+    pub(crate) fn decode(
+        value_type: u8,
+        value: &[u8],
+        strings: &'a [u8],
+        endian: Endian,
+    ) -> Option<Self> {
+        match value_type {
+            PROP_TYPE_Q24_8 => Some(Self::Number(Q24_8::from_raw(endian.read_i32(value, 0)?))),
+            PROP_TYPE_BOOL => Some(Self::Bool(read_u8(value, 0)? != 0)),
+            PROP_TYPE_STR => {
+                let off = endian.read_u16(value, 0)? as usize;
+                let len = endian.read_u16(value, 2)? as usize;
+                core::str::from_utf8(read_bytes(strings, off, len)?)
+                    .ok()
+                    .map(Self::Str)
+            }
+            _ => None,
+        }
+    }
+
+    /// Read a 4-byte property value as the u32 a little-endian pack stores.
+    ///
+    /// String references are swapped as two u16s, so they can't be read as
+    /// one u32 in big-endian packs.
+    pub(crate) fn raw(value_type: u8, value: &[u8], endian: Endian) -> u32 {
+        match value_type {
+            PROP_TYPE_Q24_8 => endian.read_u32(value, 0),
+            PROP_TYPE_STR => endian
+                .read_u16(value, 0)
+                .zip(endian.read_u16(value, 2))
+                .map(|(off, len)| u32::from(off) | (u32::from(len) << 16)),
+            _ => Endian::Little.read_u32(value, 0),
+        }
+        .unwrap_or(0)
+    }
+}
+
 /// Zero-copy view over a single character property (12 bytes).
 ///
 /// Layout:
@@ -17,30 +80,32 @@ pub const CHARACTER_PROP_SIZE: usize = 12;
 #[derive(Clone, Copy)]
 pub struct CharacterPropView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> CharacterPropView<'a> {
     /// Get the property name string reference (offset, length).
     pub fn name(&self) -> (u32, u16) {
-        let off = read_u32_le(self.data, 0).unwrap_or(0);
-        let len = read_u16_le(self.data, 4).unwrap_or(0);
+        let off = self.endian.read_u32(self.data, 0).unwrap_or(0);
+        let len = self.endian.read_u16(self.data, 4).unwrap_or(0);
         (off, len)
     }
 
-    /// Get the value type tag.
+    /// Get the value type tag (`PROP_TYPE_*`).
     /// 0 = i32 (Q24.8 fixed-point), 1 = bool, 2 = string reference
     pub fn value_type(&self) -> u8 {
         read_u8(self.data, 6).unwrap_or(0)
     }
 
-    /// Get the raw value as u32.
+    /// Get the raw value as u32, as it would read from a little-endian pack.
     pub fn value_raw(&self) -> u32 {
-        read_u32_le(self.data, 8).unwrap_or(0)
+        let value = read_bytes(self.data, 8, 4).unwrap_or_default();
+        PropValue::raw(self.value_type(), value, self.endian)
     }
 
     /// Interpret the value as Q24.8 fixed-point (signed).
     pub fn as_q24_8(&self) -> i32 {
-        read_i32_le(self.data, 8).unwrap_or(0)
+        self.endian.read_i32(self.data, 8).unwrap_or(0)
     }
 
     /// Interpret the value as boolean.
@@ -51,10 +116,16 @@ impl<'a> CharacterPropView<'a> {
     /// Interpret the value as a string reference (offset, length).
     /// The offset is stored in the lower 16 bits, length in the upper 16 bits.
     pub fn as_str_ref(&self) -> (u16, u16) {
-        let off = read_u16_le(self.data, 8).unwrap_or(0);
-        let len = read_u16_le(self.data, 10).unwrap_or(0);
+        let off = self.endian.read_u16(self.data, 8).unwrap_or(0);
+        let len = self.endian.read_u16(self.data, 10).unwrap_or(0);
         (off, len)
     }
+
+    /// Decode the value per its type tag, resolving strings in `strings`.
+    pub fn value(&self, strings: &'a [u8]) -> Option<PropValue<'a>> {
+        let value = read_bytes(self.data, 8, 4)?;
+        PropValue::decode(self.value_type(), value, strings, self.endian)
+    }
 }
 
 /// Zero-copy view over the character properties section.
@@ -63,12 +134,21 @@ impl<'a> CharacterPropView<'a> {
 #[derive(Clone, Copy)]
 pub struct CharacterPropsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> CharacterPropsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the number of character properties.
@@ -92,6 +172,7 @@ impl<'a> CharacterPropsView<'a> {
         }
         Some(CharacterPropView {
             data: &self.data[off..end],
+            endian: self.endian,
         })
     }
 
@@ -99,4 +180,21 @@ impl<'a> CharacterPropsView<'a> {
     pub fn iter(&self) -> impl Iterator<Item = CharacterPropView<'a>> + '_ {
         (0..self.len()).filter_map(|i| self.get(i))
     }
+
+    /// Iterate `(name, value)` pairs with names and string values resolved
+    /// against the string table (`PackView::string_pool`).
+    ///
+    /// Properties whose name or value can't be resolved are skipped.
+    pub fn entries(
+        &self,
+        strings: &'a [u8],
+    ) -> impl Iterator<Item = (&'a str, PropValue<'a>)> + 'a {
+        let view = *self;
+        (0..self.len()).filter_map(move |i| {
+            let prop = view.get(i)?;
+            let (off, len) = prop.name();
+            let name = read_bytes(strings, off as usize, len as usize)?;
+            Some((core::str::from_utf8(name).ok()?, prop.value(strings)?))
+        })
+    }
 }
@@ -0,0 +1,99 @@
+//! Per-state pushback views.
+
+use crate::bytes::Endian;
+use crate::fixed::Q12_4;
+
+/// StatePushback record size (4 bytes)
+pub const STATE_PUSHBACK_SIZE: usize = 4;
+
+/// Zero-copy view over the state pushback section.
+///
+/// Each entry is a StatePushback4 (4 bytes), parallel to STATES.
+#[derive(Clone, Copy)]
+pub struct StatePushbacksView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StatePushbacksView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of pushback records (one per state).
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_PUSHBACK_SIZE
+    }
+
+    /// Returns true if there are no pushback records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the pushback record for the given state index.
+    pub fn get(&self, index: usize) -> Option<StatePushbackView<'a>> {
+        let off = index.checked_mul(STATE_PUSHBACK_SIZE)?;
+        let end = off.checked_add(STATE_PUSHBACK_SIZE)?;
+        if end > self.data.len() {
+            return None;
+        }
+        Some(StatePushbackView {
+            data: &self.data[off..end],
+            endian: self.endian,
+        })
+    }
+}
+
+/// Zero-copy view over a single state pushback record (4 bytes).
+///
+/// Layout:
+/// - 0-1: hit_pushback (i16, Q12.4 fixed-point)
+/// - 2-3: block_pushback (i16, Q12.4 fixed-point)
+#[derive(Clone, Copy)]
+pub struct StatePushbackView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StatePushbackView<'a> {
+    /// Hit pushback (Q12.4 fixed-point).
+    pub fn hit_pushback_raw(&self) -> i16 {
+        self.endian.read_u16(self.data, 0).unwrap_or(0) as i16
+    }
+
+    /// Block pushback (Q12.4 fixed-point).
+    pub fn block_pushback_raw(&self) -> i16 {
+        self.endian.read_u16(self.data, 2).unwrap_or(0) as i16
+    }
+
+    /// Hit pushback in pixels.
+    pub fn hit_pushback_px(&self) -> i32 {
+        (self.hit_pushback_raw() as i32) >> 4
+    }
+
+    /// Block pushback in pixels.
+    pub fn block_pushback_px(&self) -> i32 {
+        (self.block_pushback_raw() as i32) >> 4
+    }
+
+    /// Hit pushback as Q12.4 fixed-point.
+    #[inline]
+    pub fn hit_pushback_fixed(&self) -> Q12_4 {
+        Q12_4::from_raw(self.hit_pushback_raw())
+    }
+
+    /// Block pushback as Q12.4 fixed-point.
+    #[inline]
+    pub fn block_pushback_fixed(&self) -> Q12_4 {
+        Q12_4::from_raw(self.block_pushback_raw())
+    }
+}
@@ -0,0 +1,95 @@
+//! Raw record casts (requires the `bytemuck` feature).
+//!
+//! Mirrors of the fixed-size records as `#[repr(C, packed)]` structs, so a
+//! trusted pack's STATES and SHAPES sections can be reinterpreted in place
+//! instead of decoded field by field. Multi-byte fields hold the stored
+//! bytes, so the casts are only offered when the pack's byte order matches
+//! the target's.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::bytes::Endian;
+
+use super::{PackView, SECTION_SHAPES, SECTION_STATES, SHAPE_SIZE, STATE_RECORD_SIZE};
+
+/// In-place mirror of a state record (see [`StateView`](super::StateView)).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C, packed)]
+pub struct StateRecordRaw {
+    pub state_id: u16,
+    pub mesh_key: u16,
+    pub keyframes_key: u16,
+    pub state_type: u8,
+    pub trigger: u8,
+    pub guard: u8,
+    pub flags: u8,
+    pub startup: u8,
+    pub active: u8,
+    pub recovery: u8,
+    pub kara_start: u8,
+    pub total: u16,
+    pub damage: u16,
+    pub hitstun: u8,
+    pub blockstun: u8,
+    pub hitstop: u8,
+    pub kara_frames: u8,
+    pub hit_windows_off: u32,
+    pub hit_windows_len: u16,
+    pub hurt_windows_off: u16,
+    pub hurt_windows_len: u16,
+    pub push_windows_off: u16,
+    pub push_windows_len: u16,
+}
+
+/// In-place mirror of a Shape12 record (see [`ShapeView`](super::ShapeView)).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C, packed)]
+pub struct ShapeRaw {
+    pub kind: u8,
+    pub flags: u8,
+    pub a: i16,
+    pub b: i16,
+    pub c: i16,
+    pub d: i16,
+    pub e: i16,
+}
+
+const _: () = assert!(core::mem::size_of::<StateRecordRaw>() == STATE_RECORD_SIZE);
+const _: () = assert!(core::mem::size_of::<ShapeRaw>() == SHAPE_SIZE);
+
+// SAFETY: both structs are `repr(C, packed)` with only integer fields, so they
+// have no padding, alignment 1, and every bit pattern is valid.
+unsafe impl Zeroable for StateRecordRaw {}
+unsafe impl Pod for StateRecordRaw {}
+unsafe impl Zeroable for ShapeRaw {}
+unsafe impl Pod for ShapeRaw {}
+
+impl<'a> PackView<'a> {
+    /// Reinterpret the STATES section as a slice of [`StateRecordRaw`].
+    ///
+    /// Returns `None` if there is no states section, its length is not a
+    /// whole number of records, or the pack's byte order differs from the
+    /// target's.
+    pub fn states_raw(&self) -> Option<&'a [StateRecordRaw]> {
+        cast_section(self.get_section(SECTION_STATES)?, self.endian())
+    }
+
+    /// Reinterpret the SHAPES section as a slice of [`ShapeRaw`].
+    ///
+    /// Returns `None` under the same conditions as [`PackView::states_raw`].
+    pub fn shapes_raw(&self) -> Option<&'a [ShapeRaw]> {
+        cast_section(self.get_section(SECTION_SHAPES)?, self.endian())
+    }
+}
+
+fn cast_section<T: Pod>(data: &[u8], endian: Endian) -> Option<&[T]> {
+    let native = if cfg!(target_endian = "big") {
+        Endian::Big
+    } else {
+        Endian::Little
+    };
+    if endian != native {
+        return None;
+    }
+    bytemuck::try_cast_slice(data).ok()
+}
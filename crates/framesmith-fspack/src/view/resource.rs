@@ -1,6 +1,6 @@
 //! Resource definitions, state extras, and move resource views.
 
-use crate::bytes::{read_i32_le, read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_u8, Endian};
 
 /// ResourceDef record size
 pub const RESOURCE_DEF_SIZE: usize = 12;
@@ -28,11 +28,46 @@ pub const RESOURCE_DELTA_TRIGGER_ON_USE: u8 = 0;
 pub const RESOURCE_DELTA_TRIGGER_ON_HIT: u8 = 1;
 pub const RESOURCE_DELTA_TRIGGER_ON_BLOCK: u8 = 2;
 
+/// Decoded `trigger` byte of a resource delta record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceTrigger {
+    /// Applied when the move starts.
+    OnUse,
+    /// Applied when the move hits.
+    OnHit,
+    /// Applied when the move is blocked.
+    OnBlock,
+    /// A trigger this reader doesn't know.
+    Unknown(u8),
+}
+
+impl ResourceTrigger {
+    /// Decode a raw `trigger` byte.
+    pub fn from_u8(raw: u8) -> Self {
+        match raw {
+            RESOURCE_DELTA_TRIGGER_ON_USE => Self::OnUse,
+            RESOURCE_DELTA_TRIGGER_ON_HIT => Self::OnHit,
+            RESOURCE_DELTA_TRIGGER_ON_BLOCK => Self::OnBlock,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// Encode back to the raw `trigger` byte.
+    pub fn to_u8(self) -> u8 {
+        match self {
+            Self::OnUse => RESOURCE_DELTA_TRIGGER_ON_USE,
+            Self::OnHit => RESOURCE_DELTA_TRIGGER_ON_HIT,
+            Self::OnBlock => RESOURCE_DELTA_TRIGGER_ON_BLOCK,
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+
 /// Helper to read a (u32 offset, u16 length) pair.
 #[inline]
-fn read_range(data: &[u8], base: usize) -> Option<(u32, u16)> {
-    let off = read_u32_le(data, base)?;
-    let len = read_u16_le(data, base + 4)?;
+fn read_range(data: &[u8], base: usize, endian: Endian) -> Option<(u32, u16)> {
+    let off = endian.read_u32(data, base)?;
+    let len = endian.read_u16(data, base + 4)?;
     Some((off, len))
 }
 
@@ -40,12 +75,21 @@ fn read_range(data: &[u8], base: usize) -> Option<(u32, u16)> {
 #[derive(Clone, Copy)]
 pub struct ResourceDefsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> ResourceDefsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -64,6 +108,7 @@ impl<'a> ResourceDefsView<'a> {
         }
         Some(ResourceDefView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -71,23 +116,29 @@ impl<'a> ResourceDefsView<'a> {
 #[derive(Clone, Copy)]
 pub struct ResourceDefView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> ResourceDefView<'a> {
     pub fn name_off(&self) -> u32 {
-        read_u32_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u32(self.data, 0).unwrap_or(0)
     }
 
     pub fn name_len(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
+    }
+
+    /// Amount added every frame; negative values decay the resource.
+    pub fn regen_per_frame(&self) -> i16 {
+        self.endian.read_i16(self.data, 6).unwrap_or(0)
     }
 
     pub fn start(&self) -> u16 {
-        read_u16_le(self.data, 8).unwrap_or(0)
+        self.endian.read_u16(self.data, 8).unwrap_or(0)
     }
 
     pub fn max(&self) -> u16 {
-        read_u16_le(self.data, 10).unwrap_or(0)
+        self.endian.read_u16(self.data, 10).unwrap_or(0)
     }
 }
 
@@ -95,12 +146,21 @@ impl<'a> ResourceDefView<'a> {
 #[derive(Clone, Copy)]
 pub struct StateExtrasView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> StateExtrasView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -119,6 +179,7 @@ impl<'a> StateExtrasView<'a> {
         }
         Some(StateExtrasRecordView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -126,47 +187,48 @@ impl<'a> StateExtrasView<'a> {
 #[derive(Clone, Copy)]
 pub struct StateExtrasRecordView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> StateExtrasRecordView<'a> {
     pub fn on_use_emits(&self) -> (u32, u16) {
-        read_range(self.data, 0).unwrap_or((0, 0))
+        read_range(self.data, 0, self.endian).unwrap_or((0, 0))
     }
 
     pub fn on_hit_emits(&self) -> (u32, u16) {
-        read_range(self.data, 8).unwrap_or((0, 0))
+        read_range(self.data, 8, self.endian).unwrap_or((0, 0))
     }
 
     pub fn on_block_emits(&self) -> (u32, u16) {
-        read_range(self.data, 16).unwrap_or((0, 0))
+        read_range(self.data, 16, self.endian).unwrap_or((0, 0))
     }
 
     pub fn notifies(&self) -> (u32, u16) {
-        read_range(self.data, 24).unwrap_or((0, 0))
+        read_range(self.data, 24, self.endian).unwrap_or((0, 0))
     }
 
     pub fn resource_costs(&self) -> (u32, u16) {
-        read_range(self.data, 32).unwrap_or((0, 0))
+        read_range(self.data, 32, self.endian).unwrap_or((0, 0))
     }
 
     pub fn resource_preconditions(&self) -> (u32, u16) {
-        read_range(self.data, 40).unwrap_or((0, 0))
+        read_range(self.data, 40, self.endian).unwrap_or((0, 0))
     }
 
     pub fn resource_deltas(&self) -> (u32, u16) {
-        read_range(self.data, 48).unwrap_or((0, 0))
+        read_range(self.data, 48, self.endian).unwrap_or((0, 0))
     }
 
     /// Get the input notation string reference (offset, length).
     pub fn input(&self) -> (u32, u16) {
-        read_range(self.data, 56).unwrap_or((0, 0))
+        read_range(self.data, 56, self.endian).unwrap_or((0, 0))
     }
 
     /// Get the cancel routes offset and length into CANCELS_U16.
     ///
     /// Returns (byte_offset, count) where count is the number of u16 target IDs.
     pub fn cancels(&self) -> (u32, u16) {
-        read_range(self.data, 64).unwrap_or((0, 0))
+        read_range(self.data, 64, self.endian).unwrap_or((0, 0))
     }
 }
 
@@ -174,12 +236,21 @@ impl<'a> StateExtrasRecordView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveNotifiesView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveNotifiesView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -198,6 +269,7 @@ impl<'a> MoveNotifiesView<'a> {
         }
         Some(MoveNotifyView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 
@@ -209,6 +281,7 @@ impl<'a> MoveNotifiesView<'a> {
         }
         Some(MoveNotifyView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -216,16 +289,17 @@ impl<'a> MoveNotifiesView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveNotifyView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveNotifyView<'a> {
     pub fn frame(&self) -> u16 {
-        read_u16_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u16(self.data, 0).unwrap_or(0)
     }
 
     pub fn emits(&self) -> (u32, u16) {
         // frame(u16) + pad(u16) => emits at offset 4
-        read_range(self.data, 4).unwrap_or((0, 0))
+        read_range(self.data, 4, self.endian).unwrap_or((0, 0))
     }
 }
 
@@ -233,12 +307,21 @@ impl<'a> MoveNotifyView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveResourceCostsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveResourceCostsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -257,6 +340,7 @@ impl<'a> MoveResourceCostsView<'a> {
         }
         Some(MoveResourceCostView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 
@@ -269,6 +353,7 @@ impl<'a> MoveResourceCostsView<'a> {
         }
         Some(MoveResourceCostView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -276,19 +361,20 @@ impl<'a> MoveResourceCostsView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveResourceCostView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveResourceCostView<'a> {
     pub fn name_off(&self) -> u32 {
-        read_u32_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u32(self.data, 0).unwrap_or(0)
     }
 
     pub fn name_len(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
     }
 
     pub fn amount(&self) -> u16 {
-        read_u16_le(self.data, 8).unwrap_or(0)
+        self.endian.read_u16(self.data, 8).unwrap_or(0)
     }
 }
 
@@ -296,12 +382,21 @@ impl<'a> MoveResourceCostView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveResourcePreconditionsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveResourcePreconditionsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -320,6 +415,7 @@ impl<'a> MoveResourcePreconditionsView<'a> {
         }
         Some(MoveResourcePreconditionView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 
@@ -336,6 +432,7 @@ impl<'a> MoveResourcePreconditionsView<'a> {
         }
         Some(MoveResourcePreconditionView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -343,23 +440,24 @@ impl<'a> MoveResourcePreconditionsView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveResourcePreconditionView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveResourcePreconditionView<'a> {
     pub fn name_off(&self) -> u32 {
-        read_u32_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u32(self.data, 0).unwrap_or(0)
     }
 
     pub fn name_len(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
     }
 
     pub fn min_raw(&self) -> u16 {
-        read_u16_le(self.data, 8).unwrap_or(OPT_U16_NONE)
+        self.endian.read_u16(self.data, 8).unwrap_or(OPT_U16_NONE)
     }
 
     pub fn max_raw(&self) -> u16 {
-        read_u16_le(self.data, 10).unwrap_or(OPT_U16_NONE)
+        self.endian.read_u16(self.data, 10).unwrap_or(OPT_U16_NONE)
     }
 
     pub fn min(&self) -> Option<u16> {
@@ -385,12 +483,21 @@ impl<'a> MoveResourcePreconditionView<'a> {
 #[derive(Clone, Copy)]
 pub struct MoveResourceDeltasView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveResourceDeltasView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     pub fn len(&self) -> usize {
@@ -409,6 +516,7 @@ impl<'a> MoveResourceDeltasView<'a> {
         }
         Some(MoveResourceDeltaView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 
@@ -421,29 +529,49 @@ impl<'a> MoveResourceDeltasView<'a> {
         }
         Some(MoveResourceDeltaView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
 
+/// Zero-copy view over a single resource delta record (16 bytes).
+///
+/// Layout:
+/// - 0-3: name_off (u32, resource name in the string table)
+/// - 4-5: name_len (u16)
+/// - 6-7: padding
+/// - 8-11: delta (i32)
+/// - 12: trigger (u8, RESOURCE_DELTA_TRIGGER_*)
+/// - 13-15: padding
 #[derive(Clone, Copy)]
 pub struct MoveResourceDeltaView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MoveResourceDeltaView<'a> {
+    /// Offset of the resource name in the string table.
     pub fn name_off(&self) -> u32 {
-        read_u32_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u32(self.data, 0).unwrap_or(0)
     }
 
+    /// Length of the resource name in bytes.
     pub fn name_len(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(0)
+        self.endian.read_u16(self.data, 4).unwrap_or(0)
     }
 
+    /// Signed change applied to the resource.
     pub fn delta(&self) -> i32 {
-        read_i32_le(self.data, 8).unwrap_or(0)
+        self.endian.read_i32(self.data, 8).unwrap_or(0)
     }
 
+    /// Raw trigger byte (RESOURCE_DELTA_TRIGGER_*).
     pub fn trigger(&self) -> u8 {
         read_u8(self.data, 12).unwrap_or(RESOURCE_DELTA_TRIGGER_ON_USE)
     }
+
+    /// Trigger as a [`ResourceTrigger`].
+    pub fn trigger_enum(&self) -> ResourceTrigger {
+        ResourceTrigger::from_u8(self.trigger())
+    }
 }
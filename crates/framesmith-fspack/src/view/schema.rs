@@ -3,7 +3,9 @@
 //! When a SECTION_SCHEMA is present, properties use schema IDs instead of
 //! embedded string references, reducing record size from 12 to 8 bytes.
 
-use crate::bytes::{read_i32_le, read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_bytes, read_u8, Endian};
+
+use super::PropValue;
 
 /// Schema-based property record size (8 bytes).
 /// Layout: schema_id(2) + value_type(1) + reserved(1) + value(4)
@@ -29,6 +31,7 @@ const STRREF_SIZE: usize = 8;
 #[derive(Clone, Copy)]
 pub struct SchemaView<'a> {
     data: &'a [u8],
+    endian: Endian,
     string_pool: &'a [u8],
     char_prop_count: u16,
     state_prop_count: u16,
@@ -42,16 +45,22 @@ impl<'a> SchemaView<'a> {
     /// * `data` - The SECTION_SCHEMA bytes
     /// * `string_pool` - The STRING_TABLE bytes for resolving names
     pub fn new(data: &'a [u8], string_pool: &'a [u8]) -> Option<Self> {
+        Self::new_with_endian(data, string_pool, Endian::Little)
+    }
+
+    /// Create a new schema view whose multi-byte fields are in `endian` order.
+    pub fn new_with_endian(data: &'a [u8], string_pool: &'a [u8], endian: Endian) -> Option<Self> {
         if data.len() < SCHEMA_HEADER_SIZE {
             return None;
         }
 
-        let char_prop_count = read_u16_le(data, 0)?;
-        let state_prop_count = read_u16_le(data, 2)?;
-        let tag_count = read_u16_le(data, 4)?;
+        let char_prop_count = endian.read_u16(data, 0)?;
+        let state_prop_count = endian.read_u16(data, 2)?;
+        let tag_count = endian.read_u16(data, 4)?;
 
         Some(Self {
             data,
+            endian,
             string_pool,
             char_prop_count,
             state_prop_count,
@@ -109,8 +118,8 @@ impl<'a> SchemaView<'a> {
         if off + STRREF_SIZE > self.data.len() {
             return None;
         }
-        let str_off = read_u32_le(self.data, off)? as usize;
-        let str_len = read_u16_le(self.data, off + 4)? as usize;
+        let str_off = self.endian.read_u32(self.data, off)? as usize;
+        let str_len = self.endian.read_u16(self.data, off + 4)? as usize;
 
         let end = str_off.checked_add(str_len)?;
         if end > self.string_pool.len() {
@@ -130,12 +139,13 @@ impl<'a> SchemaView<'a> {
 #[derive(Clone, Copy)]
 pub struct SchemaCharacterPropView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> SchemaCharacterPropView<'a> {
     /// Get the schema ID for looking up the property name.
     pub fn schema_id(&self) -> u16 {
-        read_u16_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u16(self.data, 0).unwrap_or(0)
     }
 
     /// Get the value type tag.
@@ -144,14 +154,15 @@ impl<'a> SchemaCharacterPropView<'a> {
         read_u8(self.data, 2).unwrap_or(0)
     }
 
-    /// Get the raw value as u32.
+    /// Get the raw value as u32, as it would read from a little-endian pack.
     pub fn value_raw(&self) -> u32 {
-        read_u32_le(self.data, 4).unwrap_or(0)
+        let value = read_bytes(self.data, 4, 4).unwrap_or_default();
+        PropValue::raw(self.value_type(), value, self.endian)
     }
 
     /// Interpret the value as Q24.8 fixed-point (signed).
     pub fn as_q24_8(&self) -> i32 {
-        read_i32_le(self.data, 4).unwrap_or(0)
+        self.endian.read_i32(self.data, 4).unwrap_or(0)
     }
 
     /// Interpret the value as boolean.
@@ -162,10 +173,16 @@ impl<'a> SchemaCharacterPropView<'a> {
     /// Interpret the value as a string reference (offset, length).
     /// The offset is stored in the lower 16 bits, length in the upper 16 bits.
     pub fn as_str_ref(&self) -> (u16, u16) {
-        let off = read_u16_le(self.data, 4).unwrap_or(0);
-        let len = read_u16_le(self.data, 6).unwrap_or(0);
+        let off = self.endian.read_u16(self.data, 4).unwrap_or(0);
+        let len = self.endian.read_u16(self.data, 6).unwrap_or(0);
         (off, len)
     }
+
+    /// Decode the value per its type tag, resolving strings in `strings`.
+    pub fn value(&self, strings: &'a [u8]) -> Option<PropValue<'a>> {
+        let value = read_bytes(self.data, 4, 4)?;
+        PropValue::decode(self.value_type(), value, strings, self.endian)
+    }
 }
 
 /// Zero-copy view over schema-based character properties.
@@ -174,12 +191,21 @@ impl<'a> SchemaCharacterPropView<'a> {
 #[derive(Clone, Copy)]
 pub struct SchemaCharacterPropsView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> SchemaCharacterPropsView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the number of character properties.
@@ -203,6 +229,7 @@ impl<'a> SchemaCharacterPropsView<'a> {
         }
         Some(SchemaCharacterPropView {
             data: &self.data[off..end],
+            endian: self.endian,
         })
     }
 
@@ -210,4 +237,23 @@ impl<'a> SchemaCharacterPropsView<'a> {
     pub fn iter(&self) -> impl Iterator<Item = SchemaCharacterPropView<'a>> + '_ {
         (0..self.len()).filter_map(|i| self.get(i))
     }
+
+    /// Iterate `(name, value)` pairs, naming each property from the schema's
+    /// character property list and resolving string values in `strings`.
+    ///
+    /// Properties whose schema ID or value can't be resolved are skipped.
+    pub fn entries(
+        &self,
+        schema: SchemaView<'a>,
+        strings: &'a [u8],
+    ) -> impl Iterator<Item = (&'a str, PropValue<'a>)> + 'a {
+        let view = *self;
+        (0..self.len()).filter_map(move |i| {
+            let prop = view.get(i)?;
+            Some((
+                schema.char_prop_name(prop.schema_id())?,
+                prop.value(strings)?,
+            ))
+        })
+    }
 }
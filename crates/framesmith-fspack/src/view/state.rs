@@ -1,6 +1,6 @@
 //! State, mesh keys, and keyframes views.
 
-use crate::bytes::{read_u16_le, read_u32_le, read_u8};
+use crate::bytes::{read_u8, Endian};
 
 /// String reference size: off(4) + len(2) + pad(2)
 pub const STRREF_SIZE: usize = 8;
@@ -11,18 +11,33 @@ pub const STATE_RECORD_SIZE: usize = 36;
 /// Sentinel value for "no mesh" or "no keyframes" (u16::MAX)
 pub const KEY_NONE: u16 = 0xFFFF;
 
+/// State flag: the state is authored as airborne.
+pub const STATE_FLAG_AIRBORNE: u8 = 0x20;
+
+/// State flag: the state can be entered during hitstun or blockstun (bursts, alpha counters).
+pub const STATE_FLAG_ALLOW_FROM_STUN: u8 = 0x40;
+
 /// Zero-copy view over the mesh keys section.
 ///
 /// Each entry is a StrRef (8 bytes): off(4) + len(2) + pad(2)
 #[derive(Clone, Copy)]
 pub struct MeshKeysView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> MeshKeysView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the number of mesh keys in this section.
@@ -43,8 +58,8 @@ impl<'a> MeshKeysView<'a> {
         if base + STRREF_SIZE > self.data.len() {
             return None;
         }
-        let off = read_u32_le(self.data, base)?;
-        let len = read_u16_le(self.data, base + 4)?;
+        let off = self.endian.read_u32(self.data, base)?;
+        let len = self.endian.read_u16(self.data, base + 4)?;
         Some((off, len))
     }
 }
@@ -55,12 +70,21 @@ impl<'a> MeshKeysView<'a> {
 #[derive(Clone, Copy)]
 pub struct KeyframesKeysView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> KeyframesKeysView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the number of keyframes keys in this section.
@@ -81,8 +105,8 @@ impl<'a> KeyframesKeysView<'a> {
         if base + STRREF_SIZE > self.data.len() {
             return None;
         }
-        let off = read_u32_le(self.data, base)?;
-        let len = read_u16_le(self.data, base + 4)?;
+        let off = self.endian.read_u32(self.data, base)?;
+        let len = self.endian.read_u16(self.data, base + 4)?;
         Some((off, len))
     }
 }
@@ -93,12 +117,21 @@ impl<'a> KeyframesKeysView<'a> {
 #[derive(Clone, Copy)]
 pub struct StatesView<'a> {
     data: &'a [u8],
+    endian: Endian,
 }
 
 impl<'a> StatesView<'a> {
     /// Create a new view from raw bytes.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
     }
 
     /// Returns the number of states in this section.
@@ -122,6 +155,7 @@ impl<'a> StatesView<'a> {
         }
         Some(StateView {
             data: &self.data[base..end],
+            endian: self.endian,
         })
     }
 }
@@ -135,17 +169,17 @@ impl<'a> StatesView<'a> {
 /// - 6: state_type (u8)
 /// - 7: trigger (u8)
 /// - 8: guard (u8)
-/// - 9: flags (u8)
+/// - 9: flags (u8, STATE_FLAG_*)
 /// - 10: startup (u8)
 /// - 11: active (u8)
 /// - 12: recovery (u8)
-/// - 13: reserved (u8)
+/// - 13: kara_start (u8)
 /// - 14-15: total (u16)
 /// - 16-17: damage (u16)
 /// - 18: hitstun (u8)
 /// - 19: blockstun (u8)
 /// - 20: hitstop (u8)
-/// - 21: reserved (u8)
+/// - 21: kara_frames (u8, 0 = no kara window)
 /// - 22-25: hit_windows_off (u32)
 /// - 26-27: hit_windows_len (u16)
 /// - 28-29: hurt_windows_off (u16)
@@ -155,6 +189,59 @@ impl<'a> StatesView<'a> {
 #[derive(Clone, Copy)]
 pub struct StateView<'a> {
     data: &'a [u8],
+    endian: Endian,
+}
+
+/// Decoded `state_type` byte of a state record.
+///
+/// Known IDs are stable; custom move types export as `Unknown(255)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateType {
+    Normal,
+    CommandNormal,
+    Special,
+    Super,
+    Movement,
+    Throw,
+    Ex,
+    Rekka,
+    /// Engine-driven states (idle, crouch, hitstun, ...).
+    System,
+    Unknown(u8),
+}
+
+impl StateType {
+    /// Decode a raw `state_type` byte.
+    pub fn from_u8(raw: u8) -> Self {
+        match raw {
+            0 => Self::Normal,
+            1 => Self::CommandNormal,
+            2 => Self::Special,
+            3 => Self::Super,
+            4 => Self::Movement,
+            5 => Self::Throw,
+            6 => Self::Ex,
+            7 => Self::Rekka,
+            8 => Self::System,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// Encode back to the raw `state_type` byte.
+    pub fn to_u8(self) -> u8 {
+        match self {
+            Self::Normal => 0,
+            Self::CommandNormal => 1,
+            Self::Special => 2,
+            Self::Super => 3,
+            Self::Movement => 4,
+            Self::Throw => 5,
+            Self::Ex => 6,
+            Self::Rekka => 7,
+            Self::System => 8,
+            Self::Unknown(raw) => raw,
+        }
+    }
 }
 
 /// Decoded cancel flags from state flags byte.
@@ -167,20 +254,37 @@ pub struct CancelFlags {
     pub self_gatling: bool,
 }
 
+/// A state's input and core frame data, as yielded by
+/// [`PackView::state_summaries`](crate::PackView::state_summaries).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StateSummary<'a> {
+    /// Index in the states array.
+    pub index: usize,
+    /// Input notation (e.g. `"5L"`), if the pack has state extras and it resolves.
+    pub input: Option<&'a str>,
+    pub startup: u8,
+    pub active: u8,
+    pub recovery: u8,
+    pub total: u16,
+    pub damage: u16,
+    /// Guard type byte (see [`StateView::guard`]).
+    pub guard: u8,
+}
+
 impl<'a> StateView<'a> {
     /// Returns the state ID (index in the states array).
     pub fn state_id(&self) -> u16 {
-        read_u16_le(self.data, 0).unwrap_or(0)
+        self.endian.read_u16(self.data, 0).unwrap_or(0)
     }
 
     /// Returns the mesh key index, or KEY_NONE (0xFFFF) if no mesh.
     pub fn mesh_key(&self) -> u16 {
-        read_u16_le(self.data, 2).unwrap_or(KEY_NONE)
+        self.endian.read_u16(self.data, 2).unwrap_or(KEY_NONE)
     }
 
     /// Returns the keyframes key index, or KEY_NONE (0xFFFF) if no keyframes.
     pub fn keyframes_key(&self) -> u16 {
-        read_u16_le(self.data, 4).unwrap_or(KEY_NONE)
+        self.endian.read_u16(self.data, 4).unwrap_or(KEY_NONE)
     }
 
     /// Returns the state type.
@@ -188,6 +292,11 @@ impl<'a> StateView<'a> {
         read_u8(self.data, 6).unwrap_or(0)
     }
 
+    /// Returns the state type decoded as a [`StateType`].
+    pub fn state_type_enum(&self) -> StateType {
+        StateType::from_u8(self.state_type())
+    }
+
     /// Returns the trigger type.
     pub fn trigger(&self) -> u8 {
         read_u8(self.data, 7).unwrap_or(0)
@@ -203,6 +312,16 @@ impl<'a> StateView<'a> {
         read_u8(self.data, 9).unwrap_or(0)
     }
 
+    /// Returns true if the state is airborne (`STATE_FLAG_AIRBORNE`).
+    pub fn is_airborne(&self) -> bool {
+        self.flags() & STATE_FLAG_AIRBORNE != 0
+    }
+
+    /// Returns true if the state can be entered while stunned (`STATE_FLAG_ALLOW_FROM_STUN`).
+    pub fn allows_from_stun(&self) -> bool {
+        self.flags() & STATE_FLAG_ALLOW_FROM_STUN != 0
+    }
+
     /// Decode cancel flags from the flags byte.
     pub fn cancel_flags(&self) -> CancelFlags {
         let f = self.flags();
@@ -232,12 +351,12 @@ impl<'a> StateView<'a> {
 
     /// Returns the total frame count.
     pub fn total(&self) -> u16 {
-        read_u16_le(self.data, 14).unwrap_or(0)
+        self.endian.read_u16(self.data, 14).unwrap_or(0)
     }
 
     /// Returns the damage value.
     pub fn damage(&self) -> u16 {
-        read_u16_le(self.data, 16).unwrap_or(0)
+        self.endian.read_u16(self.data, 16).unwrap_or(0)
     }
 
     /// Returns the hitstun frames.
@@ -255,35 +374,45 @@ impl<'a> StateView<'a> {
         read_u8(self.data, 20).unwrap_or(0)
     }
 
+    /// Returns the kara window as inclusive `(start, end)` frames, or `None`
+    /// if the state can't cancel back to idle.
+    ///
+    /// Stored as a start frame (byte 13) and frame count (byte 21).
+    pub fn kara_window(&self) -> Option<(u8, u8)> {
+        let start = read_u8(self.data, 13)?;
+        let frames = read_u8(self.data, 21).filter(|&n| n > 0)?;
+        Some((start, start.saturating_add(frames - 1)))
+    }
+
     /// Returns the byte offset within the HIT_WINDOWS section.
     pub fn hit_windows_off(&self) -> u32 {
-        read_u32_le(self.data, 22).unwrap_or(0)
+        self.endian.read_u32(self.data, 22).unwrap_or(0)
     }
 
     /// Returns the hit windows count.
     pub fn hit_windows_len(&self) -> u16 {
-        read_u16_le(self.data, 26).unwrap_or(0)
+        self.endian.read_u16(self.data, 26).unwrap_or(0)
     }
 
     /// Returns the byte offset within the HURT_WINDOWS section.
     ///
     /// Note: stored as u16 for compact layout.
     pub fn hurt_windows_off(&self) -> u16 {
-        read_u16_le(self.data, 28).unwrap_or(0)
+        self.endian.read_u16(self.data, 28).unwrap_or(0)
     }
 
     /// Returns the hurt windows count.
     pub fn hurt_windows_len(&self) -> u16 {
-        read_u16_le(self.data, 30).unwrap_or(0)
+        self.endian.read_u16(self.data, 30).unwrap_or(0)
     }
 
     /// Returns the byte offset within the PUSH_WINDOWS section.
     pub fn push_windows_off(&self) -> u16 {
-        read_u16_le(self.data, 32).unwrap_or(0)
+        self.endian.read_u16(self.data, 32).unwrap_or(0)
     }
 
     /// Returns the push windows count.
     pub fn push_windows_len(&self) -> u16 {
-        read_u16_le(self.data, 34).unwrap_or(0)
+        self.endian.read_u16(self.data, 34).unwrap_or(0)
     }
 }
@@ -0,0 +1,58 @@
+//! Per-section record counts.
+
+use super::{
+    PackView, CANCEL_DENY_SIZE, CANCEL_TAG_RULE_SIZE, EVENT_ARG_SIZE, EVENT_EMIT_SIZE,
+    HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, MOVE_NOTIFY_SIZE, PUSH_WINDOW_SIZE, RESOURCE_DEF_SIZE,
+    SECTION_CANCELS_U16, SECTION_CANCEL_DENIES, SECTION_CANCEL_TAG_RULES, SECTION_EVENT_ARGS,
+    SECTION_EVENT_EMITS, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS, SECTION_KEYFRAMES_KEYS,
+    SECTION_MESH_KEYS, SECTION_MOVE_NOTIFIES, SECTION_PUSH_WINDOWS, SECTION_RESOURCE_DEFS,
+    SECTION_SHAPES, SECTION_STATES, SHAPE_SIZE, STATE_RECORD_SIZE, STRREF_SIZE,
+};
+
+/// Record counts of the fixed-size sections, as returned by [`PackView::stats`].
+///
+/// Missing sections count as 0. Each count matches the `len()` of the
+/// corresponding typed view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PackStats {
+    pub states: usize,
+    pub hit_windows: usize,
+    pub hurt_windows: usize,
+    pub push_windows: usize,
+    pub shapes: usize,
+    pub cancels: usize,
+    pub cancel_tag_rules: usize,
+    pub cancel_denies: usize,
+    pub event_emits: usize,
+    pub event_args: usize,
+    pub move_notifies: usize,
+    pub resource_defs: usize,
+    pub mesh_keys: usize,
+    pub keyframes_keys: usize,
+}
+
+impl PackView<'_> {
+    /// Count the records in each fixed-size section.
+    ///
+    /// Only reads the section table, so it is cheaper than building every
+    /// typed view to ask for its `len()`.
+    pub fn stats(&self) -> PackStats {
+        let count = |kind: u32, size: usize| self.section_len(kind).map_or(0, |len| len / size);
+        PackStats {
+            states: count(SECTION_STATES, STATE_RECORD_SIZE),
+            hit_windows: count(SECTION_HIT_WINDOWS, HIT_WINDOW_SIZE),
+            hurt_windows: count(SECTION_HURT_WINDOWS, HURT_WINDOW_SIZE),
+            push_windows: count(SECTION_PUSH_WINDOWS, PUSH_WINDOW_SIZE),
+            shapes: count(SECTION_SHAPES, SHAPE_SIZE),
+            cancels: count(SECTION_CANCELS_U16, 2),
+            cancel_tag_rules: count(SECTION_CANCEL_TAG_RULES, CANCEL_TAG_RULE_SIZE),
+            cancel_denies: count(SECTION_CANCEL_DENIES, CANCEL_DENY_SIZE),
+            event_emits: count(SECTION_EVENT_EMITS, EVENT_EMIT_SIZE),
+            event_args: count(SECTION_EVENT_ARGS, EVENT_ARG_SIZE),
+            move_notifies: count(SECTION_MOVE_NOTIFIES, MOVE_NOTIFY_SIZE),
+            resource_defs: count(SECTION_RESOURCE_DEFS, RESOURCE_DEF_SIZE),
+            mesh_keys: count(SECTION_MESH_KEYS, STRREF_SIZE),
+            keyframes_keys: count(SECTION_KEYFRAMES_KEYS, STRREF_SIZE),
+        }
+    }
+}
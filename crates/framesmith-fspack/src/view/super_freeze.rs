@@ -0,0 +1,84 @@
+//! Per-state super freeze views.
+
+use crate::bytes::{read_u8, Endian};
+use crate::fixed::Q8_8;
+
+/// StateSuperFreeze record size (6 bytes)
+pub const STATE_SUPER_FREEZE_SIZE: usize = 6;
+
+/// Super freeze flag: flash the screen when the freeze starts.
+pub const SUPER_FREEZE_FLAG_FLASH: u8 = 0x01;
+
+/// Zero-copy view over the state super freeze section.
+///
+/// Each entry is a StateSuperFreeze6 (6 bytes), parallel to STATES.
+#[derive(Clone, Copy)]
+pub struct StateSuperFreezesView<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl<'a> StateSuperFreezesView<'a> {
+    /// Create a new view from raw bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            endian: Endian::Little,
+        }
+    }
+
+    /// Read multi-byte fields in `endian` order.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self { endian, ..self }
+    }
+
+    /// Returns the number of super freeze records (one per state).
+    pub fn len(&self) -> usize {
+        self.data.len() / STATE_SUPER_FREEZE_SIZE
+    }
+
+    /// Returns true if there are no super freeze records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the super freeze for the given state index.
+    ///
+    /// Layout:
+    /// - 0: frames (u8, 0 = no super freeze)
+    /// - 1: flags (u8, SUPER_FREEZE_FLAG_*)
+    /// - 2-3: zoom (i16, Q8.8, 1.0 = no zoom)
+    /// - 4: darken (u8, 0 = none, 255 = black)
+    /// - 5: reserved (u8)
+    ///
+    /// Returns `None` if the index is out of bounds or the state has no
+    /// super freeze.
+    pub fn get(&self, index: usize) -> Option<SuperFreezeData> {
+        let off = index.checked_mul(STATE_SUPER_FREEZE_SIZE)?;
+        let end = off.checked_add(STATE_SUPER_FREEZE_SIZE)?;
+        let data = self.data.get(off..end)?;
+        let frames = read_u8(data, 0)?;
+        if frames == 0 {
+            return None;
+        }
+        Some(SuperFreezeData {
+            frames,
+            zoom: Q8_8::from_raw(self.endian.read_i16(data, 2)?),
+            darken: read_u8(data, 4)?,
+            flash: read_u8(data, 1)? & SUPER_FREEZE_FLAG_FLASH != 0,
+        })
+    }
+}
+
+/// A state's super freeze: the pause and screen effect when a super starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuperFreezeData {
+    /// Frames both characters stay frozen.
+    pub frames: u8,
+    /// Camera zoom during the freeze ([`Q8_8::ONE`] = no zoom).
+    pub zoom: Q8_8,
+    /// How far the background darkens, from 0 (none) to 255 (black).
+    pub darken: u8,
+    /// Whether the screen flashes when the freeze starts.
+    pub flash: bool,
+}
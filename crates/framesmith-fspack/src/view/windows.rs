@@ -0,0 +1,61 @@
+//! Per-state window resolution.
+
+use super::{
+    HitWindowView, HitWindowsView, HurtWindowView, HurtWindowsView, PushWindowView,
+    PushWindowsView, StateView,
+};
+
+/// The hit, hurt, and push windows belonging to one state.
+///
+/// Built by [`PackView::state_windows`](super::PackView::state_windows); each
+/// iterator is already resolved through the state's offset/length fields.
+/// A missing section yields an empty iterator.
+#[derive(Clone, Copy)]
+pub struct StateWindows<'a> {
+    state: StateView<'a>,
+    hit: Option<HitWindowsView<'a>>,
+    hurt: Option<HurtWindowsView<'a>>,
+    push: Option<PushWindowsView<'a>>,
+}
+
+impl<'a> StateWindows<'a> {
+    pub(super) fn new(
+        state: StateView<'a>,
+        hit: Option<HitWindowsView<'a>>,
+        hurt: Option<HurtWindowsView<'a>>,
+        push: Option<PushWindowsView<'a>>,
+    ) -> Self {
+        Self {
+            state,
+            hit,
+            hurt,
+            push,
+        }
+    }
+
+    /// The state these windows belong to.
+    pub fn state(&self) -> StateView<'a> {
+        self.state
+    }
+
+    /// Iterate the state's hit windows in authored order.
+    pub fn hit_windows(&self) -> impl Iterator<Item = HitWindowView<'a>> + 'a {
+        let hit = self.hit;
+        let off = self.state.hit_windows_off();
+        (0..self.state.hit_windows_len() as usize).filter_map(move |i| hit?.get_at(off, i))
+    }
+
+    /// Iterate the state's hurt windows in authored order.
+    pub fn hurt_windows(&self) -> impl Iterator<Item = HurtWindowView<'a>> + 'a {
+        let hurt = self.hurt;
+        let off = self.state.hurt_windows_off();
+        (0..self.state.hurt_windows_len() as usize).filter_map(move |i| hurt?.get_at(off, i))
+    }
+
+    /// Iterate the state's push windows in authored order.
+    pub fn push_windows(&self) -> impl Iterator<Item = PushWindowView<'a>> + 'a {
+        let push = self.push;
+        let off = self.state.push_windows_off();
+        (0..self.state.push_windows_len() as usize).filter_map(move |i| push?.get_at(off, i))
+    }
+}
@@ -3,13 +3,19 @@
 //! This crate provides a high-level `TrainingSession` API for running
 //! character simulations in the browser.
 
-use framesmith_fspack::PackView;
+use framesmith_fspack::{
+    PackBuf, PackView, ShapeView, StateType, SHAPE_KIND_AABB, SHAPE_KIND_CAPSULE,
+    SHAPE_KIND_CIRCLE, SHAPE_KIND_RECT,
+};
 use framesmith_runtime::{
-    available_cancels, check_hits, check_pushbox, init_resources, next_frame,
-    CharacterState as RtCharacterState, FrameInput, HitResult as RtHitResult,
-    PushboxResult as RtPushboxResult,
+    apply_resource_costs, available_cancels, horizontal_distance, init_resources,
+    is_move_available, resource, resource_index, state_type_priority, step_exchange,
+    CharacterState as RtCharacterState, FrameClock as RtFrameClock, FrameInput,
+    HitResult as RtHitResult, MoveContext, PushboxResult as RtPushboxResult, Rng, TradePolicy,
+    DEFAULT_BUFFER_FRAMES, DEFAULT_FRAME_RATE, MAX_RESOURCES,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use wasm_bindgen::prelude::*;
 
 /// Convert Q24.8 fixed-point to f64.
@@ -22,6 +28,27 @@ fn from_q24_8(raw: i32) -> f64 {
 /// Property value type constant for Q24.8 numeric properties.
 const PROP_TYPE_Q24_8: u8 = 0;
 
+/// Ticks `step_back` can undo until `set_history_depth` is called.
+const DEFAULT_HISTORY_DEPTH: usize = 120;
+
+/// Extra hitstun frames a counter-hit adds on top of the hit window's hitstun.
+///
+/// The bonus lengthens the session's own stun count (`dummy_stun`), which ends
+/// combos and `BlockAfterFirstHit` blocking. The runtime never puts the dummy
+/// into hitstun, so its `instance_duration` is unchanged.
+const COUNTER_HIT_BONUS_HITSTUN: u8 = 4;
+
+/// Damage percentage each combo hit loses compared to the one before it, for a
+/// pack without a numeric `combo_scaling_step` property.
+const DEFAULT_COMBO_SCALING_STEP_PERCENT: u32 = 10;
+
+/// Lowest damage percentage combo scaling goes down to, for a pack without a
+/// numeric `combo_scaling_min` property.
+const DEFAULT_MIN_COMBO_SCALING_PERCENT: u32 = 10;
+
+/// Starting health for a character whose pack has no numeric `health` property.
+const DEFAULT_HEALTH: u32 = 1000;
+
 /// Dummy behavior states for training mode.
 #[wasm_bindgen]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -33,8 +60,53 @@ pub enum DummyState {
     BlockStand,
     BlockCrouch,
     BlockAuto,
+    /// Pick one of the other behaviors using the session's seeded PRNG, and
+    /// keep it until the dummy recovers from the next hit or block.
+    Random,
+    /// Stand open until a hit lands, then block every hit until the dummy's
+    /// hitstun or blockstun runs out.
+    BlockAfterFirstHit,
+}
+
+impl DummyState {
+    /// Whether the dummy blocks a non-throw hit, given whether it is still
+    /// reeling from an earlier one.
+    fn blocks(self, in_stun: bool) -> bool {
+        match self {
+            DummyState::BlockStand | DummyState::BlockCrouch | DummyState::BlockAuto => true,
+            DummyState::BlockAfterFirstHit => in_stun,
+            DummyState::Stand | DummyState::Crouch | DummyState::Jump | DummyState::Random => false,
+        }
+    }
+}
+
+/// How a tick resolves the player and dummy hitting each other on the same frame.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeMode {
+    /// Both hits land.
+    #[default]
+    Trade,
+    /// Neither hit lands.
+    Clash,
+    /// The hit from the higher state type (super > EX > special > other,
+    /// as of the start of the tick) lands alone; equal types trade.
+    Priority,
 }
 
+/// Behaviors `DummyState::Random` chooses between.
+const RANDOM_DUMMY_CHOICES: [DummyState; 6] = [
+    DummyState::Stand,
+    DummyState::Crouch,
+    DummyState::Jump,
+    DummyState::BlockStand,
+    DummyState::BlockCrouch,
+    DummyState::BlockAuto,
+];
+
+/// Seed used until `TrainingSession::set_seed` is called.
+const DEFAULT_SEED: u32 = 1;
+
 /// Character state exposed to JavaScript.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CharacterState {
@@ -43,6 +115,9 @@ pub struct CharacterState {
     pub hit_confirmed: bool,
     pub block_confirmed: bool,
     pub resources: Vec<u32>,
+    pub grounded: bool,
+    /// Remaining hitstop frames; the character holds its frame until 0.
+    pub hitstop: u32,
 }
 
 impl From<&RtCharacterState> for CharacterState {
@@ -53,8 +128,93 @@ impl From<&RtCharacterState> for CharacterState {
             hit_confirmed: state.hit_confirmed,
             block_confirmed: state.block_confirmed,
             resources: state.resources.iter().map(|&r| r as u32).collect(),
+            grounded: state.grounded,
+            hitstop: u32::from(state.hitstop),
+        }
+    }
+}
+
+/// Resource definition exposed to JavaScript.
+///
+/// Listed in the same order as `CharacterState::resources`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResourceInfo {
+    pub name: String,
+    pub max: u32,
+}
+
+/// Names and maxima of the resources a pack defines, in slot order.
+fn resource_infos(pack: &PackView) -> Vec<ResourceInfo> {
+    let Some(defs) = pack.resource_defs() else {
+        return Vec::new();
+    };
+    (0..defs.len().min(MAX_RESOURCES))
+        .filter_map(|i| defs.get(i))
+        .map(|def| ResourceInfo {
+            name: pack
+                .string(def.name_off(), def.name_len())
+                .unwrap_or_default()
+                .to_string(),
+            max: def.max() as u32,
+        })
+        .collect()
+}
+
+/// Look up a numeric (Q24.8) character property by name.
+///
+/// Returns None if the property doesn't exist or is a bool or string.
+fn numeric_property(pack: &PackView, name: &str) -> Option<f64> {
+    let props = pack.character_props()?;
+
+    for i in 0..props.len() {
+        let prop = props.get(i)?;
+        let (off, len) = prop.name();
+        let prop_name = pack.string(off, len)?;
+        if prop_name == name {
+            // Only return numeric (Q24.8) properties
+            if prop.value_type() == PROP_TYPE_Q24_8 {
+                return Some(from_q24_8(prop.as_q24_8()));
+            }
+            return None;
         }
     }
+    None
+}
+
+/// Health a character starts with: its `health` property, or [`DEFAULT_HEALTH`].
+fn starting_health(pack: &PackView) -> u32 {
+    numeric_property(pack, "health").map_or(DEFAULT_HEALTH, |health| health.max(0.0) as u32)
+}
+
+/// How much later hits of a combo are scaled down, from the attacker's pack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ComboScaling {
+    /// Damage percentage each hit loses compared to the one before it.
+    step_percent: u32,
+    /// Lowest damage percentage a hit goes down to.
+    min_percent: u32,
+}
+
+impl ComboScaling {
+    /// Read the `combo_scaling_step` and `combo_scaling_min` percentages from
+    /// `pack`, defaulting to 10% each.
+    fn from_pack(pack: &PackView) -> Self {
+        let percent = |name, default| {
+            numeric_property(pack, name).map_or(default, |percent: f64| percent.max(0.0) as u32)
+        };
+        ComboScaling {
+            step_percent: percent("combo_scaling_step", DEFAULT_COMBO_SCALING_STEP_PERCENT),
+            min_percent: percent("combo_scaling_min", DEFAULT_MIN_COMBO_SCALING_PERCENT),
+        }
+    }
+
+    /// Damage a hit deals after `hits` earlier hits of the same combo.
+    fn scale(self, damage: u16, hits: u32) -> u32 {
+        let percent = 100u32
+            .saturating_sub(self.step_percent.saturating_mul(hits))
+            .max(self.min_percent);
+        u32::from(damage) * percent / 100
+    }
 }
 
 /// Hit result exposed to JavaScript.
@@ -70,6 +230,14 @@ pub struct HitResult {
     pub guard: u32,
     pub hit_pushback: i32,
     pub block_pushback: i32,
+    pub is_throw: bool,
+    pub wall_bounce: bool,
+    pub ground_bounce: bool,
+    pub launch: i32,
+    /// The defender was committed to a move of their own when the hit landed.
+    pub counter_hit: bool,
+    /// The dummy blocked the hit (see `DummyState`); always false for the player.
+    pub blocked: bool,
 }
 
 impl From<&RtHitResult> for HitResult {
@@ -85,10 +253,92 @@ impl From<&RtHitResult> for HitResult {
             guard: hit.guard as u32,
             hit_pushback: hit.hit_pushback,
             block_pushback: hit.block_pushback,
+            is_throw: hit.is_throw,
+            wall_bounce: hit.wall_bounce,
+            ground_bounce: hit.ground_bounce,
+            launch: hit.launch,
+            counter_hit: false,
+            blocked: false,
+        }
+    }
+}
+
+impl From<&TickHit> for HitResult {
+    fn from(tick_hit: &TickHit) -> Self {
+        HitResult {
+            counter_hit: tick_hit.counter_hit,
+            blocked: tick_hit.blocked,
+            ..HitResult::from(&tick_hit.hit)
         }
     }
 }
 
+/// A hit landed during a tick, tagged with whether it was a counter-hit or blocked.
+///
+/// Counter-hits already include [`COUNTER_HIT_BONUS_HITSTUN`] in `hit.hitstun`.
+#[derive(Clone, Copy, Debug)]
+struct TickHit {
+    hit: RtHitResult,
+    counter_hit: bool,
+    blocked: bool,
+}
+
+impl TickHit {
+    /// Tag `hit`, applying the counter-hit bonus when the defender was committed.
+    /// Throws can't be blocked; blocked hits and throws never counter-hit.
+    fn new(
+        mut hit: RtHitResult,
+        defender: &RtCharacterState,
+        defender_pack: &PackView,
+        blocking: bool,
+    ) -> Self {
+        let blocked = blocking && !hit.is_throw;
+        let counter_hit = !hit.is_throw && !blocked && is_committed(defender, defender_pack);
+        if counter_hit {
+            hit.hitstun = hit.hitstun.saturating_add(COUNTER_HIT_BONUS_HITSTUN);
+        }
+        TickHit {
+            hit,
+            counter_hit,
+            blocked,
+        }
+    }
+
+    /// Health the defender loses before combo scaling: chip damage when
+    /// blocked, full damage otherwise.
+    fn health_damage(&self) -> u32 {
+        if self.blocked {
+            u32::from(self.hit.chip_damage)
+        } else {
+            u32::from(self.hit.damage)
+        }
+    }
+
+    /// Frames of hitstun or blockstun this hit puts the defender in.
+    fn stun(&self) -> u8 {
+        if self.blocked {
+            self.hit.blockstun
+        } else {
+            self.hit.hitstun
+        }
+    }
+}
+
+/// Whether the character is in the startup, active or recovery frames of a move.
+///
+/// `step` judges the defender's state from before the tick's exchange, so a
+/// move that ends on the frame it's hit still counts.
+///
+/// System states (idle, crouch, walk) are actionable, and so are timed
+/// instances such as hitstun or blockstun: neither can be counter-hit.
+fn is_committed(state: &RtCharacterState, pack: &PackView) -> bool {
+    state.instance_duration == 0
+        && pack
+            .states()
+            .and_then(|states| states.get(state.current_state as usize))
+            .is_some_and(|s| s.state_type_enum() != StateType::System)
+}
+
 /// Push separation result exposed to JavaScript.
 /// Contains the (dx, dy) separation values if characters are overlapping.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -117,6 +367,192 @@ pub struct FrameResult {
     /// Push separation values if characters' pushboxes are overlapping.
     /// None if there is no overlap.
     pub push_separation: Option<PushSeparation>,
+    /// Either character's health is at 0.
+    ///
+    /// Health never regenerates, so this stays set on later ticks until
+    /// `reset()`, `set_infinite_health(true)` or a `step_back()` to before
+    /// the KO restores it.
+    pub ko: bool,
+}
+
+/// A collision shape in world pixels, exposed to JavaScript.
+///
+/// Rects keep their raw Q8.8 angle; unknown kinds are omitted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BoxShape {
+    Aabb {
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+    },
+    Rect {
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+        angle_raw: i16,
+    },
+    Circle {
+        x: i32,
+        y: i32,
+        r: u32,
+    },
+    Capsule {
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        r: u32,
+    },
+}
+
+impl BoxShape {
+    /// Place a pack shape at a character's position, mirroring it around
+    /// `px` when `facing` is negative.
+    fn from_view(shape: &ShapeView, (px, py): (i32, i32), facing: i8) -> Option<Self> {
+        let mirror = facing < 0;
+        let point_x = |x: i32| px + if mirror { -x } else { x };
+        // Left edge of an extent `x..x + w`, which ends up at `-(x + w)` mirrored.
+        let left_x = |x: i32, w: u32| point_x(if mirror { x + w as i32 } else { x });
+        let (w, h) = (shape.width_px(), shape.height_px());
+        let y = shape.y_px() + py;
+        match shape.kind() {
+            SHAPE_KIND_AABB => Some(Self::Aabb {
+                x: left_x(shape.x_px(), w),
+                y,
+                w,
+                h,
+            }),
+            SHAPE_KIND_RECT => Some(Self::Rect {
+                x: left_x(shape.x_px(), w),
+                y,
+                w,
+                h,
+                angle_raw: if mirror {
+                    shape.e_raw().wrapping_neg()
+                } else {
+                    shape.e_raw()
+                },
+            }),
+            SHAPE_KIND_CIRCLE => Some(Self::Circle {
+                x: point_x(shape.x_px()),
+                y,
+                r: shape.radius_px(),
+            }),
+            SHAPE_KIND_CAPSULE => Some(Self::Capsule {
+                x1: point_x(shape.x_fixed().to_int()),
+                y1: shape.y_fixed().to_int() + py,
+                x2: point_x(shape.x2_fixed().to_int()),
+                y2: shape.y2_fixed().to_int() + py,
+                r: shape.radius_fixed().to_int().max(0) as u32,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Hit and hurt shapes active on a character's current frame.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActiveBoxes {
+    pub hit: Vec<BoxShape>,
+    pub hurt: Vec<BoxShape>,
+}
+
+/// Move a position by a world-space delta.
+fn offset(pos: (i32, i32), delta: (i32, i32)) -> (i32, i32) {
+    (pos.0.saturating_add(delta.0), pos.1.saturating_add(delta.1))
+}
+
+/// Collect the shapes of every hit and hurt window active on `state`'s frame.
+fn active_boxes(
+    state: &RtCharacterState,
+    pack: &PackView,
+    pos: (i32, i32),
+    facing: i8,
+) -> ActiveBoxes {
+    let mut boxes = ActiveBoxes::default();
+    let Some(windows) = pack.state_windows(state.current_state as usize) else {
+        return boxes;
+    };
+    let frame = state.frame;
+    let shapes = |shapes: &mut dyn Iterator<Item = ShapeView<'_>>| -> Vec<BoxShape> {
+        shapes
+            .filter_map(|s| BoxShape::from_view(&s, pos, facing))
+            .collect()
+    };
+    for hw in windows.hit_windows() {
+        if (hw.start_frame()..=hw.end_frame()).contains(&frame) {
+            boxes.hit.extend(shapes(&mut hw.shapes(pack)));
+        }
+    }
+    for hw in windows.hurt_windows() {
+        if (hw.start_frame()..=hw.end_frame()).contains(&frame) {
+            boxes.hurt.extend(shapes(&mut hw.shapes(pack)));
+        }
+    }
+    boxes
+}
+
+/// Stats of one combo on the dummy, exposed to JavaScript.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComboSummary {
+    /// Unblocked hits landed before the dummy recovered.
+    pub hits: u32,
+    /// Damage after combo scaling: by default each hit deals 10% less than the
+    /// one before, down to 10% of its damage.
+    pub total_damage: u32,
+    /// Most hits in any combo since the session was created or reset.
+    pub max_combo: u32,
+}
+
+/// Accumulates the player's combo on the dummy.
+#[derive(Clone, Copy, Debug, Default)]
+struct ComboTracker {
+    current: ComboSummary,
+    last: Option<ComboSummary>,
+    max_combo: u32,
+}
+
+impl ComboTracker {
+    /// Add an unblocked hit, scaled by how many hits came before it, and
+    /// return its scaled damage.
+    fn land(&mut self, damage: u16, scaling: ComboScaling) -> u32 {
+        let damage = scaling.scale(damage, self.current.hits);
+        self.current.total_damage += damage;
+        self.current.hits += 1;
+        self.max_combo = self.max_combo.max(self.current.hits);
+        damage
+    }
+
+    /// Close the combo in progress, if any, and keep it as the last one.
+    fn end(&mut self) {
+        if self.current.hits == 0 {
+            return;
+        }
+        self.last = Some(ComboSummary {
+            max_combo: self.max_combo,
+            ..self.current
+        });
+        self.current = ComboSummary::default();
+    }
+}
+
+/// Everything `tick` changes, saved before each tick so `step_back` can restore it.
+#[derive(Clone)]
+struct Snapshot {
+    player_state: RtCharacterState,
+    dummy_state: RtCharacterState,
+    player_pos: (i32, i32),
+    dummy_pos: (i32, i32),
+    last_hits: Vec<TickHit>,
+    dummy_stun: u8,
+    combo: ComboTracker,
+    rng: Rng,
+    random_pick: Option<DummyState>,
+    player_health: u32,
+    dummy_health: u32,
 }
 
 /// Training session for simulating a player character against a dummy.
@@ -124,9 +560,9 @@ pub struct FrameResult {
 /// Holds the FSPK data and character states for both player and dummy.
 #[wasm_bindgen]
 pub struct TrainingSession {
-    // Owned copies of the pack data
-    player_pack_data: Vec<u8>,
-    dummy_pack_data: Vec<u8>,
+    // Owned packs, validated once at construction
+    player_pack: PackBuf,
+    dummy_pack: PackBuf,
     // Current character states
     player_state: RtCharacterState,
     dummy_state: RtCharacterState,
@@ -134,7 +570,29 @@ pub struct TrainingSession {
     player_pos: (i32, i32),
     dummy_pos: (i32, i32),
     // Last hit results (cached for hit_results() call)
-    last_hits: Vec<RtHitResult>,
+    last_hits: Vec<TickHit>,
+    // Frames of hitstun/blockstun left from the player's last hit on the dummy;
+    // nonzero while a combo or blockstring is in progress
+    dummy_stun: u8,
+    // The player's combo in progress on the dummy, and the last one finished
+    combo: ComboTracker,
+    // The player's combo scaling, read from its pack
+    combo_scaling: ComboScaling,
+    // Seed and PRNG for randomized dummy behavior (reproducible for replays)
+    seed: u32,
+    rng: Rng,
+    // Behavior rolled for `DummyState::Random`, held until the dummy recovers
+    random_pick: Option<DummyState>,
+    // Pre-tick snapshots for step_back, oldest first, at most history_depth long
+    history: VecDeque<Snapshot>,
+    history_depth: usize,
+    // How same-frame hits from both sides are resolved
+    trade_mode: TradeMode,
+    // Remaining health, seeded from each pack's `health` property
+    player_health: u32,
+    dummy_health: u32,
+    // Lab toggle: hits deal no health damage
+    infinite_health: bool,
 }
 
 #[wasm_bindgen]
@@ -149,33 +607,63 @@ impl TrainingSession {
     /// Returns an error if the FSPK data is invalid.
     #[wasm_bindgen(constructor)]
     pub fn new(player_fspk: &[u8], dummy_fspk: &[u8]) -> Result<TrainingSession, JsError> {
-        // Validate the pack data by trying to parse it
-        let player_pack = PackView::parse(player_fspk)
+        let player_pack = PackBuf::parse_owned(player_fspk.to_vec())
             .map_err(|e| JsError::new(&format!("Invalid player FSPK: {:?}", e)))?;
-        let dummy_pack = PackView::parse(dummy_fspk)
+        let dummy_pack = PackBuf::parse_owned(dummy_fspk.to_vec())
             .map_err(|e| JsError::new(&format!("Invalid dummy FSPK: {:?}", e)))?;
 
+        // Dump both packs once per session; console calls only exist on wasm.
+        #[cfg(all(debug_assertions, target_arch = "wasm32"))]
+        {
+            web_sys::console::log_1(
+                &format!("[WASM] player {}", player_pack.view().debug_dump()).into(),
+            );
+            web_sys::console::log_1(
+                &format!("[WASM] dummy {}", dummy_pack.view().debug_dump()).into(),
+            );
+        }
+
         // Initialize character states
         let mut player_state = RtCharacterState::default();
         let mut dummy_state = RtCharacterState::default();
 
         // Initialize resources from pack definitions
-        init_resources(&mut player_state, &player_pack);
-        init_resources(&mut dummy_state, &dummy_pack);
+        init_resources(&mut player_state, &player_pack.view())
+            .map_err(|e| JsError::new(&format!("Invalid player FSPK: {:?}", e)))?;
+        init_resources(&mut dummy_state, &dummy_pack.view())
+            .map_err(|e| JsError::new(&format!("Invalid dummy FSPK: {:?}", e)))?;
+
+        let player_health = starting_health(&player_pack.view());
+        let dummy_health = starting_health(&dummy_pack.view());
+        let combo_scaling = ComboScaling::from_pack(&player_pack.view());
 
         Ok(TrainingSession {
-            player_pack_data: player_fspk.to_vec(),
-            dummy_pack_data: dummy_fspk.to_vec(),
+            player_pack,
+            dummy_pack,
             player_state,
             dummy_state,
             player_pos: (-100, 0), // Player starts on the left
             dummy_pos: (100, 0),   // Dummy starts on the right
             last_hits: Vec::new(),
+            dummy_stun: 0,
+            combo: ComboTracker::default(),
+            combo_scaling,
+            seed: DEFAULT_SEED,
+            rng: Rng::new(DEFAULT_SEED),
+            random_pick: None,
+            history: VecDeque::new(),
+            history_depth: DEFAULT_HISTORY_DEPTH,
+            trade_mode: TradeMode::default(),
+            player_health,
+            dummy_health,
+            infinite_health: false,
         })
     }
 
     /// Advance the simulation by one frame.
     ///
+    /// The state before the tick is kept so `step_back` can undo it.
+    ///
     /// # Arguments
     /// * `player_input` - State index the player wants to transition to (0xFFFF = no input)
     /// * `dummy_behavior` - How the dummy should behave this frame
@@ -183,180 +671,46 @@ impl TrainingSession {
     /// # Returns
     /// A FrameResult containing the new states and any hits that occurred.
     pub fn tick(&mut self, player_input: u32, dummy_behavior: DummyState) -> Result<JsValue, JsError> {
-        // PackView::parse is zero-copy: it just validates the header and stores
-        // offsets into the existing byte slice. Re-parsing each frame is cheap
-        // (~100ns) and avoids lifetime complexity from caching the view.
-        let player_pack = PackView::parse(&self.player_pack_data)
-            .map_err(|e| JsError::new(&format!("Invalid player FSPK: {:?}", e)))?;
-        let dummy_pack = PackView::parse(&self.dummy_pack_data)
-            .map_err(|e| JsError::new(&format!("Invalid dummy FSPK: {:?}", e)))?;
-
-        // Build player input
-        let player_frame_input = FrameInput {
-            requested_state: if player_input == 0xFFFF {
-                None
-            } else {
-                Some(player_input as u16)
-            },
-        };
+        let result = self.step(player_input, dummy_behavior);
+        serde_wasm_bindgen::to_value(&result)
+            .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
+    }
 
-        // Build dummy input based on behavior
-        let dummy_state = self.compute_dummy_state(dummy_behavior, &dummy_pack);
-        let dummy_frame_input = FrameInput {
-            requested_state: dummy_state,
+    /// Undo the most recent tick, restoring states, positions, hits and the
+    /// dummy PRNG to what they were before it.
+    ///
+    /// Returns false (and changes nothing) when there is no history left.
+    pub fn step_back(&mut self) -> bool {
+        let Some(snapshot) = self.history.pop_back() else {
+            return false;
         };
+        self.player_state = snapshot.player_state;
+        self.dummy_state = snapshot.dummy_state;
+        self.player_pos = snapshot.player_pos;
+        self.dummy_pos = snapshot.dummy_pos;
+        self.last_hits = snapshot.last_hits;
+        self.dummy_stun = snapshot.dummy_stun;
+        self.combo = snapshot.combo;
+        self.rng = snapshot.rng;
+        self.random_pick = snapshot.random_pick;
+        self.player_health = snapshot.player_health;
+        self.dummy_health = snapshot.dummy_health;
+        true
+    }
 
-        // Advance player state
-        let player_result = next_frame(&self.player_state, &player_pack, &player_frame_input);
-        self.player_state = player_result.state;
-
-        // Handle move completion for player
-        if player_result.move_ended {
-            Self::handle_move_ended(&mut self.player_state, &player_pack);
-        }
-
-        // Advance dummy state
-        let dummy_result = next_frame(&self.dummy_state, &dummy_pack, &dummy_frame_input);
-        self.dummy_state = dummy_result.state;
-
-        // Handle move completion for dummy
-        if dummy_result.move_ended {
-            Self::handle_move_ended(&mut self.dummy_state, &dummy_pack);
-        }
-
-        // Check for hits (player attacking dummy)
-        let hits_result = check_hits(
-            &self.player_state,
-            &player_pack,
-            self.player_pos,
-            &self.dummy_state,
-            &dummy_pack,
-            self.dummy_pos,
-        );
-
-        // Debug: Log hit detection info
-        #[cfg(debug_assertions)]
-        {
-            // Get move info for debugging
-            if let Some(moves) = player_pack.states() {
-                if let Some(mv) = moves.get(self.player_state.current_state as usize) {
-                    let hit_count = mv.hit_windows_len();
-                    if hit_count > 0 {
-                        // Get hit window details
-                        let mut hw_info = String::new();
-                        if let Some(hit_windows) = player_pack.hit_windows() {
-                            for i in 0..hit_count as usize {
-                                if let Some(hw) = hit_windows.get_at(mv.hit_windows_off(), i) {
-                                    hw_info.push_str(&format!(
-                                        " hw[{}]: frames={}-{}, damage={}, shapes_off={}, shapes_len={}",
-                                        i, hw.start_frame(), hw.end_frame(), hw.damage(),
-                                        hw.shapes_off(), hw.shapes_len()
-                                    ));
-
-                                    // Get shape details
-                                    if let Some(shapes) = player_pack.shapes() {
-                                        for j in 0..hw.shapes_len() as usize {
-                                            if let Some(shape) = shapes.get_at(hw.shapes_off(), j) {
-                                                hw_info.push_str(&format!(
-                                                    " shape[{}]: kind={}, x={}, y={}, w={}, h={}",
-                                                    j, shape.kind(), shape.x_px(), shape.y_px(),
-                                                    shape.width_px(), shape.height_px()
-                                                ));
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        // Get dummy hurtbox info
-                        let mut hrt_info = String::new();
-                        if let Some(dummy_moves) = dummy_pack.states() {
-                            if let Some(dummy_mv) = dummy_moves.get(self.dummy_state.current_state as usize) {
-                                if let Some(hurt_windows) = dummy_pack.hurt_windows() {
-                                    for i in 0..dummy_mv.hurt_windows_len() as usize {
-                                        if let Some(hrt) = hurt_windows.get_at(dummy_mv.hurt_windows_off(), i) {
-                                            hrt_info.push_str(&format!(
-                                                " hrt[{}]: frames={}-{}, shapes_off={}, shapes_len={}",
-                                                i, hrt.start_frame(), hrt.end_frame(),
-                                                hrt.shapes_off(), hrt.shapes_len()
-                                            ));
-
-                                            if let Some(shapes) = dummy_pack.shapes() {
-                                                for j in 0..hrt.shapes_len() as usize {
-                                                    if let Some(shape) = shapes.get_at(hrt.shapes_off(), j) {
-                                                        hrt_info.push_str(&format!(
-                                                            " shape[{}]: x={}, y={}, w={}, h={}",
-                                                            j, shape.x_px(), shape.y_px(),
-                                                            shape.width_px(), shape.height_px()
-                                                        ));
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        web_sys::console::log_1(&format!(
-                            "[WASM] state={}, frame={}, player_pos={:?}, dummy_pos={:?}, hits={}{}{}",
-                            self.player_state.current_state,
-                            self.player_state.frame,
-                            self.player_pos,
-                            self.dummy_pos,
-                            hits_result.len(),
-                            hw_info,
-                            hrt_info
-                        ).into());
-                    }
-                }
-            }
-        }
-
-        // Store hits for later retrieval
-        self.last_hits.clear();
-        for hit in hits_result.iter() {
-            self.last_hits.push(*hit);
-            // Report hit on player state
-            framesmith_runtime::report_hit(&mut self.player_state);
-        }
-
-        // Also check dummy attacking player (for reversals, etc.)
-        let dummy_hits_result = check_hits(
-            &self.dummy_state,
-            &dummy_pack,
-            self.dummy_pos,
-            &self.player_state,
-            &player_pack,
-            self.player_pos,
-        );
-
-        for hit in dummy_hits_result.iter() {
-            self.last_hits.push(*hit);
-            framesmith_runtime::report_hit(&mut self.dummy_state);
+    /// Set how many ticks `step_back` can undo (0 disables history).
+    ///
+    /// Shrinking the depth drops the oldest snapshots.
+    pub fn set_history_depth(&mut self, depth: u32) {
+        self.history_depth = depth as usize;
+        while self.history.len() > self.history_depth {
+            self.history.pop_front();
         }
+    }
 
-        // Check pushbox collision
-        let push_sep = check_pushbox(
-            &self.player_state,
-            &player_pack,
-            self.player_pos,
-            &self.dummy_state,
-            &dummy_pack,
-            self.dummy_pos,
-        );
-
-        // Build result
-        let result = FrameResult {
-            player: CharacterState::from(&self.player_state),
-            dummy: CharacterState::from(&self.dummy_state),
-            hits: self.last_hits.iter().map(HitResult::from).collect(),
-            push_separation: push_sep.as_ref().map(PushSeparation::from),
-        };
-
-        serde_wasm_bindgen::to_value(&result)
-            .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
+    /// Number of ticks `step_back` can currently undo.
+    pub fn history_len(&self) -> u32 {
+        self.history.len() as u32
     }
 
     /// Get the current player state.
@@ -375,9 +729,7 @@ impl TrainingSession {
 
     /// Get available cancel targets for the player's current state.
     pub fn available_cancels(&self) -> Result<JsValue, JsError> {
-        // Zero-copy parse; see comment in tick() for rationale.
-        let player_pack = PackView::parse(&self.player_pack_data)
-            .map_err(|e| JsError::new(&format!("Invalid player FSPK: {:?}", e)))?;
+        let player_pack = self.player_pack.view();
 
         let cancels = available_cancels(&self.player_state, &player_pack);
         let cancels_u32: Vec<u32> = cancels.iter().map(|&c| c as u32).collect();
@@ -386,6 +738,22 @@ impl TrainingSession {
             .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
     }
 
+    /// Get the cancel targets the player can actually perform right now.
+    ///
+    /// Like `available_cancels`, but also drops targets whose preconditions
+    /// (including distance to the dummy) fail or whose resource costs the
+    /// player cannot pay.
+    pub fn available_cancels_usable(&self) -> Result<JsValue, JsError> {
+        let cancels_u32: Vec<u32> = self
+            .usable_cancel_targets()
+            .into_iter()
+            .map(u32::from)
+            .collect();
+
+        serde_wasm_bindgen::to_value(&cancels_u32)
+            .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
+    }
+
     /// Get the hit results from the last tick.
     pub fn hit_results(&self) -> Result<JsValue, JsError> {
         let hits: Vec<HitResult> = self.last_hits.iter().map(HitResult::from).collect();
@@ -393,27 +761,78 @@ impl TrainingSession {
             .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
     }
 
+    /// Get the stats of the last finished combo on the dummy.
+    ///
+    /// A combo finishes when the dummy's hitstun runs out or it blocks. Returns
+    /// `undefined` until the first combo finishes.
+    pub fn last_combo_summary(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.combo.last)
+            .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
+    }
+
     /// Reset the session to initial state.
     pub fn reset(&mut self) -> Result<(), JsError> {
-        // Zero-copy parse; see comment in tick() for rationale.
-        let player_pack = PackView::parse(&self.player_pack_data)
-            .map_err(|e| JsError::new(&format!("Invalid player FSPK: {:?}", e)))?;
-        let dummy_pack = PackView::parse(&self.dummy_pack_data)
-            .map_err(|e| JsError::new(&format!("Invalid dummy FSPK: {:?}", e)))?;
-
         self.player_state = RtCharacterState::default();
         self.dummy_state = RtCharacterState::default();
 
-        init_resources(&mut self.player_state, &player_pack);
-        init_resources(&mut self.dummy_state, &dummy_pack);
+        init_resources(&mut self.player_state, &self.player_pack.view())
+            .map_err(|e| JsError::new(&format!("Invalid player FSPK: {:?}", e)))?;
+        init_resources(&mut self.dummy_state, &self.dummy_pack.view())
+            .map_err(|e| JsError::new(&format!("Invalid dummy FSPK: {:?}", e)))?;
 
         self.player_pos = (-100, 0);
         self.dummy_pos = (100, 0);
         self.last_hits.clear();
+        self.dummy_stun = 0;
+        self.combo = ComboTracker::default();
+        self.rng = Rng::new(self.seed);
+        self.random_pick = None;
+        self.history.clear();
+        self.player_health = starting_health(&self.player_pack.view());
+        self.dummy_health = starting_health(&self.dummy_pack.view());
 
         Ok(())
     }
 
+    /// Seed the PRNG used by `DummyState::Random`.
+    ///
+    /// The same seed replays the same sequence of dummy decisions. `reset()`
+    /// rewinds the sequence to the start of this seed.
+    pub fn set_seed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.rng = Rng::new(seed);
+        self.random_pick = None;
+    }
+
+    /// Get the remaining health of the player (`which = 0`) or dummy
+    /// (`which = 1`).
+    ///
+    /// Starts at the pack's numeric `health` property, or 1000 without one.
+    /// Returns 0 for any other index.
+    pub fn health(&self, which: u32) -> u32 {
+        match which {
+            0 => self.player_health,
+            1 => self.dummy_health,
+            _ => 0,
+        }
+    }
+
+    /// Stop hits from dealing health damage, for practicing combos without KOs.
+    ///
+    /// Turning it on refills both characters.
+    pub fn set_infinite_health(&mut self, enabled: bool) {
+        self.infinite_health = enabled;
+        if enabled {
+            self.player_health = starting_health(&self.player_pack.view());
+            self.dummy_health = starting_health(&self.dummy_pack.view());
+        }
+    }
+
+    /// Choose how hits landing on both characters in the same tick resolve.
+    pub fn set_trade_mode(&mut self, mode: TradeMode) {
+        self.trade_mode = mode;
+    }
+
     /// Set character positions (for collision checking).
     pub fn set_positions(&mut self, player_x: i32, player_y: i32, dummy_x: i32, dummy_y: i32) {
         self.player_pos = (player_x, player_y);
@@ -429,22 +848,44 @@ impl TrainingSession {
     /// # Arguments
     /// * `name` - The property name (e.g., "health", "walk_speed")
     pub fn get_property(&self, name: &str) -> Option<f64> {
-        let pack = PackView::parse(&self.player_pack_data).ok()?;
-        let props = pack.character_props()?;
-
-        for i in 0..props.len() {
-            let prop = props.get(i)?;
-            let (off, len) = prop.name();
-            let prop_name = pack.string(off, len)?;
-            if prop_name == name {
-                // Only return numeric (Q24.8) properties
-                if prop.value_type() == PROP_TYPE_Q24_8 {
-                    return Some(from_q24_8(prop.as_q24_8()));
-                }
-                return None;
-            }
-        }
-        None
+        numeric_property(&self.player_pack.view(), name)
+    }
+
+    /// Get the resource names and maxima for the player (`which = 0`) or
+    /// dummy (`which = 1`), in the order of `CharacterState::resources`.
+    pub fn resource_names(&self, which: u32) -> Result<JsValue, JsError> {
+        let infos = match which {
+            0 => resource_infos(&self.player_pack.view()),
+            1 => resource_infos(&self.dummy_pack.view()),
+            _ => return Err(JsError::new(&format!("Invalid character index: {}", which))),
+        };
+        serde_wasm_bindgen::to_value(&infos)
+            .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
+    }
+
+    /// Get the hit and hurt shapes active this frame for the player
+    /// (`which = 0`) or dummy (`which = 1`), in world pixels.
+    ///
+    /// Shapes are offset by the character's position and face the other
+    /// character, as movement does in `step_exchange`: a character facing -x
+    /// has every shape mirrored around its position.
+    pub fn active_boxes(&self, which: u32) -> Result<JsValue, JsError> {
+        let boxes = self
+            .character_boxes(which)
+            .ok_or_else(|| JsError::new(&format!("Invalid character index: {}", which)))?;
+        serde_wasm_bindgen::to_value(&boxes)
+            .map_err(|e| JsError::new(&format!("Serialization error: {:?}", e)))
+    }
+
+    /// Get the player's current value of a resource by name.
+    ///
+    /// Returns None if the player's pack doesn't define the resource.
+    ///
+    /// # Arguments
+    /// * `name` - The resource name (e.g., "meter", "heat")
+    pub fn resource_by_name(&self, name: &str) -> Option<u16> {
+        let index = resource_index(&self.player_pack.view(), name)?;
+        Some(resource(&self.player_state, index as u8))
     }
 
     /// Get a dummy character property by name.
@@ -456,79 +897,482 @@ impl TrainingSession {
     /// # Arguments
     /// * `name` - The property name (e.g., "health", "walk_speed")
     pub fn get_dummy_property(&self, name: &str) -> Option<f64> {
-        let pack = PackView::parse(&self.dummy_pack_data).ok()?;
-        let props = pack.character_props()?;
-
-        for i in 0..props.len() {
-            let prop = props.get(i)?;
-            let (off, len) = prop.name();
-            let prop_name = pack.string(off, len)?;
-            if prop_name == name {
-                // Only return numeric (Q24.8) properties
-                if prop.value_type() == PROP_TYPE_Q24_8 {
-                    return Some(from_q24_8(prop.as_q24_8()));
-                }
-                return None;
-            }
-        }
-        None
+        numeric_property(&self.dummy_pack.view(), name)
     }
 }
 
 impl TrainingSession {
+    /// Run one tick and return its result; `tick` serializes it for JavaScript.
+    fn step(&mut self, player_input: u32, dummy_behavior: DummyState) -> FrameResult {
+        self.record_snapshot();
+
+        // Roll randomized behavior before borrowing the pack data for this frame.
+        let dummy_behavior = self.resolve_dummy_behavior(dummy_behavior);
+
+        let player_pack = self.player_pack.view();
+        let dummy_pack = self.dummy_pack.view();
+
+        // Build player input
+        let player_frame_input = FrameInput {
+            requested_state: if player_input == 0xFFFF {
+                None
+            } else {
+                Some(player_input as u16)
+            },
+            // Early cancel inputs carry over until the window opens, as in a match.
+            buffer_frames: DEFAULT_BUFFER_FRAMES,
+            ..Default::default()
+        };
+
+        // Build dummy input based on behavior
+        let dummy_state = self.compute_dummy_state(dummy_behavior, &dummy_pack);
+        let dummy_frame_input = FrameInput {
+            requested_state: dummy_state,
+            ..Default::default()
+        };
+
+        // Decide blocking from the stun left by earlier hits, then let it run down.
+        let dummy_blocking = dummy_behavior.blocks(self.dummy_stun > 0);
+        let was_in_stun = self.dummy_stun > 0;
+        if self.dummy_state.hitstop == 0 {
+            self.dummy_stun = self.dummy_stun.saturating_sub(1);
+        }
+        // The dummy is actionable again, so the combo is over.
+        if self.dummy_stun == 0 {
+            self.combo.end();
+            // The next hit or block gets a fresh random behavior.
+            if was_in_stun {
+                self.random_pick = None;
+            }
+        }
+
+        let policy = match self.trade_mode {
+            TradeMode::Trade => TradePolicy::Trade,
+            TradeMode::Clash => TradePolicy::Clash,
+            TradeMode::Priority => state_type_priority(
+                &self.player_state,
+                &player_pack,
+                &self.dummy_state,
+                &dummy_pack,
+            ),
+        };
+        // Counter-hits are judged on each defender as it was when the hit came out.
+        let (player_before, dummy_before) = (self.player_state, self.dummy_state);
+        let exchange = step_exchange(
+            &self.player_state,
+            &self.dummy_state,
+            (&player_pack, &dummy_pack),
+            (&player_frame_input, &dummy_frame_input),
+            (self.player_pos, self.dummy_pos),
+            policy,
+        );
+        self.player_state = exchange.p1;
+        self.dummy_state = exchange.p2;
+        self.player_pos = offset(self.player_pos, exchange.p1_delta);
+        self.dummy_pos = offset(self.dummy_pos, exchange.p2_delta);
+
+        // Tag each landed hit for the frontend and track the combo on the dummy.
+        self.last_hits.clear();
+        for hit in exchange.p1_hits.iter() {
+            let tick_hit = TickHit::new(*hit, &dummy_before, &dummy_pack, dummy_blocking);
+            self.dummy_stun = tick_hit.stun();
+            let damage = if tick_hit.blocked {
+                self.combo.end();
+                tick_hit.health_damage()
+            } else {
+                self.combo.land(tick_hit.hit.damage, self.combo_scaling)
+            };
+            if !self.infinite_health {
+                self.dummy_health = self.dummy_health.saturating_sub(damage);
+            }
+            self.last_hits.push(tick_hit);
+        }
+        // Dummy attacking player (for reversals, etc.)
+        for hit in exchange.p2_hits.iter() {
+            let tick_hit = TickHit::new(*hit, &player_before, &player_pack, false);
+            if !self.infinite_health {
+                self.player_health = self.player_health.saturating_sub(tick_hit.health_damage());
+            }
+            self.last_hits.push(tick_hit);
+        }
+        let push_sep = exchange.push;
+
+        FrameResult {
+            player: CharacterState::from(&self.player_state),
+            dummy: CharacterState::from(&self.dummy_state),
+            hits: self.last_hits.iter().map(HitResult::from).collect(),
+            push_separation: push_sep.as_ref().map(PushSeparation::from),
+            ko: self.player_health == 0 || self.dummy_health == 0,
+        }
+    }
+
+    /// Active boxes for the player (`which = 0`) or dummy (`which = 1`).
+    fn character_boxes(&self, which: u32) -> Option<ActiveBoxes> {
+        // Each faces the other; the player faces +x when level, as in step_exchange.
+        let player_facing = if self.player_pos.0 <= self.dummy_pos.0 { 1 } else { -1 };
+        match which {
+            0 => Some(active_boxes(
+                &self.player_state,
+                &self.player_pack.view(),
+                self.player_pos,
+                player_facing,
+            )),
+            1 => Some(active_boxes(
+                &self.dummy_state,
+                &self.dummy_pack.view(),
+                self.dummy_pos,
+                -player_facing,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Save the pre-tick state, dropping the oldest snapshot past `history_depth`.
+    fn record_snapshot(&mut self) {
+        if self.history_depth == 0 {
+            return;
+        }
+        if self.history.len() == self.history_depth {
+            self.history.pop_front();
+        }
+        self.history.push_back(Snapshot {
+            player_state: self.player_state,
+            dummy_state: self.dummy_state,
+            player_pos: self.player_pos,
+            dummy_pos: self.dummy_pos,
+            last_hits: self.last_hits.clone(),
+            dummy_stun: self.dummy_stun,
+            combo: self.combo,
+            rng: self.rng,
+            random_pick: self.random_pick,
+            player_health: self.player_health,
+            dummy_health: self.dummy_health,
+        });
+    }
+
+    /// Resolve `DummyState::Random` to a concrete behavior; other behaviors pass through.
+    ///
+    /// The roll is held in `random_pick` until the dummy recovers from its next
+    /// hit or block, so one interaction sees one behavior.
+    fn resolve_dummy_behavior(&mut self, behavior: DummyState) -> DummyState {
+        match behavior {
+            DummyState::Random => *self.random_pick.get_or_insert_with(|| {
+                let idx = self.rng.next_below(RANDOM_DUMMY_CHOICES.len() as u32) as usize;
+                RANDOM_DUMMY_CHOICES[idx]
+            }),
+            other => {
+                self.random_pick = None;
+                other
+            }
+        }
+    }
+
     /// Compute what state the dummy should transition to based on its behavior.
     fn compute_dummy_state(&self, behavior: DummyState, _pack: &PackView) -> Option<u16> {
         // For now, dummy just stays in its current state
         // Future: map behavior to specific states (crouch, block, etc.)
         match behavior {
-            DummyState::Stand => None,      // Stay idle
-            DummyState::Crouch => Some(1),  // Assume state 1 is crouch (game-specific)
-            DummyState::Jump => Some(2),    // Assume state 2 is jump
-            DummyState::BlockStand => None, // Block is handled by game logic
-            DummyState::BlockCrouch => Some(1), // Crouching block
-            DummyState::BlockAuto => None,  // Auto-block handled by game logic
+            DummyState::Stand => None,              // Stay idle
+            DummyState::Crouch => Some(1),          // Assume state 1 is crouch (game-specific)
+            DummyState::Jump => Some(2),            // Assume state 2 is jump
+            DummyState::BlockStand => None,         // Block is handled by game logic
+            DummyState::BlockCrouch => Some(1),     // Crouching block
+            DummyState::BlockAuto => None,          // Auto-block handled by game logic
+            DummyState::Random => None, // Resolved by resolve_dummy_behavior before this
+            DummyState::BlockAfterFirstHit => None, // Blocking is decided per hit in step
         }
     }
 
-    /// Handle move completion - either loop system states or return to idle.
-    fn handle_move_ended(state: &mut RtCharacterState, pack: &PackView) {
-        // Check if current state is a system state (state 0 = idle, state 1 = crouch)
-        // System states loop back to frame 0 instead of transitioning
-        const IDLE_STATE: u16 = 0;
-        const MAX_SYSTEM_STATE: u16 = 1; // States 0-1 are system states that loop
+    /// Filter the player's cancel targets down to ones they can perform.
+    fn usable_cancel_targets(&self) -> Vec<u16> {
+        let pack = self.player_pack.view();
+        let context = MoveContext {
+            distance: u32::from(horizontal_distance(self.player_pos, self.dummy_pos)),
+        };
 
-        if state.current_state <= MAX_SYSTEM_STATE {
-            // System state - loop back to frame 0
-            state.frame = 0;
-        } else {
-            // Attack/action state ended - return to idle
-            state.current_state = IDLE_STATE;
-            state.frame = 0;
-            state.hit_confirmed = false;
-            state.block_confirmed = false;
+        available_cancels(&self.player_state, &pack)
+            .into_iter()
+            .filter(|&target| {
+                // Pay costs on a scratch copy; only the affordability result matters.
+                let mut scratch = self.player_state;
+                is_move_available(&self.player_state, &pack, target, &context)
+                    && apply_resource_costs(&mut scratch, &pack, target).is_ok()
+            })
+            .collect()
+    }
+}
+
+/// Converts a host's variable frame times into whole simulation ticks.
+///
+/// Call `advance` once per `requestAnimationFrame` with the elapsed
+/// milliseconds, then tick the session that many times.
+#[wasm_bindgen]
+pub struct FrameClock {
+    clock: RtFrameClock,
+    // Sub-microsecond time not yet handed to the integer clock
+    carry_micros: f64,
+}
+
+#[wasm_bindgen]
+impl FrameClock {
+    /// Create a clock at `frame_rate` frames per second (60 if omitted).
+    #[wasm_bindgen(constructor)]
+    pub fn new(frame_rate: Option<u32>) -> FrameClock {
+        FrameClock {
+            clock: RtFrameClock::new(frame_rate.unwrap_or(DEFAULT_FRAME_RATE)),
+            carry_micros: 0.0,
         }
     }
+
+    /// Add `elapsed_ms` of wall-clock time and return the frames to simulate now.
+    ///
+    /// Negative or non-finite values count as no time passing.
+    pub fn advance(&mut self, elapsed_ms: f64) -> u32 {
+        let elapsed_ms = if elapsed_ms.is_finite() {
+            elapsed_ms.max(0.0)
+        } else {
+            0.0
+        };
+        let micros = elapsed_ms * 1000.0 + self.carry_micros;
+        let whole = micros.floor().min(f64::from(u32::MAX));
+        self.carry_micros = micros - whole;
+        self.clock.advance(whole as u32)
+    }
+
+    /// Drop any partial frame, e.g. after the loop was paused.
+    pub fn reset(&mut self) {
+        self.clock.reset();
+        self.carry_micros = 0.0;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn frame_clock_turns_fractional_milliseconds_into_sixty_frames() {
+        let mut clock = FrameClock::new(None);
+        let frames: u32 = [16.6, 16.8, 16.7, 16.566_666]
+            .repeat(15)
+            .iter()
+            .map(|&ms| clock.advance(ms))
+            .sum();
+        assert_eq!(frames, 59);
+        assert_eq!(clock.advance(0.01), 1);
+        assert_eq!(clock.advance(-5.0) + clock.advance(f64::NAN), 0);
+    }
+
     #[test]
     fn dummy_state_default() {
         assert_eq!(DummyState::default(), DummyState::Stand);
     }
 
+    /// Header-only pack (no sections): enough for session plumbing that doesn't read moves.
+    fn empty_pack() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"FSPK");
+        bytes.extend_from_slice(&0u32.to_le_bytes()); // flags
+        bytes.extend_from_slice(&16u32.to_le_bytes()); // total_len
+        bytes.extend_from_slice(&0u32.to_le_bytes()); // section_count
+        bytes
+    }
+
+    fn empty_session() -> TrainingSession {
+        let pack = empty_pack();
+        TrainingSession::new(&pack, &pack).expect("header-only pack should load")
+    }
+
+    /// Roll `count` random behaviors, as if the dummy recovered between each.
+    fn random_decisions(session: &mut TrainingSession, count: usize) -> Vec<DummyState> {
+        (0..count)
+            .map(|_| {
+                session.random_pick = None;
+                session.resolve_dummy_behavior(DummyState::Random)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_produces_same_dummy_decisions() {
+        let mut a = empty_session();
+        let mut b = empty_session();
+        a.set_seed(0xC0FFEE);
+        b.set_seed(0xC0FFEE);
+
+        let seq_a = random_decisions(&mut a, 64);
+        let seq_b = random_decisions(&mut b, 64);
+
+        assert_eq!(seq_a, seq_b);
+        assert!(seq_a.iter().all(|d| *d != DummyState::Random));
+    }
+
+    #[test]
+    fn reset_rewinds_dummy_decisions() {
+        let mut session = empty_session();
+        session.set_seed(7);
+        let first = random_decisions(&mut session, 16);
+
+        session.reset().expect("reset");
+        let second = random_decisions(&mut session, 16);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn fixed_behaviors_do_not_consume_rng() {
+        let mut session = empty_session();
+        assert_eq!(
+            session.resolve_dummy_behavior(DummyState::BlockAuto),
+            DummyState::BlockAuto
+        );
+        assert_eq!(session.rng, Rng::new(DEFAULT_SEED));
+    }
+
+    #[test]
+    fn random_behavior_holds_until_the_dummy_recovers() {
+        let pack = boxed_state_pack_with(0, 10, 10);
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(0, 0, 20, 0);
+
+        session.step(0xFFFF, DummyState::Random);
+        let pick = session.random_pick.expect("first tick should roll");
+        let rng = session.rng;
+
+        // One roll covers the whole string.
+        for _ in 0..3 {
+            session.step(0xFFFF, DummyState::Random);
+            assert_eq!(session.last_hits.len(), 1);
+            assert_eq!(session.random_pick, Some(pick));
+            assert_eq!(session.rng, rng);
+        }
+
+        // Out of range the stun runs down, and the roll is dropped once the
+        // dummy can act again.
+        session.set_positions(0, 0, 200, 0);
+        while session.dummy_stun > 0 {
+            assert_eq!(session.random_pick, Some(pick));
+            session.step(0xFFFF, DummyState::Random);
+        }
+        assert_eq!(session.random_pick, None);
+
+        session.step(0xFFFF, DummyState::Random);
+        assert!(session.random_pick.is_some());
+        assert_ne!(session.rng, rng);
+    }
+
+    /// Assemble a pack from `(kind, bytes)` sections with `PackBuilder`.
+    fn build_pack(sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut builder = framesmith_fspack::PackBuilder::new();
+        for (kind, bytes) in sections {
+            builder.section(*kind, bytes);
+        }
+        builder.finish()
+    }
+
+    /// Two states: idle (0) cancels into a special (1) that costs 50 meter.
+    fn meter_special_pack() -> Vec<u8> {
+        use framesmith_fspack::{
+            SECTION_CANCELS_U16, SECTION_MOVE_RESOURCE_COSTS, SECTION_RESOURCE_DEFS,
+            SECTION_STATES, SECTION_STATE_EXTRAS, SECTION_STRING_TABLE, STATE_EXTRAS_SIZE,
+            STATE_RECORD_SIZE,
+        };
+
+        let strings = b"meter".to_vec();
+
+        let mut resource_defs = Vec::new();
+        resource_defs.extend_from_slice(&0u32.to_le_bytes()); // name off
+        resource_defs.extend_from_slice(&5u16.to_le_bytes()); // name len
+        resource_defs.extend_from_slice(&0u16.to_le_bytes());
+        resource_defs.extend_from_slice(&0u16.to_le_bytes()); // start
+        resource_defs.extend_from_slice(&100u16.to_le_bytes()); // max
+
+        let mut costs = Vec::new();
+        costs.extend_from_slice(&0u32.to_le_bytes()); // name off
+        costs.extend_from_slice(&5u16.to_le_bytes()); // name len
+        costs.extend_from_slice(&0u16.to_le_bytes());
+        costs.extend_from_slice(&50u16.to_le_bytes()); // amount
+        costs.extend_from_slice(&0u16.to_le_bytes());
+
+        let mut extras = vec![0u8; 2 * STATE_EXTRAS_SIZE];
+        // state 0: one cancel route at offset 0
+        extras[68..70].copy_from_slice(&1u16.to_le_bytes());
+        // state 1: one resource cost at offset 0
+        extras[STATE_EXTRAS_SIZE + 36..STATE_EXTRAS_SIZE + 38].copy_from_slice(&1u16.to_le_bytes());
+
+        build_pack(&[
+            (SECTION_STRING_TABLE, strings),
+            (SECTION_STATES, vec![0u8; 2 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_CANCELS_U16, 1u16.to_le_bytes().to_vec()),
+            (SECTION_RESOURCE_DEFS, resource_defs),
+            (SECTION_MOVE_RESOURCE_COSTS, costs),
+        ])
+    }
+
+    #[test]
+    fn usable_cancels_require_affordable_costs() {
+        let pack = meter_special_pack();
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+
+        // Raw routes still list the special; it just can't be paid for yet.
+        assert_eq!(
+            available_cancels(&session.player_state, &session.player_pack.view()),
+            vec![1]
+        );
+        assert!(session.usable_cancel_targets().is_empty());
+
+        session.player_state.resources[0] = 50;
+        assert_eq!(session.usable_cancel_targets(), vec![1]);
+    }
+
+    #[test]
+    fn resource_by_name_reads_player_resources() {
+        let pack = meter_special_pack();
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+
+        session.player_state.resources[0] = 42;
+        assert_eq!(session.resource_by_name("meter"), Some(42));
+        assert_eq!(session.resource_by_name("heat"), None);
+    }
+
+    #[test]
+    fn resource_infos_match_pack_definitions() {
+        use framesmith_fspack::{SECTION_RESOURCE_DEFS, SECTION_STRING_TABLE};
+
+        let mut resource_defs = Vec::new();
+        for (off, len, max) in [(0u32, 5u16, 100u16), (5, 4, 300)] {
+            resource_defs.extend_from_slice(&off.to_le_bytes()); // name off
+            resource_defs.extend_from_slice(&len.to_le_bytes()); // name len
+            resource_defs.extend_from_slice(&0u16.to_le_bytes());
+            resource_defs.extend_from_slice(&0u16.to_le_bytes()); // start
+            resource_defs.extend_from_slice(&max.to_le_bytes()); // max
+        }
+        let pack = build_pack(&[
+            (SECTION_STRING_TABLE, b"meterheat".to_vec()),
+            (SECTION_RESOURCE_DEFS, resource_defs),
+        ]);
+        let infos = resource_infos(&PackView::parse(&pack).expect("pack should load"));
+
+        let pairs: Vec<(&str, u32)> = infos.iter().map(|r| (r.name.as_str(), r.max)).collect();
+        assert_eq!(pairs, vec![("meter", 100), ("heat", 300)]);
+
+        let empty = empty_pack();
+        let empty = PackView::parse(&empty).expect("header-only pack should load");
+        assert!(resource_infos(&empty).is_empty());
+    }
+
     #[test]
     fn character_state_conversion() {
-        let rt_state = RtCharacterState {
+        let mut rt_state = RtCharacterState {
             current_state: 5,
             frame: 10,
             instance_duration: 0,
             hit_confirmed: true,
             block_confirmed: false,
-            resources: [100, 50, 0, 0, 0, 0, 0, 0],
+            ..Default::default()
         };
+        rt_state.resources[..2].copy_from_slice(&[100, 50]);
 
         let js_state = CharacterState::from(&rt_state);
 
@@ -536,9 +1380,10 @@ mod tests {
         assert_eq!(js_state.frame, 10);
         assert!(js_state.hit_confirmed);
         assert!(!js_state.block_confirmed);
-        assert_eq!(js_state.resources.len(), 8);
+        assert_eq!(js_state.resources.len(), MAX_RESOURCES);
         assert_eq!(js_state.resources[0], 100);
         assert_eq!(js_state.resources[1], 50);
+        assert!(js_state.grounded);
     }
 
     #[test]
@@ -554,6 +1399,11 @@ mod tests {
             guard: 1,
             hit_pushback: 20,
             block_pushback: 15,
+            is_throw: false,
+            wall_bounce: true,
+            ground_bounce: false,
+            launch: -6,
+            contact: None,
         };
 
         let js_hit = HitResult::from(&rt_hit);
@@ -562,5 +1412,511 @@ mod tests {
         assert_eq!(js_hit.damage, 50);
         assert_eq!(js_hit.hitstun, 15);
         assert_eq!(js_hit.hit_pushback, 20);
+        assert!(!js_hit.is_throw);
+        assert!(js_hit.wall_bounce);
+        assert_eq!(js_hit.launch, -6);
+    }
+
+    /// Single 30-frame state, so the frame counter advances on every tick.
+    fn long_state_pack() -> Vec<u8> {
+        use framesmith_fspack::{SECTION_STATES, STATE_RECORD_SIZE};
+
+        let mut states = vec![0u8; STATE_RECORD_SIZE];
+        states[14..16].copy_from_slice(&30u16.to_le_bytes()); // total
+        build_pack(&[(SECTION_STATES, states)])
+    }
+
+    #[test]
+    fn step_back_restores_earlier_frames() {
+        let pack = long_state_pack();
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+
+        let mut frame_two = None;
+        for _ in 0..5 {
+            session.step(0xFFFF, DummyState::Random);
+            if session.player_state.frame == 2 {
+                frame_two = Some((session.player_state, session.dummy_state, session.rng));
+            }
+        }
+        let (player, dummy, rng) = frame_two.expect("player should pass frame 2");
+        assert_eq!(session.history_len(), 5);
+
+        for _ in 0..3 {
+            assert!(session.step_back());
+        }
+
+        assert_eq!(session.player_state, player);
+        assert_eq!(session.dummy_state, dummy);
+        assert_eq!(session.rng, rng);
+        assert_eq!(session.history_len(), 2);
+    }
+
+    /// One 30-frame state with a 100-damage 30x40 AABB hitbox on frames 0-5
+    /// and a radius-15 circle hurtbox on every frame.
+    fn boxed_state_pack() -> Vec<u8> {
+        boxed_state_pack_with(0, 0, 0)
+    }
+
+    fn boxed_state_pack_with(hitstop: u8, hitstun: u8, blockstun: u8) -> Vec<u8> {
+        boxed_state_pack_dealing(100, hitstop, hitstun, blockstun)
+    }
+
+    fn boxed_state_pack_dealing(damage: u16, hitstop: u8, hitstun: u8, blockstun: u8) -> Vec<u8> {
+        build_pack(&boxed_state_sections(damage, hitstop, hitstun, blockstun))
+    }
+
+    fn boxed_state_sections(
+        damage: u16,
+        hitstop: u8,
+        hitstun: u8,
+        blockstun: u8,
+    ) -> Vec<(u32, Vec<u8>)> {
+        use framesmith_fspack::{
+            HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS,
+            SECTION_SHAPES, SECTION_STATES, SHAPE_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut states = vec![0u8; STATE_RECORD_SIZE];
+        states[14..16].copy_from_slice(&30u16.to_le_bytes()); // total
+        states[26..28].copy_from_slice(&1u16.to_le_bytes()); // one hit window
+        states[30..32].copy_from_slice(&1u16.to_le_bytes()); // one hurt window
+
+        let mut hit = vec![0u8; HIT_WINDOW_SIZE];
+        hit[1] = 5; // end_frame
+        hit[4..6].copy_from_slice(&damage.to_le_bytes());
+        hit[8] = hitstun;
+        hit[9] = blockstun;
+        hit[10] = hitstop;
+        hit[16..18].copy_from_slice(&1u16.to_le_bytes()); // shape 0
+
+        let mut hurt = vec![0u8; HURT_WINDOW_SIZE];
+        hurt[1] = 29; // end_frame
+        hurt[4..8].copy_from_slice(&(SHAPE_SIZE as u32).to_le_bytes()); // shape 1
+        hurt[8..10].copy_from_slice(&1u16.to_le_bytes());
+
+        // Q12.4: pixels * 16
+        let mut shapes = vec![0u8; 2 * SHAPE_SIZE];
+        for (field, value) in [(1, 10i16), (2, -20), (3, 30), (4, 40)] {
+            shapes[field * 2..field * 2 + 2].copy_from_slice(&(value * 16).to_le_bytes());
+        }
+        let circle = &mut shapes[SHAPE_SIZE..];
+        circle[0] = SHAPE_KIND_CIRCLE;
+        circle[4..6].copy_from_slice(&(-30i16 * 16).to_le_bytes());
+        circle[6..8].copy_from_slice(&(15i16 * 16).to_le_bytes());
+
+        vec![
+            (SECTION_STATES, states),
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_HURT_WINDOWS, hurt),
+            (SECTION_SHAPES, shapes),
+        ]
+    }
+
+    #[test]
+    fn movement_moves_both_characters_toward_each_other() {
+        use framesmith_fspack::{SECTION_STATE_MOVEMENT, STATE_MOVEMENT_SIZE};
+
+        // The looping state walks forward 2px a frame the whole time.
+        let mut movement = vec![0u8; STATE_MOVEMENT_SIZE];
+        movement[1] = 29; // end_frame
+        movement[4..6].copy_from_slice(&(2i16 << 8).to_le_bytes());
+        let mut sections = boxed_state_sections(100, 0, 0, 0);
+        sections.push((SECTION_STATE_MOVEMENT, movement));
+        let bytes = build_pack(&sections);
+
+        let mut session = TrainingSession::new(&bytes, &bytes).expect("pack should load");
+        for _ in 0..3 {
+            session.step(0xFFFF, DummyState::Stand);
+        }
+        assert_eq!((session.player_pos, session.dummy_pos), ((-94, 0), (94, 0)));
+    }
+
+    #[test]
+    fn active_boxes_translate_pack_shapes_by_position() {
+        let pack = boxed_state_pack();
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(-100, 0, 100, 5);
+
+        let player = session.character_boxes(0).expect("player");
+        assert_eq!(
+            player.hit,
+            vec![BoxShape::Aabb {
+                x: -90,
+                y: -20,
+                w: 30,
+                h: 40
+            }]
+        );
+        assert_eq!(
+            player.hurt,
+            vec![BoxShape::Circle {
+                x: -100,
+                y: -30,
+                r: 15
+            }]
+        );
+
+        // The dummy faces left, so its hitbox reaches back toward the player.
+        let dummy = session.character_boxes(1).expect("dummy");
+        assert_eq!(
+            dummy.hit,
+            vec![BoxShape::Aabb {
+                x: 60,
+                y: -15,
+                w: 30,
+                h: 40
+            }]
+        );
+        assert_eq!(
+            dummy.hurt,
+            vec![BoxShape::Circle {
+                x: 100,
+                y: -25,
+                r: 15
+            }]
+        );
+
+        // Past the hit window only the hurtbox remains.
+        for _ in 0..6 {
+            session.step(0xFFFF, DummyState::Stand);
+        }
+        let player = session.character_boxes(0).expect("player");
+        assert_eq!(session.player_state.frame, 6);
+        assert!(player.hit.is_empty());
+        assert_eq!(player.hurt.len(), 1);
+        assert!(session.character_boxes(2).is_none());
+
+        // Swapping sides mirrors the player instead.
+        session.reset().expect("reset");
+        session.set_positions(100, 0, -100, 0);
+        let player = session.character_boxes(0).expect("player");
+        assert_eq!(
+            player.hit,
+            vec![BoxShape::Aabb {
+                x: 60,
+                y: -20,
+                w: 30,
+                h: 40
+            }]
+        );
+        let dummy = session.character_boxes(1).expect("dummy");
+        assert_eq!(
+            dummy.hit,
+            vec![BoxShape::Aabb {
+                x: -90,
+                y: -20,
+                w: 30,
+                h: 40
+            }]
+        );
+    }
+
+    #[test]
+    fn hits_freeze_both_characters_for_hitstop() {
+        let pack = boxed_state_pack_with(4, 0, 0);
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        // Player's hitbox reaches the dummy's hurtbox; not the other way round.
+        session.set_positions(0, 0, 20, 0);
+
+        session.step(0xFFFF, DummyState::Stand);
+        assert_eq!(session.last_hits.len(), 1);
+        assert_eq!(
+            (session.player_state.hitstop, session.dummy_state.hitstop),
+            (4, 4)
+        );
+
+        for _ in 0..4 {
+            session.step(0xFFFF, DummyState::Stand);
+            assert_eq!(
+                (session.player_state.frame, session.dummy_state.frame),
+                (1, 1)
+            );
+            assert!(session.last_hits.is_empty());
+        }
+        assert_eq!(
+            (session.player_state.hitstop, session.dummy_state.hitstop),
+            (0, 0)
+        );
+
+        session.step(0xFFFF, DummyState::Stand);
+        assert_eq!(
+            (session.player_state.frame, session.dummy_state.frame),
+            (2, 2)
+        );
+    }
+
+    #[test]
+    fn block_after_first_hit_blocks_the_rest_of_the_string() {
+        let pack = boxed_state_pack_with(0, 10, 6);
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(0, 0, 20, 0);
+
+        // The hit window lands every frame it's active, and the 30-frame move
+        // loops, so each loop is one string of hits.
+        let mut blocked = Vec::new();
+        for _ in 0..40 {
+            session.step(0xFFFF, DummyState::BlockAfterFirstHit);
+            blocked.extend(session.last_hits.iter().map(|hit| hit.blocked));
+        }
+        // Open for the first hit of each string and blocking the rest; the
+        // blockstun runs out between loops, so the next string opens again.
+        assert_eq!(
+            blocked,
+            [false, true, true, true, true, false, true, true, true, true, true]
+        );
+
+        session.reset().expect("reset");
+        for _ in 0..6 {
+            session.step(0xFFFF, DummyState::Stand);
+            assert!(session.last_hits.iter().all(|hit| !hit.blocked));
+        }
+    }
+
+    #[test]
+    fn combo_summary_reports_scaled_damage_once_the_dummy_recovers() {
+        let pack = boxed_state_pack_with(0, 10, 0);
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+
+        // Three hits, then the dummy is pushed out of range. The dummy's looping
+        // state isn't a system state, so each hit counter-hits for 14 frames.
+        session.set_positions(0, 0, 20, 0);
+        for _ in 0..3 {
+            session.step(0xFFFF, DummyState::Stand);
+            assert_eq!(session.last_hits.len(), 1);
+        }
+        session.set_positions(0, 0, 200, 0);
+        for _ in 0..13 {
+            session.step(0xFFFF, DummyState::Stand);
+        }
+        assert_eq!(session.combo.last, None, "combo still in progress");
+
+        session.step(0xFFFF, DummyState::Stand);
+        // 100 + 90 + 80 after scaling.
+        let summary = ComboSummary {
+            hits: 3,
+            total_damage: 270,
+            max_combo: 3,
+        };
+        assert_eq!(session.combo.last, Some(summary));
+
+        // The next loop's first hit starts a new combo at full damage; the
+        // longest combo is kept.
+        session.set_positions(0, 0, 20, 0);
+        for _ in 0..30 {
+            session.step(0xFFFF, DummyState::Stand);
+            if !session.last_hits.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(session.last_hits.len(), 1);
+        session.set_positions(0, 0, 200, 0);
+        for _ in 0..14 {
+            session.step(0xFFFF, DummyState::Stand);
+        }
+        let summary = ComboSummary {
+            hits: 1,
+            total_damage: 100,
+            max_combo: 3,
+        };
+        assert_eq!(session.combo.last, Some(summary));
+
+        session.reset().expect("reset");
+        assert_eq!(session.combo.last, None);
+    }
+
+    #[test]
+    fn hits_drain_health_until_ko() {
+        let pack = boxed_state_pack_dealing(500, 0, 10, 0);
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(0, 0, 20, 0);
+        assert_eq!((session.health(0), session.health(1)), (1000, 1000));
+
+        let result = session.step(0xFFFF, DummyState::Stand);
+        assert_eq!(session.last_hits.len(), 1);
+        assert_eq!(session.health(1), 500);
+        assert_eq!(session.health(0), 1000, "the dummy's hitbox doesn't reach");
+        assert!(!result.ko);
+
+        // The second hit of the combo is scaled to 90%.
+        let result = session.step(0xFFFF, DummyState::Stand);
+        assert_eq!(session.health(1), 50);
+        assert!(!result.ko);
+
+        let result = session.step(0xFFFF, DummyState::Stand);
+        assert_eq!(session.health(1), 0);
+        assert!(result.ko);
+
+        // Health doesn't wrap, and stepping back revives the dummy.
+        assert!(session.step(0xFFFF, DummyState::Stand).ko);
+        assert_eq!(session.health(1), 0);
+        session.step_back();
+        session.step_back();
+        assert_eq!(session.health(1), 50);
+
+        session.reset().expect("reset");
+        assert_eq!(session.health(1), 1000);
+    }
+
+    #[test]
+    fn infinite_health_ignores_damage() {
+        let pack = boxed_state_pack_dealing(500, 0, 10, 0);
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(0, 0, 20, 0);
+        session.set_infinite_health(true);
+
+        for _ in 0..6 {
+            assert!(!session.step(0xFFFF, DummyState::Stand).ko);
+        }
+        assert_eq!(session.health(1), 1000);
+        assert_eq!(session.health(2), 0);
+    }
+
+    #[test]
+    fn starting_health_comes_from_the_health_property() {
+        use framesmith_fspack::{SECTION_CHARACTER_PROPS, SECTION_STRING_TABLE};
+
+        let mut prop = Vec::new();
+        prop.extend_from_slice(&0u32.to_le_bytes()); // name off
+        prop.extend_from_slice(&6u16.to_le_bytes()); // name len
+        prop.extend_from_slice(&[PROP_TYPE_Q24_8, 0]);
+        prop.extend_from_slice(&(1500i32 * 256).to_le_bytes());
+        let pack = build_pack(&[
+            (SECTION_STRING_TABLE, b"health".to_vec()),
+            (SECTION_CHARACTER_PROPS, prop),
+        ]);
+        let empty = empty_pack();
+        let session = TrainingSession::new(&pack, &empty).expect("pack should load");
+
+        assert_eq!(session.get_property("health"), Some(1500.0));
+        assert_eq!(
+            (session.health(0), session.health(1)),
+            (1500, DEFAULT_HEALTH)
+        );
+    }
+
+    #[test]
+    fn combo_scaling_comes_from_the_player_pack() {
+        use framesmith_fspack::{SECTION_CHARACTER_PROPS, SECTION_STRING_TABLE};
+
+        let mut props = Vec::new();
+        for (name_off, name_len, percent) in [(0u32, 18u16, 25i32), (18, 17, 50)] {
+            props.extend_from_slice(&name_off.to_le_bytes());
+            props.extend_from_slice(&name_len.to_le_bytes());
+            props.extend_from_slice(&[PROP_TYPE_Q24_8, 0]);
+            props.extend_from_slice(&(percent * 256).to_le_bytes());
+        }
+        let pack = build_pack(&[
+            (
+                SECTION_STRING_TABLE,
+                b"combo_scaling_stepcombo_scaling_min".to_vec(),
+            ),
+            (SECTION_CHARACTER_PROPS, props),
+        ]);
+        let empty = empty_pack();
+
+        // 25% less per hit, down to half damage.
+        let session = TrainingSession::new(&pack, &empty).expect("pack should load");
+        let scaled: Vec<u32> = (0..4)
+            .map(|hits| session.combo_scaling.scale(100, hits))
+            .collect();
+        assert_eq!(scaled, [100, 75, 50, 50]);
+
+        // The dummy's pack doesn't scale the player's combos.
+        let session = TrainingSession::new(&empty, &pack).expect("pack should load");
+        assert_eq!(session.combo_scaling.scale(100, 3), 70);
+        assert_eq!(session.combo_scaling.scale(100, 20), 10);
+    }
+
+    /// States: 0 idle (system), 1 jab with a hitbox on frames 0-5 and hitstun 12,
+    /// 2 a slow move with 10 startup frames. All have a radius-15 circle hurtbox.
+    fn counter_hit_pack() -> Vec<u8> {
+        let mut sections = boxed_state_sections(0, 0, 12, 0);
+        let (_, states) = &mut sections[0];
+        let jab = states.clone();
+        let mut idle = jab.clone();
+        idle[6] = 8; // system state
+        idle[26..28].fill(0); // no hit window
+        let mut slow = jab.clone();
+        slow[10] = 10; // startup
+        slow[26..28].fill(0);
+        *states = [idle, jab, slow].concat();
+        build_pack(&sections)
+    }
+
+    /// The player jabs the dummy while the dummy is in `dummy_state`.
+    fn jab_dummy_in(dummy_state: u16) -> Vec<HitResult> {
+        let pack = counter_hit_pack();
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(0, 0, 20, 0);
+        session.player_state.current_state = 1;
+        session.dummy_state.current_state = dummy_state;
+
+        session.step(0xFFFF, DummyState::Stand).hits
+    }
+
+    #[test]
+    fn hit_during_startup_is_counter_hit() {
+        let hits = jab_dummy_in(2);
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].counter_hit);
+        assert_eq!(hits[0].hitstun, 12 + u32::from(COUNTER_HIT_BONUS_HITSTUN));
+    }
+
+    #[test]
+    fn hit_at_idle_is_not_counter_hit() {
+        let hits = jab_dummy_in(0);
+        assert_eq!(hits.len(), 1);
+        assert!(!hits[0].counter_hit);
+        assert_eq!(hits[0].hitstun, 12);
+    }
+
+    #[test]
+    fn hit_on_the_last_frame_of_a_move_is_counter_hit() {
+        let pack = counter_hit_pack();
+        let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+        session.set_positions(0, 0, 20, 0);
+        session.player_state.current_state = 1;
+        session.dummy_state.current_state = 2;
+        session.dummy_state.frame = 29;
+
+        let hits = session.step(0xFFFF, DummyState::Stand).hits;
+        // The slow move ended during the exchange, but it was still running
+        // when the jab came out.
+        assert_eq!(session.dummy_state.current_state, 0);
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].counter_hit);
+    }
+
+    #[test]
+    fn counter_hit_bonus_lengthens_session_stun_only() {
+        for (dummy_state, stun) in [(0, 12), (2, 12 + COUNTER_HIT_BONUS_HITSTUN)] {
+            let pack = counter_hit_pack();
+            let mut session = TrainingSession::new(&pack, &pack).expect("pack should load");
+            session.set_positions(0, 0, 20, 0);
+            session.player_state.current_state = 1;
+            session.dummy_state.current_state = dummy_state;
+
+            session.step(0xFFFF, DummyState::Stand);
+            assert_eq!(session.dummy_stun, stun, "dummy state {dummy_state}");
+            assert_eq!(session.dummy_state.instance_duration, 0);
+        }
+    }
+
+    #[test]
+    fn history_depth_bounds_step_back() {
+        let mut session = empty_session();
+        session.set_history_depth(2);
+        for _ in 0..5 {
+            session.step(0xFFFF, DummyState::Stand);
+        }
+
+        assert_eq!(session.history_len(), 2);
+        assert!(session.step_back());
+        assert!(session.step_back());
+        assert!(!session.step_back());
+
+        session.set_history_depth(0);
+        session.step(0xFFFF, DummyState::Stand);
+        assert_eq!(session.history_len(), 0);
     }
 }
@@ -21,7 +21,7 @@ fn can_parse_real_fspk() {
     // Verify it has states
     assert!(pack.states().is_some(), "Should have states section");
     let states = pack.states().unwrap();
-    assert!(states.len() > 0, "Should have at least one state");
+    assert!(!states.is_empty(), "Should have at least one state");
 }
 
 /// Test runtime simulation with real FSPK data.
@@ -37,7 +37,7 @@ fn simulate_with_real_fspk() {
 
     // Initialize state
     let mut state = RtState::default();
-    init_resources(&mut state, &pack);
+    init_resources(&mut state, &pack).unwrap();
 
     // Simulate a few frames
     let input = FrameInput::default();
@@ -52,15 +52,18 @@ fn simulate_with_real_fspk() {
 
 #[test]
 fn character_state_conversion_roundtrip() {
-    use framesmith_runtime::CharacterState as RtState;
+    use framesmith_runtime::{CharacterState as RtState, MAX_RESOURCES};
 
+    let mut resources = [0; MAX_RESOURCES];
+    resources[..3].copy_from_slice(&[100, 50, 25]);
     let rt_state = RtState {
         current_state: 5,
         frame: 10,
         instance_duration: 0,
         hit_confirmed: true,
         block_confirmed: false,
-        resources: [100, 50, 25, 0, 0, 0, 0, 0],
+        resources,
+        ..Default::default()
     };
 
     let js_state = CharacterState::from(&rt_state);
@@ -69,7 +72,9 @@ fn character_state_conversion_roundtrip() {
     assert_eq!(js_state.frame, 10);
     assert!(js_state.hit_confirmed);
     assert!(!js_state.block_confirmed);
-    assert_eq!(js_state.resources, vec![100, 50, 25, 0, 0, 0, 0, 0]);
+    let mut expected = vec![0; MAX_RESOURCES];
+    expected[..3].copy_from_slice(&[100, 50, 25]);
+    assert_eq!(js_state.resources, expected);
 }
 
 #[test]
@@ -87,6 +92,7 @@ fn hit_result_conversion_roundtrip() {
         guard: 2,
         hit_pushback: 30,
         block_pushback: 25,
+        ..Default::default()
     };
 
     let js_hit = HitResult::from(&rt_hit);
@@ -8,6 +8,9 @@ pub const ACTION_SPECIAL: u16 = 1;
 pub const ACTION_SUPER: u16 = 2;
 pub const ACTION_JUMP: u16 = 3;
 
+/// Idle state index (state 0 by convention), the target of kara cancels.
+pub const IDLE_STATE: u16 = 0;
+
 /// Check if a state (move) has a specific tag.
 ///
 /// Searches the state's tag list for the given tag string.
@@ -18,13 +21,52 @@ fn state_has_tag(pack: &PackView, state_idx: u16, tag: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Cancel target range (CANCELS_U16 byte offset, count) of the current state's active hit.
+///
+/// The active hit is the one whose `start_frame..=end_frame` covers the
+/// current frame, so a hit's routes close when its active frames end. `None`
+/// outside every hit or when the state has no per-hit cancels.
+fn active_hit_cancels(state: &CharacterState, pack: &PackView) -> Option<(u32, u16)> {
+    pack.state_hit_cancels(state.current_state as usize)?
+        .iter()
+        .find(|hit| (hit.start_frame()..=hit.end_frame()).contains(&state.frame))
+        .map(|hit| hit.cancels())
+}
+
+/// Check if the active hit's cancel routes include `target`.
+fn active_hit_allows(state: &CharacterState, pack: &PackView, target: u16) -> bool {
+    let (Some((off, len)), Some(cancels)) = (active_hit_cancels(state, pack), pack.cancels())
+    else {
+        return false;
+    };
+    (0..len as usize).any(|i| cancels.get_at(off, i) == Some(target))
+}
+
+/// Check if the current state's kara window covers this frame.
+fn in_kara_window(state: &CharacterState, pack: &PackView) -> bool {
+    pack.states()
+        .and_then(|states| states.get(state.current_state as usize))
+        .and_then(|current| current.kara_window())
+        .is_some_and(|(start, end)| (start..=end).contains(&state.frame))
+}
+
+/// Check if the character is in a timed hitstun or blockstun instance.
+fn in_stun(state: &CharacterState) -> bool {
+    state.instance_duration > 0
+}
+
 /// Check if a cancel from current state to target move is valid.
 ///
 /// This checks (in priority order):
 /// 1. Explicit denies - block specific cancels
-/// 2. Tag-based rules (patterns like "normal->special on hit+block")
+/// 2. Stun - while stunned, only targets flagged `STATE_FLAG_ALLOW_FROM_STUN`
+///    (bursts, alpha counters) pass; every later route is closed
+/// 3. Kara window - cancel to [`IDLE_STATE`] inside the current state's window
+/// 4. Per-hit cancel routes of the active hit (multi-hit moves)
+/// 5. Tag-based rules (patterns like "normal->special on hit+block")
 ///
-/// Resource preconditions are checked for tag rules.
+/// Resource preconditions are checked for stun escapes, per-hit routes and
+/// tag rules.
 ///
 /// # Arguments
 /// * `state` - Current character state
@@ -54,7 +96,27 @@ pub fn can_cancel_to(state: &CharacterState, pack: &PackView, target: u16) -> bo
         return false;
     }
 
-    // 2. Check tag-based cancel rules
+    // 2. Stun only lets escapes through (bursts, alpha counters)
+    if in_stun(state) {
+        return moves
+            .get(target as usize)
+            .is_some_and(|t| t.allows_from_stun())
+            && crate::resource::check_resource_preconditions(state, pack, target);
+    }
+
+    // 3. Kara cancel back to idle
+    if target == IDLE_STATE && in_kara_window(state, pack) {
+        return true;
+    }
+
+    // 4. Per-hit routes of the hit currently in progress
+    if active_hit_allows(state, pack, target)
+        && crate::resource::check_resource_preconditions(state, pack, target)
+    {
+        return true;
+    }
+
+    // 5. Check tag-based cancel rules
     if let Some(rules) = pack.cancel_tag_rules() {
         for rule in rules.iter() {
             // Check from_tag matches (None means "any")
@@ -110,6 +172,73 @@ pub fn can_cancel_to(state: &CharacterState, pack: &PackView, target: u16) -> bo
     false
 }
 
+/// Check whether the character can accept a new requested state right now.
+///
+/// True at idle (state 0) outside of stun, or when [`can_cancel_to`] accepts
+/// at least one state this frame, i.e. the current move is inside a cancel
+/// window. Stun on idle (how hosts play hitstun and blockstun) is only
+/// actionable through a stun escape. This is the same gate `next_frame`
+/// applies to `FrameInput::requested_state`.
+#[must_use]
+pub fn is_actionable(state: &CharacterState, pack: &PackView) -> bool {
+    if state.current_state == IDLE_STATE && !in_stun(state) {
+        return true;
+    }
+    let move_count = pack.states().map_or(0, |m| m.len()) as u16;
+    (0..move_count).any(|target| can_cancel_to(state, pack, target))
+}
+
+/// Visit each explicit cancel route of the current state, then each route of
+/// its active hit, whose target passes resource preconditions.
+fn for_each_available_cancel(state: &CharacterState, pack: &PackView, mut f: impl FnMut(u16)) {
+    let hit_routes = active_hit_cancels(state, pack)
+        .zip(pack.cancels())
+        .into_iter()
+        .flat_map(|((off, len), cancels)| {
+            (0..len as usize).filter_map(move |i| cancels.get_at(off, i))
+        });
+
+    pack.cancels_for_state(state.current_state as usize)
+        .chain(hit_routes)
+        .filter(|&target| crate::resource::check_resource_preconditions(state, pack, target))
+        .for_each(&mut f);
+}
+
+/// Get all valid cancel targets from the current state.
+///
+/// Returns explicit cancel routes, including the active hit's per-hit routes,
+/// but not tag-rule matches; filtered by resource preconditions.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn available_cancels(state: &CharacterState, pack: &PackView) -> alloc::vec::Vec<u16> {
+    let mut result = alloc::vec::Vec::new();
+    for_each_available_cancel(state, pack, |target| result.push(target));
+    result
+}
+
+/// Get available cancels into a fixed-size buffer (`no_std` friendly).
+///
+/// Same targets as [`available_cancels`]. Stops writing when `buf` is full.
+///
+/// # Returns
+/// `(written, total)`: the number of cancels written to `buf`, and the number
+/// available. `total > written` means `buf` was too small and targets were
+/// dropped; retry with a buffer of at least `total` entries.
+pub fn available_cancels_buf(
+    state: &CharacterState,
+    pack: &PackView,
+    buf: &mut [u16],
+) -> (usize, usize) {
+    let mut total = 0;
+    for_each_available_cancel(state, pack, |target| {
+        if let Some(slot) = buf.get_mut(total) {
+            *slot = target;
+        }
+        total += 1;
+    });
+    (total.min(buf.len()), total)
+}
+
 /// Check if an action cancel is allowed based on current move's cancel flags.
 fn check_action_cancel(
     state: &CharacterState,
@@ -172,6 +301,298 @@ mod tests {
         // 2. Full integration tests are in Task 3.5
     }
 
+    use crate::test_support::tag_rule_pack;
+
+    #[test]
+    fn idle_is_actionable() {
+        let bytes = tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        assert!(is_actionable(&CharacterState::default(), &pack));
+    }
+
+    #[test]
+    fn stunned_idle_is_not_actionable() {
+        let bytes = tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let stunned = CharacterState {
+            instance_duration: 15,
+            frame: 3,
+            ..Default::default()
+        };
+        assert!(!is_actionable(&stunned, &pack));
+
+        // A stun escape still makes it actionable
+        let bytes = burst_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        assert!(is_actionable(&stunned, &pack));
+    }
+
+    #[test]
+    fn actionable_only_inside_cancel_window() {
+        let bytes = tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let at = |frame| CharacterState {
+            current_state: 1,
+            frame,
+            ..Default::default()
+        };
+
+        assert!(!is_actionable(&at(3), &pack), "startup is not cancelable");
+        assert!(is_actionable(&at(6), &pack));
+        assert!(is_actionable(&at(10), &pack));
+        assert!(!is_actionable(&at(11), &pack));
+    }
+
+    #[test]
+    fn non_cancelable_move_is_not_actionable() {
+        let bytes = tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState {
+            current_state: 3,
+            frame: 8,
+            ..Default::default()
+        };
+        assert!(!is_actionable(&state, &pack));
+    }
+
+    #[test]
+    fn available_cancels_buf_reports_truncation() {
+        use framesmith_fspack::{
+            SECTION_CANCELS_U16, SECTION_STATES, SECTION_STATE_EXTRAS, STATE_EXTRAS_SIZE,
+            STATE_RECORD_SIZE,
+        };
+
+        // State 0 routes to states 1..=10.
+        let cancels: std::vec::Vec<u8> = (1..=10u16).flat_map(u16::to_le_bytes).collect();
+        let mut extras = std::vec![0u8; STATE_EXTRAS_SIZE];
+        extras[68..70].copy_from_slice(&10u16.to_le_bytes());
+        let bytes = crate::test_support::build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 11 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_CANCELS_U16, cancels),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState::default();
+
+        let mut small = [0u16; 4];
+        assert_eq!(available_cancels_buf(&state, &pack, &mut small), (4, 10));
+        assert_eq!(small, [1, 2, 3, 4]);
+
+        let mut large = [0u16; 16];
+        assert_eq!(available_cancels_buf(&state, &pack, &mut large), (10, 10));
+        assert_eq!(large[..10], [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    /// States: 0 idle, 1 hitstun, 2 burst (`STATE_FLAG_ALLOW_FROM_STUN`), 3 normal.
+    fn burst_pack() -> std::vec::Vec<u8> {
+        use framesmith_fspack::{SECTION_STATES, STATE_FLAG_ALLOW_FROM_STUN, STATE_RECORD_SIZE};
+
+        let mut states = std::vec![0u8; 4 * STATE_RECORD_SIZE];
+        states[2 * STATE_RECORD_SIZE + 9] = STATE_FLAG_ALLOW_FROM_STUN;
+        crate::test_support::build_pack(&[(SECTION_STATES, states)])
+    }
+
+    #[test]
+    fn burst_is_performable_during_hitstun() {
+        let bytes = burst_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let hitstun = CharacterState {
+            current_state: 1,
+            frame: 3,
+            instance_duration: 20,
+            ..Default::default()
+        };
+
+        assert!(can_cancel_to(&hitstun, &pack, 2));
+        assert!(
+            !can_cancel_to(&hitstun, &pack, 3),
+            "normal moves stay locked out"
+        );
+        assert!(is_actionable(&hitstun, &pack));
+    }
+
+    #[test]
+    fn burst_flag_needs_stun() {
+        let bytes = burst_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let recovering = CharacterState {
+            current_state: 1,
+            frame: 3,
+            ..Default::default()
+        };
+
+        assert!(!can_cancel_to(&recovering, &pack, 2));
+        assert!(!is_actionable(&recovering, &pack));
+    }
+
+    #[test]
+    fn stun_closes_kara_per_hit_and_tag_routes() {
+        use framesmith_fspack::view::SECTION_CANCEL_TAG_RULES;
+        use framesmith_fspack::STATE_RECORD_SIZE;
+
+        // The rekka, with a kara window on state 1 and an any->any rule open
+        // on every frame and outcome.
+        let mut sections = rekka_sections();
+        sections[0].1[STATE_RECORD_SIZE + 21] = 30; // kara_frames, from frame 0
+        let mut rule = std::vec::Vec::new();
+        for _ in 0..2 {
+            rule.extend_from_slice(&u32::MAX.to_le_bytes()); // "any"
+            rule.extend_from_slice(&[0; 4]);
+        }
+        rule.extend_from_slice(&[0b111, 0, 255, 0, 0, 0, 0, 0]);
+        sections.push((SECTION_CANCEL_TAG_RULES, rule));
+        let bytes = crate::test_support::build_pack(&sections);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState {
+            current_state: 1,
+            frame: 6,
+            ..Default::default()
+        };
+        // Kara, per-hit route and tag rule respectively.
+        for target in [IDLE_STATE, 2, 3] {
+            assert!(can_cancel_to(&state, &pack, target), "target {target}");
+        }
+
+        state.instance_duration = 20;
+        for target in [IDLE_STATE, 2, 3] {
+            assert!(
+                !can_cancel_to(&state, &pack, target),
+                "target {target} in stun"
+            );
+        }
+        assert!(!is_actionable(&state, &pack));
+    }
+
+    /// Sections for states: 0 idle, 1 two-hit rekka (hit 1 on frames 5..=7 cancels to 2,
+    /// hit 2 on frames 15..=17 cancels to 3), 2 and 3 follow-ups.
+    fn rekka_sections() -> std::vec::Vec<(u32, std::vec::Vec<u8>)> {
+        use framesmith_fspack::{
+            SECTION_CANCELS_U16, SECTION_STATES, SECTION_STATE_HIT_CANCELS,
+            STATE_HIT_CANCELS_INDEX_ENTRY_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut cancels = std::vec::Vec::new();
+        cancels.extend_from_slice(&2u16.to_le_bytes());
+        cancels.extend_from_slice(&3u16.to_le_bytes());
+
+        let index_size = 4 * STATE_HIT_CANCELS_INDEX_ENTRY_SIZE;
+        let mut hit_cancels = std::vec![0u8; index_size];
+        hit_cancels[8..12].copy_from_slice(&(index_size as u32).to_le_bytes());
+        hit_cancels[12..14].copy_from_slice(&2u16.to_le_bytes());
+        for (start, end, off) in [(5u8, 7u8, 0u32), (15, 17, 2)] {
+            hit_cancels.extend_from_slice(&[start, end]);
+            hit_cancels.extend_from_slice(&1u16.to_le_bytes());
+            hit_cancels.extend_from_slice(&off.to_le_bytes());
+        }
+
+        std::vec![
+            (SECTION_STATES, std::vec![0u8; 4 * STATE_RECORD_SIZE]),
+            (SECTION_CANCELS_U16, cancels),
+            (SECTION_STATE_HIT_CANCELS, hit_cancels),
+        ]
+    }
+
+    #[test]
+    fn per_hit_cancels_follow_the_active_hit() {
+        let bytes = crate::test_support::build_pack(&rekka_sections());
+        let pack = PackView::parse(&bytes).unwrap();
+        let at = |frame| CharacterState {
+            current_state: 1,
+            frame,
+            ..Default::default()
+        };
+        let routes = |state: &CharacterState| {
+            let mut buf = [0u16; 4];
+            let (n, _) = available_cancels_buf(state, &pack, &mut buf);
+            buf[..n].to_vec()
+        };
+
+        let startup = at(3);
+        assert!(!can_cancel_to(&startup, &pack, 2));
+        assert!(!can_cancel_to(&startup, &pack, 3));
+        assert_eq!(routes(&startup), std::vec![]);
+
+        for frame in [5, 7] {
+            let hit_one = at(frame);
+            assert!(can_cancel_to(&hit_one, &pack, 2), "frame {frame}");
+            assert!(!can_cancel_to(&hit_one, &pack, 3), "frame {frame}");
+            assert_eq!(routes(&hit_one), std::vec![2]);
+        }
+
+        // Hit 1's window has ended and hit 2's hasn't begun
+        let between = at(10);
+        assert!(!can_cancel_to(&between, &pack, 2));
+        assert!(!can_cancel_to(&between, &pack, 3));
+        assert_eq!(routes(&between), std::vec![]);
+
+        let hit_two = at(16);
+        assert!(!can_cancel_to(&hit_two, &pack, 2));
+        assert!(can_cancel_to(&hit_two, &pack, 3));
+        assert_eq!(routes(&hit_two), std::vec![3]);
+
+        let recovery = at(18);
+        assert!(!can_cancel_to(&recovery, &pack, 3));
+        assert_eq!(routes(&recovery), std::vec![]);
+    }
+
+    #[test]
+    fn cancel_deny_overrides_per_hit_cancels() {
+        use framesmith_fspack::view::SECTION_CANCEL_DENIES;
+
+        let mut deny = std::vec::Vec::new();
+        deny.extend_from_slice(&1u16.to_le_bytes());
+        deny.extend_from_slice(&2u16.to_le_bytes());
+        let mut sections = rekka_sections();
+        sections.push((SECTION_CANCEL_DENIES, deny));
+        let bytes = crate::test_support::build_pack(&sections);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let state = CharacterState {
+            current_state: 1,
+            frame: 6,
+            ..Default::default()
+        };
+        assert!(!can_cancel_to(&state, &pack, 2));
+    }
+
+    #[test]
+    fn kara_window_allows_cancel_to_idle_only_inside_it() {
+        use framesmith_fspack::{SECTION_STATES, STATE_RECORD_SIZE};
+
+        // State 1 is a 10-frame move with a kara window on frames 1-3.
+        let mut states = std::vec![0u8; 3 * STATE_RECORD_SIZE];
+        let kara = &mut states[STATE_RECORD_SIZE..2 * STATE_RECORD_SIZE];
+        kara[13] = 1; // kara_start
+        kara[14..16].copy_from_slice(&10u16.to_le_bytes()); // total
+        kara[21] = 3; // kara_frames
+        let bytes = crate::test_support::build_pack(&[(SECTION_STATES, states)]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let at = |frame| CharacterState {
+            current_state: 1,
+            frame,
+            ..Default::default()
+        };
+
+        for frame in 0..10 {
+            let inside = (1..=3).contains(&frame);
+            assert_eq!(
+                can_cancel_to(&at(frame), &pack, IDLE_STATE),
+                inside,
+                "frame {frame}"
+            );
+            assert!(!can_cancel_to(&at(frame), &pack, 2), "frame {frame}");
+        }
+
+        // States without a window never kara cancel.
+        let other = CharacterState {
+            current_state: 2,
+            frame: 2,
+            ..Default::default()
+        };
+        assert!(!can_cancel_to(&other, &pack, IDLE_STATE));
+    }
+
     #[test]
     fn can_cancel_to_returns_false_for_empty_pack() {
         // can_cancel_to should return false when pack has no moves
@@ -0,0 +1,98 @@
+/// Logical frame rate the simulation is tuned for.
+pub const DEFAULT_FRAME_RATE: u32 = 60;
+
+const MICROS_PER_SECOND: u64 = 1_000_000;
+
+/// Fixed-timestep accumulator for hosts that don't tick at the frame rate.
+///
+/// Feed it the wall-clock time since the last call and it returns how many
+/// whole frames to simulate, carrying the remainder into the next call.
+/// Time is tracked in integer microseconds scaled by the frame rate, so one
+/// second of input always yields exactly `frame_rate` frames however it is
+/// split up.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FrameClock {
+    frame_rate: u32,
+    /// Elapsed microseconds times `frame_rate` not yet spent on a frame.
+    accumulator: u64,
+}
+
+impl FrameClock {
+    /// Create a clock ticking at `frame_rate` frames per second.
+    ///
+    /// A rate of 0 never yields frames.
+    #[must_use]
+    pub const fn new(frame_rate: u32) -> Self {
+        Self {
+            frame_rate,
+            accumulator: 0,
+        }
+    }
+
+    /// Frames per second this clock ticks at.
+    pub const fn frame_rate(&self) -> u32 {
+        self.frame_rate
+    }
+
+    /// Add `elapsed_micros` of wall-clock time and return the number of
+    /// frames to simulate now.
+    pub fn advance(&mut self, elapsed_micros: u32) -> u32 {
+        self.accumulator += u64::from(elapsed_micros) * u64::from(self.frame_rate);
+        let frames = self.accumulator / MICROS_PER_SECOND;
+        self.accumulator %= MICROS_PER_SECOND;
+        frames as u32
+    }
+
+    /// Drop the carried remainder, e.g. after the host was paused.
+    pub fn reset(&mut self) {
+        self.accumulator = 0;
+    }
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self::new(DEFAULT_FRAME_RATE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn irregular_steps_over_one_second_yield_sixty_frames() {
+        // Sums to exactly one second: jittery 60Hz, a 144Hz burst, and a stall.
+        let mut steps = std::vec::Vec::new();
+        steps.extend([16_000u32, 17_500, 15_900, 33_333, 8_100].repeat(5));
+        steps.extend([6_944u32; 20]);
+        steps.push(250_000);
+        let used: u32 = steps.iter().sum();
+        steps.push(1_000_000 - used);
+
+        let mut clock = FrameClock::default();
+        let frames: u32 = steps.iter().map(|&dt| clock.advance(dt)).sum();
+        assert_eq!(frames, 60);
+    }
+
+    #[test]
+    fn remainder_carries_into_the_next_call() {
+        let mut clock = FrameClock::new(60);
+        assert_eq!(clock.advance(10_000), 0);
+        assert_eq!(clock.advance(10_000), 1);
+        assert_eq!(clock.advance(50_000), 3);
+    }
+
+    #[test]
+    fn reset_drops_the_remainder() {
+        let mut clock = FrameClock::new(60);
+        clock.advance(16_000);
+        clock.reset();
+        assert_eq!(clock.advance(1_000), 0);
+    }
+
+    #[test]
+    fn zero_rate_never_yields_frames() {
+        let mut clock = FrameClock::new(0);
+        assert_eq!(clock.advance(u32::MAX), 0);
+    }
+}
@@ -0,0 +1,277 @@
+use framesmith_fspack::{
+    PackView, ShapeView, SHAPE_KIND_CAPSULE, SHAPE_KIND_CIRCLE, SHAPE_KIND_RECT,
+};
+
+use super::Aabb;
+use crate::state::CharacterState;
+
+/// Bounding box that encloses every hit, hurt, and push shape active on the
+/// character's current frame.
+///
+/// `facing` is `1` when the character faces +x and `-1` when it faces -x;
+/// negative values mirror shapes around `pos.0`. Rotated rects are bounded
+/// by their circumscribed circle, so the box may be loose but never misses
+/// a shape. Returns `None` when no shapes are active.
+///
+/// Use this to cull pairs of entities before calling `check_hits`.
+#[must_use]
+pub fn character_broadphase_aabb(
+    state: &CharacterState,
+    pack: &PackView,
+    pos: (i32, i32),
+    facing: i8,
+) -> Option<Aabb> {
+    let windows = pack.state_windows(state.current_state as usize)?;
+    let frame = state.frame;
+    let active = |start: u8, end: u8| frame >= start && frame <= end;
+
+    let mut bounds: Option<(i32, i32, i32, i32)> = None;
+    let mut include = |shape: ShapeView| {
+        let (x0, y0, x1, y1) = shape_bounds(&shape);
+        let (x0, x1) = if facing < 0 { (-x1, -x0) } else { (x0, x1) };
+        let b = (
+            x0.saturating_add(pos.0),
+            y0.saturating_add(pos.1),
+            x1.saturating_add(pos.0),
+            y1.saturating_add(pos.1),
+        );
+        bounds = Some(match bounds {
+            Some((min_x, min_y, max_x, max_y)) => (
+                min_x.min(b.0),
+                min_y.min(b.1),
+                max_x.max(b.2),
+                max_y.max(b.3),
+            ),
+            None => b,
+        });
+    };
+
+    for hw in windows
+        .hit_windows()
+        .filter(|w| active(w.start_frame(), w.end_frame()))
+    {
+        hw.shapes(pack).for_each(&mut include);
+    }
+    for hw in windows
+        .hurt_windows()
+        .filter(|w| active(w.start_frame(), w.end_frame()))
+    {
+        hw.shapes(pack).for_each(&mut include);
+    }
+    for pw in windows
+        .push_windows()
+        .filter(|w| active(w.start_frame(), w.end_frame()))
+    {
+        pw.shapes(pack).for_each(&mut include);
+    }
+
+    let (min_x, min_y, max_x, max_y) = bounds?;
+    Some(Aabb {
+        x: min_x,
+        y: min_y,
+        w: max_x.saturating_sub(min_x).max(0) as u32,
+        h: max_y.saturating_sub(min_y).max(0) as u32,
+    })
+}
+
+/// Character-local `(min_x, min_y, max_x, max_y)` of a shape in pixels.
+fn shape_bounds(shape: &ShapeView) -> (i32, i32, i32, i32) {
+    match shape.kind() {
+        SHAPE_KIND_CIRCLE => {
+            let (x, y, r) = (shape.x_px(), shape.y_px(), shape.radius_px() as i32);
+            (x - r, y - r, x + r, y + r)
+        }
+        SHAPE_KIND_CAPSULE => {
+            let x1 = shape.x_fixed().to_int();
+            let y1 = shape.y_fixed().to_int();
+            let x2 = shape.x2_fixed().to_int();
+            let y2 = shape.y2_fixed().to_int();
+            let r = shape.radius_fixed().to_int().max(0);
+            (
+                x1.min(x2) - r,
+                y1.min(y2) - r,
+                x1.max(x2) + r,
+                y1.max(y2) + r,
+            )
+        }
+        SHAPE_KIND_RECT => {
+            // Any rotation about the center stays inside the circumscribed
+            // circle, whose radius is at most (w + h) / 2.
+            let (w, h) = (shape.width_px() as i32, shape.height_px() as i32);
+            let (cx, cy) = (shape.x_px() + w / 2, shape.y_px() + h / 2);
+            let r = (w + h + 1) / 2;
+            (cx - r, cy - r, cx + r, cy + r)
+        }
+        _ => {
+            let (x, y) = (shape.x_px(), shape.y_px());
+            (
+                x,
+                y,
+                x + shape.width_px() as i32,
+                y + shape.height_px() as i32,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use framesmith_fspack::{
+        view::PUSH_WINDOW_SIZE, HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, SECTION_HIT_WINDOWS,
+        SECTION_HURT_WINDOWS, SECTION_PUSH_WINDOWS, SECTION_SHAPES, SECTION_STATES,
+        SHAPE_KIND_AABB, SHAPE_SIZE, STATE_RECORD_SIZE,
+    };
+    use std::vec::Vec;
+
+    fn shape(kind: u8, fields: [i16; 5]) -> Vec<u8> {
+        let mut rec = std::vec![0u8; SHAPE_SIZE];
+        rec[0] = kind;
+        for (i, v) in fields.iter().enumerate() {
+            rec[2 + i * 2..4 + i * 2].copy_from_slice(&v.to_le_bytes());
+        }
+        rec
+    }
+
+    /// State 0: hit window on frames 4..=6 (20x10 AABB at (30, -40)),
+    /// hurt window on frames 0..=10 (radius-15 circle at (0, -30)), and a
+    /// push window on frames 0..=10 (20x60 AABB at (-10, -60)).
+    fn broadphase_pack() -> Vec<u8> {
+        let mut states = std::vec![0u8; STATE_RECORD_SIZE];
+        states[26..28].copy_from_slice(&1u16.to_le_bytes());
+        states[30..32].copy_from_slice(&1u16.to_le_bytes());
+        states[34..36].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut hit = std::vec![0u8; HIT_WINDOW_SIZE];
+        hit[0] = 4;
+        hit[1] = 6;
+        hit[16..18].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut hurt = std::vec![0u8; HURT_WINDOW_SIZE];
+        hurt[1] = 10;
+        hurt[4..8].copy_from_slice(&(SHAPE_SIZE as u32).to_le_bytes());
+        hurt[8..10].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut push = std::vec![0u8; PUSH_WINDOW_SIZE];
+        push[1] = 10;
+        push[4..8].copy_from_slice(&(2 * SHAPE_SIZE as u32).to_le_bytes());
+        push[8..10].copy_from_slice(&1u16.to_le_bytes());
+
+        // Q12.4: pixels * 16
+        let mut shapes = shape(SHAPE_KIND_AABB, [30 * 16, -40 * 16, 20 * 16, 10 * 16, 0]);
+        shapes.extend(shape(SHAPE_KIND_CIRCLE, [0, -30 * 16, 15 * 16, 0, 0]));
+        shapes.extend(shape(
+            SHAPE_KIND_AABB,
+            [-10 * 16, -60 * 16, 20 * 16, 60 * 16, 0],
+        ));
+
+        crate::test_support::build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_HURT_WINDOWS, hurt),
+            (SECTION_PUSH_WINDOWS, push),
+            (SECTION_SHAPES, shapes),
+        ])
+    }
+
+    fn at_frame(frame: u8) -> CharacterState {
+        CharacterState {
+            frame,
+            ..Default::default()
+        }
+    }
+
+    fn encloses(outer: &Aabb, inner: &Aabb) -> bool {
+        inner.x >= outer.x
+            && inner.y >= outer.y
+            && inner.x + inner.w as i32 <= outer.x + outer.w as i32
+            && inner.y + inner.h as i32 <= outer.y + outer.h as i32
+    }
+
+    #[test]
+    fn broadphase_encloses_all_active_shapes() {
+        let bytes = broadphase_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let pos = (100, 200);
+
+        let aabb = character_broadphase_aabb(&at_frame(5), &pack, pos, 1).unwrap();
+        assert_eq!(
+            aabb,
+            Aabb {
+                x: 85,
+                y: 140,
+                w: 65,
+                h: 60
+            }
+        );
+        let hit = Aabb {
+            x: 130,
+            y: 160,
+            w: 20,
+            h: 10,
+        };
+        let circle = Aabb {
+            x: 85,
+            y: 155,
+            w: 30,
+            h: 30,
+        };
+        let push = Aabb {
+            x: 90,
+            y: 140,
+            w: 20,
+            h: 60,
+        };
+        for inner in [&hit, &circle, &push] {
+            assert!(encloses(&aabb, inner), "{:?} outside {:?}", inner, aabb);
+        }
+    }
+
+    #[test]
+    fn broadphase_skips_inactive_windows_and_mirrors_by_facing() {
+        let bytes = broadphase_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+
+        // Hit window inactive: circle and pushbox only.
+        let aabb = character_broadphase_aabb(&at_frame(0), &pack, (0, 0), 1).unwrap();
+        assert_eq!(
+            aabb,
+            Aabb {
+                x: -15,
+                y: -60,
+                w: 30,
+                h: 60
+            }
+        );
+
+        let mirrored = character_broadphase_aabb(&at_frame(5), &pack, (0, 0), -1).unwrap();
+        assert_eq!(
+            mirrored,
+            Aabb {
+                x: -50,
+                y: -60,
+                w: 65,
+                h: 60
+            }
+        );
+    }
+
+    #[test]
+    fn broadphase_is_none_without_active_shapes() {
+        let bytes = broadphase_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(
+            character_broadphase_aabb(&at_frame(11), &pack, (0, 0), 1),
+            None
+        );
+
+        let missing_state = CharacterState {
+            current_state: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            character_broadphase_aabb(&missing_state, &pack, (0, 0), 1),
+            None
+        );
+    }
+}
@@ -1,19 +1,29 @@
+mod broadphase;
+mod pushback;
 mod shapes;
+mod trade;
 
+pub use broadphase::character_broadphase_aabb;
+pub use pushback::{apply_pushback, apply_separation, resolve_knockback, StageBounds, WORLD_LIMIT};
+pub use trade::{move_priority, resolve_simultaneous, Resolution, TradePolicy};
 // Re-export shape types and functions for backward compatibility
 pub use shapes::{
-    Aabb, Capsule, Circle,
-    aabb_circle_overlap, aabb_overlap, capsule_overlap, circle_overlap, shapes_overlap,
+    aabb_circle_overlap, aabb_circle_overlap_info, aabb_overlap, aabb_overlap_info,
+    capsule_overlap, capsule_overlap_info, circle_overlap, circle_overlap_info, shape_overlap_info,
+    shapes_overlap, Aabb, Capsule, Circle, OverlapInfo, NORMAL_ONE,
 };
 
 use crate::state::CharacterState;
-use framesmith_fspack::{PackView, PushWindowView};
+use framesmith_fspack::{
+    PackView, PushWindowView, HURT_FLAG_FULL_INVULN, HURT_FLAG_STRIKE_INVULN,
+    HURT_FLAG_THROW_INVULN,
+};
 
 /// Maximum number of hit results that can be stored.
 pub const MAX_HIT_RESULTS: usize = 8;
 
 /// Result of a hit interaction.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct HitResult {
     /// Move ID of the attacking move.
     pub attacker_move: u16,
@@ -35,10 +45,34 @@ pub struct HitResult {
     pub hit_pushback: i32,
     /// Block pushback in pixels (applied on block).
     pub block_pushback: i32,
+    /// The connecting window is a throw (unblockable; block fields are zero).
+    pub is_throw: bool,
+    /// The hit bounces the defender off the wall.
+    pub wall_bounce: bool,
+    /// The hit bounces the defender off the ground.
+    pub ground_bounce: bool,
+    /// Vertical launch in pixels from knockback `y` (negative is up, 0 = no launch).
+    pub launch: i32,
+    /// World-space contact point of the connecting hitbox/hurtbox pair, for
+    /// placing hit sparks. `None` when the result wasn't produced by a shape test.
+    pub contact: Option<(i32, i32)>,
 }
 
 /// Check all hitbox vs hurtbox interactions between two characters.
 ///
+/// Every active hurt window is considered, so a hit window connects if it
+/// overlaps any hurt window that is vulnerable to it: strike-invulnerable
+/// windows ignore strikes, throw-invulnerable ones ignore throws, and fully
+/// invulnerable ones ignore both. An invulnerable box (an invincible arm, say)
+/// only saves the defender when it is the only thing the hitbox touches.
+///
+/// Throw windows (`HitWindowView::is_throw`) only connect against grounded
+/// defenders (`CharacterState::grounded`), and ignore blocking: their results
+/// carry no chip damage, blockstun, or block pushback.
+///
+/// Pushback comes from the attacker state's `STATE_PUSHBACK` record
+/// (`PackView::state_pushbacks`) and is 0 when the pack has none.
+///
 /// Returns hit results for the game to process.
 #[must_use]
 pub fn check_hits(
@@ -82,14 +116,11 @@ pub fn check_hits(
         Some(h) => h,
         None => return result,
     };
-    let attacker_shapes = match attacker_pack.shapes() {
-        Some(s) => s,
-        None => return result,
-    };
-    let defender_shapes = match defender_pack.shapes() {
-        Some(s) => s,
-        None => return result,
-    };
+    let pushback = attacker_pack
+        .state_pushbacks()
+        .and_then(|p| p.get(attacker_state.current_state as usize));
+    let hit_pushback = pushback.map_or(0, |p| p.hit_pushback_px());
+    let block_pushback = pushback.map_or(0, |p| p.block_pushback_px());
 
     // Iterate attacker's hit windows active this frame
     for hw_idx in 0..attacker_move.hit_windows_len() as usize {
@@ -103,6 +134,12 @@ pub fn check_hits(
             continue;
         }
 
+        // Throws can't grab airborne defenders
+        let is_throw = hw.is_throw();
+        if is_throw && !defender_state.grounded {
+            continue;
+        }
+
         // Iterate defender's hurt windows active this frame
         for hrt_idx in 0..defender_move.hurt_windows_len() as usize {
             let hrt = match hurt_windows.get_at(defender_move.hurt_windows_off(), hrt_idx) {
@@ -115,26 +152,36 @@ pub fn check_hits(
                 continue;
             }
 
+            // Invulnerable windows never mask the vulnerable ones checked after them.
+            if hurt_window_ignores(hrt.flags(), is_throw) {
+                continue;
+            }
+
             // Check shape overlaps
-            if check_window_overlap(
+            if let Some(overlap) = check_window_overlap(
                 &hw,
-                &attacker_shapes,
+                attacker_pack,
                 attacker_pos,
                 &hrt,
-                &defender_shapes,
+                defender_pack,
                 defender_pos,
             ) {
                 result.push(HitResult {
                     attacker_move: attacker_state.current_state,
                     window_index: hw_idx as u16,
                     damage: hw.damage(),
-                    chip_damage: hw.chip_damage(),
+                    chip_damage: if is_throw { 0 } else { hw.chip_damage() },
                     hitstun: hw.hitstun(),
-                    blockstun: hw.blockstun(),
+                    blockstun: if is_throw { 0 } else { hw.blockstun() },
                     hitstop: hw.hitstop(),
                     guard: hw.guard(),
-                    hit_pushback: hw.hit_pushback_px(),
-                    block_pushback: hw.block_pushback_px(),
+                    hit_pushback,
+                    block_pushback: if is_throw { 0 } else { block_pushback },
+                    is_throw,
+                    wall_bounce: hw.wall_bounce(),
+                    ground_bounce: hw.ground_bounce(),
+                    launch: i32::from(hw.launch()),
+                    contact: Some(overlap.contact),
                 });
                 // Only one hit per hit window per frame
                 break;
@@ -145,34 +192,30 @@ pub fn check_hits(
     result
 }
 
-/// Check if any hitbox shape overlaps any hurtbox shape.
+/// Whether a hurt window with `flags` is invulnerable to a strike or throw.
+fn hurt_window_ignores(flags: u8, is_throw: bool) -> bool {
+    let invuln = if is_throw {
+        HURT_FLAG_THROW_INVULN
+    } else {
+        HURT_FLAG_STRIKE_INVULN
+    };
+    flags & (invuln | HURT_FLAG_FULL_INVULN) != 0
+}
+
+/// Find the first hitbox shape that overlaps a hurtbox shape.
 fn check_window_overlap(
     hit_window: &framesmith_fspack::HitWindowView,
-    hit_shapes: &framesmith_fspack::ShapesView,
+    hit_pack: &PackView,
     hit_pos: (i32, i32),
     hurt_window: &framesmith_fspack::HurtWindowView,
-    hurt_shapes: &framesmith_fspack::ShapesView,
+    hurt_pack: &PackView,
     hurt_pos: (i32, i32),
-) -> bool {
-    for i in 0..hit_window.shapes_len() as usize {
-        let hit_shape = match hit_shapes.get_at(hit_window.shapes_off(), i) {
-            Some(s) => s,
-            None => continue,
-        };
-
-        for j in 0..hurt_window.shapes_len() as usize {
-            let hurt_shape = match hurt_shapes.get_at(hurt_window.shapes_off(), j) {
-                Some(s) => s,
-                None => continue,
-            };
-
-            if shapes_overlap(&hit_shape, hit_pos, &hurt_shape, hurt_pos) {
-                return true;
-            }
-        }
-    }
-
-    false
+) -> Option<OverlapInfo> {
+    hit_window.shapes(hit_pack).find_map(|hit_shape| {
+        hurt_window
+            .shapes(hurt_pack)
+            .find_map(|hurt_shape| shape_overlap_info(&hit_shape, hit_pos, &hurt_shape, hurt_pos))
+    })
 }
 
 /// Fixed-capacity result buffer for hit checks (no_std friendly).
@@ -264,7 +307,7 @@ pub fn calculate_pushbox_separation(p1_aabb: &Aabb, p2_aabb: &Aabb) -> Option<Pu
     } else {
         // P1 is to the right of P2
         // Overlap is how far P2's right edge extends past P1's left edge (negative to push P1 right)
-        -(p2_right.saturating_sub(p1_aabb.x))
+        p2_right.saturating_sub(p1_aabb.x).saturating_neg()
     };
 
     // Split the overlap between both characters (half each)
@@ -284,20 +327,10 @@ fn find_active_push_window<'a>(
     state: &CharacterState,
     pack: &'a PackView<'a>,
 ) -> Option<PushWindowView<'a>> {
-    let states = pack.states()?;
-    let move_state = states.get(state.current_state as usize)?;
-    let push_windows = pack.push_windows()?;
-
     let frame = state.frame;
-
-    // Iterate through push windows for this state and find one active this frame
-    for idx in 0..move_state.push_windows_len() as usize {
-        let pw = push_windows.get_at(move_state.push_windows_off(), idx)?;
-        if frame >= pw.start_frame() && frame <= pw.end_frame() {
-            return Some(pw);
-        }
-    }
-    None
+    pack.state_windows(state.current_state as usize)?
+        .push_windows()
+        .find(|pw| frame >= pw.start_frame() && frame <= pw.end_frame())
 }
 
 /// Get the AABB for a push window at a given position.
@@ -347,6 +380,9 @@ fn get_pushbox_aabb(
 /// The separation is calculated to push characters apart, splitting the overlap
 /// equally between them. Positive values indicate rightward movement.
 ///
+/// An airborne character (`CharacterState::grounded` false) only collides with
+/// other airborne characters, so jumping over a grounded opponent never pushes them.
+///
 /// # Arguments
 /// * `p1_state` - Player 1's character state
 /// * `p1_pack` - Player 1's character pack data
@@ -367,6 +403,10 @@ pub fn check_pushbox(
     let p1_pw = find_active_push_window(p1_state, p1_pack)?;
     let p2_pw = find_active_push_window(p2_state, p2_pack)?;
 
+    if p1_state.grounded != p2_state.grounded {
+        return None;
+    }
+
     // Get shapes sections
     let p1_shapes = p1_pack.shapes()?;
     let p2_shapes = p2_pack.shapes()?;
@@ -400,6 +440,11 @@ mod tests {
                 guard: 0,
                 hit_pushback: 0,
                 block_pushback: 0,
+                is_throw: false,
+                wall_bounce: false,
+                ground_bounce: false,
+                launch: 0,
+                contact: None,
             });
         }
 
@@ -407,6 +452,245 @@ mod tests {
         assert_eq!(result.len(), 8);
     }
 
+    // ==========================================================================
+    // Throw tests
+    // ==========================================================================
+
+    /// States: 0 throw (active 0..=5), 1 standing, 2 jumping, 3 throw-invulnerable.
+    ///
+    /// The jumping state is ordinary; callers mark the defender airborne.
+    fn throw_pack() -> std::vec::Vec<u8> {
+        hit_pack(framesmith_fspack::HIT_FLAG_THROW, 0)
+    }
+
+    /// Same layout as [`throw_pack`], with the given flags and launch on state 0's window.
+    fn hit_pack(flags: u8, launch: i8) -> std::vec::Vec<u8> {
+        use framesmith_fspack::{
+            HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS,
+            SECTION_SHAPES, SECTION_STATES, SECTION_STATE_PUSHBACK, SHAPE_SIZE,
+            STATE_PUSHBACK_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut states = std::vec![0u8; 4 * STATE_RECORD_SIZE];
+        states[26..28].copy_from_slice(&1u16.to_le_bytes()); // state 0: one hit window
+        for (i, hurt_off) in [(1, 0u16), (2, 0), (3, HURT_WINDOW_SIZE as u16)] {
+            let rec = &mut states[i * STATE_RECORD_SIZE..(i + 1) * STATE_RECORD_SIZE];
+            rec[28..30].copy_from_slice(&hurt_off.to_le_bytes());
+            rec[30..32].copy_from_slice(&1u16.to_le_bytes());
+        }
+
+        let mut hit = std::vec![0u8; HIT_WINDOW_SIZE];
+        hit[1] = 5; // end_frame
+        hit[3] = flags;
+        hit[4..6].copy_from_slice(&120u16.to_le_bytes()); // damage
+        hit[6..8].copy_from_slice(&6u16.to_le_bytes()); // chip
+        hit[8] = 20; // hitstun
+        hit[9] = 12; // blockstun
+        hit[11] = launch as u8;
+        hit[16..18].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+        // State 0 pushes 4px on hit and 7px on block (Q12.4)
+        let mut pushback = std::vec![0u8; 4 * STATE_PUSHBACK_SIZE];
+        pushback[0..2].copy_from_slice(&64i16.to_le_bytes());
+        pushback[2..4].copy_from_slice(&112i16.to_le_bytes());
+
+        let mut hurt = std::vec![0u8; 2 * HURT_WINDOW_SIZE];
+        for (i, flags) in [(0, 0u8), (1, HURT_FLAG_THROW_INVULN)] {
+            let rec = &mut hurt[i * HURT_WINDOW_SIZE..(i + 1) * HURT_WINDOW_SIZE];
+            rec[1] = 30; // end_frame
+            rec[2] = flags;
+            rec[8..10].copy_from_slice(&1u16.to_le_bytes()); // one shape
+        }
+
+        // 20x20 AABB at the origin (Q12.4)
+        let mut shape = std::vec![0u8; SHAPE_SIZE];
+        shape[6..8].copy_from_slice(&320i16.to_le_bytes());
+        shape[8..10].copy_from_slice(&320i16.to_le_bytes());
+
+        crate::test_support::build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_HURT_WINDOWS, hurt),
+            (SECTION_SHAPES, shape),
+            (SECTION_STATE_PUSHBACK, pushback),
+        ])
+    }
+
+    fn throw_against(defender_state: u16) -> CheckHitsResult {
+        let bytes = throw_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let attacker = CharacterState {
+            frame: 2,
+            ..Default::default()
+        };
+        let defender = CharacterState {
+            current_state: defender_state,
+            frame: 2,
+            grounded: defender_state != 2,
+            pos_y: if defender_state == 2 { -30 << 8 } else { 0 },
+            ..Default::default()
+        };
+        check_hits(&attacker, &pack, (0, 0), &defender, &pack, (10, 0))
+    }
+
+    #[test]
+    fn throw_connects_on_standing_defender_ignoring_block() {
+        let result = throw_against(1);
+        assert_eq!(result.len(), 1);
+
+        let hit = result.get(0).unwrap();
+        assert!(hit.is_throw);
+        assert_eq!(hit.damage, 120);
+        assert_eq!(hit.hitstun, 20);
+        assert_eq!(hit.chip_damage, 0);
+        assert_eq!(hit.blockstun, 0);
+        assert_eq!(hit.hit_pushback, 4);
+        assert_eq!(hit.block_pushback, 0);
+    }
+
+    #[test]
+    fn launcher_reports_bounces_and_launch() {
+        use framesmith_fspack::HIT_FLAG_WALL_BOUNCE;
+
+        let bytes = hit_pack(HIT_FLAG_WALL_BOUNCE, -45);
+        let pack = PackView::parse(&bytes).unwrap();
+        let attacker = CharacterState {
+            frame: 2,
+            ..Default::default()
+        };
+        let defender = CharacterState {
+            current_state: 1,
+            frame: 2,
+            ..Default::default()
+        };
+        let result = check_hits(&attacker, &pack, (0, 0), &defender, &pack, (10, 0));
+
+        let hit = result.get(0).unwrap();
+        assert!(!hit.is_throw);
+        assert!(hit.wall_bounce);
+        assert!(!hit.ground_bounce);
+        assert_eq!(hit.launch, -45);
+        // Non-throw hits keep their block fields.
+        assert_eq!(hit.blockstun, 12);
+        assert_eq!((hit.hit_pushback, hit.block_pushback), (4, 7));
+        // 20x20 boxes at x=0 and x=10 overlap across x 10..20.
+        assert_eq!(hit.contact, Some((15, 10)));
+    }
+
+    #[test]
+    fn throw_whiffs_on_airborne_defender() {
+        assert!(throw_against(2).is_empty());
+    }
+
+    #[test]
+    fn throw_whiffs_on_throw_invulnerable_defender() {
+        assert!(throw_against(3).is_empty());
+    }
+
+    /// State 0 strikes with a 20x20 AABB on frames 0-5. State 1 has two hurt
+    /// windows: a strike-invulnerable "arm" over x 0..20 listed first, and a
+    /// vulnerable "body" over x 20..40.
+    fn invuln_arm_pack() -> std::vec::Vec<u8> {
+        use framesmith_fspack::{
+            HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS,
+            SECTION_SHAPES, SECTION_STATES, SHAPE_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        states[26..28].copy_from_slice(&1u16.to_le_bytes()); // state 0: one hit window
+        let rec = &mut states[STATE_RECORD_SIZE..];
+        rec[30..32].copy_from_slice(&2u16.to_le_bytes()); // state 1: two hurt windows
+
+        let mut hit = std::vec![0u8; HIT_WINDOW_SIZE];
+        hit[1] = 5; // end_frame
+        hit[8] = 20; // hitstun
+        hit[16..18].copy_from_slice(&1u16.to_le_bytes()); // shape 0
+
+        let mut hurt = std::vec![0u8; 2 * HURT_WINDOW_SIZE];
+        for (i, flags) in [(0, HURT_FLAG_STRIKE_INVULN), (1, 0u8)] {
+            let rec = &mut hurt[i * HURT_WINDOW_SIZE..(i + 1) * HURT_WINDOW_SIZE];
+            rec[1] = 30; // end_frame
+            rec[2] = flags;
+            let shape_off = ((i + 1) * SHAPE_SIZE) as u32;
+            rec[4..8].copy_from_slice(&shape_off.to_le_bytes());
+            rec[8..10].copy_from_slice(&1u16.to_le_bytes());
+        }
+
+        // 20x20 AABBs at x = 0, 0 and 20 (Q12.4)
+        let mut shapes = std::vec![0u8; 3 * SHAPE_SIZE];
+        for (i, x) in [(0, 0i16), (1, 0), (2, 20)] {
+            let shape = &mut shapes[i * SHAPE_SIZE..(i + 1) * SHAPE_SIZE];
+            shape[2..4].copy_from_slice(&(x * 16).to_le_bytes());
+            shape[6..8].copy_from_slice(&320i16.to_le_bytes());
+            shape[8..10].copy_from_slice(&320i16.to_le_bytes());
+        }
+
+        crate::test_support::build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_HURT_WINDOWS, hurt),
+            (SECTION_SHAPES, shapes),
+        ])
+    }
+
+    /// Strike the `invuln_arm_pack` defender standing at x = 100 from `attacker_x`.
+    fn strike_arm_and_body(attacker_x: i32) -> CheckHitsResult {
+        let bytes = invuln_arm_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let attacker = CharacterState {
+            frame: 2,
+            ..Default::default()
+        };
+        let defender = CharacterState {
+            current_state: 1,
+            frame: 2,
+            ..Default::default()
+        };
+        check_hits(
+            &attacker,
+            &pack,
+            (attacker_x, 0),
+            &defender,
+            &pack,
+            (100, 0),
+        )
+    }
+
+    #[test]
+    fn strike_whiffs_when_only_touching_invulnerable_hurtbox() {
+        // Hitbox over x 95..115 touches only the arm (100..120).
+        assert!(strike_arm_and_body(95).is_empty());
+    }
+
+    #[test]
+    fn strike_lands_on_vulnerable_hurtbox_behind_invulnerable_one() {
+        // Hitbox over x 110..130 touches the arm and the body (120..140); the
+        // arm is listed first but doesn't mask the body.
+        let result = strike_arm_and_body(110);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.get(0).unwrap().contact, Some((125, 10)));
+
+        // Hitbox over x 130..150 touches only the body.
+        assert_eq!(strike_arm_and_body(130).len(), 1);
+    }
+
+    #[test]
+    fn throw_whiffs_out_of_range() {
+        let bytes = throw_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let attacker = CharacterState {
+            frame: 2,
+            ..Default::default()
+        };
+        let defender = CharacterState {
+            current_state: 1,
+            frame: 2,
+            ..Default::default()
+        };
+        let result = check_hits(&attacker, &pack, (0, 0), &defender, &pack, (100, 0));
+        assert!(result.is_empty());
+    }
+
     // ==========================================================================
     // Pushbox separation tests
     // ==========================================================================
@@ -564,4 +848,74 @@ mod tests {
         let result = calculate_pushbox_separation(&p1, &p2);
         assert!(result.is_none());
     }
+
+    /// One state with a 20x60 pushbox on frames 0..=10.
+    fn push_pack() -> std::vec::Vec<u8> {
+        use framesmith_fspack::{
+            view::PUSH_WINDOW_SIZE, SECTION_PUSH_WINDOWS, SECTION_SHAPES, SECTION_STATES,
+            SHAPE_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut states = std::vec![0u8; STATE_RECORD_SIZE];
+        states[34..36].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut push = std::vec![0u8; PUSH_WINDOW_SIZE];
+        push[1] = 10; // end_frame
+        push[8..10].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+        // 20x60 AABB at the origin (Q12.4)
+        let mut shape = std::vec![0u8; SHAPE_SIZE];
+        shape[6..8].copy_from_slice(&320i16.to_le_bytes());
+        shape[8..10].copy_from_slice(&960i16.to_le_bytes());
+
+        crate::test_support::build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_PUSH_WINDOWS, push),
+            (SECTION_SHAPES, shape),
+        ])
+    }
+
+    fn push_between(p1_grounded: bool, p2_grounded: bool) -> Option<PushboxResult> {
+        let bytes = push_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let p1 = CharacterState {
+            grounded: p1_grounded,
+            ..Default::default()
+        };
+        let p2 = CharacterState {
+            grounded: p2_grounded,
+            ..Default::default()
+        };
+        check_pushbox(&p1, &pack, (0, 0), &p2, &pack, (10, 0))
+    }
+
+    #[test]
+    fn grounded_pushboxes_separate() {
+        let sep = push_between(true, true).unwrap();
+        assert_eq!(
+            sep,
+            PushboxResult {
+                p1_dx: -5,
+                p2_dx: 5
+            }
+        );
+    }
+
+    #[test]
+    fn airborne_pushbox_ignores_grounded_opponent() {
+        assert_eq!(push_between(false, true), None);
+        assert_eq!(push_between(true, false), None);
+    }
+
+    #[test]
+    fn airborne_pushboxes_separate_from_each_other() {
+        let sep = push_between(false, false).unwrap();
+        assert_eq!(
+            sep,
+            PushboxResult {
+                p1_dx: -5,
+                p2_dx: 5
+            }
+        );
+    }
 }
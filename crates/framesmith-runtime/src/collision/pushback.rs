@@ -0,0 +1,315 @@
+use super::{HitResult, PushboxResult};
+
+/// Largest distance from the origin, in pixels, that [`StageBounds::WORLD`]
+/// allows. Far beyond any stage, and small enough that adding shape offsets
+/// or another position can't overflow `i32`.
+pub const WORLD_LIMIT: i32 = 1 << 24;
+
+/// Horizontal stage limits in pixels (positions are clamped to `left..=right`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StageBounds {
+    pub left: i32,
+    pub right: i32,
+}
+
+impl StageBounds {
+    /// The whole world (`-WORLD_LIMIT..=WORLD_LIMIT`), for callers without a stage.
+    pub const WORLD: StageBounds = StageBounds {
+        left: -WORLD_LIMIT,
+        right: WORLD_LIMIT,
+    };
+
+    /// Clamp `x` into the bounds. A `right` left of `left` is treated as `left`.
+    #[must_use]
+    pub fn clamp_x(self, x: i32) -> i32 {
+        x.clamp(self.left, self.right.max(self.left))
+    }
+}
+
+/// Apply a hit's pushback to both characters.
+///
+/// Picks `block_pushback` when `blocked`, otherwise `hit_pushback`:
+/// - On hit, the defender slides the full amount away from the attacker.
+/// - On block, the amount is split: the defender slides back half, the
+///   attacker recoils the other half (the defender takes any odd pixel).
+///
+/// Whatever distance the defender can't travel because of the wall is
+/// transferred to the attacker, so cornered opponents push the attacker out
+/// instead. Both positions are clamped to `bounds`; `y` is left unchanged.
+///
+/// # Returns
+/// `(new_attacker_pos, new_defender_pos)`
+#[must_use]
+pub fn apply_pushback(
+    attacker_pos: (i32, i32),
+    defender_pos: (i32, i32),
+    hit: &HitResult,
+    blocked: bool,
+    bounds: StageBounds,
+) -> ((i32, i32), (i32, i32)) {
+    let amount = if blocked {
+        hit.block_pushback
+    } else {
+        hit.hit_pushback
+    }
+    .max(0);
+
+    // Push away from the attacker; a defender exactly on top is pushed right.
+    let dir = if defender_pos.0 >= attacker_pos.0 {
+        1
+    } else {
+        -1
+    };
+
+    let (defender_share, attacker_share) = if blocked {
+        (amount - amount / 2, amount / 2)
+    } else {
+        (amount, 0)
+    };
+
+    let defender_x = bounds.clamp_x(defender_pos.0.saturating_add(dir * defender_share));
+    let defender_moved = defender_x.saturating_sub(defender_pos.0).saturating_abs();
+    let attacker_share =
+        attacker_share.saturating_add(defender_share.saturating_sub(defender_moved));
+    let attacker_x = bounds.clamp_x(attacker_pos.0.saturating_sub(dir * attacker_share));
+
+    ((attacker_x, attacker_pos.1), (defender_x, defender_pos.1))
+}
+
+/// Apply a pushbox separation (see [`check_pushbox`](super::check_pushbox))
+/// to both characters.
+///
+/// Whatever distance one character can't travel because of the wall is
+/// carried over to the other, so a cornered character pushes its opponent
+/// out. Both positions are clamped to `bounds`; `y` is left unchanged.
+///
+/// # Returns
+/// `(new_p1_pos, new_p2_pos)`
+#[must_use]
+pub fn apply_separation(
+    p1_pos: (i32, i32),
+    p2_pos: (i32, i32),
+    push: &PushboxResult,
+    bounds: StageBounds,
+) -> ((i32, i32), (i32, i32)) {
+    // Distance each push falls short of because of the wall, signed like the push.
+    let blocked = |x: i32, dx: i32| {
+        let start = bounds.clamp_x(x);
+        let moved = bounds
+            .clamp_x(start.saturating_add(dx))
+            .saturating_sub(start);
+        dx.saturating_sub(moved)
+    };
+    let p1_carry = blocked(p1_pos.0, push.p1_dx);
+    let p2_carry = blocked(p2_pos.0, push.p2_dx);
+
+    let p1_x = bounds.clamp_x(p1_pos.0.saturating_add(push.p1_dx).saturating_sub(p2_carry));
+    let p2_x = bounds.clamp_x(p2_pos.0.saturating_add(push.p2_dx).saturating_sub(p1_carry));
+    ((p1_x, p1_pos.1), (p2_x, p2_pos.1))
+}
+
+/// Resolve an authored knockback into a world-space velocity for the defender.
+///
+/// Knockback is authored from the attacker's side: `+x` is the attacker's
+/// forward, so it pushes the defender away. Read it for the attacking state
+/// from [`PackView::state_knockbacks`](framesmith_fspack::PackView::state_knockbacks).
+/// `attacker_facing` is `1` when the attacker faces +x and `-1` when it faces
+/// -x; `x` is mirrored for negative facing. `y` passes through unchanged
+/// (negative is up).
+#[must_use]
+pub fn resolve_knockback(knockback: (i32, i32), attacker_facing: i8) -> (i32, i32) {
+    let (x, y) = knockback;
+    if attacker_facing < 0 {
+        (x.saturating_neg(), y)
+    } else {
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAGE: StageBounds = StageBounds {
+        left: -300,
+        right: 300,
+    };
+
+    fn hit(hit_pushback: i32, block_pushback: i32) -> HitResult {
+        HitResult {
+            attacker_move: 0,
+            window_index: 0,
+            damage: 10,
+            chip_damage: 0,
+            hitstun: 12,
+            blockstun: 8,
+            hitstop: 4,
+            guard: 0,
+            hit_pushback,
+            block_pushback,
+            is_throw: false,
+            wall_bounce: false,
+            ground_bounce: false,
+            launch: 0,
+            contact: None,
+        }
+    }
+
+    /// The duel attack connecting at frame 3, with its pushback authored in a
+    /// STATE_PUSHBACK section as raw Q12.4 `(hit, block)`.
+    fn duel_hit(pushback: (i16, i16)) -> HitResult {
+        use crate::CharacterState;
+        use framesmith_fspack::{PackView, SECTION_STATE_PUSHBACK, STATE_PUSHBACK_SIZE};
+
+        let mut records = std::vec![0u8; 2 * STATE_PUSHBACK_SIZE];
+        records[4..6].copy_from_slice(&pushback.0.to_le_bytes());
+        records[6..8].copy_from_slice(&pushback.1.to_le_bytes());
+        let mut sections = crate::test_support::duel_sections();
+        sections.push((SECTION_STATE_PUSHBACK, records));
+        let bytes = crate::test_support::build_pack(&sections);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let attacker = CharacterState {
+            current_state: 1,
+            frame: 3,
+            ..Default::default()
+        };
+        let defender = CharacterState::default();
+        let hits = super::super::check_hits(&attacker, &pack, (0, 0), &defender, &pack, (10, 0));
+        *hits.get(0).expect("duel attack connects")
+    }
+
+    #[test]
+    fn pack_pushback_moves_characters_on_hit_and_block() {
+        // 6px on hit, 10px on block.
+        let hit = duel_hit((96, 160));
+        assert_eq!((hit.hit_pushback, hit.block_pushback), (6, 10));
+
+        let on_hit = apply_pushback((0, 0), (10, 0), &hit, false, STAGE);
+        assert_eq!(on_hit, ((0, 0), (16, 0)));
+        let on_block = apply_pushback((0, 0), (10, 0), &hit, true, STAGE);
+        assert_eq!(on_block, ((-5, 0), (15, 0)));
+    }
+
+    #[test]
+    fn hit_pushes_only_the_defender() {
+        let (attacker, defender) = apply_pushback((0, 0), (50, 0), &hit(20, 30), false, STAGE);
+        assert_eq!(attacker, (0, 0));
+        assert_eq!(defender, (70, 0));
+    }
+
+    #[test]
+    fn block_uses_block_pushback_split_between_both() {
+        let (attacker, defender) = apply_pushback((0, 0), (50, 0), &hit(20, 30), true, STAGE);
+        assert_eq!(attacker, (-15, 0));
+        assert_eq!(defender, (65, 0));
+    }
+
+    #[test]
+    fn cornered_defender_transfers_pushback_to_attacker() {
+        // Defender 5px from the right wall.
+        let on_hit = apply_pushback((245, 0), (295, 0), &hit(20, 30), false, STAGE);
+        assert_eq!(on_hit, ((230, 0), (300, 0)));
+
+        let on_block = apply_pushback((245, 0), (295, 0), &hit(20, 30), true, STAGE);
+        assert_eq!(on_block, ((220, 0), (300, 0)));
+    }
+
+    #[test]
+    fn pushback_direction_follows_sides() {
+        let (attacker, defender) = apply_pushback((50, 0), (0, 10), &hit(20, 30), true, STAGE);
+        assert_eq!(attacker, (65, 0));
+        assert_eq!(defender, (-15, 10));
+    }
+
+    #[test]
+    fn extreme_repeated_pushback_stays_in_bounds() {
+        let huge = hit(i32::MAX, i32::MAX);
+        for bounds in [
+            STAGE,
+            StageBounds::WORLD,
+            StageBounds {
+                left: i32::MIN,
+                right: i32::MAX,
+            },
+        ] {
+            let (mut attacker, mut defender) = ((0, 0), (1, 0));
+            for i in 0..1000 {
+                (attacker, defender) =
+                    apply_pushback(attacker, defender, &huge, i % 2 == 0, bounds);
+                for x in [attacker.0, defender.0] {
+                    assert!((bounds.left..=bounds.right).contains(&x), "{x} on push {i}");
+                }
+            }
+            // Cornered on the right, so every push now slams the attacker left.
+            assert_eq!((attacker.0, defender.0), (bounds.left, bounds.right));
+        }
+    }
+
+    #[test]
+    fn largest_pack_pushback_stays_in_bounds() {
+        let hit = duel_hit((i16::MAX, i16::MAX));
+        assert_eq!((hit.hit_pushback, hit.block_pushback), (2047, 2047));
+
+        for bounds in [STAGE, StageBounds::WORLD] {
+            let (mut attacker, mut defender) = ((0, 0), (10, 0));
+            for i in 0..40_000 {
+                (attacker, defender) = apply_pushback(attacker, defender, &hit, i % 3 == 0, bounds);
+                for x in [attacker.0, defender.0] {
+                    assert!((bounds.left..=bounds.right).contains(&x), "{x} on push {i}");
+                }
+            }
+            assert_eq!((attacker.0, defender.0), (bounds.left, bounds.right));
+        }
+    }
+
+    #[test]
+    fn separation_carries_out_of_the_corner() {
+        let push = PushboxResult {
+            p1_dx: 5,
+            p2_dx: -5,
+        };
+        // P1 3px from the right wall: 2px of its push carries over to P2.
+        let (p1, p2) = apply_separation((297, 0), (290, 4), &push, STAGE);
+        assert_eq!((p1, p2), ((300, 0), (283, 4)));
+
+        let huge = PushboxResult {
+            p1_dx: i32::MIN,
+            p2_dx: i32::MAX,
+        };
+        let (mut p1, mut p2) = ((0, 0), (0, 0));
+        for _ in 0..1000 {
+            (p1, p2) = apply_separation(p1, p2, &huge, StageBounds::WORLD);
+        }
+        assert_eq!((p1.0, p2.0), (-WORLD_LIMIT, WORLD_LIMIT));
+    }
+
+    #[test]
+    fn knockback_x_follows_attacker_facing() {
+        assert_eq!(resolve_knockback((6, -4), 1), (6, -4));
+        assert_eq!(resolve_knockback((6, -4), -1), (-6, -4));
+        // Negative x pulls the defender toward the attacker on either side.
+        assert_eq!(resolve_knockback((-3, 0), 1), (-3, 0));
+        assert_eq!(resolve_knockback((-3, 0), -1), (3, 0));
+    }
+
+    #[test]
+    fn knockback_resolves_from_pack_records() {
+        use framesmith_fspack::{
+            PackView, SECTION_STATES, SECTION_STATE_KNOCKBACK, STATE_RECORD_SIZE,
+        };
+
+        let mut knockback = std::vec![0u8; 4];
+        knockback[0..2].copy_from_slice(&12i16.to_le_bytes());
+        knockback[2..4].copy_from_slice(&(-20i16).to_le_bytes());
+        let bytes = crate::test_support::build_pack(&[
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+            (SECTION_STATE_KNOCKBACK, knockback),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let k = pack.state_knockbacks().unwrap().get(0).unwrap();
+        let authored = (i32::from(k.x()), i32::from(k.y()));
+        assert_eq!(resolve_knockback(authored, -1), (-12, -20));
+    }
+}
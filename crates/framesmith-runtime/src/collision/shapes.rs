@@ -132,15 +132,26 @@ fn closest_point_on_segment(p1: (i64, i64), p2: (i64, i64), p: (i64, i64)) -> (i
     )
 }
 
+/// Find the closest points between two line segments, as (on A, on B).
+fn closest_points_between_segments(
+    a1: (i64, i64),
+    a2: (i64, i64),
+    b1: (i64, i64),
+    b2: (i64, i64),
+) -> ((i64, i64), (i64, i64)) {
+    // Find closest point on segment A to segment B's closest point to A
+    let closest_on_b_to_a1 = closest_point_on_segment(b1, b2, a1);
+    let closest_on_a = closest_point_on_segment(a1, a2, closest_on_b_to_a1);
+    let closest_on_b = closest_point_on_segment(b1, b2, closest_on_a);
+    (closest_on_a, closest_on_b)
+}
+
 /// Compute squared distance between closest points on two line segments.
 fn segment_distance_sq(
     a1: (i64, i64), a2: (i64, i64),
     b1: (i64, i64), b2: (i64, i64),
 ) -> i64 {
-    // Find closest point on segment A to segment B's closest point to A
-    let closest_on_b_to_a1 = closest_point_on_segment(b1, b2, a1);
-    let closest_on_a = closest_point_on_segment(a1, a2, closest_on_b_to_a1);
-    let closest_on_b = closest_point_on_segment(b1, b2, closest_on_a);
+    let (closest_on_a, closest_on_b) = closest_points_between_segments(a1, a2, b1, b2);
 
     let dx = closest_on_a.0 - closest_on_b.0;
     let dy = closest_on_a.1 - closest_on_b.1;
@@ -203,6 +214,206 @@ pub fn shapes_overlap(
     }
 }
 
+/// Length of an [`OverlapInfo::normal`] (1.0 in Q8.8).
+pub const NORMAL_ONE: i32 = 256;
+
+/// Geometry of an overlap between two shapes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverlapInfo {
+    /// How far the shapes interpenetrate along `normal`, in pixels (at least 1).
+    pub depth: i32,
+    /// Direction from `a` toward `b` that separates them, as a Q8.8 vector of
+    /// length [`NORMAL_ONE`].
+    pub normal: (i32, i32),
+    /// World-space point in the middle of the overlap, in pixels.
+    pub contact: (i32, i32),
+}
+
+impl OverlapInfo {
+    /// The same overlap seen from `b`: the normal is reversed.
+    fn flipped(self) -> Self {
+        Self {
+            normal: (-self.normal.0, -self.normal.1),
+            ..self
+        }
+    }
+}
+
+fn to_i32(v: i64) -> i32 {
+    v.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Overlap of two round shapes: a disc of radius `ra` at `pa` and one of
+/// radius `rb` at `pb`. Circles, capsules (at their closest segment points)
+/// and boxes (as a zero-radius point) all reduce to this.
+fn round_overlap(pa: (i64, i64), ra: i64, pb: (i64, i64), rb: i64) -> Option<OverlapInfo> {
+    let dx = pb.0 - pa.0;
+    let dy = pb.1 - pa.1;
+    let dist_sq = dx * dx + dy * dy;
+    let radii_sum = ra + rb;
+    if dist_sq >= radii_sum * radii_sum {
+        return None;
+    }
+
+    let depth = radii_sum - dist_sq.isqrt();
+    if dist_sq == 0 {
+        return Some(OverlapInfo {
+            depth: to_i32(depth),
+            normal: (NORMAL_ONE, 0),
+            contact: (to_i32(pa.0), to_i32(pa.1)),
+        });
+    }
+
+    // Distance in Q8.8, so the normal keeps its precision for short offsets.
+    let one = i128::from(NORMAL_ONE);
+    let len_q8 = i128::from((dist_sq << 16).isqrt());
+    let normal = (
+        (i128::from(dx) * one * one / len_q8) as i32,
+        (i128::from(dy) * one * one / len_q8) as i32,
+    );
+    // Midway through the overlap: `ra - depth / 2` from `pa` toward `pb`.
+    let reach = i128::from(2 * ra - depth) * one;
+    let contact = (
+        pa.0 + (i128::from(dx) * reach / (2 * len_q8)) as i64,
+        pa.1 + (i128::from(dy) * reach / (2 * len_q8)) as i64,
+    );
+    Some(OverlapInfo {
+        depth: to_i32(depth),
+        normal,
+        contact: (to_i32(contact.0), to_i32(contact.1)),
+    })
+}
+
+/// Overlap geometry of two AABBs. Edge-touching is not an overlap.
+///
+/// The normal is along the axis of least penetration.
+#[must_use]
+pub fn aabb_overlap_info(a: &Aabb, b: &Aabb) -> Option<OverlapInfo> {
+    let (a_left, a_top) = (a.x as i64, a.y as i64);
+    let (b_left, b_top) = (b.x as i64, b.y as i64);
+    let (a_right, a_bottom) = (a_left + a.w as i64, a_top + a.h as i64);
+    let (b_right, b_bottom) = (b_left + b.w as i64, b_top + b.h as i64);
+
+    let (left, right) = (a_left.max(b_left), a_right.min(b_right));
+    let (top, bottom) = (a_top.max(b_top), a_bottom.min(b_bottom));
+    let (overlap_x, overlap_y) = (right - left, bottom - top);
+    if overlap_x <= 0 || overlap_y <= 0 {
+        return None;
+    }
+
+    // Compare doubled centers to stay in integers.
+    let sign = |a_center: i64, b_center: i64| if b_center >= a_center { 1 } else { -1 };
+    let (depth, normal) = if overlap_x <= overlap_y {
+        (
+            overlap_x,
+            (sign(a_left + a_right, b_left + b_right) * NORMAL_ONE, 0),
+        )
+    } else {
+        (
+            overlap_y,
+            (0, sign(a_top + a_bottom, b_top + b_bottom) * NORMAL_ONE),
+        )
+    };
+    Some(OverlapInfo {
+        depth: to_i32(depth),
+        normal,
+        contact: (
+            to_i32((left + right).div_euclid(2)),
+            to_i32((top + bottom).div_euclid(2)),
+        ),
+    })
+}
+
+/// Overlap geometry of two circles. Edge-touching is not an overlap.
+#[must_use]
+pub fn circle_overlap_info(a: &Circle, b: &Circle) -> Option<OverlapInfo> {
+    round_overlap(
+        (a.x as i64, a.y as i64),
+        a.r as i64,
+        (b.x as i64, b.y as i64),
+        b.r as i64,
+    )
+}
+
+/// Overlap geometry of an AABB (`a`) and a circle (`b`).
+///
+/// A circle centered inside the box is pushed out through the nearest edge.
+#[must_use]
+pub fn aabb_circle_overlap_info(aabb: &Aabb, circle: &Circle) -> Option<OverlapInfo> {
+    let (left, top) = (aabb.x as i64, aabb.y as i64);
+    let (right, bottom) = (left + aabb.w as i64, top + aabb.h as i64);
+    let (cx, cy, r) = (circle.x as i64, circle.y as i64, circle.r as i64);
+
+    let closest = (cx.clamp(left, right), cy.clamp(top, bottom));
+    if closest != (cx, cy) {
+        return round_overlap(closest, 0, (cx, cy), r);
+    }
+
+    // Center inside the box: exit through the nearest edge.
+    let exits = [
+        (cx - left, (-NORMAL_ONE, 0)),
+        (right - cx, (NORMAL_ONE, 0)),
+        (cy - top, (0, -NORMAL_ONE)),
+        (bottom - cy, (0, NORMAL_ONE)),
+    ];
+    let (edge_dist, normal) = exits.into_iter().min_by_key(|(d, _)| *d)?;
+    Some(OverlapInfo {
+        depth: to_i32(edge_dist + r),
+        normal,
+        contact: (circle.x, circle.y),
+    })
+}
+
+/// Overlap geometry of two capsules. Edge-touching is not an overlap.
+#[must_use]
+pub fn capsule_overlap_info(a: &Capsule, b: &Capsule) -> Option<OverlapInfo> {
+    let (on_a, on_b) = closest_points_between_segments(
+        (a.x1 as i64, a.y1 as i64),
+        (a.x2 as i64, a.y2 as i64),
+        (b.x1 as i64, b.y1 as i64),
+        (b.x2 as i64, b.y2 as i64),
+    );
+    round_overlap(on_a, a.r as i64, on_b, b.r as i64)
+}
+
+/// Overlap geometry of two shapes: penetration depth, a normal from `a`
+/// toward `b`, and a contact point.
+///
+/// Returns `Some` for exactly the pairs [`shapes_overlap`] reports as
+/// overlapping.
+#[must_use]
+pub fn shape_overlap_info(
+    a: &ShapeView,
+    a_offset: (i32, i32),
+    b: &ShapeView,
+    b_offset: (i32, i32),
+) -> Option<OverlapInfo> {
+    match (a.kind(), b.kind()) {
+        (SHAPE_KIND_AABB, SHAPE_KIND_AABB) => aabb_overlap_info(
+            &Aabb::from_shape(a, a_offset.0, a_offset.1),
+            &Aabb::from_shape(b, b_offset.0, b_offset.1),
+        ),
+        (SHAPE_KIND_CIRCLE, SHAPE_KIND_CIRCLE) => circle_overlap_info(
+            &Circle::from_shape(a, a_offset.0, a_offset.1),
+            &Circle::from_shape(b, b_offset.0, b_offset.1),
+        ),
+        (SHAPE_KIND_AABB, SHAPE_KIND_CIRCLE) => aabb_circle_overlap_info(
+            &Aabb::from_shape(a, a_offset.0, a_offset.1),
+            &Circle::from_shape(b, b_offset.0, b_offset.1),
+        ),
+        (SHAPE_KIND_CIRCLE, SHAPE_KIND_AABB) => aabb_circle_overlap_info(
+            &Aabb::from_shape(b, b_offset.0, b_offset.1),
+            &Circle::from_shape(a, a_offset.0, a_offset.1),
+        )
+        .map(OverlapInfo::flipped),
+        (SHAPE_KIND_CAPSULE, SHAPE_KIND_CAPSULE) => capsule_overlap_info(
+            &Capsule::from_shape(a, a_offset.0, a_offset.1),
+            &Capsule::from_shape(b, b_offset.0, b_offset.1),
+        ),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,4 +530,92 @@ mod tests {
         let b = Capsule { x1: 20, y1: 0, x2: 30, y2: 0, r: 5 };
         assert!(!capsule_overlap(&a, &b)); // distance 10 == 5+5
     }
+
+    #[test]
+    fn aabb_overlap_info_reports_least_penetration() {
+        let a = Aabb {
+            x: 0,
+            y: 0,
+            w: 20,
+            h: 20,
+        };
+        // 6px deep in x, 15px in y: separate along +x.
+        let b = Aabb {
+            x: 14,
+            y: 5,
+            w: 20,
+            h: 20,
+        };
+        let info = aabb_overlap_info(&a, &b).unwrap();
+        assert_eq!(info.depth, 6);
+        assert_eq!(info.normal, (NORMAL_ONE, 0));
+        assert_eq!(info.contact, (17, 12));
+
+        // b above a: separate along -y.
+        let b = Aabb {
+            x: 2,
+            y: -16,
+            w: 16,
+            h: 20,
+        };
+        let info = aabb_overlap_info(&a, &b).unwrap();
+        assert_eq!(info.depth, 4);
+        assert_eq!(info.normal, (0, -NORMAL_ONE));
+
+        let touching = Aabb {
+            x: 20,
+            y: 0,
+            w: 10,
+            h: 10,
+        };
+        assert_eq!(aabb_overlap_info(&a, &touching), None);
+    }
+
+    #[test]
+    fn circle_overlap_info_contact_is_mid_overlap() {
+        let a = Circle { x: 0, y: 0, r: 10 };
+        let b = Circle { x: 16, y: 0, r: 10 };
+        let info = circle_overlap_info(&a, &b).unwrap();
+        assert_eq!(info.depth, 4);
+        assert_eq!(info.normal, (NORMAL_ONE, 0));
+        assert_eq!(info.contact, (8, 0));
+
+        // 3-4-5 triangle: distance 10, overlap from 6 to 10 along the line.
+        let a = Circle { x: 0, y: 0, r: 10 };
+        let b = Circle { x: 6, y: 8, r: 4 };
+        let info = circle_overlap_info(&a, &b).unwrap();
+        assert_eq!(info.depth, 4);
+        assert_eq!(info.normal, (153, 204));
+        assert_eq!(info.contact, (4, 6));
+
+        let touching = Circle { x: 20, y: 0, r: 10 };
+        assert_eq!(
+            circle_overlap_info(&Circle { x: 0, y: 0, r: 10 }, &touching),
+            None
+        );
+    }
+
+    #[test]
+    fn aabb_circle_overlap_info_points_from_box_to_circle() {
+        let aabb = Aabb {
+            x: 0,
+            y: 0,
+            w: 20,
+            h: 20,
+        };
+        let circle = Circle {
+            x: 25,
+            y: 10,
+            r: 10,
+        };
+        let info = aabb_circle_overlap_info(&aabb, &circle).unwrap();
+        assert_eq!(info.depth, 5);
+        assert_eq!(info.normal, (NORMAL_ONE, 0));
+
+        // Centered inside near the top edge: pushed out upward.
+        let inside = Circle { x: 10, y: 3, r: 2 };
+        let info = aabb_circle_overlap_info(&aabb, &inside).unwrap();
+        assert_eq!(info.depth, 5);
+        assert_eq!(info.normal, (0, -NORMAL_ONE));
+    }
 }
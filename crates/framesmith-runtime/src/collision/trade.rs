@@ -0,0 +1,217 @@
+use framesmith_fspack::{PackView, StateType};
+
+use super::CheckHitsResult;
+
+/// How to resolve two characters hitting each other on the same frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TradePolicy {
+    /// Both hits land.
+    #[default]
+    Trade,
+    /// The hits cancel out and neither lands.
+    Clash,
+    /// The higher priority hit lands alone; equal priorities trade.
+    /// See [`move_priority`] for a default ranking by state type.
+    Priority { p1: u8, p2: u8 },
+}
+
+/// Outcome of [`resolve_simultaneous`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resolution {
+    /// Neither character connected.
+    NoHit,
+    /// Only P1's hits land.
+    P1Hits,
+    /// Only P2's hits land.
+    P2Hits,
+    /// Both characters' hits land.
+    Trade,
+    /// Both connected, but the hits cancel out.
+    Clash,
+}
+
+impl Resolution {
+    /// Whether P1's hits should be applied.
+    pub fn p1_hits(self) -> bool {
+        matches!(self, Resolution::P1Hits | Resolution::Trade)
+    }
+
+    /// Whether P2's hits should be applied.
+    pub fn p2_hits(self) -> bool {
+        matches!(self, Resolution::P2Hits | Resolution::Trade)
+    }
+}
+
+/// Decide which of this frame's hits land when both characters may connect.
+///
+/// `p1_hits` are P1's hits on P2 and `p2_hits` the reverse, as returned by
+/// [`check_hits`](super::check_hits) for the same frame. The policy only
+/// matters when both connect; a one-sided hit always lands.
+#[must_use]
+pub fn resolve_simultaneous(
+    p1_hits: &CheckHitsResult,
+    p2_hits: &CheckHitsResult,
+    policy: TradePolicy,
+) -> Resolution {
+    match (p1_hits.is_empty(), p2_hits.is_empty()) {
+        (true, true) => Resolution::NoHit,
+        (false, true) => Resolution::P1Hits,
+        (true, false) => Resolution::P2Hits,
+        (false, false) => match policy {
+            TradePolicy::Trade => Resolution::Trade,
+            TradePolicy::Clash => Resolution::Clash,
+            TradePolicy::Priority { p1, p2 } => match p1.cmp(&p2) {
+                core::cmp::Ordering::Greater => Resolution::P1Hits,
+                core::cmp::Ordering::Less => Resolution::P2Hits,
+                core::cmp::Ordering::Equal => Resolution::Trade,
+            },
+        },
+    }
+}
+
+/// Default trade priority of a state, ranked by its state type: supers beat
+/// EX moves, which beat specials, which beat everything else.
+///
+/// Returns 0 if `move_index` is out of range.
+pub fn move_priority(pack: &PackView, move_index: u16) -> u8 {
+    let Some(state) = pack.states().and_then(|s| s.get(usize::from(move_index))) else {
+        return 0;
+    };
+    match state.state_type_enum() {
+        StateType::Super => 3,
+        StateType::Ex => 2,
+        StateType::Special | StateType::Rekka => 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::check_hits;
+    use crate::state::CharacterState;
+
+    /// State 0 (normal) and state 1 (super) each strike and are hittable on
+    /// frames 0-5 with the same 20x20 AABB.
+    fn mutual_pack() -> std::vec::Vec<u8> {
+        use framesmith_fspack::{
+            HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS,
+            SECTION_SHAPES, SECTION_STATES, SHAPE_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+        for (i, state_type) in [(0, 0u8), (1, 3)] {
+            let rec = &mut states[i * STATE_RECORD_SIZE..(i + 1) * STATE_RECORD_SIZE];
+            rec[6] = state_type;
+            rec[26..28].copy_from_slice(&1u16.to_le_bytes()); // one hit window
+            rec[30..32].copy_from_slice(&1u16.to_le_bytes()); // one hurt window
+        }
+
+        let mut hit = std::vec![0u8; HIT_WINDOW_SIZE];
+        hit[1] = 5; // end_frame
+        hit[4..6].copy_from_slice(&50u16.to_le_bytes()); // damage
+        hit[16..18].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+        let mut hurt = std::vec![0u8; HURT_WINDOW_SIZE];
+        hurt[1] = 5; // end_frame
+        hurt[8..10].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+        // 20x20 AABB at the origin (Q12.4)
+        let mut shape = std::vec![0u8; SHAPE_SIZE];
+        shape[6..8].copy_from_slice(&320i16.to_le_bytes());
+        shape[8..10].copy_from_slice(&320i16.to_le_bytes());
+
+        crate::test_support::build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_HIT_WINDOWS, hit),
+            (SECTION_HURT_WINDOWS, hurt),
+            (SECTION_SHAPES, shape),
+        ])
+    }
+
+    /// Both characters attack each other on frame 2 with the given states.
+    fn exchange(
+        pack: &PackView,
+        p1_state: u16,
+        p2_state: u16,
+    ) -> (CheckHitsResult, CheckHitsResult) {
+        let p1 = CharacterState {
+            current_state: p1_state,
+            frame: 2,
+            ..Default::default()
+        };
+        let p2 = CharacterState {
+            current_state: p2_state,
+            frame: 2,
+            ..Default::default()
+        };
+        (
+            check_hits(&p1, pack, (0, 0), &p2, pack, (10, 0)),
+            check_hits(&p2, pack, (10, 0), &p1, pack, (0, 0)),
+        )
+    }
+
+    #[test]
+    fn same_frame_hits_trade_or_clash_by_policy() {
+        let bytes = mutual_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let (p1_hits, p2_hits) = exchange(&pack, 0, 0);
+        assert_eq!((p1_hits.len(), p2_hits.len()), (1, 1));
+
+        let trade = resolve_simultaneous(&p1_hits, &p2_hits, TradePolicy::Trade);
+        assert_eq!(trade, Resolution::Trade);
+        assert!(trade.p1_hits() && trade.p2_hits());
+
+        let clash = resolve_simultaneous(&p1_hits, &p2_hits, TradePolicy::Clash);
+        assert_eq!(clash, Resolution::Clash);
+        assert!(!clash.p1_hits() && !clash.p2_hits());
+    }
+
+    #[test]
+    fn priority_lets_the_higher_state_type_win() {
+        let bytes = mutual_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let (p1_hits, p2_hits) = exchange(&pack, 0, 1);
+        let priority = |p1: &CheckHitsResult, p2: &CheckHitsResult| TradePolicy::Priority {
+            p1: move_priority(&pack, p1.get(0).unwrap().attacker_move),
+            p2: move_priority(&pack, p2.get(0).unwrap().attacker_move),
+        };
+
+        let policy = priority(&p1_hits, &p2_hits);
+        assert_eq!(policy, TradePolicy::Priority { p1: 0, p2: 3 });
+        assert_eq!(
+            resolve_simultaneous(&p1_hits, &p2_hits, policy),
+            Resolution::P2Hits
+        );
+
+        let (p1_hits, p2_hits) = exchange(&pack, 1, 1);
+        let policy = priority(&p1_hits, &p2_hits);
+        assert_eq!(
+            resolve_simultaneous(&p1_hits, &p2_hits, policy),
+            Resolution::Trade
+        );
+    }
+
+    #[test]
+    fn one_sided_hits_ignore_the_policy() {
+        let mut hits = CheckHitsResult::new();
+        let bytes = mutual_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        hits.push(*exchange(&pack, 0, 0).0.get(0).unwrap());
+        let none = CheckHitsResult::new();
+
+        assert_eq!(
+            resolve_simultaneous(&none, &none, TradePolicy::Clash),
+            Resolution::NoHit
+        );
+        assert_eq!(
+            resolve_simultaneous(&hits, &none, TradePolicy::Clash),
+            Resolution::P1Hits
+        );
+        assert_eq!(
+            resolve_simultaneous(&none, &hits, TradePolicy::Clash),
+            Resolution::P2Hits
+        );
+        assert_eq!(move_priority(&pack, 9), 0);
+    }
+}
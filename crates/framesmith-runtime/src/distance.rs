@@ -0,0 +1,102 @@
+use framesmith_fspack::Q24_8;
+
+/// Horizontal distance between two pixel positions, saturating at `u16::MAX`.
+///
+/// Matches the clamping `check_state_conditions` applies to
+/// [`MoveContext::distance`](crate::MoveContext::distance), so it can fill it directly.
+#[inline]
+#[must_use]
+pub fn horizontal_distance(a_pos: (i32, i32), b_pos: (i32, i32)) -> u16 {
+    let dx = (i64::from(a_pos.0) - i64::from(b_pos.0)).unsigned_abs();
+    dx.min(u64::from(u16::MAX)) as u16
+}
+
+/// Straight-line distance between two pixel positions in Q24.8.
+///
+/// Uses an integer square root, so every platform gets bit-identical results.
+/// The result is floored to 1/256 px and saturates at `Q24_8(i32::MAX)`.
+#[must_use]
+pub fn euclidean_distance_q(a_pos: (i32, i32), b_pos: (i32, i32)) -> Q24_8 {
+    let dx = (i64::from(a_pos.0) - i64::from(b_pos.0)).unsigned_abs();
+    let dy = (i64::from(a_pos.1) - i64::from(b_pos.1)).unsigned_abs();
+    let squared = u128::from(dx) * u128::from(dx) + u128::from(dy) * u128::from(dy);
+    // sqrt(d² * 2^16) = d * 2^8, i.e. the distance in Q24.8.
+    let raw = isqrt(squared << (2 * Q24_8::FRAC_BITS));
+    Q24_8::from_raw(raw.min(i32::MAX as u128) as i32)
+}
+
+/// Floor of the square root, by the bit-by-bit method.
+fn isqrt(n: u128) -> u128 {
+    let mut rem = n;
+    let mut root = 0u128;
+    let mut bit = 1u128 << 126;
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn horizontal_distance_ignores_height_and_saturates() {
+        assert_eq!(horizontal_distance((10, 0), (-30, 500)), 40);
+        assert_eq!(horizontal_distance((-30, 0), (10, 0)), 40);
+        assert_eq!(horizontal_distance((i32::MIN, 0), (i32::MAX, 0)), u16::MAX);
+    }
+
+    #[test]
+    fn euclidean_distance_of_known_triangles() {
+        assert_eq!(euclidean_distance_q((0, 0), (3, 4)), Q24_8::from_int(5));
+        assert_eq!(
+            euclidean_distance_q((100, -20), (-20, 30)),
+            Q24_8::from_int(130)
+        );
+        assert_eq!(euclidean_distance_q((7, 7), (7, 7)), Q24_8::ZERO);
+        // sqrt(2) = 1.41421... -> floor(362.03...) / 256
+        assert_eq!(euclidean_distance_q((0, 0), (1, 1)), Q24_8::from_raw(362));
+    }
+
+    #[test]
+    fn euclidean_distance_saturates() {
+        let far = euclidean_distance_q((i32::MIN, i32::MIN), (i32::MAX, i32::MAX));
+        assert_eq!(far, Q24_8::from_raw(i32::MAX));
+    }
+
+    #[test]
+    fn isqrt_is_exact_floor_and_monotonic() {
+        let mut prev = 0;
+        for n in 0..20_000u128 {
+            let root = isqrt(n);
+            assert!(
+                root * root <= n && (root + 1) * (root + 1) > n,
+                "isqrt({n}) = {root}"
+            );
+            assert!(root >= prev);
+            prev = root;
+        }
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn euclidean_distance_grows_with_separation() {
+        let mut prev = Q24_8::ZERO;
+        for x in 0..500 {
+            let d = euclidean_distance_q((0, 0), (x, x / 3));
+            assert!(d >= prev);
+            assert_eq!(d, euclidean_distance_q((x, x / 3), (0, 0)));
+            prev = d;
+        }
+    }
+}
@@ -0,0 +1,512 @@
+//! One simulation step for two characters facing each other.
+
+use framesmith_fspack::{PackView, StateType};
+
+use crate::cancel::IDLE_STATE;
+use crate::collision::{
+    check_hits, check_pushbox, move_priority, resolve_simultaneous, CheckHitsResult, PushboxResult,
+    Resolution, TradePolicy,
+};
+use crate::frame::next_frame;
+use crate::resource::{grant_meter_on_hit, grant_meter_on_whiff};
+use crate::state::{freeze_for, report_hit, CharacterState, FrameInput};
+
+/// Outcome of [`step_exchange`].
+pub struct ExchangeResult {
+    /// P1's state after the step.
+    pub p1: CharacterState,
+    /// P2's state after the step.
+    pub p2: CharacterState,
+    /// P1's hits on P2 that landed (empty unless `resolution.p1_hits()`).
+    pub p1_hits: CheckHitsResult,
+    /// P2's hits on P1 that landed (empty unless `resolution.p2_hits()`).
+    pub p2_hits: CheckHitsResult,
+    /// How same-frame hits were resolved.
+    pub resolution: Resolution,
+    /// Hitstop both characters were frozen for (0 when nothing landed).
+    pub hitstop: u8,
+    /// Super freeze both characters were frozen for (0 when no state with one
+    /// started).
+    pub super_freeze: u8,
+    /// P1's world-space position change from its movement this frame.
+    pub p1_delta: (i32, i32),
+    /// P2's world-space position change from its movement this frame.
+    pub p2_delta: (i32, i32),
+    /// Pushbox separation to apply to the moved positions, if the pushboxes
+    /// overlap.
+    pub push: Option<PushboxResult>,
+}
+
+/// Advance two characters one frame and resolve the hits between them.
+///
+/// In order:
+/// 1. Each character advances with [`next_frame`]. A finished system state
+///    (idle, crouch, walk) loops to frame 0; any other finished state earns
+///    its whiff meter and moves on to its on-use `enters_state`, or to
+///    [`IDLE_STATE`] if it has none. A character entering a
+///    state with a super freeze freezes both characters for its `frames`.
+///    Each [`FrameResult::position_delta`] is flipped to world space (each
+///    character faces the other; P1 faces +x when level) and applied to
+///    `positions` for the rest of the step.
+/// 2. Hits are checked both ways at the moved positions. A character that started the frame in
+///    hitstop can't land new hits.
+/// 3. `policy` resolves a same-frame exchange (see [`resolve_simultaneous`]).
+///    With [`TradePolicy::Priority`], the given priorities are used as is.
+/// 4. Each landed hit reports a hit confirm and grants on-hit meter to its
+///    attacker, and both characters freeze for the longest landed hitstop.
+/// 5. The pushboxes are checked.
+///
+/// This finds hits but doesn't resolve them. The caller decides which were
+/// blocked, moves the characters (by `p1_delta` and `p2_delta`, then
+/// [`apply_separation`] with `push`,
+/// [`apply_pushback`] with each landed hit), puts the defender into hitstun
+/// or blockstun, and applies damage.
+///
+/// [`apply_separation`]: crate::collision::apply_separation
+/// [`apply_pushback`]: crate::collision::apply_pushback
+/// [`FrameResult::position_delta`]: crate::state::FrameResult::position_delta
+#[must_use]
+pub fn step_exchange(
+    p1: &CharacterState,
+    p2: &CharacterState,
+    packs: (&PackView, &PackView),
+    inputs: (&FrameInput, &FrameInput),
+    positions: ((i32, i32), (i32, i32)),
+    policy: TradePolicy,
+) -> ExchangeResult {
+    let (p1_pack, p2_pack) = packs;
+    let (p1_pos, p2_pos) = positions;
+    let p1_frozen = p1.hitstop > 0;
+    let p2_frozen = p2.hitstop > 0;
+
+    let (mut p1, p1_started, p1_delta) = advance(p1, p1_pack, inputs.0);
+    let (mut p2, p2_started, p2_delta) = advance(p2, p2_pack, inputs.1);
+    let p1_facing = if p1_pos.0 <= p2_pos.0 { 1 } else { -1 };
+    let p1_delta = facing_delta(p1_delta, p1_facing);
+    let p2_delta = facing_delta(p2_delta, -p1_facing);
+    let p1_pos = offset(p1_pos, p1_delta);
+    let p2_pos = offset(p2_pos, p2_delta);
+
+    let super_freeze = [(p1_started, &p1, p1_pack), (p2_started, &p2, p2_pack)]
+        .into_iter()
+        .filter(|(started, _, _)| *started)
+        .filter_map(|(_, state, pack)| pack.super_freeze(state.current_state as usize))
+        .map(|freeze| freeze.frames)
+        .max()
+        .unwrap_or(0);
+    if super_freeze > 0 {
+        freeze_for(&mut p1, super_freeze);
+        freeze_for(&mut p2, super_freeze);
+    }
+
+    let mut p1_hits = if p1_frozen {
+        CheckHitsResult::new()
+    } else {
+        check_hits(&p1, p1_pack, p1_pos, &p2, p2_pack, p2_pos)
+    };
+    let mut p2_hits = if p2_frozen {
+        CheckHitsResult::new()
+    } else {
+        check_hits(&p2, p2_pack, p2_pos, &p1, p1_pack, p1_pos)
+    };
+
+    let resolution = resolve_simultaneous(&p1_hits, &p2_hits, policy);
+    if !resolution.p1_hits() {
+        p1_hits = CheckHitsResult::new();
+    }
+    if !resolution.p2_hits() {
+        p2_hits = CheckHitsResult::new();
+    }
+
+    for _ in p1_hits.iter() {
+        report_hit(&mut p1);
+        grant_meter_on_hit(&mut p1, p1_pack);
+    }
+    for _ in p2_hits.iter() {
+        report_hit(&mut p2);
+        grant_meter_on_hit(&mut p2, p2_pack);
+    }
+
+    let hitstop = p1_hits
+        .iter()
+        .chain(p2_hits.iter())
+        .map(|hit| hit.hitstop)
+        .max()
+        .unwrap_or(0);
+    if hitstop > 0 {
+        freeze_for(&mut p1, hitstop);
+        freeze_for(&mut p2, hitstop);
+    }
+
+    let push = check_pushbox(&p1, p1_pack, p1_pos, &p2, p2_pack, p2_pos);
+
+    ExchangeResult {
+        p1,
+        p2,
+        p1_hits,
+        p2_hits,
+        resolution,
+        hitstop,
+        super_freeze,
+        p1_delta,
+        p2_delta,
+        push,
+    }
+}
+
+/// Flip a character-local delta (+x forward) to world space.
+#[inline]
+fn facing_delta(delta: (i32, i32), facing: i32) -> (i32, i32) {
+    (delta.0.saturating_mul(facing), delta.1)
+}
+
+#[inline]
+fn offset(pos: (i32, i32), delta: (i32, i32)) -> (i32, i32) {
+    (pos.0.saturating_add(delta.0), pos.1.saturating_add(delta.1))
+}
+
+/// Priority policy ranking each character's current state by type with
+/// [`move_priority`].
+pub fn state_type_priority(
+    p1: &CharacterState,
+    p1_pack: &PackView,
+    p2: &CharacterState,
+    p2_pack: &PackView,
+) -> TradePolicy {
+    TradePolicy::Priority {
+        p1: move_priority(p1_pack, p1.current_state),
+        p2: move_priority(p2_pack, p2.current_state),
+    }
+}
+
+/// Run [`next_frame`] and handle the current state running out.
+///
+/// Also returns whether a requested state started this frame and the
+/// character-local position change.
+fn advance(
+    state: &CharacterState,
+    pack: &PackView,
+    input: &FrameInput,
+) -> (CharacterState, bool, (i32, i32)) {
+    let result = next_frame(state, pack, input);
+    // Advancing always leaves frame 0, so landing on it unfrozen means a transition
+    let started = state.hitstop == 0 && !result.move_ended && result.state.frame == 0;
+    let delta = result.position_delta;
+    let mut state = result.state;
+    if !result.move_ended {
+        return (state, started, delta);
+    }
+
+    let state_type = pack
+        .states()
+        .and_then(|states| states.get(state.current_state as usize))
+        .map(|s| s.state_type_enum());
+
+    // A timed instance (hitstun/blockstun) returns to idle even from a system state.
+    if state_type == Some(StateType::System) && state.instance_duration == 0 {
+        state.frame = 0;
+    } else {
+        grant_meter_on_whiff(&mut state, pack);
+        // A move with an on-use sub-state hands off to it instead of idle
+        state.current_state = pack
+            .state_on_uses()
+            .and_then(|uses| uses.get(state.current_state as usize))
+            .and_then(|u| u.enters_state())
+            .unwrap_or(IDLE_STATE);
+        state.frame = 0;
+        state.instance_duration = 0;
+        state.hit_confirmed = false;
+        state.hit_frame = 0;
+        state.block_confirmed = false;
+    }
+    (state, false, delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::HitResult;
+
+    fn duel_pack() -> std::vec::Vec<u8> {
+        crate::test_support::build_pack(&crate::test_support::duel_sections())
+    }
+
+    /// The loop the training session ran by hand: advance both, check both
+    /// ways, confirm landed hits, freeze for the longest hitstop.
+    fn reference_step(
+        p1: &mut CharacterState,
+        p2: &mut CharacterState,
+        pack: &PackView,
+        inputs: (&FrameInput, &FrameInput),
+        positions: ((i32, i32), (i32, i32)),
+    ) -> std::vec::Vec<HitResult> {
+        let (p1_frozen, p2_frozen) = (p1.hitstop > 0, p2.hitstop > 0);
+        *p1 = advance(p1, pack, inputs.0).0;
+        *p2 = advance(p2, pack, inputs.1).0;
+
+        let mut landed = std::vec::Vec::new();
+        if !p1_frozen {
+            for hit in check_hits(p1, pack, positions.0, p2, pack, positions.1).iter() {
+                landed.push(*hit);
+                report_hit(p1);
+                grant_meter_on_hit(p1, pack);
+            }
+        }
+        if !p2_frozen {
+            for hit in check_hits(p2, pack, positions.1, p1, pack, positions.0).iter() {
+                landed.push(*hit);
+                report_hit(p2);
+                grant_meter_on_hit(p2, pack);
+            }
+        }
+        if let Some(hitstop) = landed.iter().map(|h| h.hitstop).max() {
+            freeze_for(p1, hitstop);
+            freeze_for(p2, hitstop);
+        }
+        landed
+    }
+
+    #[test]
+    fn step_exchange_matches_the_hand_written_loop() {
+        let bytes = duel_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let positions = ((0, 0), (10, 0));
+        let idle = FrameInput::default();
+
+        // P1 attacks; P2 is a frame further into the same attack and strikes first.
+        let mut p1 = CharacterState {
+            current_state: 1,
+            ..Default::default()
+        };
+        let mut p2 = CharacterState {
+            current_state: 1,
+            frame: 1,
+            ..Default::default()
+        };
+        let (mut ref1, mut ref2) = (p1, p2);
+        let mut landed = 0;
+        for frame in 0..40 {
+            let result = step_exchange(
+                &p1,
+                &p2,
+                (&pack, &pack),
+                (&idle, &idle),
+                positions,
+                TradePolicy::Trade,
+            );
+            let expected = reference_step(&mut ref1, &mut ref2, &pack, (&idle, &idle), positions);
+
+            assert_eq!(result.p1.to_bytes(), ref1.to_bytes(), "p1 on frame {frame}");
+            assert_eq!(result.p2.to_bytes(), ref2.to_bytes(), "p2 on frame {frame}");
+            let hits = result.p1_hits.iter().chain(result.p2_hits.iter());
+            assert!(hits.map(|h| h.damage).eq(expected.iter().map(|h| h.damage)));
+            assert_eq!(
+                result.hitstop,
+                expected.iter().map(|h| h.hitstop).max().unwrap_or(0)
+            );
+            assert!(result.push.is_some());
+            landed += expected.len();
+
+            p1 = result.p1;
+            p2 = result.p2;
+        }
+        assert!(landed > 0, "the scenario should land hits");
+        assert_eq!((p1.current_state, p2.current_state), (0, 0));
+    }
+
+    #[test]
+    fn clash_lands_nothing_and_freezes_no_one() {
+        let bytes = duel_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let idle = FrameInput::default();
+        let mut p1 = CharacterState {
+            current_state: 1,
+            ..Default::default()
+        };
+        let mut p2 = p1;
+        let mut clashed = false;
+        for _ in 0..6 {
+            let result = step_exchange(
+                &p1,
+                &p2,
+                (&pack, &pack),
+                (&idle, &idle),
+                ((0, 0), (10, 0)),
+                TradePolicy::Clash,
+            );
+            clashed |= result.resolution == Resolution::Clash;
+            assert!(result.p1_hits.is_empty() && result.p2_hits.is_empty());
+            assert_eq!(result.hitstop, 0);
+            p1 = result.p1;
+            p2 = result.p2;
+        }
+        assert!(clashed);
+        assert!(!p1.hit_confirmed && !p2.hit_confirmed);
+    }
+
+    #[test]
+    fn state_type_priority_ranks_current_states() {
+        let bytes = duel_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let idle = CharacterState::default();
+        let attacking = CharacterState {
+            current_state: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            state_type_priority(&idle, &pack, &attacking, &pack),
+            TradePolicy::Priority { p1: 0, p2: 0 }
+        );
+    }
+
+    #[test]
+    fn starting_a_super_freezes_both_characters() {
+        use framesmith_fspack::view::SECTION_CANCEL_TAG_RULES;
+        use framesmith_fspack::{SECTION_STATE_SUPER_FREEZE, STATE_SUPER_FREEZE_SIZE};
+
+        // State 1 freezes for 30 frames; any -> any on whiff, frame 0 only
+        let mut freezes = std::vec![0u8; 2 * STATE_SUPER_FREEZE_SIZE];
+        freezes[STATE_SUPER_FREEZE_SIZE] = 30;
+        let any = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0];
+        let mut rule = [any, any].concat();
+        rule.extend_from_slice(&[0b100, 0, 0, 0, 0, 0, 0, 0]);
+        let mut sections = crate::test_support::duel_sections();
+        sections.push((SECTION_STATE_SUPER_FREEZE, freezes));
+        sections.push((SECTION_CANCEL_TAG_RULES, rule));
+        let bytes = crate::test_support::build_pack(&sections);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let idle = FrameInput::default();
+        let request = FrameInput {
+            requested_state: Some(1),
+            ..Default::default()
+        };
+        let step = |p1: &CharacterState, p2: &CharacterState, input: &FrameInput| {
+            step_exchange(
+                p1,
+                p2,
+                (&pack, &pack),
+                (input, &idle),
+                ((0, 0), (100, 0)),
+                TradePolicy::Trade,
+            )
+        };
+
+        let p2 = CharacterState {
+            frame: 5,
+            ..Default::default()
+        };
+        let result = step(&CharacterState::default(), &p2, &request);
+        assert_eq!(result.super_freeze, 30);
+        assert_eq!(result.p1.current_state, 1);
+        assert_eq!((result.p1.hitstop, result.p2.hitstop), (30, 30));
+
+        let (mut p1, mut p2) = (result.p1, result.p2);
+        for _ in 0..30 {
+            let result = step(&p1, &p2, &idle);
+            assert_eq!(result.super_freeze, 0);
+            assert_eq!((result.p1.frame, result.p2.frame), (0, 6));
+            p1 = result.p1;
+            p2 = result.p2;
+        }
+        assert_eq!((p1.hitstop, p2.hitstop), (0, 0));
+
+        let result = step(&p1, &p2, &idle);
+        assert_eq!((result.p1.frame, result.p2.frame), (1, 7));
+    }
+
+    #[test]
+    fn movement_closes_distance_before_hits_are_checked() {
+        use framesmith_fspack::{SECTION_STATE_MOVEMENT, STATE_MOVEMENT_SIZE};
+
+        // State 1 lunges forward 12px a frame over frames 0..=2
+        let mut movement = std::vec![0u8; 2 * STATE_MOVEMENT_SIZE];
+        let lunge = &mut movement[STATE_MOVEMENT_SIZE..];
+        lunge[1] = 2; // end_frame
+        lunge[4..6].copy_from_slice(&(12i16 << 8).to_le_bytes());
+        let mut sections = crate::test_support::duel_sections();
+        sections.push((SECTION_STATE_MOVEMENT, movement));
+        let bytes = crate::test_support::build_pack(&sections);
+        let pack = PackView::parse(&bytes).unwrap();
+        let idle = FrameInput::default();
+        let attacking = CharacterState {
+            current_state: 1,
+            ..Default::default()
+        };
+
+        // 50px apart, out of reach of the 20px hitbox without the lunge
+        let run = |p1: CharacterState, p2: CharacterState| {
+            let (mut p1, mut p2) = (p1, p2);
+            let (mut p1_pos, mut p2_pos) = ((0, 0), (50, 0));
+            for _ in 0..5 {
+                let result = step_exchange(
+                    &p1,
+                    &p2,
+                    (&pack, &pack),
+                    (&idle, &idle),
+                    (p1_pos, p2_pos),
+                    TradePolicy::Trade,
+                );
+                p1_pos = offset(p1_pos, result.p1_delta);
+                p2_pos = offset(p2_pos, result.p2_delta);
+                if !result.p1_hits.is_empty() || !result.p2_hits.is_empty() {
+                    return Some((result.p1_hits.len(), result.p2_hits.len(), p1_pos, p2_pos));
+                }
+                p1 = result.p1;
+                p2 = result.p2;
+            }
+            None
+        };
+
+        // P1 lunges right, P2 (facing left) lunges left
+        assert_eq!(
+            run(attacking, CharacterState::default()),
+            Some((1, 0, (36, 0), (50, 0)))
+        );
+        assert_eq!(
+            run(CharacterState::default(), attacking),
+            Some((0, 1, (0, 0), (14, 0)))
+        );
+    }
+
+    #[test]
+    fn finished_move_hands_off_to_its_on_use_state() {
+        use framesmith_fspack::{
+            OPT_U16_NONE, SECTION_STATES, SECTION_STATE_ON_USE, STATE_RECORD_SIZE,
+        };
+
+        // Three 12-frame states; state 1 enters state 2 on use
+        let mut states = std::vec![0u8; 3 * STATE_RECORD_SIZE];
+        for rec in states.chunks_exact_mut(STATE_RECORD_SIZE) {
+            rec[14..16].copy_from_slice(&12u16.to_le_bytes()); // total
+        }
+        let mut on_use = std::vec::Vec::new();
+        for enters in [OPT_U16_NONE, 2, OPT_U16_NONE] {
+            on_use.extend_from_slice(&enters.to_le_bytes());
+            on_use.extend_from_slice(&0u16.to_le_bytes());
+        }
+        let bytes = crate::test_support::build_pack(&[
+            (SECTION_STATES, states),
+            (SECTION_STATE_ON_USE, on_use),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        let idle = FrameInput::default();
+
+        let playing = CharacterState {
+            current_state: 1,
+            frame: 10,
+            ..Default::default()
+        };
+        let (state, _, _) = advance(&playing, &pack, &idle);
+        assert_eq!((state.current_state, state.frame), (1, 11));
+
+        let (state, started, _) = advance(&state, &pack, &idle);
+        assert_eq!((state.current_state, state.frame), (2, 0));
+        assert!(!started);
+
+        // State 2 has no sub-state, so it returns to idle
+        let ended = CharacterState { frame: 11, ..state };
+        let (state, _, _) = advance(&ended, &pack, &idle);
+        assert_eq!(state.current_state, IDLE_STATE);
+    }
+}
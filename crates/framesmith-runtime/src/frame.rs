@@ -1,5 +1,10 @@
-use crate::state::{CharacterState, FrameInput, FrameResult};
-use framesmith_fspack::PackView;
+use crate::state::{
+    BufferedInput, CharacterState, FrameInput, FrameResult, CHARGE_DIRECTIONS, GRAVITY,
+    INPUT_BUFFER_LEN,
+};
+use framesmith_fspack::{
+    PackView, CHARGE_DIR_BACK, CHARGE_DIR_DOWN, CHARGE_DIR_FORWARD, CHARGE_DIR_UP,
+};
 
 /// Advance frame counter by 1, saturating at u8::MAX.
 #[inline]
@@ -10,43 +15,271 @@ fn advance_frame_counter(state: &CharacterState) -> CharacterState {
     }
 }
 
+/// Fractional bits in the Q8.8 movement velocity/acceleration.
+const MOVEMENT_FRAC_BITS: u32 = 8;
+
+/// Number of movement-window frames played before `frame`.
+#[inline]
+fn movement_steps(start: u8, end: u8, frame: u16) -> i64 {
+    if end < start {
+        return 0;
+    }
+    let len = i64::from(end - start) + 1;
+    (i64::from(frame) - i64::from(start)).clamp(0, len)
+}
+
+/// Q8.8 displacement after `steps` frames of constant acceleration.
+///
+/// Velocity on step `k` is `v + a * k`, so the sum is `v*n + a*n*(n-1)/2`.
+#[inline]
+fn axis_displacement(velocity: i16, acceleration: i16, steps: i64) -> i64 {
+    i64::from(velocity) * steps + i64::from(acceleration) * steps * (steps - 1).max(0) / 2
+}
+
+/// Whole-pixel movement for the frame `state.frame` is playing.
+///
+/// Derived from the cumulative displacement so sub-pixel remainders carry
+/// across frames without storing them in `CharacterState`.
+fn movement_delta(state: &CharacterState, pack: &PackView) -> (i32, i32) {
+    let Some(mv) = pack
+        .state_movements()
+        .and_then(|m| m.get(state.current_state as usize))
+    else {
+        return (0, 0);
+    };
+    if !mv.is_moving() {
+        return (0, 0);
+    }
+
+    let before = movement_steps(mv.start_frame(), mv.end_frame(), u16::from(state.frame));
+    let after = movement_steps(mv.start_frame(), mv.end_frame(), u16::from(state.frame) + 1);
+    let axis = |v: i16, a: i16| -> i32 {
+        let px_before = axis_displacement(v, a, before) >> MOVEMENT_FRAC_BITS;
+        let px_after = axis_displacement(v, a, after) >> MOVEMENT_FRAC_BITS;
+        (px_after - px_before) as i32
+    };
+
+    (
+        axis(mv.velocity_x(), mv.acceleration_x()),
+        axis(mv.velocity_y(), mv.acceleration_y()),
+    )
+}
+
+#[inline]
+fn saturate_i32(v: i64) -> i32 {
+    v.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+}
+
+/// Vertical position and velocity (Q8.8) after the frame `state.frame` is playing.
+///
+/// Inside an airborne movement window the record drives the character;
+/// otherwise airborne characters fall under [`GRAVITY`]. Returns `None` for
+/// grounded characters outside an airborne window. The caller snaps to the
+/// ground once the returned position is at or below it.
+fn vertical_step(state: &CharacterState, pack: &PackView) -> Option<(i32, i32)> {
+    let airborne_move = pack
+        .state_movements()
+        .and_then(|m| m.get(state.current_state as usize))
+        .filter(|mv| mv.is_airborne());
+    if let Some(mv) = airborne_move {
+        let before = movement_steps(mv.start_frame(), mv.end_frame(), u16::from(state.frame));
+        let after = movement_steps(mv.start_frame(), mv.end_frame(), u16::from(state.frame) + 1);
+        if after > before {
+            let (v, a) = (mv.velocity_y(), mv.acceleration_y());
+            let dy = axis_displacement(v, a, after) - axis_displacement(v, a, before);
+            let vel = i64::from(v) + i64::from(a) * after;
+            return Some((saturate_i32(i64::from(state.pos_y) + dy), saturate_i32(vel)));
+        }
+    }
+
+    if state.grounded {
+        return None;
+    }
+    Some((
+        state.pos_y.saturating_add(state.vel_y),
+        state.vel_y.saturating_add(GRAVITY),
+    ))
+}
+
+/// Apply the [`vertical_step`] for the frame `state` is playing to `new_state`,
+/// snapping to the ground on landing.
+///
+/// Returns the whole-pixel vertical change, or `None` when [`vertical_step`]
+/// leaves the character where it is.
+fn apply_vertical_step(
+    state: &CharacterState,
+    pack: &PackView,
+    new_state: &mut CharacterState,
+) -> Option<i32> {
+    let (pos_y, vel_y) = vertical_step(state, pack)?;
+    let landed = pos_y >= 0;
+    new_state.grounded = landed;
+    new_state.pos_y = if landed { 0 } else { pos_y };
+    new_state.vel_y = if landed { 0 } else { vel_y };
+    Some((new_state.pos_y >> MOVEMENT_FRAC_BITS) - (state.pos_y >> MOVEMENT_FRAC_BITS))
+}
+
+/// Age the input buffer by one frame and append this frame's request.
+///
+/// Entries older than `input.buffer_frames` expire. When the buffer is full
+/// the oldest entry is dropped.
+fn buffer_input(
+    state: &CharacterState,
+    input: &FrameInput,
+) -> [Option<BufferedInput>; INPUT_BUFFER_LEN] {
+    let mut buffer = [None; INPUT_BUFFER_LEN];
+    if input.buffer_frames == 0 {
+        return buffer;
+    }
+    let mut len = 0;
+    let aged = state.input_buffer.iter().flatten().map(|b| BufferedInput {
+        state: b.state,
+        age: b.age.saturating_add(1),
+    });
+    let fresh = input
+        .requested_state
+        .map(|state| BufferedInput { state, age: 0 });
+    for entry in aged.chain(fresh) {
+        if entry.age > input.buffer_frames {
+            continue;
+        }
+        if len == INPUT_BUFFER_LEN {
+            buffer.rotate_left(1);
+            len -= 1;
+        }
+        buffer[len] = Some(entry);
+        len += 1;
+    }
+    buffer
+}
+
+/// Whether a numpad `direction` includes the given `CHARGE_DIR_*`.
+#[inline]
+fn holds_charge_direction(direction: u8, charge_dir: u8) -> bool {
+    match charge_dir {
+        CHARGE_DIR_DOWN => matches!(direction, 1..=3),
+        CHARGE_DIR_BACK => matches!(direction, 1 | 4 | 7),
+        CHARGE_DIR_UP => matches!(direction, 7..=9),
+        CHARGE_DIR_FORWARD => matches!(direction, 3 | 6 | 9),
+        _ => false,
+    }
+}
+
+/// Count up charge for every held direction and reset the released ones.
+fn accumulate_charge(state: &CharacterState, input: &FrameInput) -> [u8; CHARGE_DIRECTIONS] {
+    let mut charge = [0; CHARGE_DIRECTIONS];
+    for (dir, frames) in charge.iter_mut().enumerate() {
+        if holds_charge_direction(input.direction, dir as u8) {
+            *frames = state.charge[dir].saturating_add(1);
+        }
+    }
+    charge
+}
+
 /// Compute the next frame state for a character.
 ///
 /// This is a pure function - it does not mutate the input state.
 /// The game decides whether to apply the returned state.
 ///
+/// `input.requested_state` is honored only when [`can_cancel_to`](crate::can_cancel_to)
+/// allows it this frame. Otherwise it is kept in `state.input_buffer` for up
+/// to `input.buffer_frames` frames and retried each frame, so a cancel input
+/// made slightly early still comes out once the window opens. A request
+/// whose grounded/airborne or charge conditions fail (see
+/// [`check_state_conditions`](crate::check_state_conditions)), or whose
+/// resource costs can't be paid (see
+/// [`apply_resource_costs`](crate::apply_resource_costs)), doesn't come out
+/// either and stays buffered the same way. Distance conditions need the
+/// opponent, so the game checks those with
+/// [`is_move_available`](crate::is_move_available) before requesting.
+///
+/// Entering a state applies its on-use effects once (see
+/// [`apply_on_use`](crate::resource::apply_on_use)). The state then plays out
+/// as usual; an on-use `enters_state` takes over only when it ends (see
+/// [`step_exchange`](crate::step_exchange)).
+///
+/// `input.direction` updates `state.charge`, which charge preconditions in
+/// [`is_move_available`](crate::is_move_available) read.
+///
+/// Airborne movement windows lift the character off the ground; once no such
+/// window is playing it falls under [`GRAVITY`](crate::GRAVITY) and snaps to
+/// the ground (`grounded`, `pos_y = 0`) on landing. Transition frames keep the
+/// vertical state unchanged.
+///
+/// While `state.hitstop` is nonzero (see [`freeze_for`](crate::freeze_for))
+/// the frame only counts hitstop down: the frame counter, position and state
+/// hold, and requests are buffered rather than honored.
+///
 /// # Arguments
 /// * `state` - Current character state
 /// * `pack` - Character data pack (moves, cancels, etc.)
 /// * `input` - Frame input (requested state, etc.)
 ///
 /// # Returns
-/// New state and whether the move ended this frame.
+/// New state, whether the move ended this frame, and the position change
+/// from the state's movement window (zero when the pack has no movement).
 #[must_use]
 pub fn next_frame(
     state: &CharacterState,
     pack: &PackView,
     input: &FrameInput,
 ) -> FrameResult {
-    // Try to transition if a state was requested
-    if let Some(target) = input.requested_state {
-        if crate::cancel::can_cancel_to(state, pack, target) {
-            let mut new_state = *state;
-            new_state.current_state = target;
-            new_state.frame = 0;
-            new_state.hit_confirmed = false;
-            new_state.block_confirmed = false;
-            // Apply resource costs for the target move
-            crate::resource::apply_resource_costs(&mut new_state, pack, target);
-            return FrameResult {
-                state: new_state,
-                move_ended: false,
-            };
+    let buffer = buffer_input(state, input);
+    let charge = accumulate_charge(state, input);
+
+    // Frozen in hitstop: inputs keep buffering and charging, nothing else moves
+    if state.hitstop > 0 {
+        let mut new_state = *state;
+        new_state.hitstop -= 1;
+        new_state.input_buffer = buffer;
+        new_state.charge = charge;
+        return FrameResult {
+            state: new_state,
+            move_ended: false,
+            position_delta: (0, 0),
+        };
+    }
+
+    // Try the fresh request first, then buffered ones newest first
+    let candidates = input
+        .requested_state
+        .into_iter()
+        .chain(buffer.iter().rev().flatten().map(|b| b.state));
+    for target in candidates {
+        if !crate::cancel::can_cancel_to(state, pack, target) {
+            continue;
+        }
+        // A move whose own conditions fail (air-only on the ground, charge not
+        // held long enough) doesn't come out either; it stays buffered
+        if !crate::precondition::check_own_conditions(state, pack, target) {
+            continue;
         }
+        let mut new_state = *state;
+        // An unaffordable move doesn't come out; its request stays buffered
+        if crate::resource::apply_resource_costs(&mut new_state, pack, target).is_err() {
+            continue;
+        }
+        new_state.current_state = target;
+        new_state.frame = 0;
+        new_state.hit_confirmed = false;
+        new_state.hit_frame = 0;
+        new_state.block_confirmed = false;
+        new_state.input_buffer = [None; INPUT_BUFFER_LEN];
+        new_state.charge = charge;
+        crate::resource::apply_on_use(&mut new_state, pack, target);
+        // Airborne cancels keep rising or falling through the transition frame
+        let dy = apply_vertical_step(state, pack, &mut new_state).unwrap_or(0);
+        return FrameResult {
+            state: new_state,
+            move_ended: false,
+            position_delta: (0, dy),
+        };
     }
 
     // Advance frame
-    let new_state = advance_frame_counter(state);
+    let mut new_state = advance_frame_counter(state);
+    new_state.input_buffer = buffer;
+    new_state.charge = charge;
 
     // Check if state ended
     // Use instance_duration if set, otherwise use state's default total
@@ -65,15 +298,22 @@ pub fn next_frame(
         false
     };
 
+    let mut position_delta = movement_delta(state, pack);
+    if let Some(dy) = apply_vertical_step(state, pack, &mut new_state) {
+        position_delta.1 = dy;
+    }
+
     FrameResult {
         state: new_state,
         move_ended,
+        position_delta,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::vec::Vec;
 
     #[test]
     fn next_frame_advances_frame_counter() {
@@ -98,6 +338,576 @@ mod tests {
         assert_eq!(next.frame, 255);
     }
 
+    /// Pack with one state of `total` frames and a movement record.
+    fn movement_pack(
+        total: u16,
+        window: (u8, u8),
+        velocity: (i16, i16),
+        accel: (i16, i16),
+    ) -> Vec<u8> {
+        movement_pack_with_flags(total, window, velocity, accel, 0)
+    }
+
+    fn movement_pack_with_flags(
+        total: u16,
+        window: (u8, u8),
+        velocity: (i16, i16),
+        accel: (i16, i16),
+        flags: u8,
+    ) -> Vec<u8> {
+        use framesmith_fspack::{
+            SECTION_STATES, SECTION_STATE_MOVEMENT, STATE_MOVEMENT_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut state = std::vec![0u8; STATE_RECORD_SIZE];
+        state[14..16].copy_from_slice(&total.to_le_bytes());
+
+        let mut movement = std::vec![0u8; STATE_MOVEMENT_SIZE];
+        movement[0] = window.0;
+        movement[1] = window.1;
+        movement[2] = flags;
+        movement[4..6].copy_from_slice(&velocity.0.to_le_bytes());
+        movement[6..8].copy_from_slice(&velocity.1.to_le_bytes());
+        movement[8..10].copy_from_slice(&accel.0.to_le_bytes());
+        movement[10..12].copy_from_slice(&accel.1.to_le_bytes());
+
+        crate::test_support::build_pack(&[
+            (SECTION_STATES, state),
+            (SECTION_STATE_MOVEMENT, movement),
+        ])
+    }
+
+    /// Run a state from frame 0 to its end, returning per-frame deltas.
+    fn run_to_end(pack: &PackView) -> Vec<(i32, i32)> {
+        let mut state = CharacterState::default();
+        let mut deltas = Vec::new();
+        loop {
+            let result = next_frame(&state, pack, &FrameInput::default());
+            deltas.push(result.position_delta);
+            state = result.state;
+            if result.move_ended {
+                return deltas;
+            }
+        }
+    }
+
+    #[test]
+    fn constant_velocity_moves_only_inside_window() {
+        // 4px/frame over frames 3..=6
+        let bytes = movement_pack(10, (3, 6), (4 << 8, 0), (0, 0));
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let deltas = run_to_end(&pack);
+
+        assert_eq!(deltas.len(), 10);
+        for (frame, delta) in deltas.iter().enumerate() {
+            let expected = if (3..=6).contains(&frame) { 4 } else { 0 };
+            assert_eq!(*delta, (expected, 0), "frame {}", frame);
+        }
+        let total_x: i32 = deltas.iter().map(|d| d.0).sum();
+        assert_eq!(total_x, 16);
+    }
+
+    #[test]
+    fn acceleration_accumulates_sub_pixel_movement() {
+        // 1.5px/frame decelerating by 0.25px/frame over frames 0..=3:
+        // 1.5 + 1.25 + 1.0 + 0.75 = 4.5px, floored to 4 whole pixels.
+        let bytes = movement_pack(8, (0, 3), (384, -128), (-64, 32));
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let deltas = run_to_end(&pack);
+        let total: (i32, i32) = deltas
+            .iter()
+            .fold((0, 0), |acc, d| (acc.0 + d.0, acc.1 + d.1));
+
+        // y: -0.5 - 0.375 - 0.25 - 0.125 = -1.25px, floored to -2
+        assert_eq!(total, (4, -2));
+    }
+
+    #[test]
+    fn rejected_request_still_moves() {
+        let bytes = movement_pack(10, (0, 9), (4 << 8, 0), (0, 0));
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState::default();
+
+        // No cancel routes exist, so a request falls through to a normal advance.
+        let result = next_frame(
+            &state,
+            &pack,
+            &FrameInput {
+                requested_state: Some(0),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.position_delta, (4, 0));
+    }
+
+    #[test]
+    fn illegal_request_during_startup_is_ignored() {
+        let bytes = crate::test_support::tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState {
+            current_state: 1,
+            frame: 2,
+            ..Default::default()
+        };
+
+        // The normal -> special window opens on frame 6.
+        let result = next_frame(
+            &state,
+            &pack,
+            &FrameInput {
+                requested_state: Some(2),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.state.current_state, 1);
+        assert_eq!(result.state.frame, 3);
+    }
+
+    #[test]
+    fn legal_request_inside_cancel_window_transitions() {
+        let bytes = crate::test_support::tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState {
+            current_state: 1,
+            frame: 7,
+            hit_confirmed: true,
+            ..Default::default()
+        };
+
+        let result = next_frame(
+            &state,
+            &pack,
+            &FrameInput {
+                requested_state: Some(2),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.state.current_state, 2);
+        assert_eq!(result.state.frame, 0);
+        assert!(!result.state.hit_confirmed);
+        assert!(!result.move_ended);
+    }
+
+    /// Step state 1 of the tag-rule pack from `start_frame`, requesting state 2
+    /// once on the first frame, and return the state after `ticks` frames.
+    fn buffered_request(start_frame: u8, buffer_frames: u8, ticks: usize) -> CharacterState {
+        let bytes = crate::test_support::tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState {
+            current_state: 1,
+            frame: start_frame,
+            ..Default::default()
+        };
+        for tick in 0..ticks {
+            let input = FrameInput {
+                requested_state: (tick == 0).then_some(2),
+                buffer_frames,
+                ..Default::default()
+            };
+            state = next_frame(&state, &pack, &input).state;
+        }
+        state
+    }
+
+    #[test]
+    fn buffered_request_fires_when_window_opens() {
+        // Pressed on frame 4; the window opens on frame 6 (two frames later).
+        let state = buffered_request(4, 4, 2);
+        assert_eq!((state.current_state, state.frame), (1, 6), "still waiting");
+
+        let state = buffered_request(4, 4, 3);
+        assert_eq!((state.current_state, state.frame), (2, 0));
+        assert_eq!(state.input_buffer, [None; INPUT_BUFFER_LEN]);
+    }
+
+    #[test]
+    fn buffered_request_expires_after_buffer_frames() {
+        // Pressed on frame 3 with a 1-frame buffer: gone before frame 6.
+        let state = buffered_request(3, 1, 5);
+        assert_eq!((state.current_state, state.frame), (1, 8));
+        assert_eq!(state.input_buffer, [None; INPUT_BUFFER_LEN]);
+    }
+
+    #[test]
+    fn zero_buffer_frames_drops_early_requests() {
+        let state = buffered_request(5, 0, 3);
+        assert_eq!((state.current_state, state.frame), (1, 8));
+    }
+
+    #[test]
+    fn held_direction_accumulates_charge_until_released() {
+        let bytes = crate::test_support::tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let down_back = FrameInput {
+            direction: 1,
+            ..Default::default()
+        };
+
+        let mut state = CharacterState::default();
+        for _ in 0..3 {
+            state = next_frame(&state, &pack, &down_back).state;
+        }
+        assert_eq!(state.charge[CHARGE_DIR_DOWN as usize], 3);
+        assert_eq!(state.charge[CHARGE_DIR_BACK as usize], 3);
+        assert_eq!(state.charge[CHARGE_DIR_UP as usize], 0);
+
+        // Sliding to down-forward keeps down charge but drops back charge.
+        let down_forward = FrameInput {
+            direction: 3,
+            ..Default::default()
+        };
+        state = next_frame(&state, &pack, &down_forward).state;
+        assert_eq!(state.charge[CHARGE_DIR_DOWN as usize], 4);
+        assert_eq!(state.charge[CHARGE_DIR_BACK as usize], 0);
+        assert_eq!(state.charge[CHARGE_DIR_FORWARD as usize], 1);
+    }
+
+    #[test]
+    fn jump_leaves_the_ground_and_lands() {
+        use framesmith_fspack::MOVEMENT_FLAG_AIRBORNE;
+
+        // -4 px/frame up, 0.25 px/frame^2 down: back at the ground after 33 frames.
+        let bytes =
+            movement_pack_with_flags(40, (0, 39), (0, -1024), (0, 64), MOVEMENT_FLAG_AIRBORNE);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState::default();
+        let mut total_dy = 0;
+        for step in 1..=40 {
+            let result = next_frame(&state, &pack, &FrameInput::default());
+            state = result.state;
+            total_dy += result.position_delta.1;
+            if step < 33 {
+                assert!(!state.grounded, "step {step} should be airborne");
+                assert!(state.pos_y < 0, "step {step} should be above ground");
+                assert_eq!(total_dy, state.pos_y >> 8);
+            } else {
+                assert!(state.grounded, "step {step} should have landed");
+                assert_eq!((state.pos_y, state.vel_y), (0, 0));
+            }
+        }
+        assert_eq!(total_dy, 0);
+    }
+
+    #[test]
+    fn airborne_character_falls_under_gravity() {
+        let bytes = movement_pack(60, (0, 0), (0, 0), (0, 0));
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState {
+            grounded: false,
+            pos_y: -20 << 8,
+            ..Default::default()
+        };
+        let mut frames = 0;
+        while !state.grounded {
+            let result = next_frame(&state, &pack, &FrameInput::default());
+            assert!(result.position_delta.1 >= 0, "falling never moves up");
+            state = result.state;
+            frames += 1;
+            assert!(frames < 60, "never landed");
+        }
+        assert_eq!(state.pos_y, 0);
+        assert!(frames > 1);
+    }
+
+    #[test]
+    fn air_to_air_cancel_keeps_falling() {
+        let bytes = crate::test_support::tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState {
+            current_state: 1,
+            frame: 7,
+            grounded: false,
+            pos_y: -40 << 8,
+            vel_y: 3 << 8,
+            ..Default::default()
+        };
+
+        let result = next_frame(
+            &state,
+            &pack,
+            &FrameInput {
+                requested_state: Some(2),
+                ..Default::default()
+            },
+        );
+        assert_eq!(result.state.current_state, 2);
+        assert!(!result.state.grounded);
+        assert_eq!(result.state.pos_y, -37 << 8);
+        assert_eq!(result.state.vel_y, (3 << 8) + GRAVITY);
+        assert_eq!(result.position_delta, (0, 3));
+    }
+
+    #[test]
+    fn hitstop_holds_frame_then_resumes() {
+        let bytes = movement_pack(20, (0, 19), (0x0100, 0), (0, 0));
+        let pack = PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState {
+            frame: 4,
+            ..Default::default()
+        };
+        crate::freeze_for(&mut state, 3);
+        // A shorter freeze doesn't cut an existing one short.
+        crate::freeze_for(&mut state, 1);
+        assert_eq!(state.hitstop, 3);
+
+        let input = FrameInput::default();
+        for remaining in (0..3).rev() {
+            let result = next_frame(&state, &pack, &input);
+            state = result.state;
+            assert_eq!(state.frame, 4);
+            assert_eq!(state.hitstop, remaining);
+            assert_eq!(result.position_delta, (0, 0));
+        }
+
+        let result = next_frame(&state, &pack, &input);
+        assert_eq!(result.state.frame, 5);
+        assert_eq!(result.position_delta, (1, 0));
+    }
+
+    #[test]
+    fn hitstop_buffers_requests_until_it_ends() {
+        let bytes = crate::test_support::tag_rule_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState {
+            current_state: 1,
+            frame: 7,
+            hit_confirmed: true,
+            hitstop: 2,
+            ..Default::default()
+        };
+
+        let request = FrameInput {
+            requested_state: Some(2),
+            buffer_frames: 4,
+            ..Default::default()
+        };
+        let idle = FrameInput {
+            buffer_frames: 4,
+            ..Default::default()
+        };
+        state = next_frame(&state, &pack, &request).state;
+        assert_eq!((state.current_state, state.frame), (1, 7));
+        state = next_frame(&state, &pack, &idle).state;
+        assert_eq!((state.current_state, state.frame), (1, 7));
+
+        state = next_frame(&state, &pack, &idle).state;
+        assert_eq!((state.current_state, state.frame), (2, 0));
+    }
+
+    /// A "meter" resource capped at 250 and an any -> any cancel rule on
+    /// frame 0. State 1 gains 50 meter on use; state 2 has ON_USE (+30) and
+    /// ON_HIT (+99) meter deltas and enters state 3 on use.
+    fn on_use_sections() -> Vec<(u32, Vec<u8>)> {
+        use framesmith_fspack::view::SECTION_CANCEL_TAG_RULES;
+        use framesmith_fspack::{
+            MOVE_RESOURCE_DELTA_SIZE, OPT_U16_NONE, RESOURCE_DEF_SIZE,
+            RESOURCE_DELTA_TRIGGER_ON_HIT, RESOURCE_DELTA_TRIGGER_ON_USE,
+            SECTION_MOVE_RESOURCE_DELTAS, SECTION_RESOURCE_DEFS, SECTION_STATES,
+            SECTION_STATE_EXTRAS, SECTION_STATE_ON_USE, SECTION_STRING_TABLE, STATE_EXTRAS_SIZE,
+            STATE_RECORD_SIZE,
+        };
+
+        let mut def = std::vec![0u8; RESOURCE_DEF_SIZE];
+        def[4..6].copy_from_slice(&5u16.to_le_bytes());
+        def[10..12].copy_from_slice(&250u16.to_le_bytes());
+
+        let mut states = std::vec![0u8; 4 * STATE_RECORD_SIZE];
+        for rec in states.chunks_exact_mut(STATE_RECORD_SIZE) {
+            rec[14..16].copy_from_slice(&20u16.to_le_bytes()); // total
+        }
+
+        let mut on_use = Vec::new();
+        let none = OPT_U16_NONE;
+        for (enters, gain) in [(none, 0u16), (none, 50), (3, 0), (none, 0)] {
+            on_use.extend_from_slice(&enters.to_le_bytes());
+            on_use.extend_from_slice(&gain.to_le_bytes());
+        }
+
+        let mut deltas = Vec::new();
+        let triggers = [
+            (30i32, RESOURCE_DELTA_TRIGGER_ON_USE),
+            (99, RESOURCE_DELTA_TRIGGER_ON_HIT),
+        ];
+        for (delta, trigger) in triggers {
+            let mut rec = std::vec![0u8; MOVE_RESOURCE_DELTA_SIZE];
+            rec[4..6].copy_from_slice(&5u16.to_le_bytes()); // "meter"
+            rec[8..12].copy_from_slice(&delta.to_le_bytes());
+            rec[12] = trigger;
+            deltas.extend_from_slice(&rec);
+        }
+        let mut extras = std::vec![0u8; 4 * STATE_EXTRAS_SIZE];
+        extras[2 * STATE_EXTRAS_SIZE + 52..2 * STATE_EXTRAS_SIZE + 54]
+            .copy_from_slice(&2u16.to_le_bytes()); // state 2: both deltas
+
+        // any -> any on whiff, frame 0 only
+        let any = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0];
+        let mut rule = [any, any].concat();
+        rule.extend_from_slice(&[0b100, 0, 0, 0, 0, 0, 0, 0]);
+
+        std::vec![
+            (SECTION_STRING_TABLE, b"meter".to_vec()),
+            (SECTION_RESOURCE_DEFS, def),
+            (SECTION_STATES, states),
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_MOVE_RESOURCE_DELTAS, deltas),
+            (SECTION_STATE_ON_USE, on_use),
+            (SECTION_CANCEL_TAG_RULES, rule),
+        ]
+    }
+
+    fn on_use_pack() -> Vec<u8> {
+        crate::test_support::build_pack(&on_use_sections())
+    }
+
+    #[test]
+    fn on_use_meter_is_granted_once_at_entry() {
+        let bytes = on_use_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let request = FrameInput {
+            requested_state: Some(1),
+            ..Default::default()
+        };
+
+        let mut state = next_frame(&CharacterState::default(), &pack, &request).state;
+        assert_eq!((state.current_state, state.frame), (1, 0));
+        assert_eq!(crate::resource(&state, 0), 50);
+
+        for _ in 0..10 {
+            state = next_frame(&state, &pack, &FrameInput::default()).state;
+        }
+        assert_eq!(state.frame, 10);
+        assert_eq!(crate::resource(&state, 0), 50);
+    }
+
+    #[test]
+    fn on_use_deltas_apply_and_the_move_still_plays() {
+        let bytes = on_use_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let request = FrameInput {
+            requested_state: Some(2),
+            ..Default::default()
+        };
+
+        // Only the ON_USE delta applies; state 3 waits for the move to end.
+        let state = next_frame(&CharacterState::default(), &pack, &request).state;
+        assert_eq!((state.current_state, state.frame), (2, 0));
+        assert_eq!(crate::resource(&state, 0), 30);
+    }
+
+    #[test]
+    fn unaffordable_request_stays_buffered() {
+        use framesmith_fspack::{
+            MOVE_RESOURCE_COST_SIZE, SECTION_MOVE_RESOURCE_COSTS, SECTION_STATE_EXTRAS,
+            STATE_EXTRAS_SIZE,
+        };
+
+        // State 1 also costs 80 meter
+        let mut cost = std::vec![0u8; MOVE_RESOURCE_COST_SIZE];
+        cost[4..6].copy_from_slice(&5u16.to_le_bytes()); // "meter"
+        cost[8..10].copy_from_slice(&80u16.to_le_bytes());
+        let mut sections = on_use_sections();
+        for (kind, bytes) in &mut sections {
+            if *kind == SECTION_STATE_EXTRAS {
+                bytes[STATE_EXTRAS_SIZE + 36..STATE_EXTRAS_SIZE + 38]
+                    .copy_from_slice(&1u16.to_le_bytes());
+            }
+        }
+        sections.push((SECTION_MOVE_RESOURCE_COSTS, cost));
+        let bytes = crate::test_support::build_pack(&sections);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState::default();
+        crate::resource::set_resource(&mut state, 0, 79);
+        let request = FrameInput {
+            requested_state: Some(1),
+            buffer_frames: 5,
+            ..Default::default()
+        };
+        let next = next_frame(&state, &pack, &request).state;
+        assert_eq!((next.current_state, next.frame), (0, 1));
+        assert_eq!(next.resources, state.resources);
+        assert_eq!(next.input_buffer[0].map(|b| b.state), Some(1));
+
+        // With the meter it comes out and is paid for
+        crate::resource::set_resource(&mut state, 0, 80);
+        let next = next_frame(&state, &pack, &request).state;
+        assert_eq!((next.current_state, next.frame), (1, 0));
+        assert_eq!(crate::resource(&next, 0), 50, "80 paid, 50 gained on use");
+    }
+
+    /// State 1 has one condition of `kind` and idle cancels into it on whiff.
+    fn conditioned_pack(kind: u8, arg: u8, min: u16) -> Vec<u8> {
+        use framesmith_fspack::view::SECTION_CANCEL_TAG_RULES;
+
+        let any = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0];
+        let mut rule = [any, any].concat();
+        rule.extend_from_slice(&[0b100, 0, 255, 0, 0, 0, 0, 0]);
+        let mut sections = crate::test_support::condition_sections(kind, arg, min, 0xFFFF);
+        sections.push((SECTION_CANCEL_TAG_RULES, rule));
+        crate::test_support::build_pack(&sections)
+    }
+
+    const REQUEST_1: FrameInput = FrameInput {
+        requested_state: Some(1),
+        buffer_frames: 5,
+        direction: 5,
+    };
+
+    #[test]
+    fn airborne_only_request_on_the_ground_stays_buffered() {
+        use framesmith_fspack::CONDITION_KIND_AIRBORNE;
+
+        let bytes = conditioned_pack(CONDITION_KIND_AIRBORNE, 0, 0xFFFF);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let next = next_frame(&CharacterState::default(), &pack, &REQUEST_1).state;
+        assert_eq!((next.current_state, next.frame), (0, 1));
+        assert_eq!(next.input_buffer[0].map(|b| b.state), Some(1));
+
+        // Once airborne the buffered request comes out
+        let airborne = CharacterState {
+            grounded: false,
+            pos_y: -40 << 8,
+            ..next
+        };
+        let hold = FrameInput {
+            requested_state: None,
+            ..REQUEST_1
+        };
+        let next = next_frame(&airborne, &pack, &hold).state;
+        assert_eq!((next.current_state, next.frame), (1, 0));
+    }
+
+    #[test]
+    fn charge_request_without_charge_stays_buffered() {
+        use framesmith_fspack::{CHARGE_DIR_DOWN, CONDITION_KIND_CHARGE};
+
+        let bytes = conditioned_pack(CONDITION_KIND_CHARGE, CHARGE_DIR_DOWN, 40);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState::default();
+        state.charge[CHARGE_DIR_DOWN as usize] = 39;
+        let next = next_frame(&state, &pack, &REQUEST_1).state;
+        assert_eq!((next.current_state, next.frame), (0, 1));
+        assert_eq!(next.input_buffer[0].map(|b| b.state), Some(1));
+
+        state.charge[CHARGE_DIR_DOWN as usize] = 40;
+        let next = next_frame(&state, &pack, &REQUEST_1).state;
+        assert_eq!((next.current_state, next.frame), (1, 0));
+    }
+
+    #[test]
+    fn pack_without_movement_has_zero_delta() {
+        let pack_bytes = [b'F', b'S', b'P', b'K', 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0];
+        let pack = PackView::parse(&pack_bytes).unwrap();
+        let result = next_frame(&CharacterState::default(), &pack, &FrameInput::default());
+        assert_eq!(result.position_delta, (0, 0));
+    }
+
     #[test]
     fn instance_duration_field_behavior() {
         // This test documents the expected behavior:
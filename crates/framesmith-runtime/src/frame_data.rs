@@ -0,0 +1,95 @@
+use framesmith_fspack::PackView;
+
+/// Classic frame-data summary of a state, read straight from the pack.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameData {
+    /// Frames before the first active frame.
+    pub startup: u8,
+    pub active: u8,
+    pub recovery: u8,
+    /// Total frames of the state.
+    pub total: u16,
+    /// Frames the attacker recovers before the defender when the hit lands.
+    pub on_hit: i16,
+    /// Frames the attacker recovers before the defender when it is blocked.
+    pub on_block: i16,
+}
+
+/// Compute a state's frame data without running a simulation.
+///
+/// Advantage assumes the move connects on its first active frame and both
+/// characters return to neutral afterwards: the defender's hitstun or
+/// blockstun minus the attacker's remaining `active - 1 + recovery` frames.
+/// Hitstop freezes both characters equally, so it doesn't change advantage.
+///
+/// Returns `None` if the pack has no state at `state_idx`.
+pub fn compute_frame_data(pack: &PackView, state_idx: u16) -> Option<FrameData> {
+    let state = pack.states()?.get(state_idx as usize)?;
+    let remaining = i16::from(state.active().saturating_sub(1)) + i16::from(state.recovery());
+    Some(FrameData {
+        startup: state.startup(),
+        active: state.active(),
+        recovery: state.recovery(),
+        total: state.total(),
+        on_hit: i16::from(state.hitstun()) - remaining,
+        on_block: i16::from(state.blockstun()) - remaining,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::build_pack;
+    use framesmith_fspack::{SECTION_STATES, STATE_RECORD_SIZE};
+    use std::vec::Vec;
+
+    /// One state with the given timings and stun.
+    fn timing_pack(startup: u8, active: u8, recovery: u8, hitstun: u8, blockstun: u8) -> Vec<u8> {
+        let mut state = std::vec![0u8; STATE_RECORD_SIZE];
+        state[10] = startup;
+        state[11] = active;
+        state[12] = recovery;
+        let total = u16::from(startup) + u16::from(active) + u16::from(recovery);
+        state[14..16].copy_from_slice(&total.to_le_bytes());
+        state[18] = hitstun;
+        state[19] = blockstun;
+        build_pack(&[(SECTION_STATES, state)])
+    }
+
+    #[test]
+    fn advantage_counts_from_first_active_frame() {
+        // 5f jab, 2 active, 8 recovery: 9 frames left after connecting.
+        let bytes = timing_pack(5, 2, 8, 20, 10);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let data = compute_frame_data(&pack, 0).unwrap();
+        assert_eq!(
+            data,
+            FrameData {
+                startup: 5,
+                active: 2,
+                recovery: 8,
+                total: 15,
+                on_hit: 11,
+                on_block: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn unsafe_on_block_is_negative() {
+        let bytes = timing_pack(12, 4, 24, 22, 12);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        let data = compute_frame_data(&pack, 0).unwrap();
+        assert_eq!(data.on_hit, -5);
+        assert_eq!(data.on_block, -15);
+    }
+
+    #[test]
+    fn missing_state_has_no_frame_data() {
+        let bytes = timing_pack(5, 2, 8, 20, 10);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(compute_frame_data(&pack, 1), None);
+    }
+}
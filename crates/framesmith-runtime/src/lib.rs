@@ -4,18 +4,57 @@
 extern crate alloc;
 
 pub mod cancel;
+pub mod clock;
 pub mod collision;
+pub mod distance;
+pub mod exchange;
 pub mod frame;
+pub mod frame_data;
+pub mod precondition;
+pub mod replay;
 pub mod resource;
+pub mod rng;
 pub mod state;
+pub mod validate;
 
 // Re-export main types
-pub use state::{CharacterState, FrameInput, FrameResult, MAX_RESOURCES};
-pub use state::{report_block, report_hit};
+#[cfg(feature = "alloc")]
+pub use cancel::available_cancels;
+pub use cancel::{
+    available_cancels_buf, can_cancel_to, is_actionable, ACTION_CHAIN, ACTION_JUMP, ACTION_SPECIAL,
+    ACTION_SUPER, IDLE_STATE,
+};
+pub use clock::{FrameClock, DEFAULT_FRAME_RATE};
+pub use collision::{
+    aabb_circle_overlap, aabb_overlap, apply_pushback, apply_separation,
+    calculate_pushbox_separation, capsule_overlap, character_broadphase_aabb, check_hits,
+    check_pushbox, circle_overlap, move_priority, resolve_knockback, resolve_simultaneous,
+    shape_overlap_info, shapes_overlap, Aabb, Capsule, CheckHitsResult, Circle, HitResult,
+    OverlapInfo, PushboxResult, Resolution, StageBounds, TradePolicy, MAX_HIT_RESULTS, WORLD_LIMIT,
+};
+pub use distance::{euclidean_distance_q, horizontal_distance};
+pub use exchange::{state_type_priority, step_exchange, ExchangeResult};
 pub use frame::next_frame;
-pub use cancel::{can_cancel_to, ACTION_CHAIN, ACTION_SPECIAL, ACTION_SUPER, ACTION_JUMP};
-pub use collision::{aabb_circle_overlap, aabb_overlap, calculate_pushbox_separation, capsule_overlap, check_hits, check_pushbox, circle_overlap, shapes_overlap, Aabb, Capsule, CheckHitsResult, Circle, HitResult, PushboxResult, MAX_HIT_RESULTS};
-pub use resource::{apply_resource_costs, check_resource_preconditions, init_resources, resource, set_resource};
+pub use frame_data::{compute_frame_data, FrameData};
+pub use precondition::{check_state_conditions, is_move_available, MoveContext};
+#[cfg(feature = "alloc")]
+pub use replay::encode_replay;
+pub use replay::{
+    replay_len, run_replay, verify_replay, write_replay, FinalState, ReplayError, ReplayHeader,
+    ReplayPolicy, ReplayView, REPLAY_FRAME_SIZE, REPLAY_HEADER_SIZE, REPLAY_MAGIC, REPLAY_VERSION,
+};
+pub use resource::{
+    apply_on_use, apply_resource_costs, check_resource_preconditions, grant_meter_on_hit,
+    grant_meter_on_whiff, init_resources, resource, resource_index, set_resource,
+    InsufficientResource, ResourceCapacityExceeded, METER_RESOURCE,
+};
+pub use rng::Rng;
+pub use state::{freeze_for, hit_confirmed_recently, report_block, report_hit};
+pub use state::{
+    BufferedInput, CharacterState, FrameInput, FrameResult, CHARACTER_STATE_BYTES,
+    CHARGE_DIRECTIONS, DEFAULT_BUFFER_FRAMES, GRAVITY, INPUT_BUFFER_LEN, MAX_RESOURCES,
+};
+pub use validate::{validate_runtime_pack, RuntimePackError};
 
 // Re-export fspack for convenience
 pub use framesmith_fspack::PackView;
@@ -23,6 +62,9 @@ pub use framesmith_fspack::PackView;
 #[cfg(test)]
 extern crate std;
 
+#[cfg(test)]
+mod test_support;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,231 @@
+use crate::resource::{check_precondition_value, check_resource_preconditions};
+use crate::state::CharacterState;
+use framesmith_fspack::{
+    PackView, CONDITION_KIND_AIRBORNE, CONDITION_KIND_CHARGE, CONDITION_KIND_DISTANCE,
+    CONDITION_KIND_GROUNDED,
+};
+
+/// Match situation the runtime cannot derive from one character's state.
+///
+/// The game fills this in each frame before asking whether a move is usable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MoveContext {
+    /// Horizontal distance to the opponent in pixels; see
+    /// [`horizontal_distance`](crate::horizontal_distance).
+    pub distance: u32,
+}
+
+/// Check a move's packed non-resource preconditions against the state and context.
+///
+/// Unknown condition kinds and charge directions pass so older runtimes
+/// accept newer packs.
+pub fn check_state_conditions(
+    state: &CharacterState,
+    pack: &PackView,
+    move_index: u16,
+    context: &MoveContext,
+) -> bool {
+    // Bounds are u16; a farther distance still fails any max.
+    let distance = context.distance.min(u32::from(u16::MAX)) as u16;
+    check_conditions(state, pack, move_index, Some(distance))
+}
+
+/// Check the preconditions the character's own state decides: charge and
+/// grounded/airborne. Distance needs the opponent, so it passes here.
+pub(crate) fn check_own_conditions(
+    state: &CharacterState,
+    pack: &PackView,
+    move_index: u16,
+) -> bool {
+    check_conditions(state, pack, move_index, None)
+}
+
+/// Check packed conditions; distance ones pass when `distance` is `None`.
+fn check_conditions(
+    state: &CharacterState,
+    pack: &PackView,
+    move_index: u16,
+    distance: Option<u16>,
+) -> bool {
+    let Some(conditions) = pack.state_conditions(move_index as usize) else {
+        return true;
+    };
+
+    (0..conditions.len())
+        .filter_map(|i| conditions.get(i))
+        .all(|cond| match cond.kind() {
+            CONDITION_KIND_DISTANCE => {
+                distance.is_none_or(|d| check_precondition_value(d, cond.min(), cond.max()))
+            }
+            CONDITION_KIND_CHARGE => match state.charge.get(cond.arg() as usize) {
+                Some(&held) => check_precondition_value(u16::from(held), cond.min(), cond.max()),
+                None => true,
+            },
+            CONDITION_KIND_GROUNDED => state.grounded,
+            CONDITION_KIND_AIRBORNE => !state.grounded,
+            _ => true,
+        })
+}
+
+/// Check whether every precondition of a move is satisfied.
+///
+/// Covers resource preconditions, charge, grounded/airborne, and
+/// context-dependent ones such as distance. Cancel legality is separate; combine with
+/// [`can_cancel_to`](crate::can_cancel_to).
+pub fn is_move_available(
+    state: &CharacterState,
+    pack: &PackView,
+    move_index: u16,
+    context: &MoveContext,
+) -> bool {
+    check_resource_preconditions(state, pack, move_index)
+        && check_state_conditions(state, pack, move_index, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::FrameInput;
+    use crate::test_support::{build_pack, condition_sections};
+    use framesmith_fspack::CHARGE_DIR_DOWN;
+    use std::vec::Vec;
+
+    /// Two states; state 1 has a single condition of `kind`.
+    fn condition_pack(kind: u8, arg: u8, min: u16, max: u16) -> Vec<u8> {
+        build_pack(&condition_sections(kind, arg, min, max))
+    }
+
+    /// Two states; state 1 requires the opponent to be within `min..=max`.
+    fn distance_pack(min: u16, max: u16) -> Vec<u8> {
+        condition_pack(CONDITION_KIND_DISTANCE, 0, min, max)
+    }
+
+    #[test]
+    fn min_distance_gates_move() {
+        let bytes = distance_pack(100, 0xFFFF);
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState::default();
+
+        assert!(!is_move_available(
+            &state,
+            &pack,
+            1,
+            &MoveContext { distance: 50 }
+        ));
+        assert!(is_move_available(
+            &state,
+            &pack,
+            1,
+            &MoveContext { distance: 150 }
+        ));
+        assert!(is_move_available(
+            &state,
+            &pack,
+            1,
+            &MoveContext { distance: 100 }
+        ));
+    }
+
+    #[test]
+    fn max_distance_rejects_far_opponent() {
+        let bytes = distance_pack(0xFFFF, 80);
+        let pack = PackView::parse(&bytes).unwrap();
+        let state = CharacterState::default();
+
+        assert!(is_move_available(
+            &state,
+            &pack,
+            1,
+            &MoveContext { distance: 80 }
+        ));
+        assert!(!is_move_available(
+            &state,
+            &pack,
+            1,
+            &MoveContext { distance: 81 }
+        ));
+        assert!(!is_move_available(
+            &state,
+            &pack,
+            1,
+            &MoveContext {
+                distance: 1_000_000
+            }
+        ));
+    }
+
+    #[test]
+    fn states_without_conditions_are_available() {
+        let bytes = distance_pack(100, 0xFFFF);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert!(is_move_available(
+            &CharacterState::default(),
+            &pack,
+            0,
+            &MoveContext::default()
+        ));
+    }
+
+    #[test]
+    fn down_charge_requires_min_frames_held() {
+        let bytes = condition_pack(CONDITION_KIND_CHARGE, CHARGE_DIR_DOWN, 40, 0xFFFF);
+        let pack = PackView::parse(&bytes).unwrap();
+        let hold_down = FrameInput {
+            direction: 2,
+            ..Default::default()
+        };
+        let context = MoveContext::default();
+
+        let mut state = CharacterState::default();
+        for _ in 0..30 {
+            state = crate::next_frame(&state, &pack, &hold_down).state;
+        }
+        assert!(!is_move_available(&state, &pack, 1, &context));
+
+        for _ in 30..40 {
+            state = crate::next_frame(&state, &pack, &hold_down).state;
+        }
+        assert!(is_move_available(&state, &pack, 1, &context));
+
+        // Releasing to neutral drops the charge.
+        state = crate::next_frame(&state, &pack, &FrameInput::default()).state;
+        assert!(!is_move_available(&state, &pack, 1, &context));
+    }
+
+    #[test]
+    fn air_move_is_unavailable_while_grounded() {
+        let bytes = condition_pack(CONDITION_KIND_AIRBORNE, 0, 0xFFFF, 0xFFFF);
+        let pack = PackView::parse(&bytes).unwrap();
+        let context = MoveContext::default();
+
+        let grounded = CharacterState::default();
+        assert!(!is_move_available(&grounded, &pack, 1, &context));
+
+        let airborne = CharacterState {
+            grounded: false,
+            pos_y: -40 << 8,
+            ..Default::default()
+        };
+        assert!(is_move_available(&airborne, &pack, 1, &context));
+    }
+
+    #[test]
+    fn ground_move_is_unavailable_while_airborne() {
+        let bytes = condition_pack(CONDITION_KIND_GROUNDED, 0, 0xFFFF, 0xFFFF);
+        let pack = PackView::parse(&bytes).unwrap();
+        let context = MoveContext::default();
+
+        assert!(is_move_available(
+            &CharacterState::default(),
+            &pack,
+            1,
+            &context
+        ));
+        let airborne = CharacterState {
+            grounded: false,
+            ..Default::default()
+        };
+        assert!(!is_move_available(&airborne, &pack, 1, &context));
+    }
+}
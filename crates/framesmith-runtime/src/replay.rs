@@ -0,0 +1,617 @@
+//! Input replays for reproducing a match and checking determinism.
+//!
+//! A replay records everything [`step_exchange`] needs to rerun a match: the
+//! host's seed, the content hash of both packs, the starting positions, the
+//! trade policy and both characters' inputs for every frame. [`run_replay`]
+//! plays it back and [`verify_replay`] compares the result against a hash taken when it was
+//! recorded, so a reported desync can be reproduced from the file alone.
+//!
+//! Layout (little-endian):
+//! - 0-3: magic `FSRP`
+//! - 4-5: version (u16, [`REPLAY_VERSION`])
+//! - 6-7: reserved
+//! - 8-11: seed (u32)
+//! - 12-19: P1 pack content hash (u64)
+//! - 20-27: P2 pack content hash (u64)
+//! - 28-43: starting positions (i32 x, i32 y for P1, then P2)
+//! - 44-47: frame count (u32)
+//! - 48: trade policy (u8, see [`ReplayPolicy`]); 49-50: P1 and P2 priority
+//!   (u8, for [`TradePolicy::Priority`]); 51: reserved
+//! - then one [`REPLAY_FRAME_SIZE`] record per frame: P1's input, then P2's,
+//!   each `requested_state` (u16, `0xFFFF` = none), `buffer_frames` (u8) and
+//!   `direction` (u8)
+
+use framesmith_fspack::PackView;
+
+use crate::collision::{apply_separation, StageBounds, TradePolicy};
+use crate::exchange::{state_type_priority, step_exchange};
+use crate::resource::{init_resources, ResourceCapacityExceeded};
+use crate::state::{CharacterState, FrameInput, CHARACTER_STATE_BYTES};
+
+/// Magic bytes identifying a replay.
+pub const REPLAY_MAGIC: [u8; 4] = *b"FSRP";
+
+/// Replay format version written by [`write_replay`].
+pub const REPLAY_VERSION: u16 = 1;
+
+/// Size of the replay header in bytes.
+pub const REPLAY_HEADER_SIZE: usize = 52;
+
+/// Size of one frame of inputs (both characters) in bytes.
+pub const REPLAY_FRAME_SIZE: usize = 8;
+
+const NO_REQUEST: u16 = 0xFFFF;
+
+const POLICY_TRADE: u8 = 0;
+const POLICY_CLASH: u8 = 1;
+const POLICY_PRIORITY: u8 = 2;
+const POLICY_STATE_TYPE_PRIORITY: u8 = 3;
+
+/// How a replay resolves same-frame hits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayPolicy {
+    /// The same policy on every frame.
+    Fixed(TradePolicy),
+    /// [`state_type_priority`] of both characters' states on each frame.
+    StateTypePriority,
+}
+
+impl Default for ReplayPolicy {
+    fn default() -> Self {
+        Self::Fixed(TradePolicy::Trade)
+    }
+}
+
+impl ReplayPolicy {
+    /// The policy for one [`step_exchange`] between `p1` and `p2`.
+    #[must_use]
+    pub fn for_frame(
+        self,
+        p1: &CharacterState,
+        p2: &CharacterState,
+        packs: (&PackView, &PackView),
+    ) -> TradePolicy {
+        match self {
+            Self::Fixed(policy) => policy,
+            Self::StateTypePriority => state_type_priority(p1, packs.0, p2, packs.1),
+        }
+    }
+
+    fn to_bytes(self) -> [u8; 3] {
+        match self {
+            Self::Fixed(TradePolicy::Trade) => [POLICY_TRADE, 0, 0],
+            Self::Fixed(TradePolicy::Clash) => [POLICY_CLASH, 0, 0],
+            Self::Fixed(TradePolicy::Priority { p1, p2 }) => [POLICY_PRIORITY, p1, p2],
+            Self::StateTypePriority => [POLICY_STATE_TYPE_PRIORITY, 0, 0],
+        }
+    }
+
+    fn from_bytes(bytes: [u8; 3]) -> Option<Self> {
+        let [kind, p1, p2] = bytes;
+        Some(match kind {
+            POLICY_TRADE => Self::Fixed(TradePolicy::Trade),
+            POLICY_CLASH => Self::Fixed(TradePolicy::Clash),
+            POLICY_PRIORITY => Self::Fixed(TradePolicy::Priority { p1, p2 }),
+            POLICY_STATE_TYPE_PRIORITY => Self::StateTypePriority,
+            _ => return None,
+        })
+    }
+}
+
+/// Everything about a replay except its inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayHeader {
+    /// Seed the host used for its own randomness (e.g. a training dummy's
+    /// [`Rng`](crate::Rng)). The runtime doesn't draw from it; hosts reseed
+    /// with it before playing the replay back.
+    pub seed: u32,
+    /// [`PackView::content_hash`] of P1's and P2's packs.
+    pub pack_hashes: (u64, u64),
+    /// Starting positions of P1 and P2.
+    pub positions: ((i32, i32), (i32, i32)),
+    /// Trade policy passed to [`step_exchange`] on every frame.
+    pub policy: ReplayPolicy,
+}
+
+impl ReplayHeader {
+    /// Header for a match between `packs`, hashing both. The policy is
+    /// [`TradePolicy::Trade`]; set another with [`ReplayHeader::with_policy`].
+    #[must_use]
+    pub fn new(
+        seed: u32,
+        packs: (&PackView, &PackView),
+        positions: ((i32, i32), (i32, i32)),
+    ) -> Self {
+        Self {
+            seed,
+            pack_hashes: (packs.0.content_hash(), packs.1.content_hash()),
+            positions,
+            policy: ReplayPolicy::default(),
+        }
+    }
+
+    /// Resolve same-frame hits with `policy`.
+    #[must_use]
+    pub fn with_policy(self, policy: ReplayPolicy) -> Self {
+        Self { policy, ..self }
+    }
+}
+
+/// Why a replay couldn't be read or played back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// Shorter than the header.
+    TooShort,
+    /// Doesn't start with [`REPLAY_MAGIC`].
+    BadMagic,
+    /// Written by any format version other than [`REPLAY_VERSION`].
+    UnsupportedVersion(u16),
+    /// The byte length doesn't match the frame count in the header.
+    LengthMismatch,
+    /// The header names a trade policy this version doesn't know.
+    UnknownPolicy(u8),
+    /// A pack's content hash differs from the one recorded (1 = P1, 2 = P2).
+    PackMismatch {
+        /// Which player's pack differs.
+        player: u8,
+    },
+    /// A pack defines more resources than `CharacterState` can hold.
+    Resources(ResourceCapacityExceeded),
+}
+
+/// Number of bytes [`write_replay`] needs for `frames` frames.
+#[must_use]
+pub const fn replay_len(frames: usize) -> usize {
+    REPLAY_HEADER_SIZE + frames * REPLAY_FRAME_SIZE
+}
+
+/// Serialize a replay into `out`.
+///
+/// Returns the number of bytes written, or `None` if `out` is shorter than
+/// [`replay_len`] or there are more than `u32::MAX` frames.
+pub fn write_replay(
+    header: &ReplayHeader,
+    frames: &[(FrameInput, FrameInput)],
+    out: &mut [u8],
+) -> Option<usize> {
+    let frame_count = u32::try_from(frames.len()).ok()?;
+    let len = replay_len(frames.len());
+    let out = out.get_mut(..len)?;
+
+    let ((p1_x, p1_y), (p2_x, p2_y)) = header.positions;
+    out[0..4].copy_from_slice(&REPLAY_MAGIC);
+    out[4..6].copy_from_slice(&REPLAY_VERSION.to_le_bytes());
+    out[6..8].fill(0);
+    out[8..12].copy_from_slice(&header.seed.to_le_bytes());
+    out[12..20].copy_from_slice(&header.pack_hashes.0.to_le_bytes());
+    out[20..28].copy_from_slice(&header.pack_hashes.1.to_le_bytes());
+    out[28..32].copy_from_slice(&p1_x.to_le_bytes());
+    out[32..36].copy_from_slice(&p1_y.to_le_bytes());
+    out[36..40].copy_from_slice(&p2_x.to_le_bytes());
+    out[40..44].copy_from_slice(&p2_y.to_le_bytes());
+    out[44..48].copy_from_slice(&frame_count.to_le_bytes());
+    out[48..51].copy_from_slice(&header.policy.to_bytes());
+    out[51] = 0;
+
+    let records = out[REPLAY_HEADER_SIZE..].chunks_exact_mut(REPLAY_FRAME_SIZE);
+    for (record, (p1, p2)) in records.zip(frames) {
+        write_input(&mut record[..4], p1);
+        write_input(&mut record[4..], p2);
+    }
+    Some(len)
+}
+
+/// Serialize a replay into a new buffer.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_replay(
+    header: &ReplayHeader,
+    frames: &[(FrameInput, FrameInput)],
+) -> alloc::vec::Vec<u8> {
+    let mut out = alloc::vec![0u8; replay_len(frames.len())];
+    let written = write_replay(header, frames, &mut out);
+    debug_assert_eq!(written, Some(out.len()));
+    out
+}
+
+fn write_input(out: &mut [u8], input: &FrameInput) {
+    let requested = input.requested_state.unwrap_or(NO_REQUEST);
+    out[0..2].copy_from_slice(&requested.to_le_bytes());
+    out[2] = input.buffer_frames;
+    out[3] = input.direction;
+}
+
+fn read_input(data: &[u8]) -> FrameInput {
+    let requested = u16::from_le_bytes([data[0], data[1]]);
+    FrameInput {
+        requested_state: (requested != NO_REQUEST).then_some(requested),
+        buffer_frames: data[2],
+        direction: data[3],
+    }
+}
+
+/// Zero-copy view over serialized replay bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct ReplayView<'a> {
+    header: ReplayHeader,
+    frames: &'a [u8],
+}
+
+impl<'a> ReplayView<'a> {
+    /// Parse replay bytes written by [`write_replay`].
+    ///
+    /// # Errors
+    /// Returns [`ReplayError::TooShort`], [`ReplayError::BadMagic`],
+    /// [`ReplayError::UnsupportedVersion`], [`ReplayError::UnknownPolicy`] or
+    /// [`ReplayError::LengthMismatch`] if the bytes aren't a whole replay.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, ReplayError> {
+        let header = bytes
+            .get(..REPLAY_HEADER_SIZE)
+            .ok_or(ReplayError::TooShort)?;
+        if header[0..4] != REPLAY_MAGIC {
+            return Err(ReplayError::BadMagic);
+        }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+        let policy = [header[48], header[49], header[50]];
+        let policy =
+            ReplayPolicy::from_bytes(policy).ok_or(ReplayError::UnknownPolicy(policy[0]))?;
+
+        let u32_at = |at: usize| {
+            u32::from_le_bytes([header[at], header[at + 1], header[at + 2], header[at + 3]])
+        };
+        let i32_at = |at: usize| u32_at(at) as i32;
+        let u64_at = |at: usize| u64::from(u32_at(at)) | (u64::from(u32_at(at + 4)) << 32);
+
+        let frames = &bytes[REPLAY_HEADER_SIZE..];
+        let frame_count = u32_at(44) as usize;
+        if frame_count.checked_mul(REPLAY_FRAME_SIZE) != Some(frames.len()) {
+            return Err(ReplayError::LengthMismatch);
+        }
+
+        Ok(Self {
+            header: ReplayHeader {
+                seed: u32_at(8),
+                pack_hashes: (u64_at(12), u64_at(20)),
+                positions: ((i32_at(28), i32_at(32)), (i32_at(36), i32_at(40))),
+                policy,
+            },
+            frames,
+        })
+    }
+
+    /// Seed, pack hashes, starting positions and trade policy.
+    pub fn header(&self) -> ReplayHeader {
+        self.header
+    }
+
+    /// Number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len() / REPLAY_FRAME_SIZE
+    }
+
+    /// Returns true if no frames were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// P1's and P2's inputs on frame `index`.
+    pub fn frame(&self, index: usize) -> Option<(FrameInput, FrameInput)> {
+        let off = index.checked_mul(REPLAY_FRAME_SIZE)?;
+        let record = self.frames.get(off..off.checked_add(REPLAY_FRAME_SIZE)?)?;
+        Some((read_input(&record[..4]), read_input(&record[4..])))
+    }
+
+    /// Iterate over every frame's inputs in order.
+    pub fn frames(&self) -> impl Iterator<Item = (FrameInput, FrameInput)> + 'a {
+        self.frames
+            .chunks_exact(REPLAY_FRAME_SIZE)
+            .map(|record| (read_input(&record[..4]), read_input(&record[4..])))
+    }
+}
+
+/// Where a replay left both characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FinalState {
+    /// P1's state after the last frame.
+    pub p1: CharacterState,
+    /// P2's state after the last frame.
+    pub p2: CharacterState,
+    /// P1's and P2's positions after the last frame.
+    pub positions: ((i32, i32), (i32, i32)),
+    /// Number of frames played.
+    pub frames: u32,
+}
+
+impl FinalState {
+    /// 64-bit FNV-1a hash of both states, the positions and the frame count.
+    ///
+    /// Stable across runs and platforms, so it can be stored alongside a
+    /// replay and checked with [`verify_replay`].
+    pub fn hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let ((p1_x, p1_y), (p2_x, p2_y)) = self.positions;
+        let mut bytes = [0u8; 2 * CHARACTER_STATE_BYTES + 20];
+        let (states, rest) = bytes.split_at_mut(2 * CHARACTER_STATE_BYTES);
+        states[..CHARACTER_STATE_BYTES].copy_from_slice(&self.p1.to_bytes());
+        states[CHARACTER_STATE_BYTES..].copy_from_slice(&self.p2.to_bytes());
+        for (chunk, value) in
+            rest.chunks_exact_mut(4)
+                .zip([p1_x, p1_y, p2_x, p2_y, self.frames as i32])
+        {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+
+        bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &b| {
+            (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
+
+/// Play a replay back from the start.
+///
+/// Both characters start in a default state with resources initialized from
+/// their pack, at the recorded positions. Each frame runs [`step_exchange`]
+/// with the recorded [`ReplayPolicy`], moves both characters by their movement
+/// deltas and applies the pushbox separation with [`apply_separation`], all
+/// inside [`StageBounds::WORLD`]; nothing else moves them.
+///
+/// # Errors
+/// Returns [`ReplayError::PackMismatch`] if either pack's content hash differs
+/// from the recorded one, or [`ReplayError::Resources`] if a pack's resources
+/// don't fit.
+pub fn run_replay(
+    packs: (&PackView, &PackView),
+    replay: &ReplayView,
+) -> Result<FinalState, ReplayError> {
+    let header = replay.header();
+    if packs.0.content_hash() != header.pack_hashes.0 {
+        return Err(ReplayError::PackMismatch { player: 1 });
+    }
+    if packs.1.content_hash() != header.pack_hashes.1 {
+        return Err(ReplayError::PackMismatch { player: 2 });
+    }
+
+    let mut p1 = CharacterState::default();
+    let mut p2 = CharacterState::default();
+    init_resources(&mut p1, packs.0).map_err(ReplayError::Resources)?;
+    init_resources(&mut p2, packs.1).map_err(ReplayError::Resources)?;
+    let (mut p1_pos, mut p2_pos) = header.positions;
+
+    for (p1_input, p2_input) in replay.frames() {
+        let result = step_exchange(
+            &p1,
+            &p2,
+            packs,
+            (&p1_input, &p2_input),
+            (p1_pos, p2_pos),
+            header.policy.for_frame(&p1, &p2, packs),
+        );
+        p1 = result.p1;
+        p2 = result.p2;
+        p1_pos = move_by(p1_pos, result.p1_delta);
+        p2_pos = move_by(p2_pos, result.p2_delta);
+        if let Some(push) = result.push {
+            (p1_pos, p2_pos) = apply_separation(p1_pos, p2_pos, &push, StageBounds::WORLD);
+        }
+    }
+
+    Ok(FinalState {
+        p1,
+        p2,
+        positions: (p1_pos, p2_pos),
+        frames: replay.len() as u32,
+    })
+}
+
+/// Move `pos` by a movement delta, keeping x inside [`StageBounds::WORLD`].
+fn move_by(pos: (i32, i32), delta: (i32, i32)) -> (i32, i32) {
+    (
+        StageBounds::WORLD.clamp_x(pos.0.saturating_add(delta.0)),
+        pos.1.saturating_add(delta.1),
+    )
+}
+
+/// Play a replay back and check it ends in the state hashed as
+/// `expected_hash` (see [`FinalState::hash`]).
+///
+/// Returns false if the replay can't be played with these packs.
+pub fn verify_replay(
+    packs: (&PackView, &PackView),
+    replay: &ReplayView,
+    expected_hash: u64,
+) -> bool {
+    run_replay(packs, replay).is_ok_and(|state| state.hash() == expected_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+    use std::vec::Vec;
+
+    /// The duel pack with an 80px-wide hitbox centred on the attacker, so the
+    /// attack reaches past the pushboxes on either side, and an any -> any
+    /// cancel on whiff, so either character can start it whenever it isn't
+    /// connecting.
+    fn replay_pack() -> Vec<u8> {
+        use framesmith_fspack::view::SECTION_CANCEL_TAG_RULES;
+        use framesmith_fspack::{SECTION_HIT_WINDOWS, SECTION_SHAPES, SHAPE_SIZE};
+
+        let any = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0];
+        let mut rule = [any, any].concat();
+        rule.extend_from_slice(&[0b100, 0, 255, 0, 0, 0, 0, 0]);
+        let mut sections = crate::test_support::duel_sections();
+        for (kind, bytes) in &mut sections {
+            if *kind == SECTION_SHAPES {
+                let mut wide = bytes[..SHAPE_SIZE].to_vec();
+                wide[2..4].copy_from_slice(&(-640i16).to_le_bytes());
+                wide[6..8].copy_from_slice(&1280i16.to_le_bytes());
+                bytes.extend(wide);
+            } else if *kind == SECTION_HIT_WINDOWS {
+                bytes[12..16].copy_from_slice(&(SHAPE_SIZE as u32).to_le_bytes());
+            }
+        }
+        sections.push((SECTION_CANCEL_TAG_RULES, rule));
+        crate::test_support::build_pack(&sections)
+    }
+
+    /// Random inputs for both characters, drawn from `seed`.
+    fn random_inputs(seed: u32, frames: usize) -> Vec<(FrameInput, FrameInput)> {
+        let mut rng = Rng::new(seed);
+        let input = |rng: &mut Rng| FrameInput {
+            requested_state: (rng.next_below(8) == 0).then_some(1),
+            buffer_frames: rng.next_below(4) as u8,
+            direction: rng.next_below(10) as u8,
+        };
+        (0..frames)
+            .map(|_| (input(&mut rng), input(&mut rng)))
+            .collect()
+    }
+
+    #[test]
+    fn recorded_run_replays_to_the_same_final_state() {
+        let bytes = replay_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let packs = (&pack, &pack);
+        let positions = ((0, 0), (12, 0));
+        let inputs = random_inputs(7, 300);
+
+        // Live run, as a host would drive it
+        let (mut p1, mut p2) = (CharacterState::default(), CharacterState::default());
+        let (mut p1_pos, mut p2_pos) = positions;
+        let mut hits = 0;
+        for (p1_input, p2_input) in &inputs {
+            let result = step_exchange(
+                &p1,
+                &p2,
+                packs,
+                (p1_input, p2_input),
+                (p1_pos, p2_pos),
+                TradePolicy::Trade,
+            );
+            hits += result.p1_hits.len() + result.p2_hits.len();
+            p1 = result.p1;
+            p2 = result.p2;
+            p1_pos = move_by(p1_pos, result.p1_delta);
+            p2_pos = move_by(p2_pos, result.p2_delta);
+            if let Some(push) = result.push {
+                (p1_pos, p2_pos) = apply_separation(p1_pos, p2_pos, &push, StageBounds::WORLD);
+            }
+        }
+        assert!(hits > 0, "the run should land hits");
+        let live = FinalState {
+            p1,
+            p2,
+            positions: (p1_pos, p2_pos),
+            frames: 300,
+        };
+
+        let header = ReplayHeader::new(7, packs, positions);
+        let mut encoded = std::vec![0u8; replay_len(inputs.len())];
+        assert_eq!(
+            write_replay(&header, &inputs, &mut encoded),
+            Some(encoded.len())
+        );
+        assert_eq!(write_replay(&header, &inputs, &mut encoded[1..]), None);
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(encode_replay(&header, &inputs), encoded);
+
+        let replay = ReplayView::parse(&encoded).unwrap();
+        assert_eq!(replay.header(), header);
+        assert_eq!(replay.len(), 300);
+        let (p1_input, p2_input) = replay.frame(0).unwrap();
+        assert_eq!(p1_input.requested_state, inputs[0].0.requested_state);
+        assert_eq!(p2_input.direction, inputs[0].1.direction);
+        assert!(replay.frame(300).is_none());
+
+        assert_eq!(run_replay(packs, &replay), Ok(live));
+        assert!(verify_replay(packs, &replay, live.hash()));
+        assert!(!verify_replay(packs, &replay, live.hash() ^ 1));
+    }
+
+    #[test]
+    fn replay_plays_back_with_the_recorded_policy() {
+        let bytes = replay_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let packs = (&pack, &pack);
+        let positions = ((0, 0), (0, 0));
+        // Both attack on the same frame from the same spot, so their hits meet
+        let attack = FrameInput {
+            requested_state: Some(1),
+            ..Default::default()
+        };
+        let mut inputs = std::vec![(attack, attack)];
+        inputs.resize(6, (FrameInput::default(), FrameInput::default()));
+
+        let play = |policy: ReplayPolicy| {
+            let header = ReplayHeader::new(0, packs, positions).with_policy(policy);
+            let mut encoded = std::vec![0u8; replay_len(inputs.len())];
+            write_replay(&header, &inputs, &mut encoded).unwrap();
+            let replay = ReplayView::parse(&encoded).unwrap();
+            assert_eq!(replay.header().policy, policy);
+            run_replay(packs, &replay).unwrap()
+        };
+
+        let traded = play(ReplayPolicy::default());
+
+        let clashed = play(ReplayPolicy::Fixed(TradePolicy::Clash));
+        let p1_wins = play(ReplayPolicy::Fixed(TradePolicy::Priority { p1: 1, p2: 0 }));
+        assert!(traded.p1.hit_confirmed && traded.p2.hit_confirmed);
+        assert!(!clashed.p1.hit_confirmed && !clashed.p2.hit_confirmed);
+        assert!(p1_wins.p1.hit_confirmed && !p1_wins.p2.hit_confirmed);
+        // Same state type on both sides ranks equal, so it trades
+        assert_eq!(play(ReplayPolicy::StateTypePriority), traded);
+    }
+
+    #[test]
+    fn replay_rejects_other_packs_and_malformed_bytes() {
+        let bytes = replay_pack();
+        let pack = PackView::parse(&bytes).unwrap();
+        let other_bytes = crate::test_support::tag_rule_pack();
+        let other = PackView::parse(&other_bytes).unwrap();
+
+        let header = ReplayHeader::new(1, (&pack, &pack), ((0, 0), (40, 0)));
+        let inputs = random_inputs(1, 10);
+        let mut encoded = std::vec![0u8; replay_len(inputs.len())];
+        write_replay(&header, &inputs, &mut encoded).unwrap();
+        let replay = ReplayView::parse(&encoded).unwrap();
+
+        assert_eq!(
+            run_replay((&pack, &other), &replay),
+            Err(ReplayError::PackMismatch { player: 2 })
+        );
+        let expected = run_replay((&pack, &pack), &replay).unwrap().hash();
+        assert!(!verify_replay((&other, &pack), &replay, expected));
+
+        let parse = |bytes: &[u8]| ReplayView::parse(bytes).map(|_| ());
+        assert_eq!(
+            parse(&encoded[..REPLAY_HEADER_SIZE - 1]),
+            Err(ReplayError::TooShort)
+        );
+        assert_eq!(
+            parse(&encoded[..encoded.len() - 1]),
+            Err(ReplayError::LengthMismatch)
+        );
+        let mut unknown = encoded.clone();
+        unknown[48] = 9;
+        assert_eq!(parse(&unknown), Err(ReplayError::UnknownPolicy(9)));
+        let mut bad = encoded.clone();
+        bad[0] = b'X';
+        assert_eq!(parse(&bad), Err(ReplayError::BadMagic));
+        let mut newer = encoded.clone();
+        newer[4..6].copy_from_slice(&(REPLAY_VERSION + 1).to_le_bytes());
+        assert_eq!(
+            parse(&newer),
+            Err(ReplayError::UnsupportedVersion(REPLAY_VERSION + 1))
+        );
+        let mut zero = encoded.clone();
+        zero[4..6].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(parse(&zero), Err(ReplayError::UnsupportedVersion(0)));
+    }
+}
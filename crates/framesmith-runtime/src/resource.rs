@@ -18,53 +18,182 @@ pub fn set_resource(state: &mut CharacterState, index: u8, value: u16) {
     }
 }
 
+/// Resolve a resource name from the pack's RESOURCE_DEFS to its slot index.
+///
+/// Returns `None` if the pack has no resource with that name, or if it sits
+/// past the `MAX_RESOURCES` slots `CharacterState` tracks.
+pub fn resource_index(pack: &framesmith_fspack::PackView, name: &str) -> Option<usize> {
+    let defs = pack.resource_defs()?;
+    (0..defs.len().min(MAX_RESOURCES)).find(|&i| {
+        defs.get(i)
+            .and_then(|def| pack.string(def.name_off(), def.name_len()))
+            == Some(name)
+    })
+}
+
+/// A resource cost the character can't afford.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsufficientResource {
+    /// Resource slot index (see [`resource_index`]).
+    pub index: u8,
+    /// Total cost of the move for this resource.
+    pub required: u16,
+    /// Current value of the resource.
+    pub available: u16,
+}
+
 /// Apply resource costs for a move transition.
 ///
-/// Deducts costs from state. Returns true if all costs were paid,
-/// false if any resource was insufficient (costs still deducted).
+/// All-or-nothing: every cost is checked against the current pools first,
+/// and resources are only deducted if all of them can be paid. Costs naming
+/// the same resource more than once are summed.
+///
+/// # Errors
+/// Returns the first unaffordable resource, leaving `state` unchanged.
 pub fn apply_resource_costs(
     state: &mut CharacterState,
     pack: &framesmith_fspack::PackView,
     move_index: u16,
-) -> bool {
-    let extras = match pack.state_extras() {
-        Some(e) => e,
-        None => return true,
+) -> Result<(), InsufficientResource> {
+    let Some(extra) = pack
+        .state_extras()
+        .and_then(|extras| extras.get(move_index as usize))
+    else {
+        return Ok(());
     };
-    let extra = match extras.get(move_index as usize) {
-        Some(e) => e,
-        None => return true,
+    let (Some(costs_view), Some(defs)) = (pack.move_resource_costs(), pack.resource_defs()) else {
+        return Ok(());
     };
-    let costs_view = match pack.move_resource_costs() {
-        Some(c) => c,
-        None => return true,
-    };
-    let resource_defs = pack.resource_defs();
 
+    // Total cost per resource slot
+    let mut totals = [0u32; MAX_RESOURCES];
     let (off, len) = extra.resource_costs();
-    let mut all_paid = true;
+    for i in 0..len as usize {
+        let Some(cost) = costs_view.get_at(off, i) else {
+            continue;
+        };
+        // Find resource index by name
+        let slot = (0..defs.len().min(MAX_RESOURCES)).find(|&res_idx| {
+            defs.get(res_idx).is_some_and(|def| {
+                def.name_off() == cost.name_off() && def.name_len() == cost.name_len()
+            })
+        });
+        if let Some(res_idx) = slot {
+            totals[res_idx] += u32::from(cost.amount());
+        }
+    }
+
+    for (res_idx, &total) in totals.iter().enumerate() {
+        let available = resource(state, res_idx as u8);
+        if u32::from(available) < total {
+            return Err(InsufficientResource {
+                index: res_idx as u8,
+                required: total.min(u32::from(u16::MAX)) as u16,
+                available,
+            });
+        }
+    }
+
+    for (res_idx, &total) in totals.iter().enumerate() {
+        let current = resource(state, res_idx as u8);
+        set_resource(state, res_idx as u8, current - total as u16);
+    }
+
+    Ok(())
+}
+
+/// Resource pool that meter gain is awarded to.
+pub const METER_RESOURCE: &str = "meter";
+
+/// Award the current state's on-hit meter gain.
+///
+/// Call once per hit the state lands, alongside [`report_hit`](crate::report_hit).
+/// The gain goes to the [`METER_RESOURCE`] pool, capped at its `max`; packs
+/// without that resource are left unchanged.
+pub fn grant_meter_on_hit(state: &mut CharacterState, pack: &framesmith_fspack::PackView) {
+    let gain = pack
+        .state_meter_gains()
+        .and_then(|gains| gains.get(state.current_state as usize))
+        .map_or(0, |g| g.meter_gain_hit());
+    add_meter(state, pack, gain);
+}
+
+/// Award the current state's on-whiff meter gain.
+///
+/// Call when the state ends, before leaving it. Does nothing if the state
+/// connected (`hit_confirmed` or `block_confirmed`).
+pub fn grant_meter_on_whiff(state: &mut CharacterState, pack: &framesmith_fspack::PackView) {
+    if state.hit_confirmed || state.block_confirmed {
+        return;
+    }
+    let gain = pack
+        .state_meter_gains()
+        .and_then(|gains| gains.get(state.current_state as usize))
+        .map_or(0, |g| g.meter_gain_whiff());
+    add_meter(state, pack, gain);
+}
+
+/// Apply a move's on-use resource effects as it starts.
+///
+/// Grants the move's on-use meter (capped like [`grant_meter_on_hit`]) and
+/// applies each of its ON_USE resource deltas, clamping the pool to
+/// `0..=max`. Deltas naming resources the pack doesn't define are skipped.
+/// [`next_frame`](crate::next_frame) calls this once when it enters a state.
+pub fn apply_on_use(
+    state: &mut CharacterState,
+    pack: &framesmith_fspack::PackView,
+    move_index: u16,
+) {
+    let gain = pack
+        .state_on_uses()
+        .and_then(|uses| uses.get(move_index as usize))
+        .map_or(0, |u| u.gain_meter());
+    add_meter(state, pack, gain);
 
+    let Some(extra) = pack
+        .state_extras()
+        .and_then(|extras| extras.get(move_index as usize))
+    else {
+        return;
+    };
+    let (Some(deltas), Some(defs)) = (pack.move_resource_deltas(), pack.resource_defs()) else {
+        return;
+    };
+    let (off, len) = extra.resource_deltas();
     for i in 0..len as usize {
-        if let Some(cost) = costs_view.get_at(off, i) {
-            // Find resource index by name
-            if let Some(defs) = &resource_defs {
-                for res_idx in 0..defs.len().min(MAX_RESOURCES) {
-                    if let Some(def) = defs.get(res_idx) {
-                        if def.name_off() == cost.name_off() && def.name_len() == cost.name_len() {
-                            let current = resource(state, res_idx as u8);
-                            if current < cost.amount() {
-                                all_paid = false;
-                            }
-                            set_resource(state, res_idx as u8, current.saturating_sub(cost.amount()));
-                            break;
-                        }
-                    }
-                }
-            }
+        let Some(delta) = deltas.get_at(off, i) else {
+            continue;
+        };
+        if delta.trigger_enum() != framesmith_fspack::ResourceTrigger::OnUse {
+            continue;
         }
+        let slot = (0..defs.len().min(MAX_RESOURCES)).find(|&res_idx| {
+            defs.get(res_idx).is_some_and(|def| {
+                def.name_off() == delta.name_off() && def.name_len() == delta.name_len()
+            })
+        });
+        let Some(res_idx) = slot else {
+            continue;
+        };
+        let max = defs.get(res_idx).map_or(u16::MAX, |def| def.max());
+        let value = i64::from(resource(state, res_idx as u8)) + i64::from(delta.delta());
+        set_resource(state, res_idx as u8, value.clamp(0, i64::from(max)) as u16);
     }
+}
 
-    all_paid
+fn add_meter(state: &mut CharacterState, pack: &framesmith_fspack::PackView, gain: u16) {
+    if gain == 0 {
+        return;
+    }
+    let Some(slot) = resource_index(pack, METER_RESOURCE) else {
+        return;
+    };
+    let max = pack
+        .resource_defs()
+        .and_then(|defs| defs.get(slot))
+        .map_or(u16::MAX, |def| def.max());
+    let current = resource(state, slot as u8);
+    set_resource(state, slot as u8, current.saturating_add(gain).min(max));
 }
 
 /// Check if a resource value satisfies a precondition.
@@ -130,8 +259,32 @@ pub fn check_resource_preconditions(
     true
 }
 
+/// The pack defines more resource pools than `CharacterState` can hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResourceCapacityExceeded {
+    /// Number of resources in the pack's RESOURCE_DEFS.
+    pub count: usize,
+    /// Slots available ([`MAX_RESOURCES`]).
+    pub capacity: usize,
+}
+
 /// Initialize resources from pack's resource definitions.
-pub fn init_resources(state: &mut CharacterState, pack: &framesmith_fspack::PackView) {
+///
+/// # Errors
+/// Returns [`ResourceCapacityExceeded`] if the pack defines more than
+/// [`MAX_RESOURCES`] resources, leaving `state` unchanged.
+pub fn init_resources(
+    state: &mut CharacterState,
+    pack: &framesmith_fspack::PackView,
+) -> Result<(), ResourceCapacityExceeded> {
+    let count = pack.resource_defs().map_or(0, |defs| defs.len());
+    if count > MAX_RESOURCES {
+        return Err(ResourceCapacityExceeded {
+            count,
+            capacity: MAX_RESOURCES,
+        });
+    }
+
     // Reset all to zero first
     state.resources = [0; MAX_RESOURCES];
 
@@ -142,6 +295,7 @@ pub fn init_resources(state: &mut CharacterState, pack: &framesmith_fspack::Pack
             }
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -183,31 +337,210 @@ mod tests {
 
     #[test]
     fn out_of_bounds_resource_returns_zero() {
-        let state = CharacterState::default();
-        assert_eq!(resource(&state, 8), 0);
+        // Every slot holds something, so a zero can only come from past the end
+        let mut state = CharacterState {
+            resources: [1; MAX_RESOURCES],
+            ..Default::default()
+        };
+        set_resource(&mut state, 8, 300);
+        assert_eq!(resource(&state, 8), 300);
+        assert_eq!(resource(&state, MAX_RESOURCES as u8 - 1), 1);
+
+        set_resource(&mut state, MAX_RESOURCES as u8, 50);
+        assert_eq!(resource(&state, MAX_RESOURCES as u8), 0);
         assert_eq!(resource(&state, 255), 0);
     }
 
-    /// Tests that the resource primitives support the deduction pattern.
-    ///
-    /// Note: `apply_resource_costs` requires a full PackView with move_extras,
-    /// move_resource_costs, and resource_defs sections - too complex for unit tests
-    /// in a no_std crate. That function is integration-tested via frame.rs when
-    /// processing real .fspk packs.
     #[test]
-    fn resource_primitives_support_deduction() {
+    fn resource_index_resolves_names_from_resource_defs() {
+        use crate::test_support::build_pack;
+        use framesmith_fspack::{
+            PackView, RESOURCE_DEF_SIZE, SECTION_RESOURCE_DEFS, SECTION_STRING_TABLE,
+        };
+        use std::vec::Vec;
+
+        let mut defs = Vec::new();
+        for (off, len, start) in [(0u32, 5u16, 0u16), (5, 4, 100)] {
+            let mut rec = std::vec![0u8; RESOURCE_DEF_SIZE];
+            rec[0..4].copy_from_slice(&off.to_le_bytes());
+            rec[4..6].copy_from_slice(&len.to_le_bytes());
+            rec[8..10].copy_from_slice(&start.to_le_bytes());
+            defs.extend_from_slice(&rec);
+        }
+        let bytes = build_pack(&[
+            (SECTION_STRING_TABLE, b"meterheat".to_vec()),
+            (SECTION_RESOURCE_DEFS, defs),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+
+        assert_eq!(resource_index(&pack, "meter"), Some(0));
+        assert_eq!(resource_index(&pack, "heat"), Some(1));
+        assert_eq!(resource_index(&pack, "ammo"), None);
+        assert_eq!(resource_index(&pack, "met"), None);
+
         let mut state = CharacterState::default();
-        set_resource(&mut state, 0, 100); // meter
-        set_resource(&mut state, 1, 50);  // heat
-
-        // Simulate deducting 30 from resource 0, 10 from resource 1
-        let costs = [(0u8, 30u16), (1u8, 10u16)];
-        for (idx, amount) in costs {
-            let current = resource(&state, idx);
-            set_resource(&mut state, idx, current.saturating_sub(amount));
+        init_resources(&mut state, &pack).unwrap();
+        let heat = resource_index(&pack, "heat").unwrap();
+        assert_eq!(resource(&state, heat as u8), 100);
+    }
+
+    /// `count` unnamed resources, each starting at `10 * slot`.
+    fn pack_with_resources(count: usize) -> std::vec::Vec<u8> {
+        use crate::test_support::build_pack;
+        use framesmith_fspack::{RESOURCE_DEF_SIZE, SECTION_RESOURCE_DEFS};
+
+        let mut defs = std::vec![0u8; count * RESOURCE_DEF_SIZE];
+        for (slot, def) in defs.chunks_exact_mut(RESOURCE_DEF_SIZE).enumerate() {
+            def[8..10].copy_from_slice(&(slot as u16 * 10).to_le_bytes());
         }
+        build_pack(&[(SECTION_RESOURCE_DEFS, defs)])
+    }
+
+    #[test]
+    fn init_resources_fills_ten_pools() {
+        let bytes = pack_with_resources(10);
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState::default();
+        assert_eq!(init_resources(&mut state, &pack), Ok(()));
+        assert_eq!(resource(&state, 9), 90);
+        assert_eq!(resource(&state, 10), 0);
+    }
+
+    #[test]
+    fn init_resources_rejects_packs_past_capacity() {
+        let bytes = pack_with_resources(MAX_RESOURCES + 1);
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+
+        let mut state = CharacterState::default();
+        state.resources[0] = 7;
+        assert_eq!(
+            init_resources(&mut state, &pack),
+            Err(ResourceCapacityExceeded {
+                count: MAX_RESOURCES + 1,
+                capacity: MAX_RESOURCES,
+            })
+        );
+        assert_eq!(resource(&state, 0), 7, "state is left unchanged");
+    }
+
+    /// Resources "meter" (slot 0) and "heat" (slot 1); state 0 costs
+    /// 50 meter + 30 heat.
+    fn costly_move_pack() -> std::vec::Vec<u8> {
+        use crate::test_support::build_pack;
+        use framesmith_fspack::{
+            MOVE_RESOURCE_COST_SIZE, RESOURCE_DEF_SIZE, SECTION_MOVE_RESOURCE_COSTS,
+            SECTION_RESOURCE_DEFS, SECTION_STATE_EXTRAS, SECTION_STRING_TABLE, STATE_EXTRAS_SIZE,
+        };
+        use std::vec::Vec;
+
+        let mut defs = Vec::new();
+        let mut costs = Vec::new();
+        for (off, len, amount) in [(0u32, 5u16, 50u16), (5, 4, 30)] {
+            let mut def = std::vec![0u8; RESOURCE_DEF_SIZE];
+            def[0..4].copy_from_slice(&off.to_le_bytes());
+            def[4..6].copy_from_slice(&len.to_le_bytes());
+            defs.extend_from_slice(&def);
+
+            let mut cost = std::vec![0u8; MOVE_RESOURCE_COST_SIZE];
+            cost[0..4].copy_from_slice(&off.to_le_bytes());
+            cost[4..6].copy_from_slice(&len.to_le_bytes());
+            cost[8..10].copy_from_slice(&amount.to_le_bytes());
+            costs.extend_from_slice(&cost);
+        }
+
+        let mut extras = std::vec![0u8; STATE_EXTRAS_SIZE];
+        extras[36..38].copy_from_slice(&2u16.to_le_bytes()); // two costs at offset 0
+
+        build_pack(&[
+            (SECTION_STRING_TABLE, b"meterheat".to_vec()),
+            (SECTION_RESOURCE_DEFS, defs),
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_MOVE_RESOURCE_COSTS, costs),
+        ])
+    }
+
+    #[test]
+    fn apply_resource_costs_deducts_every_cost() {
+        let bytes = costly_move_pack();
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState::default();
+        set_resource(&mut state, 0, 100);
+        set_resource(&mut state, 1, 40);
+
+        assert_eq!(apply_resource_costs(&mut state, &pack, 0), Ok(()));
+        assert_eq!(resource(&state, 0), 50);
+        assert_eq!(resource(&state, 1), 10);
+    }
+
+    #[test]
+    fn apply_resource_costs_is_all_or_nothing() {
+        let bytes = costly_move_pack();
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState::default();
+        set_resource(&mut state, 0, 50);
+        set_resource(&mut state, 1, 10);
+        let before = state;
+
+        assert_eq!(
+            apply_resource_costs(&mut state, &pack, 0),
+            Err(InsufficientResource {
+                index: 1,
+                required: 30,
+                available: 10,
+            })
+        );
+        assert_eq!(state, before);
+    }
+
+    /// A "meter" resource capped at 250; state 0 gains 100 on hit and 20 on whiff.
+    fn meter_gain_pack() -> std::vec::Vec<u8> {
+        use crate::test_support::build_pack;
+        use framesmith_fspack::{
+            RESOURCE_DEF_SIZE, SECTION_RESOURCE_DEFS, SECTION_STATES, SECTION_STATE_METER_GAIN,
+            SECTION_STRING_TABLE, STATE_METER_GAIN_SIZE, STATE_RECORD_SIZE,
+        };
+
+        let mut def = std::vec![0u8; RESOURCE_DEF_SIZE];
+        def[4..6].copy_from_slice(&5u16.to_le_bytes());
+        def[10..12].copy_from_slice(&250u16.to_le_bytes());
+
+        let mut gains = std::vec![0u8; STATE_METER_GAIN_SIZE];
+        gains[0..2].copy_from_slice(&100u16.to_le_bytes());
+        gains[2..4].copy_from_slice(&20u16.to_le_bytes());
+
+        build_pack(&[
+            (SECTION_STRING_TABLE, b"meter".to_vec()),
+            (SECTION_RESOURCE_DEFS, def),
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+            (SECTION_STATE_METER_GAIN, gains),
+        ])
+    }
+
+    #[test]
+    fn hits_grant_meter_up_to_the_pool_max() {
+        let bytes = meter_gain_pack();
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState::default();
+
+        grant_meter_on_hit(&mut state, &pack);
+        assert_eq!(resource(&state, 0), 100);
+        grant_meter_on_hit(&mut state, &pack);
+        grant_meter_on_hit(&mut state, &pack);
+        assert_eq!(resource(&state, 0), 250);
+    }
+
+    #[test]
+    fn whiff_meter_is_only_granted_when_the_state_never_connected() {
+        let bytes = meter_gain_pack();
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+        let mut state = CharacterState::default();
+
+        grant_meter_on_whiff(&mut state, &pack);
+        assert_eq!(resource(&state, 0), 20);
 
-        assert_eq!(resource(&state, 0), 70);
-        assert_eq!(resource(&state, 1), 40);
+        crate::report_hit(&mut state);
+        grant_meter_on_whiff(&mut state, &pack);
+        assert_eq!(resource(&state, 0), 20);
     }
 }
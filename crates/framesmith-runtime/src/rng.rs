@@ -0,0 +1,87 @@
+/// Seed used when a caller passes 0, since xorshift never leaves the all-zero state.
+const ZERO_SEED_REPLACEMENT: u32 = 0x9E37_79B9;
+
+/// Small deterministic PRNG (xorshift32).
+///
+/// Not cryptographically secure. Intended for reproducible gameplay
+/// randomization (training dummy decisions, replays): the same seed always
+/// yields the same sequence on every platform.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rng {
+    state: u32,
+}
+
+impl Rng {
+    /// Create a generator from a seed. A seed of 0 is remapped to a fixed non-zero value.
+    #[must_use]
+    pub const fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 {
+                ZERO_SEED_REPLACEMENT
+            } else {
+                seed
+            },
+        }
+    }
+
+    /// Advance the generator and return the next value.
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Return a value in `0..bound`. Returns 0 when `bound` is 0.
+    pub fn next_below(&mut self, bound: u32) -> u32 {
+        if bound == 0 {
+            return 0;
+        }
+        // Multiply-shift uses the high bits, which are better mixed than the low bits `%` would use.
+        ((self.next_u32() as u64 * bound as u64) >> 32) as u32
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Rng::new(1234);
+        let mut b = Rng::new(1234);
+        for _ in 0..100 {
+            assert_eq!(a.next_u32(), b.next_u32());
+        }
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(2);
+        assert_ne!(a.next_u32(), b.next_u32());
+    }
+
+    #[test]
+    fn zero_seed_does_not_stick_at_zero() {
+        let mut rng = Rng::new(0);
+        assert_ne!(rng.next_u32(), 0);
+    }
+
+    #[test]
+    fn next_below_stays_in_range() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            assert!(rng.next_below(6) < 6);
+        }
+        assert_eq!(rng.next_below(0), 0);
+    }
+}
@@ -1,13 +1,49 @@
 /// Maximum number of resource pools per character.
-pub const MAX_RESOURCES: usize = 8;
+///
+/// Sixteen leaves room for resource-heavy characters (heat, ammo, stance,
+/// install...) at 32 bytes of `CharacterState`. [`init_resources`](crate::init_resources)
+/// rejects packs that define more.
+pub const MAX_RESOURCES: usize = 16;
+
+/// Number of requested states the input buffer remembers.
+pub const INPUT_BUFFER_LEN: usize = 4;
+
+/// Buffer window used by game integrations that don't pick their own.
+pub const DEFAULT_BUFFER_FRAMES: u8 = 4;
+
+/// Number of tracked charge directions (indexed by `CHARGE_DIR_*`).
+pub const CHARGE_DIRECTIONS: usize = 4;
+
+/// Downward acceleration applied to airborne characters outside an airborne
+/// movement window (Q8.8 pixels per frame squared).
+pub const GRAVITY: i32 = 0x0080;
+
+/// Size of `CharacterState::to_bytes` output.
+pub const CHARACTER_STATE_BYTES: usize = 67;
+
+const FLAG_HIT_CONFIRMED: u8 = 0x01;
+const FLAG_BLOCK_CONFIRMED: u8 = 0x02;
+const FLAG_GROUNDED: u8 = 0x04;
+
+/// A requested state waiting for a legal cancel.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BufferedInput {
+    /// Requested state index.
+    pub state: u16,
+    /// Frames since the input was made (0 = this frame).
+    pub age: u8,
+}
 
 /// Character simulation state.
 ///
-/// This struct is intentionally small, `Copy`, and deterministic for:
-/// - Cheap cloning (rollback netcode)
+/// This struct is fixed-size, `Copy`, and deterministic for:
+/// - Cheap cloning (rollback netcode; 80 bytes, most of it the
+///   [`MAX_RESOURCES`] pools)
 /// - No heap allocations (no_std compatible)
 /// - Predictable simulation (no floats, no randomness)
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+///
+/// The default state is idle on frame 0, grounded at height 0.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct CharacterState {
     /// Current state index (0 = idle by convention).
     pub current_state: u16,
@@ -21,6 +57,147 @@ pub struct CharacterState {
     pub block_confirmed: bool,
     /// Resource pool values (meter, heat, ammo, etc.).
     pub resources: [u16; MAX_RESOURCES],
+    /// Recent requested states that weren't legal yet, oldest first.
+    pub input_buffer: [Option<BufferedInput>; INPUT_BUFFER_LEN],
+    /// Frames each charge direction has been held, indexed by `CHARGE_DIR_*`.
+    pub charge: [u8; CHARGE_DIRECTIONS],
+    /// Character is standing on the ground.
+    pub grounded: bool,
+    /// Height relative to the ground (Q8.8 pixels, negative = above ground).
+    pub pos_y: i32,
+    /// Vertical velocity (Q8.8 pixels per frame, negative = upward).
+    pub vel_y: i32,
+    /// Remaining hitstop frames. While nonzero, `next_frame` holds the
+    /// character in place instead of advancing. Set with [`freeze_for`].
+    pub hitstop: u8,
+    /// `frame` at which [`report_hit`] last confirmed a hit. Only meaningful
+    /// while `hit_confirmed` is set.
+    pub hit_frame: u8,
+}
+
+impl Default for CharacterState {
+    fn default() -> Self {
+        Self {
+            current_state: 0,
+            frame: 0,
+            instance_duration: 0,
+            hit_confirmed: false,
+            block_confirmed: false,
+            resources: [0; MAX_RESOURCES],
+            input_buffer: [None; INPUT_BUFFER_LEN],
+            charge: [0; CHARGE_DIRECTIONS],
+            grounded: true,
+            pos_y: 0,
+            vel_y: 0,
+            hitstop: 0,
+            hit_frame: 0,
+        }
+    }
+}
+
+impl CharacterState {
+    /// Serialize to a fixed little-endian layout for netcode snapshots.
+    ///
+    /// Layout (`CHARACTER_STATE_BYTES` bytes):
+    /// - 0-1: current_state (u16)
+    /// - 2: frame (u8)
+    /// - 3: instance_duration (u8)
+    /// - 4: flags (bit 0 hit_confirmed, bit 1 block_confirmed, bit 2 grounded)
+    /// - 5: hitstop (u8)
+    /// - 6-37: resources (u16 each)
+    /// - 38-53: input buffer, 4 bytes per slot: present (u8), age (u8), state (u16)
+    /// - 54-57: charge (u8 each)
+    /// - 58-61: pos_y (i32)
+    /// - 62-65: vel_y (i32)
+    /// - 66: hit_frame (u8)
+    ///
+    /// Identical states always produce identical bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; CHARACTER_STATE_BYTES] {
+        let mut out = [0u8; CHARACTER_STATE_BYTES];
+        out[0..2].copy_from_slice(&self.current_state.to_le_bytes());
+        out[2] = self.frame;
+        out[3] = self.instance_duration;
+        let mut flags = 0;
+        if self.hit_confirmed {
+            flags |= FLAG_HIT_CONFIRMED;
+        }
+        if self.block_confirmed {
+            flags |= FLAG_BLOCK_CONFIRMED;
+        }
+        if self.grounded {
+            flags |= FLAG_GROUNDED;
+        }
+        out[4] = flags;
+        out[5] = self.hitstop;
+        for (i, value) in self.resources.iter().enumerate() {
+            out[6 + i * 2..8 + i * 2].copy_from_slice(&value.to_le_bytes());
+        }
+        for (i, slot) in self.input_buffer.iter().enumerate() {
+            if let Some(buffered) = slot {
+                let base = 38 + i * 4;
+                out[base] = 1;
+                out[base + 1] = buffered.age;
+                out[base + 2..base + 4].copy_from_slice(&buffered.state.to_le_bytes());
+            }
+        }
+        out[54..58].copy_from_slice(&self.charge);
+        out[58..62].copy_from_slice(&self.pos_y.to_le_bytes());
+        out[62..66].copy_from_slice(&self.vel_y.to_le_bytes());
+        out[66] = self.hit_frame;
+        out
+    }
+
+    /// Deserialize a snapshot written by [`CharacterState::to_bytes`].
+    ///
+    /// Returns `None` if `bytes` is not exactly `CHARACTER_STATE_BYTES` long or
+    /// holds unknown flag bits or an invalid input-buffer tag.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes: &[u8; CHARACTER_STATE_BYTES] = bytes.try_into().ok()?;
+        let u16_at = |off: usize| u16::from_le_bytes([bytes[off], bytes[off + 1]]);
+        let i32_at = |off: usize| {
+            i32::from_le_bytes([bytes[off], bytes[off + 1], bytes[off + 2], bytes[off + 3]])
+        };
+
+        let flags = bytes[4];
+        let known_flags = FLAG_HIT_CONFIRMED | FLAG_BLOCK_CONFIRMED | FLAG_GROUNDED;
+        if flags & !known_flags != 0 {
+            return None;
+        }
+
+        let mut input_buffer = [None; INPUT_BUFFER_LEN];
+        for (i, slot) in input_buffer.iter_mut().enumerate() {
+            let base = 38 + i * 4;
+            *slot = match bytes[base] {
+                0 if bytes[base + 1..base + 4] == [0, 0, 0] => None,
+                1 => Some(BufferedInput {
+                    state: u16_at(base + 2),
+                    age: bytes[base + 1],
+                }),
+                _ => return None,
+            };
+        }
+
+        let mut charge = [0u8; CHARGE_DIRECTIONS];
+        charge.copy_from_slice(&bytes[54..58]);
+
+        Some(Self {
+            current_state: u16_at(0),
+            frame: bytes[2],
+            instance_duration: bytes[3],
+            hit_confirmed: flags & FLAG_HIT_CONFIRMED != 0,
+            block_confirmed: flags & FLAG_BLOCK_CONFIRMED != 0,
+            resources: core::array::from_fn(|i| u16_at(6 + i * 2)),
+            input_buffer,
+            charge,
+            grounded: flags & FLAG_GROUNDED != 0,
+            pos_y: i32_at(58),
+            vel_y: i32_at(62),
+            hitstop: bytes[5],
+            hit_frame: bytes[66],
+        })
+    }
 }
 
 /// Input for a single frame of simulation.
@@ -29,6 +206,12 @@ pub struct FrameInput {
     /// State to transition to, if cancel is valid.
     /// `None` means continue current state.
     pub requested_state: Option<u16>,
+    /// How many frames a request that isn't legal yet stays buffered.
+    /// 0 disables buffering (the request is dropped).
+    pub buffer_frames: u8,
+    /// Held stick direction in numpad notation relative to facing
+    /// (4 = back, 6 = forward). Values outside 1-9 count as neutral.
+    pub direction: u8,
 }
 
 /// Result of simulating one frame.
@@ -39,14 +222,34 @@ pub struct FrameResult {
     /// True if the move reached its final frame.
     /// Game decides whether to loop or transition.
     pub move_ended: bool,
+    /// Whole-pixel position change from the state's movement this frame.
+    ///
+    /// Character-local: +x is forward, so the game flips x by facing.
+    /// While airborne, y follows `pos_y` (movement or gravity, stopping at
+    /// the ground).
+    pub position_delta: (i32, i32),
 }
 
 /// Report that the current state connected with a hit.
 ///
-/// This opens on-hit cancel windows.
+/// This opens on-hit cancel windows and records the frame for
+/// [`hit_confirmed_recently`].
 #[inline]
 pub fn report_hit(state: &mut CharacterState) {
     state.hit_confirmed = true;
+    state.hit_frame = state.frame;
+}
+
+/// True if the current state connected within the last `window_frames`
+/// frames.
+///
+/// Counts from the most recent [`report_hit`], so the hit frame itself and
+/// the `window_frames` frames after it qualify. Hitstop holds `frame`, so the
+/// window only starts running once the freeze ends. Use it to gate
+/// confirm-only cancels so a late confirm is refused.
+#[inline]
+pub fn hit_confirmed_recently(state: &CharacterState, window_frames: u8) -> bool {
+    state.hit_confirmed && state.frame.saturating_sub(state.hit_frame) <= window_frames
 }
 
 /// Report that the current state was blocked.
@@ -57,6 +260,17 @@ pub fn report_block(state: &mut CharacterState) {
     state.block_confirmed = true;
 }
 
+/// Freeze the character for `frames` frames of hitstop.
+///
+/// While frozen, [`next_frame`](crate::next_frame) holds the frame counter,
+/// position and state. Apply the same hitstop to both attacker and defender
+/// when a hit connects, before stun begins. A longer freeze already in
+/// progress is kept.
+#[inline]
+pub fn freeze_for(state: &mut CharacterState, frames: u8) {
+    state.hitstop = state.hitstop.max(frames);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,19 +283,98 @@ mod tests {
         assert_eq!(state.frame, 0);
         assert!(!state.hit_confirmed);
         assert!(!state.block_confirmed);
+        assert!(state.grounded);
+        assert_eq!((state.pos_y, state.vel_y), (0, 0));
+    }
+
+    #[test]
+    fn character_state_size_is_pinned() {
+        // Exact size, so layout growth is a deliberate change for rollback
+        // snapshots
+        // 38 bytes of core state (16 resource pools) + 4 buffered inputs
+        // (6 bytes each as Option) + 4 charge counters + grounded flag +
+        // hitstop counter + vertical position/velocity + hit frame, padded to
+        // 4-byte alignment
+        assert_eq!(core::mem::size_of::<CharacterState>(), 80);
+    }
+
+    fn busy_state() -> CharacterState {
+        let mut input_buffer = [None; INPUT_BUFFER_LEN];
+        input_buffer[1] = Some(BufferedInput { state: 12, age: 3 });
+        input_buffer[3] = Some(BufferedInput { state: 0, age: 0 });
+        CharacterState {
+            current_state: 0x1234,
+            frame: 17,
+            instance_duration: 40,
+            hit_confirmed: true,
+            block_confirmed: false,
+            resources: [100, 0, 65535, 7, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 9],
+            input_buffer,
+            charge: [45, 0, 3, 0],
+            grounded: false,
+            pos_y: -30 << 8,
+            vel_y: 0x0180,
+            hitstop: 6,
+            hit_frame: 15,
+        }
+    }
+
+    #[test]
+    fn character_state_bytes_roundtrip() {
+        let airborne = CharacterState {
+            grounded: false,
+            pos_y: i32::MIN,
+            vel_y: i32::MAX,
+            block_confirmed: true,
+            ..Default::default()
+        };
+        for state in [CharacterState::default(), busy_state(), airborne] {
+            let bytes = state.to_bytes();
+            assert_eq!(CharacterState::from_bytes(&bytes), Some(state));
+        }
     }
 
     #[test]
-    fn character_state_size_is_small() {
-        // Exact size verification for rollback netcode efficiency
-        // Still 22 bytes - instance_duration fills existing padding after frame
-        assert_eq!(core::mem::size_of::<CharacterState>(), 22);
+    fn character_state_bytes_are_deterministic() {
+        assert_eq!(busy_state().to_bytes(), busy_state().to_bytes());
+        assert_ne!(
+            busy_state().to_bytes(),
+            CharacterState::default().to_bytes()
+        );
+
+        // Only the fields that differ change the snapshot.
+        let mut moved = busy_state();
+        moved.frame += 1;
+        let (a, b) = (busy_state().to_bytes(), moved.to_bytes());
+        let diff: std::vec::Vec<usize> = (0..CHARACTER_STATE_BYTES)
+            .filter(|&i| a[i] != b[i])
+            .collect();
+        assert_eq!(diff, std::vec![2]);
+    }
+
+    #[test]
+    fn character_state_from_bytes_rejects_malformed_input() {
+        let bytes = busy_state().to_bytes();
+        assert_eq!(
+            CharacterState::from_bytes(&bytes[..CHARACTER_STATE_BYTES - 1]),
+            None
+        );
+
+        let mut bad_flags = bytes;
+        bad_flags[4] |= 0x80;
+        assert_eq!(CharacterState::from_bytes(&bad_flags), None);
+
+        let mut bad_buffer = bytes;
+        bad_buffer[38] = 2;
+        assert_eq!(CharacterState::from_bytes(&bad_buffer), None);
     }
 
     #[test]
     fn frame_input_default_has_no_requested_state() {
         let input = FrameInput::default();
         assert!(input.requested_state.is_none());
+        assert_eq!(input.buffer_frames, 0);
+        assert_eq!(input.direction, 0);
     }
 
     #[test]
@@ -89,6 +382,7 @@ mod tests {
         let result = FrameResult {
             state: CharacterState::default(),
             move_ended: false,
+            position_delta: (0, 0),
         };
         assert!(!result.move_ended);
     }
@@ -102,6 +396,29 @@ mod tests {
         assert!(state.hit_confirmed);
     }
 
+    #[test]
+    fn hit_confirm_window_closes_after_window_frames() {
+        let mut state = CharacterState {
+            frame: 9,
+            ..Default::default()
+        };
+        assert!(!hit_confirmed_recently(&state, 255));
+
+        report_hit(&mut state);
+        for elapsed in 0..=4 {
+            state.frame = 9 + elapsed;
+            assert!(hit_confirmed_recently(&state, 4), "frame +{elapsed}");
+        }
+        state.frame = 14;
+        assert!(!hit_confirmed_recently(&state, 4));
+
+        // A later hit restarts the window.
+        report_hit(&mut state);
+        assert!(hit_confirmed_recently(&state, 0));
+        state.frame = 15;
+        assert!(!hit_confirmed_recently(&state, 0));
+    }
+
     #[test]
     fn report_block_sets_flag() {
         let mut state = CharacterState::default();
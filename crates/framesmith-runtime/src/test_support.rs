@@ -0,0 +1,128 @@
+//! Hand-assembled packs for unit tests.
+
+use framesmith_fspack::PackBuilder;
+use std::vec::Vec;
+
+/// Assemble a pack from `(kind, bytes)` sections with [`PackBuilder`].
+pub(crate) fn build_pack(sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let mut builder = PackBuilder::new();
+    for (kind, bytes) in sections {
+        builder.section(*kind, bytes);
+    }
+    builder.finish()
+}
+
+/// States: 0 idle, 1 "normal" (cancels to specials on frames 6..=10),
+/// 2 "special", 3 untagged heavy.
+pub(crate) fn tag_rule_pack() -> Vec<u8> {
+    use framesmith_fspack::view::{
+        SECTION_CANCEL_TAG_RULES, SECTION_STATE_TAGS, SECTION_STATE_TAG_RANGES,
+    };
+    use framesmith_fspack::{SECTION_STATES, SECTION_STRING_TABLE, STATE_RECORD_SIZE};
+
+    let strings = b"normalspecial".to_vec();
+    let strref = |off: u32, len: u16| {
+        let mut b = Vec::new();
+        b.extend_from_slice(&off.to_le_bytes());
+        b.extend_from_slice(&len.to_le_bytes());
+        b.extend_from_slice(&0u16.to_le_bytes());
+        b
+    };
+
+    let mut tags = strref(0, 6); // "normal"
+    tags.extend(strref(6, 7)); // "special"
+
+    let mut ranges = Vec::new();
+    for (off, count) in [(0u32, 0u16), (0, 1), (8, 1), (0, 0)] {
+        ranges.extend_from_slice(&off.to_le_bytes());
+        ranges.extend_from_slice(&count.to_le_bytes());
+        ranges.extend_from_slice(&0u16.to_le_bytes());
+    }
+
+    let mut rules = strref(0, 6);
+    rules.extend(strref(6, 7));
+    rules.extend_from_slice(&[0b111, 6, 10, 0, 0, 0, 0, 0]);
+
+    build_pack(&[
+        (SECTION_STRING_TABLE, strings),
+        (SECTION_STATES, std::vec![0u8; 4 * STATE_RECORD_SIZE]),
+        (SECTION_STATE_TAG_RANGES, ranges),
+        (SECTION_STATE_TAGS, tags),
+        (SECTION_CANCEL_TAG_RULES, rules),
+    ])
+}
+
+/// State 0 is a looping idle and state 1 a 12-frame attack hitting on
+/// frames 3-4 for 7 frames of hitstop. Both share a 20x20 hurtbox and
+/// pushbox at the origin.
+pub(crate) fn duel_sections() -> Vec<(u32, Vec<u8>)> {
+    use framesmith_fspack::{
+        HIT_WINDOW_SIZE, HURT_WINDOW_SIZE, SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS,
+        SECTION_PUSH_WINDOWS, SECTION_SHAPES, SECTION_STATES, SHAPE_SIZE, STATE_RECORD_SIZE,
+    };
+
+    let mut states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+    for (i, state_type, total) in [(0, 8u8, 20u16), (1, 0, 12)] {
+        let rec = &mut states[i * STATE_RECORD_SIZE..(i + 1) * STATE_RECORD_SIZE];
+        rec[6] = state_type;
+        rec[14..16].copy_from_slice(&total.to_le_bytes());
+        rec[30..32].copy_from_slice(&1u16.to_le_bytes()); // one hurt window
+        rec[34..36].copy_from_slice(&1u16.to_le_bytes()); // one push window
+    }
+    states[STATE_RECORD_SIZE + 26..STATE_RECORD_SIZE + 28].copy_from_slice(&1u16.to_le_bytes()); // state 1: one hit window
+
+    let mut hit = std::vec![0u8; HIT_WINDOW_SIZE];
+    hit[0] = 3; // start_frame
+    hit[1] = 4; // end_frame
+    hit[4..6].copy_from_slice(&40u16.to_le_bytes()); // damage
+    hit[8] = 15; // hitstun
+    hit[10] = 7; // hitstop
+    hit[16..18].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+    let mut hurt = std::vec![0u8; HURT_WINDOW_SIZE];
+    hurt[1] = 255; // end_frame
+    hurt[8..10].copy_from_slice(&1u16.to_le_bytes()); // one shape
+
+    // 20x20 AABB at the origin (Q12.4)
+    let mut shape = std::vec![0u8; SHAPE_SIZE];
+    shape[6..8].copy_from_slice(&320i16.to_le_bytes());
+    shape[8..10].copy_from_slice(&320i16.to_le_bytes());
+
+    std::vec![
+        (SECTION_STATES, states),
+        (SECTION_HIT_WINDOWS, hit),
+        (SECTION_HURT_WINDOWS, hurt.clone()),
+        (SECTION_PUSH_WINDOWS, hurt),
+        (SECTION_SHAPES, shape),
+    ]
+}
+
+/// Sections for two states; state 1 has a single condition of `kind`
+/// bounded by `min..=max`.
+pub(crate) fn condition_sections(kind: u8, arg: u8, min: u16, max: u16) -> Vec<(u32, Vec<u8>)> {
+    use framesmith_fspack::{
+        SECTION_STATES, SECTION_STATE_CONDITIONS, STATE_CONDITIONS_INDEX_ENTRY_SIZE,
+        STATE_RECORD_SIZE,
+    };
+
+    let states = std::vec![0u8; 2 * STATE_RECORD_SIZE];
+
+    let mut conditions = Vec::new();
+    let records_off = (2 * STATE_CONDITIONS_INDEX_ENTRY_SIZE) as u32;
+    // state 0: no conditions
+    conditions.extend_from_slice(&0u32.to_le_bytes());
+    conditions.extend_from_slice(&0u16.to_le_bytes());
+    conditions.extend_from_slice(&0u16.to_le_bytes());
+    // state 1: one condition
+    conditions.extend_from_slice(&records_off.to_le_bytes());
+    conditions.extend_from_slice(&1u16.to_le_bytes());
+    conditions.extend_from_slice(&0u16.to_le_bytes());
+    conditions.extend_from_slice(&[kind, arg, 0, 0]);
+    conditions.extend_from_slice(&min.to_le_bytes());
+    conditions.extend_from_slice(&max.to_le_bytes());
+
+    std::vec![
+        (SECTION_STATES, states),
+        (SECTION_STATE_CONDITIONS, conditions),
+    ]
+}
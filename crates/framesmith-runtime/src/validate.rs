@@ -0,0 +1,188 @@
+//! Load-time checks of the state graph a runtime relies on.
+//!
+//! `PackView::parse` only checks that the binary layout is in bounds. A pack
+//! can still parse cleanly and route a cancel to a state that doesn't exist,
+//! which mid-match shows up as a silent `None`. Run [`validate_runtime_pack`]
+//! once after loading to catch that up front.
+
+use crate::cancel::IDLE_STATE;
+use crate::state::MAX_RESOURCES;
+use framesmith_fspack::PackView;
+
+/// A runtime invariant the pack breaks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuntimePackError {
+    /// The pack has no state at [`IDLE_STATE`] (no STATES section, or it is empty).
+    MissingIdle,
+    /// An explicit cancel route of `state` targets a state index past the end.
+    CancelTargetOutOfRange {
+        /// State index holding the route.
+        state: u16,
+        /// The out-of-range target.
+        target: u16,
+    },
+    /// A per-hit cancel route of `state` targets a state index past the end.
+    HitCancelTargetOutOfRange {
+        /// State index holding the route.
+        state: u16,
+        /// Index of the hit within the state's per-hit cancel records.
+        hit: u16,
+        /// The out-of-range target.
+        target: u16,
+    },
+    /// RESOURCE_DEFS holds more pools than [`MAX_RESOURCES`].
+    TooManyResources {
+        /// Number of resources the pack defines.
+        count: usize,
+    },
+}
+
+/// Check the runtime invariants of a parsed pack.
+///
+/// Confirms that idle exists, that every explicit and per-hit cancel route
+/// targets a state within `states().len()`, and that the resource pools fit
+/// in `CharacterState`.
+///
+/// # Errors
+/// The first broken invariant found, scanning states in index order.
+pub fn validate_runtime_pack(pack: &PackView) -> Result<(), RuntimePackError> {
+    let state_count = pack.states().map_or(0, |states| states.len());
+    if usize::from(IDLE_STATE) >= state_count {
+        return Err(RuntimePackError::MissingIdle);
+    }
+    let resource_count = pack.resource_defs().map_or(0, |defs| defs.len());
+    if resource_count > MAX_RESOURCES {
+        return Err(RuntimePackError::TooManyResources {
+            count: resource_count,
+        });
+    }
+    let in_range = |target: u16| usize::from(target) < state_count;
+
+    for state_idx in 0..state_count {
+        let state = state_idx as u16;
+
+        if let Some(target) = pack.cancels_for_state(state_idx).find(|&t| !in_range(t)) {
+            return Err(RuntimePackError::CancelTargetOutOfRange { state, target });
+        }
+
+        let (Some(hits), Some(cancels)) = (pack.state_hit_cancels(state_idx), pack.cancels())
+        else {
+            continue;
+        };
+        for (hit, record) in hits.iter().enumerate() {
+            let (off, len) = record.cancels();
+            if let Some(target) = cancels.get_range(off, len).find(|&t| !in_range(t)) {
+                return Err(RuntimePackError::HitCancelTargetOutOfRange {
+                    state,
+                    hit: hit as u16,
+                    target,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::build_pack;
+    use framesmith_fspack::{
+        SECTION_CANCELS_U16, SECTION_STATES, SECTION_STATE_EXTRAS, SECTION_STATE_HIT_CANCELS,
+        STATE_EXTRAS_SIZE, STATE_HIT_CANCELS_INDEX_ENTRY_SIZE, STATE_RECORD_SIZE,
+    };
+    use std::vec::Vec;
+
+    /// Three states; state 1 routes to `targets` through STATE_EXTRAS.
+    fn routed_pack(targets: &[u16]) -> Vec<u8> {
+        let mut extras = std::vec![0u8; 3 * STATE_EXTRAS_SIZE];
+        let rec = &mut extras[STATE_EXTRAS_SIZE..];
+        rec[68..70].copy_from_slice(&(targets.len() as u16).to_le_bytes());
+
+        let cancels: Vec<u8> = targets.iter().flat_map(|t| t.to_le_bytes()).collect();
+        build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 3 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_EXTRAS, extras),
+            (SECTION_CANCELS_U16, cancels),
+        ])
+    }
+
+    #[test]
+    fn in_range_cancel_routes_pass() {
+        let bytes = routed_pack(&[0, 2]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(validate_runtime_pack(&pack), Ok(()));
+    }
+
+    #[test]
+    fn cancel_target_past_last_state_is_reported() {
+        let bytes = routed_pack(&[2, 3]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(
+            validate_runtime_pack(&pack),
+            Err(RuntimePackError::CancelTargetOutOfRange {
+                state: 1,
+                target: 3
+            })
+        );
+    }
+
+    #[test]
+    fn hit_cancel_target_past_last_state_is_reported() {
+        // State 0 has one hit whose routes are CANCELS_U16 entries [1, 7].
+        let index_size = STATE_HIT_CANCELS_INDEX_ENTRY_SIZE;
+        let mut section = std::vec![0u8; index_size];
+        section[0..4].copy_from_slice(&(index_size as u32).to_le_bytes());
+        section[4..6].copy_from_slice(&1u16.to_le_bytes());
+        section.extend_from_slice(&[0, 5]); // frames 0-5
+        section.extend_from_slice(&2u16.to_le_bytes());
+        section.extend_from_slice(&0u32.to_le_bytes());
+
+        let cancels: Vec<u8> = [1u16, 7].iter().flat_map(|t| t.to_le_bytes()).collect();
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; 2 * STATE_RECORD_SIZE]),
+            (SECTION_STATE_HIT_CANCELS, section),
+            (SECTION_CANCELS_U16, cancels),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(
+            validate_runtime_pack(&pack),
+            Err(RuntimePackError::HitCancelTargetOutOfRange {
+                state: 0,
+                hit: 0,
+                target: 7
+            })
+        );
+    }
+
+    #[test]
+    fn resources_past_capacity_are_reported() {
+        use framesmith_fspack::{RESOURCE_DEF_SIZE, SECTION_RESOURCE_DEFS};
+
+        let bytes = build_pack(&[
+            (SECTION_STATES, std::vec![0u8; STATE_RECORD_SIZE]),
+            (
+                SECTION_RESOURCE_DEFS,
+                std::vec![0u8; (MAX_RESOURCES + 1) * RESOURCE_DEF_SIZE],
+            ),
+        ]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(
+            validate_runtime_pack(&pack),
+            Err(RuntimePackError::TooManyResources {
+                count: MAX_RESOURCES + 1
+            })
+        );
+    }
+
+    #[test]
+    fn pack_without_states_is_missing_idle() {
+        let bytes = build_pack(&[]);
+        let pack = PackView::parse(&bytes).unwrap();
+        assert_eq!(
+            validate_runtime_pack(&pack),
+            Err(RuntimePackError::MissingIdle)
+        );
+    }
+}
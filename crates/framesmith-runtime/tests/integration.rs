@@ -10,7 +10,7 @@ fn state_is_deterministic() {
         instance_duration: 0,
         hit_confirmed: true,
         block_confirmed: false,
-        resources: [100, 50, 0, 0, 0, 0, 0, 0],
+        ..Default::default()
     };
 
     let copy1 = state;
@@ -15,6 +15,7 @@ use std::process;
 enum Adapter {
     #[default]
     ZxFspack,
+    ZxFspackBigEndian,
     JsonBlob,
 }
 
@@ -22,6 +23,7 @@ impl Adapter {
     fn parse(s: &str) -> Result<Self, String> {
         match s {
             "fspk" => Ok(Self::ZxFspack),
+            "fspk-be" => Ok(Self::ZxFspackBigEndian),
             "json-blob" => Ok(Self::JsonBlob),
             _ => Err(format!("Unknown adapter: {}", s)),
         }
@@ -30,13 +32,14 @@ impl Adapter {
     fn as_str(self) -> &'static str {
         match self {
             Self::ZxFspack => "fspk",
+            Self::ZxFspackBigEndian => "fspk-be",
             Self::JsonBlob => "json-blob",
         }
     }
 
     fn default_ext(self) -> &'static str {
         match self {
-            Self::ZxFspack => ".fspk",
+            Self::ZxFspack | Self::ZxFspackBigEndian => ".fspk",
             Self::JsonBlob => ".json",
         }
     }
@@ -56,7 +59,7 @@ struct ExportArgs {
 }
 
 fn usage() -> &'static str {
-    "Framesmith CLI\n\nUSAGE:\n  framesmith export [options]\n\nOPTIONS:\n  --project <dir>         Project root (expects <dir>/characters)\n  --characters-dir <dir>  Characters directory (overrides --project)\n  --character <id>        Character ID (folder name under characters dir)\n  --all                   Export all characters\n  --out <file>            Output file (single-character export)\n  --out-dir <dir>         Output directory (export all)\n  --adapter <name>        Adapter: fspk (default), json-blob\n  --pretty                Pretty JSON output (json-blob only)\n  --keep-going            Continue exporting others after an error (export all only)\n  -h, --help              Print help\n\nENV:\n  FRAMESMITH_CHARACTERS_DIR  Default characters directory if not provided\n"
+    "Framesmith CLI\n\nUSAGE:\n  framesmith export [options]\n\nOPTIONS:\n  --project <dir>         Project root (expects <dir>/characters)\n  --characters-dir <dir>  Characters directory (overrides --project)\n  --character <id>        Character ID (folder name under characters dir)\n  --all                   Export all characters\n  --out <file>            Output file (single-character export)\n  --out-dir <dir>         Output directory (export all)\n  --adapter <name>        Adapter: fspk (default), fspk-be, json-blob\n  --pretty                Pretty JSON output (json-blob only)\n  --keep-going            Continue exporting others after an error (export all only)\n  -h, --help              Print help\n\nENV:\n  FRAMESMITH_CHARACTERS_DIR  Default characters directory if not provided\n"
 }
 
 fn main() {
@@ -153,7 +156,7 @@ fn cmd_export(args: &[String]) -> Result<(), String> {
         return Err("--out-dir cannot be used with --character (use --out)".to_string());
     }
 
-    if cfg.adapter == Adapter::ZxFspack && cfg.pretty {
+    if cfg.adapter != Adapter::JsonBlob && cfg.pretty {
         return Err("--pretty is only supported for json-blob".to_string());
     }
 
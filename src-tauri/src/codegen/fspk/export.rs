@@ -3,34 +3,56 @@
 use std::collections::HashMap;
 
 use crate::codegen::fspk_format::{
-    write_u16_le, write_u32_le, write_u8, FLAGS_RESERVED, HEADER_SIZE, MAGIC,
-    SCHEMA_HEADER_SIZE, SECTION_CANCEL_DENIES, SECTION_CANCEL_TAG_RULES, SECTION_CHARACTER_PROPS,
-    SECTION_EVENT_ARGS, SECTION_EVENT_EMITS, SECTION_HEADER_SIZE, SECTION_HIT_WINDOWS,
-    SECTION_HURT_WINDOWS, SECTION_KEYFRAMES_KEYS, SECTION_MESH_KEYS, SECTION_MOVE_NOTIFIES,
-    SECTION_MOVE_RESOURCE_COSTS, SECTION_MOVE_RESOURCE_DELTAS,
-    SECTION_MOVE_RESOURCE_PRECONDITIONS, SECTION_PUSH_WINDOWS, SECTION_RESOURCE_DEFS,
-    SECTION_SCHEMA, SECTION_SHAPES, SECTION_STATES, SECTION_STATE_EXTRAS, SECTION_STATE_PROPS,
-    SECTION_STATE_TAGS, SECTION_STATE_TAG_RANGES, SECTION_STRING_TABLE, STATE_EXTRAS72_SIZE,
-    STRREF_SIZE,
+    write_u16_le, write_u32_le, write_u8, CONDITION_KIND_AIRBORNE, CONDITION_KIND_CHARGE,
+    CONDITION_KIND_DISTANCE, CONDITION_KIND_GROUNDED, HEADER_SIZE, MAGIC, MAX_SECTIONS,
+    METADATA_KEY_CHARACTER_ID, METADATA_KEY_EXPORTER_VERSION, PACK_FLAG_SORTED_INPUTS,
+    SCHEMA_HEADER_SIZE, SECTION_CANCELS_U16, SECTION_CANCEL_DENIES, SECTION_CANCEL_TAG_RULES,
+    SECTION_CHARACTER_PROPS, SECTION_EVENT_ARGS, SECTION_EVENT_EMITS, SECTION_HEADER_SIZE,
+    SECTION_HIT_WINDOWS, SECTION_HURT_WINDOWS, SECTION_KEYFRAMES_KEYS, SECTION_MESH_KEYS,
+    SECTION_METADATA, SECTION_MOVE_NOTIFIES, SECTION_MOVE_RESOURCE_COSTS,
+    SECTION_MOVE_RESOURCE_DELTAS, SECTION_MOVE_RESOURCE_PRECONDITIONS, SECTION_PUSH_WINDOWS,
+    SECTION_RESOURCE_DEFS, SECTION_SCHEMA, SECTION_SHAPES, SECTION_STATES,
+    SECTION_STATE_CONDITIONS, SECTION_STATE_EXTRAS, SECTION_STATE_HIT_CANCELS,
+    SECTION_STATE_KNOCKBACK, SECTION_STATE_METER_GAIN, SECTION_STATE_MOVEMENT,
+    SECTION_STATE_ON_USE, SECTION_STATE_PROPS, SECTION_STATE_PUSHBACK, SECTION_STATE_SUPER_FREEZE,
+    SECTION_STATE_TAGS, SECTION_STATE_TAG_RANGES, SECTION_STRING_TABLE,
+    STATE_CONDITIONS_INDEX_ENTRY_SIZE, STATE_EXTRAS72_SIZE, STATE_HIT_CANCELS_INDEX_ENTRY_SIZE,
+    STATE_KNOCKBACK4_SIZE, STATE_METER_GAIN4_SIZE, STATE_MOVEMENT12_SIZE, STATE_PUSHBACK4_SIZE,
+    STATE_SUPER_FREEZE6_SIZE, STRREF_SIZE,
 };
 use crate::commands::CharacterData;
 use crate::rules::MergedRules;
+use crate::schema::State;
 
 use super::builders::{align_up, SectionData, SectionHeader, StringTable};
 use super::moves::{build_asset_keys, pack_moves};
+use super::packing::{
+    charge_direction_to_u8, pack_state_knockback, pack_state_meter_gain, pack_state_movement,
+    pack_state_on_use, pack_state_pushback, pack_state_super_freeze,
+};
 use super::properties::{
     find_similar, pack_character_props, pack_character_props_with_schema, pack_state_props,
     pack_state_props_with_schema,
 };
 use super::sections::{
-    pack_event_emits, pack_resource_defs, OPT_U16_NONE, RESOURCE_DELTA_TRIGGER_ON_BLOCK,
-    RESOURCE_DELTA_TRIGGER_ON_HIT, RESOURCE_DELTA_TRIGGER_ON_USE,
+    pack_event_emits, pack_metadata, pack_resource_defs, OPT_U16_NONE,
+    RESOURCE_DELTA_TRIGGER_ON_BLOCK, RESOURCE_DELTA_TRIGGER_ON_HIT, RESOURCE_DELTA_TRIGGER_ON_USE,
 };
 use super::types::CancelLookup;
 use super::utils::{
     checked_u16, checked_u32, write_i32_le, write_range, write_section_header, write_strref,
 };
 
+/// Sort states into their exported index order.
+///
+/// States are ordered by input, then by state id so variants that share their
+/// base's input (e.g. `5H~level1`) keep a fixed order. System states named with
+/// a digit prefix (`0_idle`, `1_crouch`) sort before attacks, which puts the
+/// conventional `0_idle` at the runtime's idle index 0.
+pub fn sort_states(moves: &mut [State]) {
+    moves.sort_by(|a, b| (&a.input, &a.id).cmp(&(&b.input, &b.id)));
+}
+
 /// Export character data to FSPK binary format.
 ///
 /// When `rules` is provided with a property/tag schema, the export will:
@@ -38,7 +60,14 @@ use super::utils::{
 /// - Validate all property names and tags against the schema, returning errors for unknown names
 /// - Write a SECTION_SCHEMA containing property and tag name definitions
 ///
+/// Every pack ends with a SECTION_METADATA naming the character id and exporter
+/// version. No build timestamp is written, so unchanged data exports to
+/// identical bytes.
+///
 /// Returns the packed binary data as a Vec<u8>.
+///
+/// State indices follow [`sort_states`], independent of the order `char_data.moves`
+/// was read in.
 #[allow(clippy::vec_init_then_push)] // Intentional: base sections first, optional sections conditionally added
 pub fn export_fspk(
     char_data: &CharacterData,
@@ -47,7 +76,7 @@ pub fn export_fspk(
     // Canonicalize move ordering so move indices are deterministic.
     // (Do this here as a backstop even if callers already sorted.)
     let mut char_data = char_data.clone();
-    char_data.moves.sort_by(|a, b| a.input.cmp(&b.input));
+    sort_states(&mut char_data.moves);
 
     // Step 1: Build string table and asset keys
     let mut strings = StringTable::new();
@@ -281,6 +310,190 @@ pub fn export_fspk(
         event_args_data.clear();
     }
 
+    // Build state movement section (parallel to STATES, zeroed for states without movement)
+    let mut state_movement_data: Vec<u8> = Vec::new();
+    if char_data.moves.iter().any(|m| m.movement.is_some()) {
+        for mv in &char_data.moves {
+            let record = pack_state_movement(mv).unwrap_or([0u8; STATE_MOVEMENT12_SIZE]);
+            state_movement_data.extend_from_slice(&record);
+        }
+    }
+
+    // Build state on-use section (parallel to STATES)
+    let mut state_on_use_data: Vec<u8> = Vec::new();
+    let has_on_use = char_data.moves.iter().any(|mv| {
+        mv.on_use
+            .as_ref()
+            .is_some_and(|u| u.enters_state.is_some() || u.gain_meter.is_some())
+    });
+    if has_on_use {
+        for mv in &char_data.moves {
+            let record = pack_state_on_use(mv, &cancel_lookup.input_to_index);
+            state_on_use_data.extend_from_slice(&record);
+        }
+    }
+
+    // Build state super freeze section (parallel to STATES, zeroed for states without one)
+    let mut state_super_freeze_data: Vec<u8> = Vec::new();
+    if char_data.moves.iter().any(|m| m.super_freeze.is_some()) {
+        for mv in &char_data.moves {
+            let record = pack_state_super_freeze(mv).unwrap_or([0u8; STATE_SUPER_FREEZE6_SIZE]);
+            state_super_freeze_data.extend_from_slice(&record);
+        }
+    }
+
+    // Build state meter gain section (parallel to STATES, zeroed for states without gain)
+    let mut state_meter_gain_data: Vec<u8> = Vec::new();
+    if char_data
+        .moves
+        .iter()
+        .any(|m| m.meter_gain.hit != 0 || m.meter_gain.whiff != 0)
+    {
+        for mv in &char_data.moves {
+            let record = pack_state_meter_gain(mv).unwrap_or([0u8; STATE_METER_GAIN4_SIZE]);
+            state_meter_gain_data.extend_from_slice(&record);
+        }
+    }
+
+    // Build state knockback section (parallel to STATES, zeroed for states without knockback)
+    let mut state_knockback_data: Vec<u8> = Vec::new();
+    let knockbacks = char_data
+        .moves
+        .iter()
+        .map(pack_state_knockback)
+        .collect::<Result<Vec<_>, _>>()?;
+    if knockbacks.iter().any(Option::is_some) {
+        for record in knockbacks {
+            let record = record.unwrap_or([0u8; STATE_KNOCKBACK4_SIZE]);
+            state_knockback_data.extend_from_slice(&record);
+        }
+    }
+
+    // Build state pushback section (parallel to STATES, zeroed for states without pushback)
+    let mut state_pushback_data: Vec<u8> = Vec::new();
+    let pushbacks = char_data
+        .moves
+        .iter()
+        .map(pack_state_pushback)
+        .collect::<Result<Vec<_>, _>>()?;
+    if pushbacks.iter().any(Option::is_some) {
+        for record in pushbacks {
+            let record = record.unwrap_or([0u8; STATE_PUSHBACK4_SIZE]);
+            state_pushback_data.extend_from_slice(&record);
+        }
+    }
+
+    // Build state conditions section: an index parallel to STATES, then StateCondition8
+    // records. Only non-resource preconditions the runtime can evaluate are packed here.
+    let mut state_conditions_data: Vec<u8> = Vec::new();
+    let has_state_conditions = char_data.moves.iter().any(|mv| {
+        mv.preconditions.iter().flatten().any(|pre| {
+            matches!(
+                pre,
+                crate::schema::Precondition::Distance { .. }
+                    | crate::schema::Precondition::Charge { .. }
+                    | crate::schema::Precondition::Grounded
+                    | crate::schema::Precondition::Airborne
+            )
+        })
+    });
+    if has_state_conditions {
+        let index_size = char_data.moves.len() * STATE_CONDITIONS_INDEX_ENTRY_SIZE;
+        let mut records: Vec<u8> = Vec::new();
+        for mv in &char_data.moves {
+            let off = checked_u32(index_size + records.len(), "state_conditions_off")?;
+            let mut count: u16 = 0;
+            for pre in mv.preconditions.iter().flatten() {
+                let (kind, arg, min, max) = match pre {
+                    crate::schema::Precondition::Distance { min, max } => {
+                        (CONDITION_KIND_DISTANCE, 0, *min, *max)
+                    }
+                    crate::schema::Precondition::Charge {
+                        direction,
+                        min_frames,
+                    } => {
+                        let dir = charge_direction_to_u8(direction)
+                            .map_err(|e| format!("move '{}': {}", mv.input, e))?;
+                        (
+                            CONDITION_KIND_CHARGE,
+                            dir,
+                            Some(u16::from(*min_frames)),
+                            None,
+                        )
+                    }
+                    crate::schema::Precondition::Grounded => {
+                        (CONDITION_KIND_GROUNDED, 0, None, None)
+                    }
+                    crate::schema::Precondition::Airborne => {
+                        (CONDITION_KIND_AIRBORNE, 0, None, None)
+                    }
+                    _ => continue,
+                };
+                write_u8(&mut records, kind);
+                write_u8(&mut records, arg);
+                write_u16_le(&mut records, 0); // padding
+                write_u16_le(&mut records, min.unwrap_or(OPT_U16_NONE));
+                write_u16_le(&mut records, max.unwrap_or(OPT_U16_NONE));
+                count = count
+                    .checked_add(1)
+                    .ok_or_else(|| "state conditions count overflows u16".to_string())?;
+            }
+            write_u32_le(&mut state_conditions_data, if count > 0 { off } else { 0 });
+            write_u16_le(&mut state_conditions_data, count);
+            write_u16_le(&mut state_conditions_data, 0); // padding
+        }
+        state_conditions_data.extend(records);
+    }
+
+    // Build per-hit cancel routes (index + HitCancel8 records, targets in CANCELS_U16)
+    let mut cancels_u16_data: Vec<u8> = Vec::new();
+    let mut state_hit_cancels_data: Vec<u8> = Vec::new();
+    let has_hit_cancels = char_data
+        .moves
+        .iter()
+        .any(|mv| mv.hits.iter().flatten().any(|hit| !hit.cancels.is_empty()));
+    if has_hit_cancels {
+        let index_size = char_data.moves.len() * STATE_HIT_CANCELS_INDEX_ENTRY_SIZE;
+        let mut records: Vec<u8> = Vec::new();
+        for mv in &char_data.moves {
+            let off = checked_u32(index_size + records.len(), "state_hit_cancels_off")?;
+            // Every hit gets a record once any hit has routes, so a hit without
+            // cancels still closes the previous hit's routes.
+            let mut hits: Vec<_> = mv.hits.iter().flatten().collect();
+            if hits.iter().all(|hit| hit.cancels.is_empty()) {
+                hits.clear();
+            }
+            hits.sort_by_key(|hit| hit.frames.0);
+            let count = checked_u16(hits.len(), "state hit cancels count")?;
+            for hit in hits {
+                let targets_off = checked_u32(cancels_u16_data.len(), "hit cancels offset")?;
+                for target in &hit.cancels {
+                    let idx = cancel_lookup
+                        .input_to_index
+                        .get(target.as_str())
+                        .ok_or_else(|| {
+                            format!(
+                                "move '{}': hit cancel target '{}' not found",
+                                mv.input, target
+                            )
+                        })?;
+                    write_u16_le(&mut cancels_u16_data, *idx);
+                }
+                write_u8(&mut records, hit.frames.0);
+                write_u8(&mut records, hit.frames.1);
+                write_u16_le(
+                    &mut records,
+                    checked_u16(hit.cancels.len(), "hit cancels len")?,
+                );
+                write_u32_le(&mut records, targets_off);
+            }
+            write_u32_le(&mut state_hit_cancels_data, if count > 0 { off } else { 0 });
+            write_u16_le(&mut state_hit_cancels_data, count);
+            write_u16_le(&mut state_hit_cancels_data, 0); // padding
+        }
+        state_hit_cancels_data.extend(records);
+    }
+
     // Build state tag sections (one range entry per move, tags are StrRefs)
     // Note: move_type (the "type" field) is also included as a tag so that
     // tag-based cancel rules can match on it (e.g., "system" -> "any")
@@ -356,17 +569,24 @@ pub fn export_fspk(
 
     // Encode cancel denies
     // CancelDeny4: from_idx (u16) + to_idx (u16) = 4 bytes
-    let mut cancel_denies_data: Vec<u8> = Vec::new();
+    // `deny` is a HashMap, so pairs are sorted to keep the export deterministic.
+    let mut deny_pairs: Vec<(u16, u16)> = Vec::new();
     for (from_input, deny_list) in &char_data.cancel_table.deny {
         if let Some(&from_idx) = cancel_lookup.input_to_index.get(from_input.as_str()) {
             for to_input in deny_list {
                 if let Some(&to_idx) = cancel_lookup.input_to_index.get(to_input.as_str()) {
-                    write_u16_le(&mut cancel_denies_data, from_idx);
-                    write_u16_le(&mut cancel_denies_data, to_idx);
+                    deny_pairs.push((from_idx, to_idx));
                 }
             }
         }
     }
+    deny_pairs.sort_unstable();
+    deny_pairs.dedup();
+    let mut cancel_denies_data: Vec<u8> = Vec::new();
+    for (from_idx, to_idx) in deny_pairs {
+        write_u16_le(&mut cancel_denies_data, from_idx);
+        write_u16_le(&mut cancel_denies_data, to_idx);
+    }
 
     // Build schema lookups if rules with property/tag schema are provided
     let has_schema = rules
@@ -713,6 +933,75 @@ pub fn export_fspk(
         });
     }
 
+    if !state_movement_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_MOVEMENT,
+            align: 4,
+            bytes: state_movement_data,
+        });
+    }
+
+    if !state_on_use_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_ON_USE,
+            align: 2,
+            bytes: state_on_use_data,
+        });
+    }
+
+    if !state_super_freeze_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_SUPER_FREEZE,
+            align: 2,
+            bytes: state_super_freeze_data,
+        });
+    }
+
+    if !state_meter_gain_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_METER_GAIN,
+            align: 2,
+            bytes: state_meter_gain_data,
+        });
+    }
+
+    if !state_knockback_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_KNOCKBACK,
+            align: 2,
+            bytes: state_knockback_data,
+        });
+    }
+
+    if !state_pushback_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_PUSHBACK,
+            align: 2,
+            bytes: state_pushback_data,
+        });
+    }
+
+    if !state_conditions_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_STATE_CONDITIONS,
+            align: 4,
+            bytes: state_conditions_data,
+        });
+    }
+
+    if !state_hit_cancels_data.is_empty() {
+        sections.push(SectionData {
+            kind: SECTION_CANCELS_U16,
+            align: 2,
+            bytes: cancels_u16_data,
+        });
+        sections.push(SectionData {
+            kind: SECTION_STATE_HIT_CANCELS,
+            align: 4,
+            bytes: state_hit_cancels_data,
+        });
+    }
+
     // Add schema section if present (must come after string table is finalized)
     if !schema_section_data.is_empty() {
         sections.push(SectionData {
@@ -722,10 +1011,21 @@ pub fn export_fspk(
         });
     }
 
-    if sections.len() > 24 {
+    // Diagnostic metadata goes last; runtimes ignore it.
+    sections.push(SectionData {
+        kind: SECTION_METADATA,
+        align: 1,
+        bytes: pack_metadata(&[
+            (METADATA_KEY_CHARACTER_ID, char_data.character.id.as_str()),
+            (METADATA_KEY_EXPORTER_VERSION, env!("CARGO_PKG_VERSION")),
+        ])?,
+    });
+
+    if sections.len() > MAX_SECTIONS {
         return Err(format!(
-            "Too many sections ({}), MAX_SECTIONS is 24",
-            sections.len()
+            "Too many sections ({}), MAX_SECTIONS is {}",
+            sections.len(),
+            MAX_SECTIONS
         ));
     }
 
@@ -755,7 +1055,8 @@ pub fn export_fspk(
     // Step 6: Build the final binary
     let mut output = Vec::with_capacity(current_offset);
     output.extend_from_slice(&MAGIC);
-    write_u32_le(&mut output, FLAGS_RESERVED);
+    // States were ordered by `sort_states` above.
+    write_u32_le(&mut output, PACK_FLAG_SORTED_INPUTS);
     write_u32_le(&mut output, total_len);
     write_u32_le(&mut output, section_count);
 
@@ -787,6 +1088,59 @@ pub fn export_fspk(
     Ok(output)
 }
 
+/// List the parts of `char_data` that [`export_fspk`] can't encode.
+///
+/// Covers `on_use.enters_state`: a name that isn't a state input (a mode such
+/// as `rekka`) exports as none, and `duration`, `persistent` and `exit_input`
+/// are never written. The export still succeeds; callers show these as
+/// warnings.
+pub fn fspk_export_warnings(char_data: &CharacterData) -> Vec<String> {
+    let inputs: std::collections::HashSet<&str> =
+        char_data.moves.iter().map(|m| m.input.as_str()).collect();
+
+    let mut warnings = Vec::new();
+    for mv in &char_data.moves {
+        let Some(enters) = mv.on_use.as_ref().and_then(|u| u.enters_state.as_ref()) else {
+            continue;
+        };
+        if !inputs.contains(enters.name.as_str()) {
+            warnings.push(format!(
+                "{}: on_use.enters_state '{}' is not a state; exported as none",
+                mv.input, enters.name
+            ));
+        }
+        let dropped = [
+            ("duration", enters.duration.is_some()),
+            ("persistent", enters.persistent.is_some()),
+            ("exit_input", enters.exit_input.is_some()),
+        ];
+        for (field, set) in dropped {
+            if set {
+                warnings.push(format!(
+                    "{}: on_use.enters_state.{} is not exported",
+                    mv.input, field
+                ));
+            }
+        }
+    }
+    warnings
+}
+
+/// Export character data to FSPK with section payloads stored big-endian.
+///
+/// Same pack as [`export_fspk`], converted with
+/// [`framesmith_fspack::to_big_endian`] so the header's BIG_ENDIAN flag is set.
+/// Views built with the fspack `big-endian` feature read it in place.
+pub fn export_fspk_big_endian(
+    char_data: &CharacterData,
+    rules: Option<&MergedRules>,
+) -> Result<Vec<u8>, String> {
+    let mut bytes = export_fspk(char_data, rules)?;
+    framesmith_fspack::to_big_endian(&mut bytes)
+        .map_err(|e| format!("big-endian conversion failed: {:?}", e))?;
+    Ok(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -851,6 +1205,9 @@ mod tests {
             costs: None,
             movement: None,
             super_freeze: None,
+            kara: None,
+            airborne: None,
+            allow_from_stun: None,
             on_use: None,
             on_hit: None,
             on_block: None,
@@ -911,6 +1268,9 @@ mod tests {
             costs: None,
             movement: None,
             super_freeze: None,
+            kara: None,
+            airborne: None,
+            allow_from_stun: None,
             on_use: None,
             on_hit: None,
             on_block: None,
@@ -930,6 +1290,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let result = export_fspk(&char_data, None);
@@ -943,7 +1304,10 @@ mod tests {
 
         // Verify flags
         let flags = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
-        assert_eq!(flags, 0, "Flags should be 0");
+        assert_eq!(
+            flags, PACK_FLAG_SORTED_INPUTS,
+            "Flags should mark sorted inputs"
+        );
 
         // Verify total length matches actual output
         let total_len = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
@@ -953,9 +1317,10 @@ mod tests {
             "Total length should match actual output size"
         );
 
-        // Verify section count: 8 base + STATE_EXTRAS + CHARACTER_PROPS = 10
+        // Verify section count: 8 base + STATE_EXTRAS + CHARACTER_PROPS + STATE_METER_GAIN
+        // + STATE_PUSHBACK + METADATA = 13
         let section_count = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
-        assert_eq!(section_count, 10, "Section count should be 10");
+        assert_eq!(section_count, 13, "Section count should be 13");
     }
 
     #[test]
@@ -965,6 +1330,7 @@ mod tests {
             character: make_test_character("empty"),
             moves: vec![],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let result = export_fspk(&char_data, None);
@@ -978,9 +1344,9 @@ mod tests {
         // Should still have valid FSPK header
         assert_eq!(&bytes[0..4], b"FSPK");
 
-        // Section count should be 8 base + CHARACTER_PROPS = 9 (no moves = no STATE_EXTRAS)
+        // 8 base + CHARACTER_PROPS + METADATA = 10 (no moves = no STATE_EXTRAS)
         let section_count = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
-        assert_eq!(section_count, 9);
+        assert_eq!(section_count, 10);
     }
 
     #[test]
@@ -993,12 +1359,14 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![move_a.clone(), move_b.clone(), move_c.clone()],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let char_data2 = CharacterData {
             character: make_test_character("test"),
             moves: vec![move_c, move_a, move_b],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let bytes1 = export_fspk(&char_data1, None).unwrap();
@@ -1009,12 +1377,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_export_fspk_orders_variants_by_id() {
+        let state = |input: &str, id: &str, damage: u16| State {
+            id: Some(id.to_string()),
+            damage,
+            ..make_test_move(input, "")
+        };
+        // Variants keep their base's input, so only the id tells them apart.
+        let moves = vec![
+            state("5H", "5H~level2", 300),
+            state("5L", "5L", 40),
+            state("5H", "5H", 100),
+            state("0_idle", "0_idle", 0),
+            state("5H", "5H~level1", 200),
+        ];
+        let mut reversed = moves.clone();
+        reversed.reverse();
+
+        let export = |moves: Vec<State>| {
+            export_fspk(
+                &CharacterData {
+                    character: make_test_character("test"),
+                    moves,
+                    cancel_table: make_empty_cancel_table(),
+                    variant_errors: Vec::new(),
+                },
+                None,
+            )
+            .unwrap()
+        };
+        let bytes = export(moves);
+        assert_eq!(
+            bytes,
+            export(reversed),
+            "state indices should not depend on read order"
+        );
+
+        let pack = framesmith_fspack::PackView::parse(&bytes).unwrap();
+        let states = pack.states().unwrap();
+        let damages: Vec<u16> = (0..states.len())
+            .map(|i| states.get(i).unwrap().damage())
+            .collect();
+        assert_eq!(damages, vec![0, 100, 200, 300, 40]);
+    }
+
     #[test]
     fn test_export_fspk_section_headers() {
         let char_data = CharacterData {
             character: make_test_character("test"),
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let bytes = export_fspk(&char_data, None).unwrap();
@@ -1047,9 +1461,10 @@ mod tests {
         }
 
         // MOVE_EXTRAS and CHARACTER_PROPS are expected when there are moves.
-        // 8 base + STATE_EXTRAS + CHARACTER_PROPS = 10
+        // 8 base + STATE_EXTRAS + CHARACTER_PROPS + STATE_METER_GAIN + STATE_PUSHBACK
+        // + METADATA = 13
         assert_eq!(
-            section_count, 10,
+            section_count, 13,
             "Expected STATE_EXTRAS and CHARACTER_PROPS sections to be present"
         );
         let extras_kind_off = HEADER_SIZE + 8 * SECTION_HEADER_SIZE;
@@ -1068,6 +1483,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let bytes = export_fspk(&char_data, None).unwrap();
@@ -1109,12 +1525,14 @@ mod tests {
             name: "a".repeat(u16::MAX as usize + 1),
             start: 0,
             max: 1,
+            regen: 0,
         }];
 
         let char_data = CharacterData {
             character,
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let result = export_fspk(&char_data, None);
@@ -1147,6 +1565,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![mv1, mv2],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let result = export_fspk(&char_data, None);
@@ -1164,6 +1583,7 @@ mod tests {
                 make_test_move("2L", "stand_light"), // Shares animation with 5L
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let bytes = export_fspk(&char_data, None).unwrap();
@@ -1199,6 +1619,7 @@ mod tests {
                 make_test_move("5H", "stand_heavy"),
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Export to bytes
@@ -1207,8 +1628,9 @@ mod tests {
         // Parse with framesmith_fspack reader
         let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse should succeed");
 
-        // 8 base + STATE_EXTRAS + CHARACTER_PROPS = 10 sections
-        assert_eq!(pack.section_count(), 10);
+        // 8 base + STATE_EXTRAS + CHARACTER_PROPS + STATE_METER_GAIN + STATE_PUSHBACK
+        // + METADATA = 13 sections
+        assert_eq!(pack.section_count(), 13);
 
         // Verify move count matches
         let moves = pack.states().expect("should have MOVES section");
@@ -1251,6 +1673,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![make_move_with_hitboxes()],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Export to bytes
@@ -1312,6 +1735,7 @@ mod tests {
             character: make_test_character("empty"),
             moves: vec![],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Export to bytes
@@ -1320,8 +1744,8 @@ mod tests {
         // Parse with framesmith_fspack reader
         let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse should succeed");
 
-        // 8 base + CHARACTER_PROPS = 9 (no moves = no STATE_EXTRAS)
-        assert_eq!(pack.section_count(), 9);
+        // 8 base + CHARACTER_PROPS + METADATA = 10 (no moves = no STATE_EXTRAS)
+        assert_eq!(pack.section_count(), 10);
 
         // Verify moves section is empty
         let moves = pack.states().expect("should have MOVES section");
@@ -1348,6 +1772,7 @@ mod tests {
                 make_test_move("2M", "stand_medium"), // Same animation as 5M
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Export to bytes
@@ -1388,6 +1813,7 @@ mod tests {
                 make_test_move("idle", ""), // No animation
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Export to bytes
@@ -1431,6 +1857,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let rules = MergedRules {
@@ -1469,6 +1896,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Schema missing some character properties
@@ -1503,6 +1931,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![mv],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Schema with tags but missing the one used
@@ -1545,6 +1974,7 @@ mod tests {
             character: make_test_character("test"),
             moves: vec![mv],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Schema with tags but missing the move type
@@ -1592,6 +2022,7 @@ mod tests {
             character: char,
             moves: vec![make_test_move("5L", "stand_light")],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         // Export without schema
@@ -12,4 +12,4 @@ mod sections;
 mod types;
 mod utils;
 
-pub use export::export_fspk;
+pub use export::{export_fspk, export_fspk_big_endian, fspk_export_warnings, sort_states};
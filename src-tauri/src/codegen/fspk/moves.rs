@@ -2,11 +2,16 @@
 
 use std::collections::HashMap;
 
-use crate::codegen::fspk_format::KEY_NONE;
+use crate::codegen::fspk_format::{
+    HIT_FLAG_THROW, KEY_NONE, STATE_FLAG_AIRBORNE, STATE_FLAG_ALLOW_FROM_STUN,
+};
 use crate::commands::CharacterData;
 use crate::schema::State;
 
-use super::packing::{guard_type_to_u8, pack_hit_window, pack_hurt_window, pack_move_record, pack_shape};
+use super::packing::{
+    check_state_frames, guard_type_to_u8, on_hit_reaction, pack_frame_hurt_window, pack_hit_window,
+    pack_hitbox_shape, pack_hurt_window, pack_move_record, pack_multi_hit_window, pack_shape,
+};
 use super::types::{CancelLookup, PackedMoveData, StrRef, StringTable};
 use super::utils::{checked_u16, checked_u32};
 
@@ -34,6 +39,7 @@ pub fn pack_moves(
 
     for (idx, mv) in moves.iter().enumerate() {
         let move_id = checked_u16(idx, "move_id")?;
+        check_state_frames(mv)?;
 
         // Look up animation index if map is provided
         let anim_index = anim_to_index
@@ -52,10 +58,35 @@ pub fn pack_moves(
         let push_windows_off = checked_u16(packed.push_windows.len(), "push_windows_off")?;
 
         // Pack hitboxes -> shapes + hit_windows
-        for hb in &mv.hitboxes {
+        let (reaction_flags, launch) = on_hit_reaction(mv)?;
+        let hit_flags = if mv.move_type.as_deref() == Some("throw") {
+            HIT_FLAG_THROW
+        } else {
+            0
+        } | reaction_flags;
+        // Multi-hit moves carry per-hit data in `hits`; the flat hitboxes are
+        // only used when there are none.
+        let hits = mv.hits.as_deref().unwrap_or(&[]);
+        for hit in hits {
+            let shape_off = checked_u32(packed.shapes.len(), "shape_off")?;
+            for shape in &hit.hitboxes {
+                packed.shapes.extend_from_slice(&pack_hitbox_shape(shape));
+            }
+            let shapes_len = checked_u16(hit.hitboxes.len(), "hit window shapes_len")?;
+            let mut hit_window = pack_multi_hit_window(hit, shape_off, shapes_len);
+            hit_window[3] = hit_flags;
+            hit_window[11] = launch;
+            packed.hit_windows.extend_from_slice(&hit_window);
+        }
+        let flat_hitboxes = if hits.is_empty() {
+            mv.hitboxes.as_slice()
+        } else {
+            &[]
+        };
+        for hb in flat_hitboxes {
             let shape_off = checked_u32(packed.shapes.len(), "shape_off")?;
             packed.shapes.extend_from_slice(&pack_shape(&hb.r#box));
-            packed.hit_windows.extend_from_slice(&pack_hit_window(
+            let mut hit_window = pack_hit_window(
                 hb,
                 shape_off,
                 mv.damage,
@@ -63,15 +94,36 @@ pub fn pack_moves(
                 mv.blockstun,
                 mv.hitstop,
                 guard_type_to_u8(&mv.guard),
-            ));
+            );
+            hit_window[3] = hit_flags;
+            hit_window[11] = launch;
+            packed.hit_windows.extend_from_slice(&hit_window);
         }
 
-        // Pack hurtboxes -> shapes + hurt_windows
-        for hb in &mv.hurtboxes {
+        // Pack hurtboxes -> shapes + hurt_windows. Advanced hurtboxes carry
+        // invuln/armor flags, so the flagless legacy boxes are only used when
+        // there are none.
+        let advanced_hurtboxes = mv.advanced_hurtboxes.as_deref().unwrap_or(&[]);
+        let legacy_hurtboxes = if advanced_hurtboxes.is_empty() {
+            mv.hurtboxes.as_slice()
+        } else {
+            &[]
+        };
+        for hb in legacy_hurtboxes {
             let shape_off = checked_u32(packed.shapes.len(), "shape_off")?;
             packed.shapes.extend_from_slice(&pack_shape(&hb.r#box));
             packed.hurt_windows.extend_from_slice(&pack_hurt_window(hb, shape_off));
         }
+        for hb in advanced_hurtboxes {
+            let shape_off = checked_u32(packed.shapes.len(), "shape_off")?;
+            for shape in &hb.boxes {
+                packed.shapes.extend_from_slice(&pack_hitbox_shape(shape));
+            }
+            let shapes_len = checked_u16(hb.boxes.len(), "hurt window shapes_len")?;
+            packed
+                .hurt_windows
+                .extend_from_slice(&pack_frame_hurt_window(hb, shape_off, shapes_len));
+        }
 
         // Pack pushboxes -> shapes + push_windows (same 12-byte format as hurt windows)
         for pb in &mv.pushboxes {
@@ -81,12 +133,22 @@ pub fn pack_moves(
         }
 
         // Calculate lengths
-        let hit_windows_len = checked_u16(mv.hitboxes.len(), "hit_windows_len")?;
-        let hurt_windows_len = checked_u16(mv.hurtboxes.len(), "hurt_windows_len")?;
+        let hit_windows_len = checked_u16(hits.len() + flat_hitboxes.len(), "hit_windows_len")?;
+        let hurt_windows_len = checked_u16(
+            legacy_hurtboxes.len() + advanced_hurtboxes.len(),
+            "hurt_windows_len",
+        )?;
         let push_windows_len = checked_u16(mv.pushboxes.len(), "push_windows_len")?;
 
-        // Cancel flags are now handled via tag_rules, so MoveRecord.flags is always 0
-        let flags: u8 = 0;
+        // Cancel flags are now handled via tag_rules; MoveRecord.flags only
+        // carries state flags.
+        let mut flags: u8 = 0;
+        if mv.airborne == Some(true) {
+            flags |= STATE_FLAG_AIRBORNE;
+        }
+        if mv.allow_from_stun == Some(true) {
+            flags |= STATE_FLAG_ALLOW_FROM_STUN;
+        }
         let _ = cancel_lookup; // Silence unused warning; used later for deny resolution
 
         // Pack move record - mesh_key and keyframes_key both use the same animation index
@@ -208,6 +270,7 @@ mod tests {
                 make_test_move("5M", "stand_medium"),
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let mut strings1 = StringTable::new();
@@ -221,6 +284,7 @@ mod tests {
                 make_test_move("5H", "stand_heavy"),
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let mut strings2 = StringTable::new();
@@ -241,6 +305,7 @@ mod tests {
                 make_test_move("5M", "stand_medium"),
             ],
             cancel_table: make_empty_cancel_table(),
+            variant_errors: Vec::new(),
         };
 
         let mut strings = StringTable::new();
@@ -259,6 +324,32 @@ mod tests {
         assert_eq!(packed.hurt_windows.len(), 0);
     }
 
+    #[test]
+    fn test_pack_moves_rejects_total_over_frame_limit() {
+        let mut mv = make_test_move("5H", "stand_heavy");
+        mv.startup = 20;
+        mv.active = 30;
+        mv.recovery = 250;
+
+        let Err(err) = pack_moves(&[mv], None, None) else {
+            panic!("expected a frame limit error");
+        };
+        assert!(err.contains("'5H'"), "{err}");
+        assert!(err.contains("total frames"), "{err}");
+        assert!(err.contains("300"), "{err}");
+    }
+
+    #[test]
+    fn test_pack_moves_explicit_total_overrides_long_phases() {
+        let mut mv = make_test_move("5H", "stand_heavy");
+        mv.startup = 20;
+        mv.active = 30;
+        mv.recovery = 250;
+        mv.total = Some(255);
+
+        assert!(pack_moves(&[mv], None, None).is_ok());
+    }
+
     #[test]
     fn test_pack_moves_count_matches() {
         let moves = vec![
@@ -1,10 +1,24 @@
 //! Binary record packing for shapes, hitboxes, and move records.
 
+use std::collections::HashMap;
+
 use crate::codegen::fspk_format::{
-    to_q12_4, to_q12_4_unsigned, HIT_WINDOW24_SIZE, HURT_WINDOW12_SIZE, SHAPE12_SIZE,
-    SHAPE_KIND_AABB, STATE_RECORD_SIZE,
+    to_q12_4, to_q12_4_unsigned, to_q8_8, to_q8_8_degrees, CHARGE_DIR_BACK, CHARGE_DIR_DOWN,
+    CHARGE_DIR_FORWARD, CHARGE_DIR_UP, HIT_FLAG_GROUND_BOUNCE, HIT_FLAG_WALL_BOUNCE,
+    HIT_WINDOW24_SIZE, HURT_FLAG_ARMOR, HURT_FLAG_FULL_INVULN, HURT_FLAG_PROJECTILE_INVULN,
+    HURT_FLAG_STRIKE_INVULN, HURT_FLAG_THROW_INVULN, HURT_WINDOW12_SIZE, MOVEMENT_FLAG_AIRBORNE,
+    SHAPE12_SIZE, SHAPE_KIND_AABB, SHAPE_KIND_CAPSULE, SHAPE_KIND_CIRCLE, SHAPE_KIND_RECT,
+    STATE_KNOCKBACK4_SIZE, STATE_METER_GAIN4_SIZE, STATE_MOVEMENT12_SIZE, STATE_ON_USE4_SIZE,
+    STATE_PUSHBACK4_SIZE, STATE_RECORD_SIZE, STATE_SUPER_FREEZE6_SIZE, STATE_TYPE_COMMAND_NORMAL,
+    STATE_TYPE_EX, STATE_TYPE_MOVEMENT, STATE_TYPE_NORMAL, STATE_TYPE_REKKA, STATE_TYPE_SPECIAL,
+    STATE_TYPE_SUPER, STATE_TYPE_SYSTEM, STATE_TYPE_THROW, STATE_TYPE_UNKNOWN,
+    SUPER_FREEZE_FLAG_FLASH,
+};
+use crate::schema::{
+    FrameHitbox, FrameHurtbox, GuardType, Hit, HitboxShape, HurtboxFlag, Rect, State,
 };
-use crate::schema::{FrameHitbox, GuardType, Rect, State};
+
+use super::sections::OPT_U16_NONE;
 
 /// Pack a Rect into a Shape12 (AABB) structure.
 ///
@@ -35,6 +49,74 @@ pub fn pack_shape(rect: &Rect) -> [u8; SHAPE12_SIZE] {
     buf
 }
 
+/// Pack a HitboxShape into a Shape12 structure.
+///
+/// Field mapping per kind (see `fspk_format` SHAPE_KIND_* docs):
+/// - AABB: a=x, b=y, c=w, d=h
+/// - Rect: a=x, b=y, c=w, d=h, e=angle (Q8.8 degrees)
+/// - Circle: a=x, b=y, c=r
+/// - Capsule: a=x1, b=y1, c=x2, d=y2, e=r (Q8.8)
+pub fn pack_hitbox_shape(shape: &HitboxShape) -> [u8; SHAPE12_SIZE] {
+    let (kind, a, b, c, d, e) = match shape {
+        HitboxShape::Aabb { x, y, w, h } => (
+            SHAPE_KIND_AABB,
+            to_q12_4(*x as f32),
+            to_q12_4(*y as f32),
+            to_q12_4_unsigned(*w as f32),
+            to_q12_4_unsigned(*h as f32),
+            0i16,
+        ),
+        HitboxShape::Rect { x, y, w, h, angle } => (
+            SHAPE_KIND_RECT,
+            to_q12_4(*x as f32),
+            to_q12_4(*y as f32),
+            to_q12_4_unsigned(*w as f32),
+            to_q12_4_unsigned(*h as f32),
+            to_q8_8_degrees(*angle),
+        ),
+        HitboxShape::Circle { x, y, r } => (
+            SHAPE_KIND_CIRCLE,
+            to_q12_4(*x as f32),
+            to_q12_4(*y as f32),
+            to_q12_4_unsigned(*r as f32),
+            0u16,
+            0i16,
+        ),
+        HitboxShape::Capsule { x1, y1, x2, y2, r } => (
+            SHAPE_KIND_CAPSULE,
+            to_q12_4(*x1 as f32),
+            to_q12_4(*y1 as f32),
+            to_q12_4(*x2 as f32) as u16,
+            to_q12_4(*y2 as f32) as u16,
+            to_q8_8(*r as f32),
+        ),
+    };
+
+    let mut buf = [0u8; SHAPE12_SIZE];
+    buf[0] = kind;
+    buf[1] = 0; // flags
+    buf[2..4].copy_from_slice(&a.to_le_bytes());
+    buf[4..6].copy_from_slice(&b.to_le_bytes());
+    buf[6..8].copy_from_slice(&c.to_le_bytes());
+    buf[8..10].copy_from_slice(&d.to_le_bytes());
+    buf[10..12].copy_from_slice(&e.to_le_bytes());
+
+    buf
+}
+
+/// Convert a list of HurtboxFlag values to the packed hurt window flag byte.
+pub fn hurtbox_flags_to_u8(flags: &[HurtboxFlag]) -> u8 {
+    flags.iter().fold(0u8, |acc, flag| {
+        acc | match flag {
+            HurtboxFlag::StrikeInvuln => HURT_FLAG_STRIKE_INVULN,
+            HurtboxFlag::ThrowInvuln => HURT_FLAG_THROW_INVULN,
+            HurtboxFlag::ProjectileInvuln => HURT_FLAG_PROJECTILE_INVULN,
+            HurtboxFlag::FullInvuln => HURT_FLAG_FULL_INVULN,
+            HurtboxFlag::Armor => HURT_FLAG_ARMOR,
+        }
+    })
+}
+
 /// Convert GuardType to u8 for binary encoding.
 pub fn guard_type_to_u8(guard: &GuardType) -> u8 {
     match guard {
@@ -45,19 +127,33 @@ pub fn guard_type_to_u8(guard: &GuardType) -> u8 {
     }
 }
 
+/// Convert a charge precondition direction to its CHARGE_DIR_* code.
+pub fn charge_direction_to_u8(direction: &str) -> Result<u8, String> {
+    match direction {
+        "down" => Ok(CHARGE_DIR_DOWN),
+        "back" => Ok(CHARGE_DIR_BACK),
+        "up" => Ok(CHARGE_DIR_UP),
+        "forward" => Ok(CHARGE_DIR_FORWARD),
+        other => Err(format!(
+            "unknown charge direction '{}' (expected down, back, up, or forward)",
+            other
+        )),
+    }
+}
+
 /// Pack a FrameHitbox into a HitWindow24 structure.
 ///
 /// HitWindow24 layout (24 bytes) - must match view.rs HitWindowView:
 /// - 0: start_frame (u8)
 /// - 1: end_frame (u8)
 /// - 2: guard (u8)
-/// - 3: reserved (u8)
+/// - 3: flags (u8) - written as 0; callers set the move's HIT_FLAG_* bits
 /// - 4-5: damage (u16 LE)
 /// - 6-7: chip_damage (u16 LE)
 /// - 8: hitstun (u8)
 /// - 9: blockstun (u8)
 /// - 10: hitstop (u8)
-/// - 11: reserved (u8)
+/// - 11: launch (i8) - written as 0; callers set it from [`on_hit_reaction`]
 /// - 12-15: shapes_off (u32 LE)
 /// - 16-17: shapes_len (u16 LE)
 /// - 18-21: cancels_off (u32 LE)
@@ -76,16 +172,40 @@ pub fn pack_hit_window(
     buf[0] = hb.frames.0; // start_frame
     buf[1] = hb.frames.1; // end_frame
     buf[2] = guard; // guard
-    buf[3] = 0; // reserved
+    buf[3] = 0; // flags
     buf[4..6].copy_from_slice(&damage.to_le_bytes()); // damage
     buf[6..8].copy_from_slice(&0u16.to_le_bytes()); // chip_damage (TODO: add to schema)
     buf[8] = hitstun; // hitstun
     buf[9] = blockstun; // blockstun
     buf[10] = hitstop; // hitstop
-    buf[11] = 0; // reserved
+    buf[11] = 0; // launch
     buf[12..16].copy_from_slice(&shapes_off.to_le_bytes()); // shapes_off
     buf[16..18].copy_from_slice(&1u16.to_le_bytes()); // shapes_len = 1
-    // bytes 18-27 are cancels/pushback (already zeroed, not used in v1)
+
+    buf
+}
+
+/// Pack one entry of a multi-hit move's `hits` into a HitWindow24 structure.
+///
+/// Same layout as [`pack_hit_window`], but damage, chip, stun, and guard come
+/// from the hit itself and `shapes_len` covers all of its hitboxes.
+pub fn pack_multi_hit_window(
+    hit: &Hit,
+    shapes_off: u32,
+    shapes_len: u16,
+) -> [u8; HIT_WINDOW24_SIZE] {
+    let mut buf = [0u8; HIT_WINDOW24_SIZE];
+
+    buf[0] = hit.frames.0; // start_frame
+    buf[1] = hit.frames.1; // end_frame
+    buf[2] = guard_type_to_u8(&hit.guard); // guard
+    buf[4..6].copy_from_slice(&hit.damage.to_le_bytes()); // damage
+    buf[6..8].copy_from_slice(&hit.chip_damage.unwrap_or(0).to_le_bytes()); // chip_damage
+    buf[8] = hit.hitstun; // hitstun
+    buf[9] = hit.blockstun; // blockstun
+    buf[10] = hit.hitstop; // hitstop
+    buf[12..16].copy_from_slice(&shapes_off.to_le_bytes()); // shapes_off
+    buf[16..18].copy_from_slice(&shapes_len.to_le_bytes()); // shapes_len
 
     buf
 }
@@ -99,13 +219,35 @@ pub fn pack_hit_window(
 /// - 8-9: shapes_len (u16) - number of shapes (always 1 for v1)
 /// - 10-11: padding
 pub fn pack_hurt_window(hb: &FrameHitbox, shapes_off: u32) -> [u8; HURT_WINDOW12_SIZE] {
+    pack_window12(hb.frames, 0, shapes_off, 1)
+}
+
+/// Pack a FrameHurtbox (advanced hurtbox) into a HurtWindow12 structure.
+///
+/// Same layout as [`pack_hurt_window`], but the flags byte is taken from
+/// `FrameHurtbox::flags` and `shapes_len` covers every box in the window.
+pub fn pack_frame_hurt_window(
+    hb: &FrameHurtbox,
+    shapes_off: u32,
+    shapes_len: u16,
+) -> [u8; HURT_WINDOW12_SIZE] {
+    let flags = hurtbox_flags_to_u8(hb.flags.as_deref().unwrap_or(&[]));
+    pack_window12(hb.frames, flags, shapes_off, shapes_len)
+}
+
+fn pack_window12(
+    frames: (u8, u8),
+    flags: u8,
+    shapes_off: u32,
+    shapes_len: u16,
+) -> [u8; HURT_WINDOW12_SIZE] {
     let mut buf = [0u8; HURT_WINDOW12_SIZE];
 
-    buf[0] = hb.frames.0; // frame_start
-    buf[1] = hb.frames.1; // frame_end
-    buf[2..4].copy_from_slice(&0u16.to_le_bytes()); // flags = 0 for v1
+    buf[0] = frames.0; // frame_start
+    buf[1] = frames.1; // frame_end
+    buf[2..4].copy_from_slice(&(flags as u16).to_le_bytes()); // flags (high byte reserved)
     buf[4..8].copy_from_slice(&shapes_off.to_le_bytes()); // shapes_off
-    buf[8..10].copy_from_slice(&1u16.to_le_bytes()); // shapes_len = 1
+    buf[8..10].copy_from_slice(&shapes_len.to_le_bytes()); // shapes_len
     // bytes 10-11 are padding (already zeroed)
 
     buf
@@ -115,16 +257,17 @@ pub fn pack_hurt_window(hb: &FrameHitbox, shapes_off: u32) -> [u8; HURT_WINDOW12
 /// Maps common type strings to fixed IDs for runtime compatibility.
 pub fn move_type_to_u8(move_type: Option<&String>) -> u8 {
     match move_type.map(|s| s.as_str()) {
-        Some("normal") => 0,
-        Some("command_normal") => 1,
-        Some("special") => 2,
-        Some("super") => 3,
-        Some("movement") => 4,
-        Some("throw") => 5,
-        Some("ex") => 6,
-        Some("rekka") => 7,
-        Some(_) => 255, // unknown custom type
-        None => 0,      // default to normal
+        Some("normal") => STATE_TYPE_NORMAL,
+        Some("command_normal") => STATE_TYPE_COMMAND_NORMAL,
+        Some("special") => STATE_TYPE_SPECIAL,
+        Some("super") => STATE_TYPE_SUPER,
+        Some("movement") => STATE_TYPE_MOVEMENT,
+        Some("throw") => STATE_TYPE_THROW,
+        Some("ex") => STATE_TYPE_EX,
+        Some("rekka") => STATE_TYPE_REKKA,
+        Some("system") => STATE_TYPE_SYSTEM,
+        Some(_) => STATE_TYPE_UNKNOWN, // unknown custom type
+        None => STATE_TYPE_NORMAL,     // default to normal
     }
 }
 
@@ -148,17 +291,17 @@ pub fn trigger_type_to_u8(trigger: Option<&crate::schema::TriggerType>) -> u8 {
 /// - 6: move_type (u8)
 /// - 7: trigger (u8)
 /// - 8: guard (u8)
-/// - 9: flags (u8)
+/// - 9: flags (u8, STATE_FLAG_*)
 /// - 10: startup (u8)
 /// - 11: active (u8)
 /// - 12: recovery (u8)
-/// - 13: reserved (u8)
+/// - 13: kara_start (u8)
 /// - 14-15: total (u16)
 /// - 16-17: damage (u16)
 /// - 18: hitstun (u8)
 /// - 19: blockstun (u8)
 /// - 20: hitstop (u8)
-/// - 21: reserved (u8)
+/// - 21: kara_frames (u8, 0 = no kara window)
 /// - 22-25: hit_windows_off (u32)
 /// - 26-27: hit_windows_len (u16)
 /// - 28-29: hurt_windows_off (u16)
@@ -187,21 +330,19 @@ pub fn pack_move_record(
     buf[6] = move_type_to_u8(mv.move_type.as_ref()); // move_type
     buf[7] = trigger_type_to_u8(mv.trigger.as_ref()); // trigger
     buf[8] = guard_type_to_u8(&mv.guard); // guard
-    buf[9] = flags; // cancel flags
+    buf[9] = flags; // state flags
     buf[10] = mv.startup; // startup
     buf[11] = mv.active; // active
     buf[12] = mv.recovery; // recovery
-    buf[13] = 0; // reserved
-    let total = mv
-        .total
-        .map(|t| t as u16)
-        .unwrap_or_else(|| (mv.startup as u16) + (mv.active as u16) + (mv.recovery as u16));
+    let (kara_start, kara_frames) = kara_window(mv);
+    buf[13] = kara_start; // kara_start
+    let total = state_total_frames(mv);
     buf[14..16].copy_from_slice(&total.to_le_bytes()); // total
     buf[16..18].copy_from_slice(&mv.damage.to_le_bytes()); // damage
     buf[18] = mv.hitstun; // hitstun
     buf[19] = mv.blockstun; // blockstun
     buf[20] = mv.hitstop; // hitstop
-    buf[21] = 0; // reserved
+    buf[21] = kara_frames; // kara_frames
     buf[22..26].copy_from_slice(&hit_windows_off.to_le_bytes()); // hit_windows_off
     buf[26..28].copy_from_slice(&hit_windows_len.to_le_bytes()); // hit_windows_len
     buf[28..30].copy_from_slice(&hurt_windows_off.to_le_bytes()); // hurt_windows_off (u16)
@@ -212,6 +353,277 @@ pub fn pack_move_record(
     buf
 }
 
+/// Kara window as (start frame, frame count); (0, 0) when the state has none.
+///
+/// An inverted window (start after end) is rejected by validation and packs
+/// as no window.
+fn kara_window(mv: &State) -> (u8, u8) {
+    match mv.kara {
+        Some((start, end)) if start <= end => (start, (end - start).saturating_add(1)),
+        _ => (0, 0),
+    }
+}
+
+/// Total frames of a state: explicit `total`, else startup + active + recovery.
+fn state_total_frames(mv: &State) -> u16 {
+    mv.total
+        .map(|t| t as u16)
+        .unwrap_or_else(|| (mv.startup as u16) + (mv.active as u16) + (mv.recovery as u16))
+}
+
+/// Longest state the runtime can play; its frame counter is a u8.
+const MAX_STATE_FRAMES: u16 = u8::MAX as u16;
+
+/// Reject states longer than the runtime's frame counter can reach.
+///
+/// The record stores `total` as u16, but the runtime compares it against a
+/// u8 frame counter, so anything above 255 would wrap at playback.
+pub fn check_state_frames(mv: &State) -> Result<(), String> {
+    let total = state_total_frames(mv);
+    if total > MAX_STATE_FRAMES {
+        return Err(format!(
+            "move '{}': total frames (startup + active + recovery) is {}, exceeds the limit of {}",
+            mv.input, total, MAX_STATE_FRAMES
+        ));
+    }
+    Ok(())
+}
+
+/// Bounce flags and launch byte from a state's `on_hit`, shared by all of its
+/// hit windows.
+///
+/// Launch is the `y` of a `"launch"` knockback, which must fit in an i8.
+pub fn on_hit_reaction(mv: &State) -> Result<(u8, u8), String> {
+    let Some(on_hit) = &mv.on_hit else {
+        return Ok((0, 0));
+    };
+
+    let mut flags = 0;
+    if on_hit.wall_bounce == Some(true) {
+        flags |= HIT_FLAG_WALL_BOUNCE;
+    }
+    if on_hit.ground_bounce == Some(true) {
+        flags |= HIT_FLAG_GROUND_BOUNCE;
+    }
+
+    let launch = match &on_hit.knockback {
+        Some(kb) if kb.knockback_type == "launch" => {
+            let y = kb.y.unwrap_or(0);
+            i8::try_from(y).map_err(|_| {
+                format!(
+                    "move '{}': launch knockback y {} is outside {}..={}",
+                    mv.input,
+                    y,
+                    i8::MIN,
+                    i8::MAX
+                )
+            })?
+        }
+        _ => 0,
+    };
+    Ok((flags, launch as u8))
+}
+
+/// Pack a state's movement into a StateMovement12 structure.
+///
+/// StateMovement12 layout:
+/// - 0: start_frame (u8)
+/// - 1: end_frame (u8, inclusive)
+/// - 2: flags (u8)
+/// - 3: reserved (u8)
+/// - 4-5: velocity_x (i16, Q8.8)
+/// - 6-7: velocity_y (i16, Q8.8)
+/// - 8-9: acceleration_x (i16, Q8.8)
+/// - 10-11: acceleration_y (i16, Q8.8)
+///
+/// The window defaults to the whole state. A distance-only movement is
+/// spread linearly over the window; `curve` is not encoded.
+///
+/// Returns `None` if the state has no movement.
+pub fn pack_state_movement(mv: &State) -> Option<[u8; STATE_MOVEMENT12_SIZE]> {
+    let movement = mv.movement.as_ref()?;
+    let mut buf = [0u8; STATE_MOVEMENT12_SIZE];
+
+    let (start, end) = movement.frames.unwrap_or_else(|| {
+        let last = state_total_frames(mv).saturating_sub(1).min(u8::MAX as u16) as u8;
+        (0, last)
+    });
+    let window_len = (end.saturating_sub(start) as f32) + 1.0;
+
+    let (vel_x, vel_y) = match (&movement.velocity, movement.distance) {
+        (Some(v), _) => (v.x, v.y),
+        (None, Some(distance)) => {
+            let sign = match movement.direction.as_deref() {
+                Some("backward") | Some("back") => -1.0,
+                _ => 1.0,
+            };
+            (sign * distance as f32 / window_len, 0.0)
+        }
+        (None, None) => (0.0, 0.0),
+    };
+    let (accel_x, accel_y) = movement
+        .acceleration
+        .as_ref()
+        .map(|a| (a.x, a.y))
+        .unwrap_or((0.0, 0.0));
+
+    buf[0] = start;
+    buf[1] = end;
+    buf[2] = if movement.airborne.unwrap_or(false) {
+        MOVEMENT_FLAG_AIRBORNE
+    } else {
+        0
+    };
+    buf[4..6].copy_from_slice(&to_q8_8(vel_x).to_le_bytes());
+    buf[6..8].copy_from_slice(&to_q8_8(vel_y).to_le_bytes());
+    buf[8..10].copy_from_slice(&to_q8_8(accel_x).to_le_bytes());
+    buf[10..12].copy_from_slice(&to_q8_8(accel_y).to_le_bytes());
+
+    Some(buf)
+}
+
+/// Pack a state's on-use effects into a StateOnUse4 structure.
+///
+/// StateOnUse4 layout:
+/// - 0-1: enters_state (u16, state index, 0xFFFF = none)
+/// - 2-3: gain_meter (u16)
+///
+/// `enters_state.name` is resolved against state inputs. Names that aren't a
+/// state (modes such as `rekka` checked by `state` preconditions) are not
+/// encoded, nor are `duration`, `persistent` and `exit_input`;
+/// [`fspk_export_warnings`](super::export::fspk_export_warnings) reports them.
+pub fn pack_state_on_use(
+    mv: &State,
+    input_to_index: &HashMap<&str, u16>,
+) -> [u8; STATE_ON_USE4_SIZE] {
+    let on_use = mv.on_use.as_ref();
+    let enters_state = on_use
+        .and_then(|u| u.enters_state.as_ref())
+        .and_then(|enters| input_to_index.get(enters.name.as_str()).copied())
+        .unwrap_or(OPT_U16_NONE);
+    let gain_meter = on_use.and_then(|u| u.gain_meter).unwrap_or(0);
+
+    let mut buf = [0u8; STATE_ON_USE4_SIZE];
+    buf[0..2].copy_from_slice(&enters_state.to_le_bytes());
+    buf[2..4].copy_from_slice(&gain_meter.to_le_bytes());
+    buf
+}
+
+/// Pack a state's super freeze into a StateSuperFreeze6 structure.
+///
+/// StateSuperFreeze6 layout:
+/// - 0: frames (u8, 0 = no super freeze)
+/// - 1: flags (u8, SUPER_FREEZE_FLAG_*)
+/// - 2-3: zoom (i16, Q8.8, defaults to 1.0)
+/// - 4: darken (u8, 0.0-1.0 scaled to 0-255, defaults to 0)
+/// - 5: reserved
+///
+/// Returns `None` if the state has no super freeze.
+pub fn pack_state_super_freeze(mv: &State) -> Option<[u8; STATE_SUPER_FREEZE6_SIZE]> {
+    let freeze = mv.super_freeze.as_ref()?;
+    let flags = if freeze.flash.unwrap_or(false) {
+        SUPER_FREEZE_FLAG_FLASH
+    } else {
+        0
+    };
+    let zoom = to_q8_8(freeze.zoom.unwrap_or(1.0));
+    let darken = (freeze.darken.unwrap_or(0.0).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    let mut buf = [0u8; STATE_SUPER_FREEZE6_SIZE];
+    buf[0] = freeze.frames;
+    buf[1] = flags;
+    buf[2..4].copy_from_slice(&zoom.to_le_bytes());
+    buf[4] = darken;
+    Some(buf)
+}
+
+/// Pack a state's meter gain into a StateMeterGain4 structure.
+///
+/// StateMeterGain4 layout:
+/// - 0-1: meter_gain_hit (u16)
+/// - 2-3: meter_gain_whiff (u16)
+///
+/// Returns `None` if the state gains no meter.
+pub fn pack_state_meter_gain(mv: &State) -> Option<[u8; STATE_METER_GAIN4_SIZE]> {
+    let gain = &mv.meter_gain;
+    if gain.hit == 0 && gain.whiff == 0 {
+        return None;
+    }
+
+    let mut buf = [0u8; STATE_METER_GAIN4_SIZE];
+    buf[0..2].copy_from_slice(&gain.hit.to_le_bytes());
+    buf[2..4].copy_from_slice(&gain.whiff.to_le_bytes());
+    Some(buf)
+}
+
+/// Pack a state's `on_hit.knockback` into a StateKnockback4 structure.
+///
+/// StateKnockback4 layout:
+/// - 0-1: x (i16, pixels; positive pushes away from the attacker)
+/// - 2-3: y (i16, pixels; negative is up)
+///
+/// Returns `Ok(None)` if the state has no knockback or both components are 0.
+pub fn pack_state_knockback(mv: &State) -> Result<Option<[u8; STATE_KNOCKBACK4_SIZE]>, String> {
+    let Some(knockback) = mv
+        .on_hit
+        .as_ref()
+        .and_then(|on_hit| on_hit.knockback.as_ref())
+    else {
+        return Ok(None);
+    };
+    let (x, y) = (knockback.x.unwrap_or(0), knockback.y.unwrap_or(0));
+    if x == 0 && y == 0 {
+        return Ok(None);
+    }
+
+    let component = |name: &str, value: i32| {
+        i16::try_from(value).map_err(|_| {
+            format!(
+                "move '{}': knockback {} {} is outside {}..={}",
+                mv.input,
+                name,
+                value,
+                i16::MIN,
+                i16::MAX
+            )
+        })
+    };
+    let mut buf = [0u8; STATE_KNOCKBACK4_SIZE];
+    buf[0..2].copy_from_slice(&component("x", x)?.to_le_bytes());
+    buf[2..4].copy_from_slice(&component("y", y)?.to_le_bytes());
+    Ok(Some(buf))
+}
+
+/// Pack a state's `pushback` into a StatePushback4 structure.
+///
+/// StatePushback4 layout:
+/// - 0-1: hit (i16, Q12.4 pixels)
+/// - 2-3: block (i16, Q12.4 pixels)
+///
+/// Returns `Ok(None)` if both values are 0.
+pub fn pack_state_pushback(mv: &State) -> Result<Option<[u8; STATE_PUSHBACK4_SIZE]>, String> {
+    let pushback = &mv.pushback;
+    if pushback.hit == 0 && pushback.block == 0 {
+        return Ok(None);
+    }
+
+    let (min, max) = (i32::from(i16::MIN) >> 4, i32::from(i16::MAX) >> 4);
+    let component = |name: &str, value: i32| {
+        if (min..=max).contains(&value) {
+            Ok(to_q12_4(value as f32))
+        } else {
+            Err(format!(
+                "move '{}': pushback {} {} is outside {}..={}",
+                mv.input, name, value, min, max
+            ))
+        }
+    };
+    let mut buf = [0u8; STATE_PUSHBACK4_SIZE];
+    buf[0..2].copy_from_slice(&component("hit", pushback.hit)?.to_le_bytes());
+    buf[2..4].copy_from_slice(&component("block", pushback.block)?.to_le_bytes());
+    Ok(Some(buf))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,6 +675,7 @@ mod tests {
         assert_eq!(hw[0], 5); // frame_start
         assert_eq!(hw[1], 8); // frame_end
         assert_eq!(hw[2], 1); // guard (mid)
+        assert_eq!(hw[3], 0); // flags
     }
 
     #[test]
@@ -287,6 +700,50 @@ mod tests {
         assert_eq!(shapes_len, 1);
     }
 
+    #[test]
+    fn test_hurtbox_flags_encoding() {
+        assert_eq!(hurtbox_flags_to_u8(&[]), 0);
+        assert_eq!(hurtbox_flags_to_u8(&[HurtboxFlag::Armor]), HURT_FLAG_ARMOR);
+        assert_eq!(
+            hurtbox_flags_to_u8(&[HurtboxFlag::StrikeInvuln, HurtboxFlag::ThrowInvuln]),
+            HURT_FLAG_STRIKE_INVULN | HURT_FLAG_THROW_INVULN
+        );
+    }
+
+    #[test]
+    fn test_pack_frame_hurt_window_writes_flags() {
+        let hb = FrameHurtbox {
+            frames: (2, 9),
+            boxes: vec![
+                HitboxShape::Aabb {
+                    x: 0,
+                    y: 0,
+                    w: 10,
+                    h: 10,
+                },
+                HitboxShape::Circle { x: 5, y: 5, r: 4 },
+            ],
+            flags: Some(vec![HurtboxFlag::Armor]),
+        };
+        let hw = pack_frame_hurt_window(&hb, 24, 2);
+
+        assert_eq!(hw[0], 2);
+        assert_eq!(hw[1], 9);
+        assert_eq!(u16::from_le_bytes([hw[2], hw[3]]), HURT_FLAG_ARMOR as u16);
+        assert_eq!(u32::from_le_bytes([hw[4], hw[5], hw[6], hw[7]]), 24);
+        assert_eq!(u16::from_le_bytes([hw[8], hw[9]]), 2);
+    }
+
+    #[test]
+    fn test_pack_hitbox_shape_circle() {
+        let shape = pack_hitbox_shape(&HitboxShape::Circle { x: 1, y: -2, r: 3 });
+
+        assert_eq!(shape[0], SHAPE_KIND_CIRCLE);
+        assert_eq!(i16::from_le_bytes([shape[2], shape[3]]), 16);
+        assert_eq!(i16::from_le_bytes([shape[4], shape[5]]), -32);
+        assert_eq!(u16::from_le_bytes([shape[6], shape[7]]), 48);
+    }
+
     #[test]
     fn test_guard_type_encoding() {
         assert_eq!(guard_type_to_u8(&GuardType::High), 0);
@@ -331,4 +788,111 @@ mod tests {
         assert_eq!(read_u16_le(&buf, 8), Some(1), "shapes_len should be 1");
     }
 
+    #[test]
+    fn test_pack_state_movement_velocity_and_window() {
+        use crate::schema::{Movement, Vec2};
+
+        let mv = State {
+            movement: Some(Movement {
+                velocity: Some(Vec2 { x: 4.0, y: -2.0 }),
+                acceleration: Some(Vec2 { x: -0.25, y: 0.5 }),
+                frames: Some((3, 6)),
+                airborne: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let buf = pack_state_movement(&mv).expect("movement record");
+
+        assert_eq!(buf[0], 3, "start_frame");
+        assert_eq!(buf[1], 6, "end_frame");
+        assert_eq!(buf[2], MOVEMENT_FLAG_AIRBORNE);
+        assert_eq!(i16::from_le_bytes([buf[4], buf[5]]), 4 * 256);
+        assert_eq!(i16::from_le_bytes([buf[6], buf[7]]), -2 * 256);
+        assert_eq!(i16::from_le_bytes([buf[8], buf[9]]), -64);
+        assert_eq!(i16::from_le_bytes([buf[10], buf[11]]), 128);
+    }
+
+    #[test]
+    fn test_pack_state_movement_distance_spreads_over_state() {
+        use crate::schema::Movement;
+
+        let mv = State {
+            startup: 3,
+            active: 1,
+            recovery: 4,
+            movement: Some(Movement {
+                distance: Some(40),
+                direction: Some("backward".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let buf = pack_state_movement(&mv).expect("movement record");
+
+        // 8-frame state, 40px backward => -5px per frame
+        assert_eq!((buf[0], buf[1]), (0, 7));
+        assert_eq!(i16::from_le_bytes([buf[4], buf[5]]), -5 * 256);
+        assert!(pack_state_movement(&State::default()).is_none());
+    }
+
+    #[test]
+    fn test_pack_state_knockback_components_and_range() {
+        use crate::schema::{Knockback, OnHit};
+
+        let with_knockback = |x, y| State {
+            input: "6H".to_string(),
+            on_hit: Some(OnHit {
+                knockback: Some(Knockback {
+                    knockback_type: "push".to_string(),
+                    x,
+                    y,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let buf = pack_state_knockback(&with_knockback(Some(-24), Some(-8)))
+            .unwrap()
+            .expect("knockback record");
+        assert_eq!(i16::from_le_bytes([buf[0], buf[1]]), -24);
+        assert_eq!(i16::from_le_bytes([buf[2], buf[3]]), -8);
+
+        assert_eq!(
+            pack_state_knockback(&with_knockback(None, Some(0))),
+            Ok(None)
+        );
+        assert_eq!(pack_state_knockback(&State::default()), Ok(None));
+        let err = pack_state_knockback(&with_knockback(Some(40_000), None)).unwrap_err();
+        assert!(err.contains("knockback x 40000"), "{}", err);
+    }
+
+    #[test]
+    fn test_pack_state_pushback_q12_4_and_range() {
+        use crate::schema::Pushback;
+
+        let with_pushback = |hit, block| State {
+            input: "5H".to_string(),
+            pushback: Pushback { hit, block },
+            ..Default::default()
+        };
+
+        let buf = pack_state_pushback(&with_pushback(3, -5))
+            .unwrap()
+            .expect("pushback record");
+        assert_eq!(i16::from_le_bytes([buf[0], buf[1]]), 48);
+        assert_eq!(i16::from_le_bytes([buf[2], buf[3]]), -80);
+
+        assert_eq!(pack_state_pushback(&with_pushback(0, 0)), Ok(None));
+        assert!(pack_state_pushback(&with_pushback(2047, -2048)).is_ok());
+        let err = pack_state_pushback(&with_pushback(0, 2048)).unwrap_err();
+        assert!(
+            err.contains("pushback block 2048 is outside -2048..=2047"),
+            "{}",
+            err
+        );
+    }
 }
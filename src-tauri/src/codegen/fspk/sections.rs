@@ -1,6 +1,6 @@
 //! Section building helpers for FSPK export.
 
-use crate::codegen::fspk_format::{write_u16_le, write_u32_le, write_u8};
+use crate::codegen::fspk_format::{write_i16_le, write_u16_le, write_u32_le, write_u8};
 use crate::schema::{EventArgValue, EventEmit};
 
 use super::types::StringTable;
@@ -92,6 +92,23 @@ pub fn pack_event_emits(
     Ok((emits_off, emits_len))
 }
 
+/// Pack key-value entries into the METADATA section.
+///
+/// Each entry is key_len(2) + value_len(2) followed by the key and value bytes.
+pub fn pack_metadata(entries: &[(&str, &str)]) -> Result<Vec<u8>, String> {
+    let mut data = Vec::new();
+    for (key, value) in entries {
+        write_u16_le(&mut data, checked_u16(key.len(), "metadata key length")?);
+        write_u16_le(
+            &mut data,
+            checked_u16(value.len(), "metadata value length")?,
+        );
+        data.extend_from_slice(key.as_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+    Ok(data)
+}
+
 /// Pack resource definitions into the RESOURCE_DEFS section.
 ///
 /// Returns the packed binary data.
@@ -101,8 +118,10 @@ pub fn pack_resource_defs(
 ) -> Result<Vec<u8>, String> {
     let mut data = Vec::new();
     for res in resources {
-        let name = strings.intern(&res.name)?;
-        write_strref(&mut data, name);
+        let (name_off, name_len) = strings.intern(&res.name)?;
+        write_u32_le(&mut data, name_off);
+        write_u16_le(&mut data, name_len);
+        write_i16_le(&mut data, res.regen);
         write_u16_le(&mut data, res.start);
         write_u16_le(&mut data, res.max);
     }
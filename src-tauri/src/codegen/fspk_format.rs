@@ -36,8 +36,8 @@
 /// Magic bytes "FSPK" as a 4-byte array
 pub const MAGIC: [u8; 4] = [b'F', b'S', b'P', b'K'];
 
-/// Reserved flags value (must be 0 for v1)
-pub const FLAGS_RESERVED: u32 = 0;
+/// Header flag: states are ordered by input, then state id (see `sort_states`)
+pub const PACK_FLAG_SORTED_INPUTS: u32 = 1 << 0;
 
 // =============================================================================
 // Section Kinds (stable numeric IDs - do not reuse deleted values)
@@ -125,6 +125,114 @@ pub const SECTION_STATE_PROPS: u32 = 23;
 /// reducing property record size from 12 to 8 bytes.
 pub const SECTION_SCHEMA: u32 = 24;
 
+/// Array of StateMovement12 structs (parallel to STATES, all-zero for states without movement)
+pub const SECTION_STATE_MOVEMENT: u32 = 25;
+
+/// Per-state non-resource preconditions.
+/// Layout: index (8 bytes per state: offset u32 + count u16 + pad u16) followed by StateCondition8 records.
+/// Offsets are byte offsets from the start of this section.
+pub const SECTION_STATE_CONDITIONS: u32 = 26;
+
+/// Per-hit cancel routes for multi-hit states.
+/// Layout: index (8 bytes per state: offset u32 + count u16 + pad u16) followed by HitCancel8 records.
+/// Offsets are byte offsets from the start of this section; records are sorted by start frame.
+pub const SECTION_STATE_HIT_CANCELS: u32 = 27;
+
+/// Pack metadata for diagnostics (source character, exporter version).
+/// Layout: entries back to back, each key_len u16 + value_len u16 + key bytes + value bytes.
+/// Strings are inline rather than in STRING_TABLE so the section can be stripped.
+pub const SECTION_METADATA: u32 = 28;
+
+/// Array of StateOnUse4 structs (parallel to STATES).
+/// Present only when at least one state has `on_use.enters_state` or `on_use.gain_meter`.
+pub const SECTION_STATE_ON_USE: u32 = 29;
+
+/// Array of StateSuperFreeze6 structs (parallel to STATES).
+/// Present only when at least one state has a `super_freeze`.
+pub const SECTION_STATE_SUPER_FREEZE: u32 = 30;
+
+/// Array of StateMeterGain4 structs (parallel to STATES).
+/// Present only when at least one state has a nonzero `meter_gain`.
+pub const SECTION_STATE_METER_GAIN: u32 = 31;
+
+/// Array of StateKnockback4 structs (parallel to STATES).
+/// Present only when at least one state has a nonzero `on_hit.knockback`.
+pub const SECTION_STATE_KNOCKBACK: u32 = 32;
+
+/// Array of StatePushback4 structs (parallel to STATES).
+/// Present only when at least one state has nonzero `pushback`.
+pub const SECTION_STATE_PUSHBACK: u32 = 33;
+
+/// Metadata key for the source character id
+pub const METADATA_KEY_CHARACTER_ID: &str = "character_id";
+
+/// Metadata key for the exporter version
+pub const METADATA_KEY_EXPORTER_VERSION: &str = "exporter_version";
+
+/// Maximum number of sections a reader accepts
+pub const MAX_SECTIONS: usize = 40;
+
+/// State movement record size: start(1) + end(1) + flags(1) + pad(1) + vel_x(2) + vel_y(2) + accel_x(2) + accel_y(2) = 12 bytes
+/// Velocity and acceleration are Q8.8 pixels per frame, +x forward.
+pub const STATE_MOVEMENT12_SIZE: usize = 12;
+
+/// State on-use record size: enters_state(2) + gain_meter(2) = 4 bytes
+/// enters_state is a state index, 0xFFFF for "none".
+pub const STATE_ON_USE4_SIZE: usize = 4;
+
+/// State super freeze record size: frames(1) + flags(1) + zoom(2) + darken(1) + pad(1) = 6 bytes
+/// frames = 0 means no super freeze; zoom is Q8.8 (1.0 = no zoom).
+pub const STATE_SUPER_FREEZE6_SIZE: usize = 6;
+
+/// State meter gain record size: meter_gain_hit(2) + meter_gain_whiff(2) = 4 bytes
+pub const STATE_METER_GAIN4_SIZE: usize = 4;
+
+/// State knockback record size: x(2) + y(2) = 4 bytes
+/// Both are i16 pixels; +x pushes away from the attacker, negative y is up.
+pub const STATE_KNOCKBACK4_SIZE: usize = 4;
+
+/// State pushback record size: hit(2) + block(2) = 4 bytes
+/// Both are i16 Q12.4 pixels, pushing the defender away from the attacker.
+pub const STATE_PUSHBACK4_SIZE: usize = 4;
+
+/// State condition record size: kind(1) + arg(1) + pad(2) + min(2) + max(2) = 8 bytes
+/// min/max use 0xFFFF for "none".
+pub const STATE_CONDITION8_SIZE: usize = 8;
+
+/// State conditions index entry size: offset(4) + count(2) + pad(2)
+pub const STATE_CONDITIONS_INDEX_ENTRY_SIZE: usize = 8;
+
+/// Hit cancel record size: start(1) + end(1) + len(2) + off(4) = 8 bytes
+/// `off` is a byte offset into CANCELS_U16, `len` the number of targets.
+pub const HIT_CANCEL8_SIZE: usize = 8;
+
+/// State hit cancels index entry size: offset(4) + count(2) + pad(2)
+pub const STATE_HIT_CANCELS_INDEX_ENTRY_SIZE: usize = 8;
+
+/// Condition kind: distance to the opponent in pixels
+pub const CONDITION_KIND_DISTANCE: u8 = 1;
+
+/// Condition kind: frames a direction has been held (arg = CHARGE_DIR_*, min = frames)
+pub const CONDITION_KIND_CHARGE: u8 = 2;
+
+/// Condition kind: character is on the ground (no args)
+pub const CONDITION_KIND_GROUNDED: u8 = 3;
+
+/// Condition kind: character is in the air (no args)
+pub const CONDITION_KIND_AIRBORNE: u8 = 4;
+
+/// Charge directions (condition arg byte)
+pub const CHARGE_DIR_DOWN: u8 = 0;
+pub const CHARGE_DIR_BACK: u8 = 1;
+pub const CHARGE_DIR_UP: u8 = 2;
+pub const CHARGE_DIR_FORWARD: u8 = 3;
+
+/// Movement flag: state is airborne while moving
+pub const MOVEMENT_FLAG_AIRBORNE: u8 = 0x01;
+
+/// Super freeze flag: flash the screen when the freeze starts
+pub const SUPER_FREEZE_FLAG_FLASH: u8 = 0x01;
+
 /// Character property record size: name_off(4) + name_len(2) + type(1) + reserved(1) + value(4) = 12 bytes
 pub const CHARACTER_PROP12_SIZE: usize = 12;
 
@@ -183,6 +291,61 @@ pub const CANCEL_FLAG_SUPER: u8 = 0x04;
 /// Move can cancel into jump
 pub const CANCEL_FLAG_JUMP: u8 = 0x08;
 
+/// State is authored as airborne
+pub const STATE_FLAG_AIRBORNE: u8 = 0x20;
+
+/// State can be entered during hitstun or blockstun
+pub const STATE_FLAG_ALLOW_FROM_STUN: u8 = 0x40;
+
+// =============================================================================
+// State Type Constants (StateRecord.state_type byte)
+// =============================================================================
+
+pub const STATE_TYPE_NORMAL: u8 = 0;
+pub const STATE_TYPE_COMMAND_NORMAL: u8 = 1;
+pub const STATE_TYPE_SPECIAL: u8 = 2;
+pub const STATE_TYPE_SUPER: u8 = 3;
+pub const STATE_TYPE_MOVEMENT: u8 = 4;
+pub const STATE_TYPE_THROW: u8 = 5;
+pub const STATE_TYPE_EX: u8 = 6;
+pub const STATE_TYPE_REKKA: u8 = 7;
+/// Engine-driven states (idle, crouch, hitstun, ...)
+pub const STATE_TYPE_SYSTEM: u8 = 8;
+/// Custom move type not known to the runtime
+pub const STATE_TYPE_UNKNOWN: u8 = 255;
+
+// =============================================================================
+// Hurt Window Flag Constants (HurtWindow12.hurt_flags, low byte)
+// =============================================================================
+
+/// Hurtbox ignores strike (non-throw, non-projectile) hits
+pub const HURT_FLAG_STRIKE_INVULN: u8 = 0x01;
+
+/// Hurtbox ignores throws
+pub const HURT_FLAG_THROW_INVULN: u8 = 0x02;
+
+/// Hurtbox ignores projectiles
+pub const HURT_FLAG_PROJECTILE_INVULN: u8 = 0x04;
+
+/// Hurtbox ignores every kind of hit
+pub const HURT_FLAG_FULL_INVULN: u8 = 0x08;
+
+/// Hurtbox takes hits but does not enter hitstun
+pub const HURT_FLAG_ARMOR: u8 = 0x10;
+
+// =============================================================================
+// Hit Window Flag Constants (HitWindow24.flags)
+// =============================================================================
+
+/// Hit window is a throw (unblockable, grounded targets only)
+pub const HIT_FLAG_THROW: u8 = 0x01;
+
+/// Hit bounces the defender off the wall
+pub const HIT_FLAG_WALL_BOUNCE: u8 = 0x02;
+
+/// Hit bounces the defender off the ground
+pub const HIT_FLAG_GROUND_BOUNCE: u8 = 0x04;
+
 // =============================================================================
 // Shape Kind Constants
 // =============================================================================
@@ -196,7 +359,7 @@ pub const SHAPE_KIND_RECT: u8 = 1;
 /// Circle: x=a, y=b, r=u16(c)
 pub const SHAPE_KIND_CIRCLE: u8 = 2;
 
-/// Capsule: x1=a, y1=b, x2=c, y2=d, r=u16(e)
+/// Capsule: x1=a, y1=b, x2=c, y2=d, r=e (Q8.8)
 pub const SHAPE_KIND_CAPSULE: u8 = 3;
 
 // =============================================================================
@@ -270,6 +433,12 @@ pub fn to_q8_8_degrees(degrees: f32) -> i16 {
     (degrees * 256.0).round() as i16
 }
 
+/// Convert a floating-point value to Q8.8 fixed point
+#[inline]
+pub fn to_q8_8(value: f32) -> i16 {
+    (value * 256.0).round() as i16
+}
+
 /// Convert a floating-point value to Q24.8 fixed point.
 /// Range: ±8,388,607.996, Precision: 1/256 ≈ 0.0039
 #[inline]
@@ -337,8 +506,11 @@ mod tests {
     }
 
     #[test]
-    fn test_flags_reserved_is_zero() {
-        assert_eq!(FLAGS_RESERVED, 0);
+    fn test_pack_flags_match_reader() {
+        assert_eq!(
+            PACK_FLAG_SORTED_INPUTS,
+            framesmith_fspack::PACK_FLAG_SORTED_INPUTS
+        );
     }
 
     #[test]
@@ -367,6 +539,16 @@ mod tests {
             SECTION_CHARACTER_PROPS,
             SECTION_PUSH_WINDOWS,
             SECTION_STATE_PROPS,
+            SECTION_SCHEMA,
+            SECTION_STATE_MOVEMENT,
+            SECTION_STATE_CONDITIONS,
+            SECTION_STATE_HIT_CANCELS,
+            SECTION_METADATA,
+            SECTION_STATE_ON_USE,
+            SECTION_STATE_SUPER_FREEZE,
+            SECTION_STATE_METER_GAIN,
+            SECTION_STATE_KNOCKBACK,
+            SECTION_STATE_PUSHBACK,
         ];
         let mut sorted = kinds;
         sorted.sort();
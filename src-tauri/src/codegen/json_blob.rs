@@ -59,6 +59,7 @@ mod tests {
             character,
             moves,
             cancel_table: CancelTable::default(),
+            variant_errors: Vec::new(),
         };
 
         let json = export_json_blob(&data).unwrap();
@@ -97,6 +98,7 @@ mod tests {
             character,
             moves: states,
             cancel_table,
+            variant_errors: Vec::new(),
         };
 
         let json = export_json_blob(&char_data).unwrap();
@@ -2,5 +2,5 @@ mod fspk;
 pub mod fspk_format;
 mod json_blob;
 
-pub use fspk::export_fspk;
+pub use fspk::{export_fspk, export_fspk_big_endian, fspk_export_warnings, sort_states};
 pub use json_blob::{export_json_blob, export_json_blob_pretty};
@@ -1,8 +1,12 @@
 use crate::codegen::export_fspk;
+use crate::rules::RulesFile;
 use crate::schema::{CancelTable, Character, CharacterAssets, PropertyValue, State};
 use base64::Engine;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
 
 fn validate_asset_relative_path(relative_path: &str) -> Result<PathBuf, String> {
     if relative_path.is_empty() {
@@ -36,6 +40,34 @@ pub(super) fn project_rules_path(characters_dir: &str) -> PathBuf {
     project_root.join("framesmith.rules.json")
 }
 
+/// Load the project rules and a character's own `rules.json`, either of which may be absent.
+pub(super) fn load_character_rules(
+    characters_dir: &str,
+    char_path: &Path,
+) -> Result<(Option<RulesFile>, Option<RulesFile>), String> {
+    let project_rules_path = project_rules_path(characters_dir);
+    let project_rules =
+        crate::rules::load_rules_file_resolved(&project_rules_path).map_err(|e| {
+            format!(
+                "Failed to load project rules file {}: {}",
+                project_rules_path.display(),
+                e
+            )
+        })?;
+
+    let character_rules_path = char_path.join("rules.json");
+    let character_rules =
+        crate::rules::load_rules_file_resolved(&character_rules_path).map_err(|e| {
+            format!(
+                "Failed to load character rules file {}: {}",
+                character_rules_path.display(),
+                e
+            )
+        })?;
+
+    Ok((project_rules, character_rules))
+}
+
 /// Named states: Vec<(filename_without_extension, State)>
 pub(super) type NamedStates = Vec<(String, State)>;
 
@@ -141,6 +173,18 @@ pub struct CharacterData {
     pub character: Character,
     pub moves: Vec<State>,
     pub cancel_table: CancelTable,
+    /// Variants that couldn't be resolved when loading; they are left out of `moves`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variant_errors: Vec<String>,
+}
+
+/// Outcome of [`save_move`]: whether the state file was written, and every
+/// validation issue found either way.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SaveMoveResult {
+    /// False when validation errors blocked the save.
+    pub saved: bool,
+    pub issues: crate::rules::IssuesBySeverity,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -183,25 +227,10 @@ pub fn load_character(
         load_character_files(&characters_dir, &character_id)?;
 
     let all_named_moves = resolve_and_merge_globals(&characters_dir, &char_path, named_moves)?;
-    let moves = crate::variant::flatten_variants(all_named_moves)?;
-
-    let project_rules_path = project_rules_path(&characters_dir);
-    let project_rules = crate::rules::load_rules_file(&project_rules_path).map_err(|e| {
-        format!(
-            "Failed to load project rules file {}: {}",
-            project_rules_path.display(),
-            e
-        )
-    })?;
+    // Broken variants don't stop the editor from loading; they're reported instead.
+    let (moves, variant_errors) = crate::variant::flatten_variants_lenient(all_named_moves);
 
-    let character_rules_path = char_path.join("rules.json");
-    let character_rules = crate::rules::load_rules_file(&character_rules_path).map_err(|e| {
-        format!(
-            "Failed to load character rules file {}: {}",
-            character_rules_path.display(),
-            e
-        )
-    })?;
+    let (project_rules, character_rules) = load_character_rules(&characters_dir, &char_path)?;
 
     let mut resolved_moves = Vec::with_capacity(moves.len());
     for mv in moves {
@@ -215,15 +244,39 @@ pub fn load_character(
     }
 
     // Canonicalize move ordering so indices are deterministic and match exporter/runtime.
-    resolved_moves.sort_by(|a, b| a.input.cmp(&b.input));
+    crate::codegen::sort_states(&mut resolved_moves);
 
     Ok(CharacterData {
         character,
         moves: resolved_moves,
         cancel_table,
+        variant_errors,
     })
 }
 
+/// Resolve one stored move through the apply rules without saving it.
+///
+/// Returns the move as [`load_character`] would, so the editor can show which
+/// fields the rules fill in. Nothing on disk is modified.
+#[tauri::command]
+pub fn preview_resolved_move(
+    characters_dir: String,
+    character_id: String,
+    move_input: String,
+) -> Result<State, String> {
+    let (char_path, _, named_moves, _) = load_character_files(&characters_dir, &character_id)?;
+
+    let all_named_moves = resolve_and_merge_globals(&characters_dir, &char_path, named_moves)?;
+    let mv = crate::variant::flatten_variants(all_named_moves)?
+        .into_iter()
+        .find(|mv| mv.input == move_input)
+        .ok_or_else(|| format!("Move '{}' not found", move_input))?;
+
+    let (project_rules, character_rules) = load_character_rules(&characters_dir, &char_path)?;
+    crate::rules::apply_rules_to_move(project_rules.as_ref(), character_rules.as_ref(), &mv)
+        .map_err(|e| format!("Failed to apply rules to move '{}': {}", mv.input, e))
+}
+
 #[tauri::command]
 pub fn load_character_assets(
     characters_dir: String,
@@ -280,8 +333,19 @@ pub fn read_character_asset_base64(
     Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
 }
 
+/// Validate a move and write it to the character's `states/` directory.
+///
+/// Validation always runs and its issues come back grouped by severity. With
+/// `block_on_error` (the default), a move with errors is not written and the
+/// result has `saved: false`; otherwise it is written regardless. Warnings and
+/// info notes never block.
 #[tauri::command]
-pub fn save_move(characters_dir: String, character_id: String, mv: State) -> Result<(), String> {
+pub fn save_move(
+    characters_dir: String,
+    character_id: String,
+    mv: State,
+    block_on_error: Option<bool>,
+) -> Result<SaveMoveResult, String> {
     // Validate character_id to prevent path traversal
     if character_id.contains("..") || character_id.contains('/') || character_id.contains('\\') {
         return Err("Invalid character ID".to_string());
@@ -295,23 +359,7 @@ pub fn save_move(characters_dir: String, character_id: String, mv: State) -> Res
     let char_path = Path::new(&characters_dir).join(&character_id);
 
     // Load rules for registry-aware validation.
-    let project_rules_path = project_rules_path(&characters_dir);
-    let project_rules = crate::rules::load_rules_file(&project_rules_path).map_err(|e| {
-        format!(
-            "Failed to load project rules file {}: {}",
-            project_rules_path.display(),
-            e
-        )
-    })?;
-
-    let character_rules_path = char_path.join("rules.json");
-    let character_rules = crate::rules::load_rules_file(&character_rules_path).map_err(|e| {
-        format!(
-            "Failed to load character rules file {}: {}",
-            character_rules_path.display(),
-            e
-        )
-    })?;
+    let (project_rules, character_rules) = load_character_rules(&characters_dir, &char_path)?;
 
     // Load character.json for resource validation.
     let char_file = char_path.join("character.json");
@@ -325,6 +373,7 @@ pub fn save_move(characters_dir: String, character_id: String, mv: State) -> Res
     for issue in issues.iter_mut() {
         issue.field = format!("character.{}", issue.field);
     }
+    issues.extend(crate::rules::validate_registry_move_types(&registry));
 
     let move_issues = crate::rules::validate_move_with_rules(
         project_rules.as_ref(),
@@ -334,14 +383,34 @@ pub fn save_move(characters_dir: String, character_id: String, mv: State) -> Res
     .map_err(|e| format!("Failed to validate move '{}': {}", mv.input, e))?;
     issues.extend(move_issues);
 
-    let errors: Vec<String> = issues
-        .into_iter()
-        .filter(|i| i.severity == crate::rules::Severity::Error)
-        .map(|i| format!("{}: {}", i.field, i.message))
-        .collect();
+    // Flag animations missing from assets.json, when the character has one.
+    // An unreadable manifest is a warning too; it shouldn't stop the save.
+    let assets_file = char_path.join("assets.json");
+    if assets_file.exists() {
+        let assets = fs::read_to_string(&assets_file)
+            .map_err(|e| format!("Failed to read assets.json: {}", e))
+            .and_then(|content| {
+                serde_json::from_str::<CharacterAssets>(&content)
+                    .map_err(|e| format!("Invalid assets.json format: {}", e))
+            });
+        match assets {
+            Ok(assets) => issues.extend(crate::rules::validate_move_animation_with_assets(
+                &mv, &assets,
+            )),
+            Err(message) => issues.push(crate::rules::ValidationIssue {
+                field: "animation".to_string(),
+                message: format!("Animation not checked: {}", message),
+                severity: crate::rules::Severity::Warning,
+            }),
+        }
+    }
 
-    if !errors.is_empty() {
-        return Err(format!("Validation errors: {}", errors.join("; ")));
+    let issues = crate::rules::IssuesBySeverity::from_issues(issues);
+    if issues.has_errors() && block_on_error.unwrap_or(true) {
+        return Ok(SaveMoveResult {
+            saved: false,
+            issues,
+        });
     }
 
     let state_path = char_path
@@ -352,7 +421,10 @@ pub fn save_move(characters_dir: String, character_id: String, mv: State) -> Res
         .map_err(|e| format!("Failed to serialize move: {}", e))?;
     fs::write(&state_path, content).map_err(|e| format!("Failed to write state file: {}", e))?;
 
-    Ok(())
+    Ok(SaveMoveResult {
+        saved: true,
+        issues,
+    })
 }
 
 #[tauri::command]
@@ -476,8 +548,94 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
     Ok(())
 }
 
+/// A value in another character's data that names a deleted character.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DanglingReference {
+    /// Character whose file holds the reference.
+    pub character: String,
+    /// File within that character's directory, e.g. `globals.json`.
+    pub file: String,
+    /// JSON pointer to the referencing value, e.g. `/includes/0/state`.
+    pub path: String,
+}
+
+/// Outcome of [`delete_character`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeleteCharacterResult {
+    /// Values in other characters' globals and cancel tables that named the
+    /// deleted character and now dangle.
+    pub dangling_references: Vec<DanglingReference>,
+}
+
+/// Character files that may name other characters.
+const CHARACTER_REFERENCE_FILES: [&str; 2] = ["globals.json", "cancel_table.json"];
+
+/// Find string values equal to `character_id` in the other characters' reference files.
+///
+/// Files that are missing or aren't valid JSON are skipped.
+fn find_character_references(characters_dir: &Path, character_id: &str) -> Vec<DanglingReference> {
+    fn walk(value: &serde_json::Value, target: &str, pointer: &mut String, out: &mut Vec<String>) {
+        match value {
+            serde_json::Value::String(s) if s == target => out.push(pointer.clone()),
+            serde_json::Value::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    let len = pointer.len();
+                    pointer.push_str(&format!("/{i}"));
+                    walk(item, target, pointer, out);
+                    pointer.truncate(len);
+                }
+            }
+            serde_json::Value::Object(map) => {
+                for (key, item) in map {
+                    let len = pointer.len();
+                    pointer.push('/');
+                    pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                    walk(item, target, pointer, out);
+                    pointer.truncate(len);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Ok(entries) = fs::read_dir(characters_dir) else {
+        return Vec::new();
+    };
+    let mut others: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|id| id != character_id)
+        .collect();
+    others.sort();
+
+    let mut references = Vec::new();
+    for other in others {
+        for file in CHARACTER_REFERENCE_FILES {
+            let Ok(content) = fs::read_to_string(characters_dir.join(&other).join(file)) else {
+                continue;
+            };
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(&content) else {
+                continue;
+            };
+            let mut paths = Vec::new();
+            walk(&value, character_id, &mut String::new(), &mut paths);
+            references.extend(paths.into_iter().map(|path| DanglingReference {
+                character: other.clone(),
+                file: file.to_string(),
+                path,
+            }));
+        }
+    }
+    references
+}
+
+/// Delete a character's directory and report references to it that now dangle.
 #[tauri::command]
-pub fn delete_character(characters_dir: String, character_id: String) -> Result<(), String> {
+pub fn delete_character(
+    characters_dir: String,
+    character_id: String,
+) -> Result<DeleteCharacterResult, String> {
     validate_character_id(&character_id)?;
 
     let char_path = Path::new(&characters_dir).join(&character_id);
@@ -491,7 +649,9 @@ pub fn delete_character(characters_dir: String, character_id: String) -> Result<
     fs::remove_dir_all(&char_path)
         .map_err(|e| format!("Failed to delete character: {}", e))?;
 
-    Ok(())
+    Ok(DeleteCharacterResult {
+        dangling_references: find_character_references(Path::new(&characters_dir), &character_id),
+    })
 }
 
 pub fn validate_move_input(input: &str) -> Result<(), String> {
@@ -582,6 +742,9 @@ pub fn create_move(
         costs: None,
         movement: None,
         super_freeze: None,
+        kara: None,
+        airborne: None,
+        allow_from_stun: None,
         on_use: None,
         on_hit: None,
         on_block: None,
@@ -601,37 +764,127 @@ pub fn create_move(
     Ok(mv)
 }
 
+/// FSPK bytes for a character plus a hash of those bytes.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct CharacterFspk {
+    /// Base64-encoded FSPK data.
+    pub data: String,
+    /// Hex FNV-1a hash of the pack; identical packs have identical hashes.
+    pub hash: String,
+}
+
+/// Size and modification time of one export input (None if it doesn't exist).
+type FileStamp = (PathBuf, Option<(u64, Option<SystemTime>)>);
+
+struct CachedFspk {
+    sources: Vec<FileStamp>,
+    pack: CharacterFspk,
+}
+
+/// Exported packs keyed by character directory, reused until a source file changes.
+fn fspk_cache() -> &'static Mutex<HashMap<PathBuf, CachedFspk>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, CachedFspk>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn json_files_in(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().map(|e| e == "json").unwrap_or(false))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Stamp every file that feeds the export: character, cancel table, states,
+/// character and project rules, and the globals manifest and global states.
+pub(super) fn fspk_source_stamps(characters_dir: &str, char_path: &Path) -> Vec<FileStamp> {
+    let mut paths = vec![
+        char_path.join("character.json"),
+        char_path.join("cancel_table.json"),
+        char_path.join("rules.json"),
+        char_path.join("globals.json"),
+        project_rules_path(characters_dir),
+    ];
+    paths.extend(crate::rules::rules_include_paths(
+        &char_path.join("rules.json"),
+    ));
+    paths.extend(crate::rules::rules_include_paths(&project_rules_path(
+        characters_dir,
+    )));
+    paths.extend(json_files_in(&char_path.join("states")));
+    if let Some(project_dir) = Path::new(characters_dir).parent() {
+        paths.extend(json_files_in(&project_dir.join("globals").join("states")));
+    }
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let stamp = fs::metadata(&path)
+                .ok()
+                .map(|meta| (meta.len(), meta.modified().ok()));
+            (path, stamp)
+        })
+        .collect()
+}
+
+fn fnv1a_hex(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
 /// Get FSPK bytes for a character (for training mode WASM runtime).
 ///
-/// Returns the FSPK data as base64-encoded string.
+/// The export is cached per character and only rebuilt when one of its source
+/// files (states, character, cancel table, rules or globals) changes size or
+/// modification time. The returned hash lets the frontend skip re-parsing a
+/// pack it already has.
 #[tauri::command]
 pub fn get_character_fspk(
     characters_dir: String,
     character_id: String,
-) -> Result<String, String> {
+) -> Result<CharacterFspk, String> {
+    // Invalid IDs never reach the cache: build_character_fspk rejects them first.
+    let char_path = Path::new(&characters_dir).join(&character_id);
+    let sources = fspk_source_stamps(&characters_dir, &char_path);
+
+    if let Some(cached) = fspk_cache()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .get(&char_path)
+        .filter(|cached| cached.sources == sources)
+    {
+        return Ok(cached.pack.clone());
+    }
+
+    let bytes = build_character_fspk(&characters_dir, &character_id)?;
+    let pack = CharacterFspk {
+        data: base64::engine::general_purpose::STANDARD.encode(&bytes),
+        hash: fnv1a_hex(&bytes),
+    };
+    fspk_cache().lock().map_err(|e| e.to_string())?.insert(
+        char_path,
+        CachedFspk {
+            sources,
+            pack: pack.clone(),
+        },
+    );
+    Ok(pack)
+}
+
+/// Load, validate and export a character's pack without touching the cache.
+fn build_character_fspk(characters_dir: &str, character_id: &str) -> Result<Vec<u8>, String> {
     let (char_path, character, named_moves, cancel_table) =
-        load_character_files(&characters_dir, &character_id)?;
+        load_character_files(characters_dir, character_id)?;
 
-    let all_named_moves = resolve_and_merge_globals(&characters_dir, &char_path, named_moves)?;
+    let all_named_moves = resolve_and_merge_globals(characters_dir, &char_path, named_moves)?;
     let base_moves = crate::variant::flatten_variants(all_named_moves)?;
 
-    let project_rules_path = project_rules_path(&characters_dir);
-    let project_rules = crate::rules::load_rules_file(&project_rules_path).map_err(|e| {
-        format!(
-            "Failed to load project rules file {}: {}",
-            project_rules_path.display(),
-            e
-        )
-    })?;
-
-    let character_rules_path = char_path.join("rules.json");
-    let character_rules = crate::rules::load_rules_file(&character_rules_path).map_err(|e| {
-        format!(
-            "Failed to load character rules file {}: {}",
-            character_rules_path.display(),
-            e
-        )
-    })?;
+    let (project_rules, character_rules) = load_character_rules(characters_dir, &char_path)?;
 
     let mut error_messages = Vec::new();
 
@@ -644,6 +897,11 @@ pub fn get_character_fspk(
             .filter(|i| i.severity == crate::rules::Severity::Error)
             .map(|i| format!("character {}: {}", i.field, i.message)),
     );
+    error_messages.extend(
+        crate::rules::validate_registry_move_types(&registry)
+            .into_iter()
+            .map(|i| format!("{}: {}", i.field, i.message)),
+    );
 
     let mut resolved_moves = Vec::with_capacity(base_moves.len());
     for mv in base_moves {
@@ -678,12 +936,41 @@ pub fn get_character_fspk(
         character,
         moves: resolved_moves,
         cancel_table,
+        variant_errors: Vec::new(),
     };
 
     let merged_rules =
         crate::rules::MergedRules::merge(project_rules.as_ref(), character_rules.as_ref());
-    let bytes = export_fspk(&char_data, Some(&merged_rules))?;
-    Ok(base64::engine::general_purpose::STANDARD.encode(&bytes))
+    export_fspk(&char_data, Some(&merged_rules))
+}
+
+/// Export the player and dummy characters and check both can run in a
+/// `TrainingSession`.
+///
+/// # Errors
+/// Names the first character that fails to export, parse, or pass
+/// `validate_runtime_pack`.
+pub fn validate_training_pair(
+    characters_dir: &str,
+    character_id: &str,
+    dummy_id: &str,
+) -> Result<(), String> {
+    let roles: &[(&str, &str)] = if dummy_id == character_id {
+        &[("character", character_id)]
+    } else {
+        &[("player", character_id), ("dummy", dummy_id)]
+    };
+    for &(role, id) in roles {
+        validate_training_pack(characters_dir, id)
+            .map_err(|e| format!("Training {} '{}' is not runnable: {}", role, id, e))?;
+    }
+    Ok(())
+}
+
+fn validate_training_pack(characters_dir: &str, character_id: &str) -> Result<(), String> {
+    let bytes = build_character_fspk(characters_dir, character_id)?;
+    let pack = framesmith_fspack::PackView::parse(&bytes).map_err(|e| format!("{:?}", e))?;
+    framesmith_runtime::validate_runtime_pack(&pack).map_err(|e| format!("{:?}", e))
 }
 
 #[tauri::command]
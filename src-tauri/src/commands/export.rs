@@ -1,10 +1,13 @@
-use crate::codegen::{export_fspk, export_json_blob, export_json_blob_pretty};
+use crate::codegen::{
+    export_fspk, export_fspk_big_endian, export_json_blob, export_json_blob_pretty,
+    fspk_export_warnings,
+};
 use crate::schema::State;
 use std::fs;
 
 // Import CharacterData and internal helpers from the character module
 use super::character::{
-    load_character_files, project_rules_path, resolve_and_merge_globals, CharacterData,
+    load_character_files, load_character_rules, resolve_and_merge_globals, CharacterData,
 };
 
 #[tauri::command]
@@ -21,23 +24,7 @@ pub fn export_character(
     let all_named_moves = resolve_and_merge_globals(&characters_dir, &char_path, named_moves)?;
     let base_moves = crate::variant::flatten_variants(all_named_moves)?;
 
-    let project_rules_path = project_rules_path(&characters_dir);
-    let project_rules = crate::rules::load_rules_file(&project_rules_path).map_err(|e| {
-        format!(
-            "Failed to load project rules file {}: {}",
-            project_rules_path.display(),
-            e
-        )
-    })?;
-
-    let character_rules_path = char_path.join("rules.json");
-    let character_rules = crate::rules::load_rules_file(&character_rules_path).map_err(|e| {
-        format!(
-            "Failed to load character rules file {}: {}",
-            character_rules_path.display(),
-            e
-        )
-    })?;
+    let (project_rules, character_rules) = load_character_rules(&characters_dir, &char_path)?;
 
     let mut error_messages = Vec::new();
 
@@ -49,6 +36,11 @@ pub fn export_character(
             .filter(|i| i.severity == crate::rules::Severity::Error)
             .map(|i| format!("character {}: {}", i.field, i.message)),
     );
+    error_messages.extend(
+        crate::rules::validate_registry_move_types(&registry)
+            .into_iter()
+            .map(|i| format!("{}: {}", i.field, i.message)),
+    );
 
     let mut resolved_moves = Vec::with_capacity(base_moves.len());
     for mv in base_moves {
@@ -83,6 +75,7 @@ pub fn export_character(
         character,
         moves: resolved_moves,
         cancel_table,
+        variant_errors: Vec::new(),
     };
 
     let output = match adapter.as_str() {
@@ -93,10 +86,17 @@ pub fn export_character(
                 export_json_blob(&char_data)?
             }
         }
-        "fspk" => {
+        "fspk" | "fspk-be" => {
+            for warning in fspk_export_warnings(&char_data) {
+                eprintln!("Warning: {}", warning);
+            }
             let merged_rules =
                 crate::rules::MergedRules::merge(project_rules.as_ref(), character_rules.as_ref());
-            let bytes = export_fspk(&char_data, Some(&merged_rules))?;
+            let bytes = if adapter == "fspk-be" {
+                export_fspk_big_endian(&char_data, Some(&merged_rules))?
+            } else {
+                export_fspk(&char_data, Some(&merged_rules))?
+            };
             fs::write(&output_path, bytes)
                 .map_err(|e| format!("Failed to write export file: {}", e))?;
             return Ok(());
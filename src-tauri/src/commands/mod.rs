@@ -6,8 +6,10 @@ pub mod project;
 // Re-export all command functions for backward compatibility
 pub use character::{
     clone_character, create_character, create_move, delete_character, get_character_fspk,
-    get_character_globals, load_character, load_character_assets, read_character_asset_base64,
-    save_character_globals, save_move, CharacterData, CharacterSummary,
+    get_character_globals, load_character, load_character_assets, preview_resolved_move,
+    read_character_asset_base64, save_character_globals, save_move, validate_training_pair,
+    CharacterData, CharacterFspk, CharacterSummary, DanglingReference, DeleteCharacterResult,
+    SaveMoveResult,
 };
 
 pub use export::{
@@ -137,6 +139,242 @@ mod tests {
         assert_eq!(inputs, vec!["5L", "5M"]);
     }
 
+    /// Character with a single 5L state, ready to export.
+    fn setup_exportable_character(temp_dir: &TempDir) -> String {
+        let characters_dir = setup_test_character(temp_dir);
+        let char_dir = Path::new(&characters_dir).join("test-char");
+        fs::write(
+            char_dir.join("character.json"),
+            r#"{ "id": "test-char", "name": "Test", "resources": [] }"#,
+        )
+        .unwrap();
+        fs::write(
+            char_dir.join("states").join("5L.json"),
+            r#"{ "input": "5L", "startup": 5, "active": 2, "recovery": 10, "damage": 500 }"#,
+        )
+        .unwrap();
+        characters_dir
+    }
+
+    #[test]
+    fn test_get_character_fspk_reuses_cached_pack() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+
+        let first = get_character_fspk(characters_dir.clone(), "test-char".to_string()).unwrap();
+        let second = get_character_fspk(characters_dir, "test-char".to_string()).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.hash.len(), 16);
+    }
+
+    #[test]
+    fn test_get_character_fspk_hash_changes_after_move_edit() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+        let before = get_character_fspk(characters_dir.clone(), "test-char".to_string()).unwrap();
+
+        fs::write(
+            Path::new(&characters_dir)
+                .join("test-char")
+                .join("states")
+                .join("5L.json"),
+            r#"{ "input": "5L", "startup": 5, "active": 2, "recovery": 10, "damage": 1200 }"#,
+        )
+        .unwrap();
+        let after = get_character_fspk(characters_dir, "test-char".to_string()).unwrap();
+
+        assert_ne!(before.hash, after.hash);
+        assert_ne!(before.data, after.data);
+    }
+
+    #[test]
+    fn test_get_character_fspk_rebuilds_after_rules_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+        let before = get_character_fspk(characters_dir.clone(), "test-char".to_string()).unwrap();
+
+        fs::write(
+            temp_dir.path().join("framesmith.rules.json"),
+            r#"{ "version": 1, "apply": [{ "match": {}, "set": { "hitstop": 3 } }], "validate": [] }"#,
+        )
+        .unwrap();
+        let after = get_character_fspk(characters_dir, "test-char".to_string()).unwrap();
+
+        assert_ne!(before.hash, after.hash);
+    }
+
+    #[test]
+    fn test_validate_training_pair_accepts_exportable_characters() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+
+        assert_eq!(
+            validate_training_pair(&characters_dir, "test-char", "test-char"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_training_pair_reports_unexportable_dummy() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+        let broken_dir = Path::new(&characters_dir).join("broken");
+        fs::create_dir_all(broken_dir.join("states")).unwrap();
+        fs::write(
+            broken_dir.join("character.json"),
+            r#"{ "id": "broken", "name": "Broken", "resources": [] }"#,
+        )
+        .unwrap();
+        // 300 total frames is past the runtime's 255-frame limit, so export fails.
+        fs::write(
+            broken_dir.join("states").join("5L.json"),
+            r#"{ "input": "5L", "startup": 100, "active": 100, "recovery": 100 }"#,
+        )
+        .unwrap();
+
+        let err = validate_training_pair(&characters_dir, "test-char", "broken").unwrap_err();
+        assert!(
+            err.starts_with("Training dummy 'broken' is not runnable: "),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_preview_resolved_move_applies_rules_without_saving() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+        fs::write(
+            temp_dir.path().join("framesmith.rules.json"),
+            r#"{ "version": 1, "apply": [{ "match": {}, "set": { "hitstop": 8 } }], "validate": [] }"#,
+        )
+        .unwrap();
+        let state_path = Path::new(&characters_dir)
+            .join("test-char")
+            .join("states")
+            .join("5L.json");
+        let stored = fs::read_to_string(&state_path).unwrap();
+
+        let preview = preview_resolved_move(
+            characters_dir.clone(),
+            "test-char".to_string(),
+            "5L".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(preview.input, "5L");
+        assert_eq!(preview.hitstop, 8);
+        assert_eq!(fs::read_to_string(&state_path).unwrap(), stored);
+
+        let err = preview_resolved_move(characters_dir, "test-char".to_string(), "2H".to_string())
+            .unwrap_err();
+        assert_eq!(err, "Move '2H' not found");
+    }
+
+    #[test]
+    fn test_save_move_blocks_on_validation_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+        let state_path = Path::new(&characters_dir)
+            .join("test-char")
+            .join("states")
+            .join("5M.json");
+        let mv = crate::schema::State {
+            input: "5M".to_string(),
+            startup: 0,
+            active: 2,
+            ..Default::default()
+        };
+
+        let blocked = save_move(
+            characters_dir.clone(),
+            "test-char".to_string(),
+            mv.clone(),
+            Some(true),
+        )
+        .unwrap();
+        assert!(!blocked.saved);
+        assert!(blocked
+            .issues
+            .errors
+            .iter()
+            .any(|i| i.field == "startup" && i.message == "startup must be at least 1 frame"));
+        assert!(!state_path.exists());
+
+        // The default blocks too.
+        let default = save_move(
+            characters_dir.clone(),
+            "test-char".to_string(),
+            mv.clone(),
+            None,
+        )
+        .unwrap();
+        assert!(!default.saved);
+        assert!(!state_path.exists());
+
+        let forced = save_move(characters_dir, "test-char".to_string(), mv, Some(false)).unwrap();
+        assert!(forced.saved);
+        assert_eq!(forced.issues, blocked.issues);
+        assert!(state_path.exists());
+    }
+
+    #[test]
+    fn test_save_move_warns_on_malformed_assets_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_exportable_character(&temp_dir);
+        let char_path = Path::new(&characters_dir).join("test-char");
+        fs::write(char_path.join("assets.json"), "{ not json").unwrap();
+        let mv = crate::schema::State {
+            input: "5M".to_string(),
+            startup: 3,
+            active: 2,
+            recovery: 5,
+            animation: "5M".to_string(),
+            ..Default::default()
+        };
+
+        let result = save_move(characters_dir, "test-char".to_string(), mv, None).unwrap();
+        assert!(result.saved);
+        assert!(result.issues.errors.is_empty());
+        assert!(result
+            .issues
+            .warnings
+            .iter()
+            .any(|i| i.field == "animation" && i.message.starts_with("Animation not checked: ")));
+        assert!(char_path.join("states").join("5M.json").exists());
+    }
+
+    #[test]
+    fn test_delete_character_reports_dangling_references() {
+        let temp_dir = TempDir::new().unwrap();
+        let characters_dir = setup_test_character(&temp_dir);
+        let other_dir = Path::new(&characters_dir).join("other-char");
+        fs::create_dir_all(&other_dir).unwrap();
+        let globals = serde_json::json!({
+            "includes": [
+                { "state": "burst", "as": "5A" },
+                { "state": "test-char", "as": "5B" },
+            ]
+        });
+        fs::write(other_dir.join("globals.json"), globals.to_string()).unwrap();
+
+        let result = delete_character(characters_dir.clone(), "test-char".to_string()).unwrap();
+
+        assert!(!Path::new(&characters_dir).join("test-char").exists());
+        assert_eq!(
+            result.dangling_references,
+            vec![DanglingReference {
+                character: "other-char".to_string(),
+                file: "globals.json".to_string(),
+                path: "/includes/1/state".to_string(),
+            }]
+        );
+
+        let unreferenced = delete_character(characters_dir, "other-char".to_string()).unwrap();
+        assert!(unreferenced.dangling_references.is_empty());
+    }
+
     #[test]
     fn test_validate_move_input_empty() {
         let result = character::validate_move_input("");
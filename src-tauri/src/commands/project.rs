@@ -26,11 +26,6 @@ pub struct MergedRegistry {
     pub chain_order: Option<Vec<String>>,
 }
 
-fn project_rules_path(characters_dir: &str) -> std::path::PathBuf {
-    let project_root = Path::new(characters_dir).parent().unwrap_or(Path::new("."));
-    project_root.join("framesmith.rules.json")
-}
-
 #[tauri::command]
 pub async fn open_folder_dialog(app: tauri::AppHandle) -> Option<String> {
     let folder = app
@@ -170,23 +165,8 @@ pub fn load_rules_registry(
         return Err(format!("Character '{}' not found", character_id));
     }
 
-    let project_rules_path = project_rules_path(&characters_dir);
-    let project_rules = crate::rules::load_rules_file(&project_rules_path).map_err(|e| {
-        format!(
-            "Failed to load project rules file {}: {}",
-            project_rules_path.display(),
-            e
-        )
-    })?;
-
-    let character_rules_path = char_path.join("rules.json");
-    let character_rules = crate::rules::load_rules_file(&character_rules_path).map_err(|e| {
-        format!(
-            "Failed to load character rules file {}: {}",
-            character_rules_path.display(),
-            e
-        )
-    })?;
+    let (project_rules, character_rules) =
+        super::character::load_character_rules(&characters_dir, &char_path)?;
 
     let registry = crate::rules::merged_registry(project_rules.as_ref(), character_rules.as_ref());
 
@@ -201,17 +181,24 @@ pub fn load_rules_registry(
 ///
 /// Creates a new browser window for training mode that can run independently
 /// of the main editor window. If the window already exists, focuses it instead.
+///
+/// The character is exported and validated first (training runs it against
+/// itself as the dummy), so a pack the training session can't load is
+/// reported here and no window opens.
 #[tauri::command]
 pub async fn open_training_window(
     app: tauri::AppHandle,
+    characters_dir: String,
     character_id: String,
 ) -> Result<(), String> {
+    use tauri::Manager;
     use tauri::WebviewUrl;
     use tauri::WebviewWindowBuilder;
-    use tauri::Manager;
 
     const WINDOW_LABEL: &str = "training-detached";
 
+    super::character::validate_training_pair(&characters_dir, &character_id, &character_id)?;
+
     // Check if window already exists
     if let Some(existing_window) = app.get_webview_window(WINDOW_LABEL) {
         // Window exists, just focus it
@@ -10,8 +10,9 @@ use commands::{
     clone_character, create_character, create_move, create_project, delete_character,
     delete_global_state, export_character, get_character_fspk, get_character_globals,
     get_global_state, list_characters, list_global_states, load_character, load_character_assets,
-    load_rules_registry, open_folder_dialog, open_training_window, read_character_asset_base64,
-    save_character_globals, save_global_state, save_move, validate_project,
+    load_rules_registry, open_folder_dialog, open_training_window, preview_resolved_move,
+    read_character_asset_base64, save_character_globals, save_global_state, save_move,
+    validate_project,
 };
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -26,6 +27,7 @@ pub fn run() {
             load_rules_registry,
             read_character_asset_base64,
             save_move,
+            preview_resolved_move,
             export_character,
             get_character_fspk,
             open_folder_dialog,
@@ -64,7 +64,9 @@ pub struct UpdateStateParam {
 pub struct ExportCharacterParam {
     #[schemars(description = "The character ID (folder name under characters dir)")]
     pub character_id: String,
-    #[schemars(description = "Export adapter: 'fspk' (default) or 'json-blob'")]
+    #[schemars(
+        description = "Export adapter: 'fspk' (default), 'fspk-be' (big-endian fspk) or 'json-blob'"
+    )]
     pub adapter: Option<String>,
     #[schemars(description = "Output file path, relative to the project root or absolute under the project root")]
     pub output_path: String,
@@ -74,7 +76,9 @@ pub struct ExportCharacterParam {
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ExportAllCharactersParam {
-    #[schemars(description = "Export adapter: 'fspk' (default) or 'json-blob'")]
+    #[schemars(
+        description = "Export adapter: 'fspk' (default), 'fspk-be' (big-endian fspk) or 'json-blob'"
+    )]
     pub adapter: Option<String>,
     #[schemars(description = "Output directory, relative to the project root or absolute under the project root")]
     pub out_dir: String,
@@ -131,7 +135,9 @@ impl FramesmithMcp {
         )]))
     }
 
-    #[tool(description = "Export a character to a file (runs validation + rules). Supports fspk (.fspk) and json-blob (.json).")]
+    #[tool(
+        description = "Export a character to a file (runs validation + rules). Supports fspk (.fspk), fspk-be (big-endian .fspk) and json-blob (.json)."
+    )]
     async fn export_character(
         &self,
         rmcp::handler::server::wrapper::Parameters(params): rmcp::handler::server::wrapper::Parameters<ExportCharacterParam>,
@@ -140,7 +146,7 @@ impl FramesmithMcp {
 
         let adapter = params.adapter.unwrap_or_else(|| "fspk".to_string());
         let pretty = params.pretty.unwrap_or(false);
-        if adapter == "fspk" && pretty {
+        if matches!(adapter.as_str(), "fspk" | "fspk-be") && pretty {
             return Err(McpError {
                 code: rmcp::model::ErrorCode::INVALID_PARAMS,
                 message: Cow::from("pretty=true is only supported for json-blob"),
@@ -204,7 +210,7 @@ impl FramesmithMcp {
         let adapter = params.adapter.unwrap_or_else(|| "fspk".to_string());
         let pretty = params.pretty.unwrap_or(false);
         let keep_going = params.keep_going.unwrap_or(false);
-        if adapter == "fspk" && pretty {
+        if matches!(adapter.as_str(), "fspk" | "fspk-be") && pretty {
             return Err(McpError {
                 code: rmcp::model::ErrorCode::INVALID_PARAMS,
                 message: Cow::from("pretty=true is only supported for json-blob"),
@@ -351,24 +357,11 @@ impl FramesmithMcp {
         &self,
         rmcp::handler::server::wrapper::Parameters(params): rmcp::handler::server::wrapper::Parameters<UpdateStateParam>,
     ) -> Result<CallToolResult, McpError> {
-        use crate::commands::save_move;
-        save_move(
-            self.characters_dir.clone(),
-            params.character_id.clone(),
-            params.state_data.clone(),
-        )
-        .map_err(|e| {
-            let code = if e.starts_with("Validation errors:") || e.starts_with("Invalid ") {
-                rmcp::model::ErrorCode::INVALID_PARAMS
-            } else {
-                rmcp::model::ErrorCode::INTERNAL_ERROR
-            };
-            McpError {
-                code,
-                message: Cow::from(e),
-                data: None,
-            }
-        })?;
+        save_state(
+            &self.characters_dir,
+            &params.character_id,
+            &params.state_data,
+        )?;
 
         Ok(CallToolResult::success(vec![Content::text(format!(
             "Successfully updated state '{}' for character '{}'",
@@ -381,7 +374,6 @@ impl FramesmithMcp {
         &self,
         rmcp::handler::server::wrapper::Parameters(params): rmcp::handler::server::wrapper::Parameters<UpdateStateParam>,
     ) -> Result<CallToolResult, McpError> {
-        use crate::commands::save_move;
         use std::path::Path;
 
         // Check if state already exists
@@ -401,23 +393,11 @@ impl FramesmithMcp {
             });
         }
 
-        save_move(
-            self.characters_dir.clone(),
-            params.character_id.clone(),
-            params.state_data.clone(),
-        )
-        .map_err(|e| {
-            let code = if e.starts_with("Validation errors:") || e.starts_with("Invalid ") {
-                rmcp::model::ErrorCode::INVALID_PARAMS
-            } else {
-                rmcp::model::ErrorCode::INTERNAL_ERROR
-            };
-            McpError {
-                code,
-                message: Cow::from(e),
-                data: None,
-            }
-        })?;
+        save_state(
+            &self.characters_dir,
+            &params.character_id,
+            &params.state_data,
+        )?;
 
         Ok(CallToolResult::success(vec![Content::text(format!(
             "Successfully created state '{}' for character '{}'",
@@ -950,12 +930,53 @@ fn resolve_output_path_under_project(project_root: &Path, user_path: &str) -> Re
 
 fn adapter_default_ext(adapter: &str) -> &'static str {
     match adapter {
-        "fspk" => ".fspk",
+        "fspk" | "fspk-be" => ".fspk",
         "json-blob" => ".json",
         _ => ".bin",
     }
 }
 
+/// Save a state through `save_move`, refusing states with validation errors.
+fn save_state(
+    characters_dir: &str,
+    character_id: &str,
+    state: &crate::schema::State,
+) -> Result<(), McpError> {
+    let result = crate::commands::save_move(
+        characters_dir.to_string(),
+        character_id.to_string(),
+        state.clone(),
+        Some(true),
+    )
+    .map_err(|e| {
+        let code = if e.starts_with("Invalid ") {
+            rmcp::model::ErrorCode::INVALID_PARAMS
+        } else {
+            rmcp::model::ErrorCode::INTERNAL_ERROR
+        };
+        McpError {
+            code,
+            message: Cow::from(e),
+            data: None,
+        }
+    })?;
+
+    if !result.saved {
+        let errors: Vec<String> = result
+            .issues
+            .errors
+            .iter()
+            .map(|i| format!("{}: {}", i.field, i.message))
+            .collect();
+        return Err(McpError {
+            code: rmcp::model::ErrorCode::INVALID_PARAMS,
+            message: Cow::from(format!("Validation errors: {}", errors.join("; "))),
+            data: None,
+        });
+    }
+    Ok(())
+}
+
 /// Validate a global state ID for path safety
 fn validate_global_state_id(id: &str) -> Result<(), McpError> {
     if id.is_empty() {
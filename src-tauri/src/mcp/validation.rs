@@ -81,6 +81,15 @@ pub fn validate_move(mv: &State) -> Result<(), Vec<ValidationError>> {
         }
     }
 
+    if let Some((start, end)) = mv.kara {
+        if start > end {
+            errors.push(ValidationError {
+                field: "kara".to_string(),
+                message: "start frame cannot be after end frame".to_string(),
+            });
+        }
+    }
+
     // Validate hits array (v2 schema)
     if let Some(ref hits) = mv.hits {
         validate_hits(hits, &mut errors);
@@ -165,6 +174,9 @@ mod tests {
             costs: None,
             movement: None,
             super_freeze: None,
+            kara: None,
+            airborne: None,
+            allow_from_stun: None,
             on_hit: None,
             advanced_hurtboxes: None,
             move_type: None,
@@ -428,6 +440,17 @@ mod tests {
             && e.message.contains("must have either distance or velocity")));
     }
 
+    #[test]
+    fn test_kara_window_start_after_end_fails() {
+        let mut mv = make_valid_move();
+        mv.kara = Some((1, 3));
+        assert!(validate_move(&mv).is_ok());
+
+        mv.kara = Some((3, 1));
+        let errors = validate_move(&mv).unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "kara"));
+    }
+
     #[test]
     fn test_super_freeze_zero_frames_fails() {
         let mut mv = make_valid_move();
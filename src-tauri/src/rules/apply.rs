@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use super::{matchers::MatchSpec, RulesError, RulesFile};
 
 /// A rule that sets default values on moves matching certain criteria.
-/// Only fills in values that are unset (null, empty, or zero).
+/// Only fills in values that are unset (null, empty, or zero), or enum fields
+/// still at their schema default (see `ENUM_DEFAULT_FIELDS`).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ApplyRule {
     /// Criteria for which moves this rule applies to.
@@ -14,6 +15,20 @@ pub struct ApplyRule {
     #[serde(deserialize_with = "deserialize_object_value")]
     #[schemars(with = "std::collections::HashMap<String, serde_json::Value>")]
     pub set: serde_json::Value,
+    /// How a character rule interacts with a project rule that has the same `match`.
+    #[serde(default)]
+    pub merge: ApplyMerge,
+}
+
+/// Merge behavior for a character apply rule whose `match` equals a project rule's.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ApplyMerge {
+    /// Drop the project rule; only the character rule applies.
+    #[default]
+    Replace,
+    /// Keep the project rule; the project rule applies first, then the character rule.
+    Extend,
 }
 
 fn deserialize_object_value<'de, D>(deserializer: D) -> Result<serde_json::Value, D::Error>
@@ -27,6 +42,11 @@ where
     }
 }
 
+/// Merge project and character apply rules into evaluation order.
+///
+/// Project rules come first, then character rules. A character rule with
+/// `merge: "replace"` removes project rules with an identical `match`;
+/// `merge: "extend"` keeps them, so both apply (project first).
 pub fn merged_apply_rules(
     project: Option<&RulesFile>,
     character: Option<&RulesFile>,
@@ -39,7 +59,9 @@ pub fn merged_apply_rules(
     };
 
     for rule in character_apply {
-        merged.retain(|existing| existing.match_spec != rule.match_spec);
+        if rule.merge == ApplyMerge::Replace {
+            merged.retain(|existing| existing.match_spec != rule.match_spec);
+        }
     }
 
     merged.extend(character_apply.iter().cloned());
@@ -110,6 +132,57 @@ fn set_value_at_path(root: &mut serde_json::Value, path: &[String], value: serde
     map.insert(leaf_key.clone(), value);
 }
 
+/// Integer `State` fields a rule can set, as `(path, type, min, max)`.
+const INTEGER_FIELDS: &[(&str, &str, i64, i64)] = &[
+    ("startup", "u8", 0, u8::MAX as i64),
+    ("active", "u8", 0, u8::MAX as i64),
+    ("recovery", "u8", 0, u8::MAX as i64),
+    ("damage", "u16", 0, u16::MAX as i64),
+    ("hitstun", "u8", 0, u8::MAX as i64),
+    ("blockstun", "u8", 0, u8::MAX as i64),
+    ("hitstop", "u8", 0, u8::MAX as i64),
+    ("total", "u8", 0, u8::MAX as i64),
+    ("pushback.hit", "i32", i32::MIN as i64, i32::MAX as i64),
+    ("pushback.block", "i32", i32::MIN as i64, i32::MAX as i64),
+    ("meter_gain.hit", "u16", 0, u16::MAX as i64),
+    ("meter_gain.whiff", "u16", 0, u16::MAX as i64),
+];
+
+/// Check that a rule's `set` values for integer `State` fields fit the field's type.
+fn check_integer_fields(set: &serde_json::Value) -> Result<(), RulesError> {
+    for &(field, ty, min, max) in INTEGER_FIELDS {
+        let path: Vec<String> = field.split('.').map(str::to_string).collect();
+        let Some(serde_json::Value::Number(num)) = get_value_at_path(set, &path) else {
+            continue;
+        };
+        if !num.as_i64().is_some_and(|v| (min..=max).contains(&v)) {
+            return Err(RulesError::FieldOutOfRange {
+                field: field.to_string(),
+                value: num.to_string(),
+                ty,
+                min,
+                max,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Enum `State` fields whose schema default counts as unset, as `(path, default)`.
+///
+/// A move that omits `guard` loads as `mid`, so the default can't be told
+/// apart from an authored value. Treating it as unset lets a rule set
+/// `guard: "low"` on every move that didn't pick another guard; a move that
+/// really wants `mid` under such a rule has to be left out by the rule's `match`.
+const ENUM_DEFAULT_FIELDS: &[(&str, &str)] = &[("guard", "mid")];
+
+/// Check whether `value` at `path` is an enum field's schema default.
+fn is_enum_default(path: &[String], value: &serde_json::Value) -> bool {
+    ENUM_DEFAULT_FIELDS.iter().any(|&(field, default)| {
+        field.split('.').eq(path.iter().map(String::as_str)) && value.as_str() == Some(default)
+    })
+}
+
 fn apply_set_object(
     set: &serde_json::Value,
     base: &serde_json::Value,
@@ -126,7 +199,7 @@ fn apply_set_object(
         }
         leaf => {
             let base_value = get_value_at_path(base, path);
-            if base_value.is_none_or(is_unset_value) {
+            if base_value.is_none_or(|v| is_unset_value(v) || is_enum_default(path, v)) {
                 set_value_at_path(resolved, path, leaf.clone());
             }
         }
@@ -146,6 +219,7 @@ pub fn apply_rules_to_move(
             continue;
         }
 
+        check_integer_fields(&rule.set)?;
         let mut path = Vec::new();
         apply_set_object(&rule.set, &base, &mut resolved, &mut path);
     }
@@ -163,6 +237,7 @@ mod tests {
     fn rules_with_apply(apply: Vec<ApplyRule>) -> RulesFile {
         RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: None,
             apply,
             validate: Vec::new(),
@@ -204,8 +279,11 @@ mod tests {
                     guard: None,
                     tags: None,
                     input: None,
+                    name: None,
+                    animation: None,
                 },
                 set: serde_json::json!({ "hitstop": 8 }),
+                merge: ApplyMerge::Replace,
             },
             ApplyRule {
                 match_spec: MatchSpec {
@@ -214,8 +292,11 @@ mod tests {
                     guard: None,
                     tags: None,
                     input: None,
+                    name: None,
+                    animation: None,
                 },
                 set: serde_json::json!({ "hitstop": 10 }),
+                merge: ApplyMerge::Replace,
             },
         ]);
 
@@ -237,8 +318,11 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             set: serde_json::json!({ "hitstop": 8 }),
+            merge: ApplyMerge::Replace,
         }]);
 
         let mut mv = crate::schema::State::default();
@@ -249,6 +333,36 @@ mod tests {
         assert_eq!(resolved.hitstop, 6);
     }
 
+    #[test]
+    fn test_apply_rules_set_guard_on_moves_left_at_default() {
+        let project = rules_with_apply(vec![ApplyRule {
+            match_spec: MatchSpec {
+                r#type: None,
+                button: None,
+                guard: None,
+                tags: None,
+                input: None,
+                name: None,
+                animation: None,
+            },
+            set: serde_json::json!({ "guard": "low" }),
+            merge: ApplyMerge::Replace,
+        }]);
+
+        // Default `mid` reads as unset, so the rule applies.
+        let mut mv = crate::schema::State {
+            input: "2L".to_string(),
+            ..Default::default()
+        };
+        let resolved = apply_rules_to_move(Some(&project), None, &mv).unwrap();
+        assert!(matches!(resolved.guard, crate::schema::GuardType::Low));
+
+        // Any other guard was chosen on purpose and is kept.
+        mv.guard = crate::schema::GuardType::High;
+        let resolved = apply_rules_to_move(Some(&project), None, &mv).unwrap();
+        assert!(matches!(resolved.guard, crate::schema::GuardType::High));
+    }
+
     #[test]
     fn test_character_apply_rule_replaces_project_rule_with_same_match() {
         let match_normal = MatchSpec {
@@ -257,15 +371,19 @@ mod tests {
             guard: None,
             tags: None,
             input: None,
+            name: None,
+            animation: None,
         };
 
         let project = rules_with_apply(vec![ApplyRule {
             match_spec: match_normal.clone(),
             set: serde_json::json!({ "hitstop": 8 }),
+            merge: ApplyMerge::Replace,
         }]);
         let character = rules_with_apply(vec![ApplyRule {
             match_spec: match_normal,
             set: serde_json::json!({ "hitstop": 9 }),
+            merge: ApplyMerge::Replace,
         }]);
 
         let mut mv = crate::schema::State::default();
@@ -276,4 +394,103 @@ mod tests {
         let resolved = apply_rules_to_move(Some(&project), Some(&character), &mv).unwrap();
         assert_eq!(resolved.hitstop, 9);
     }
+
+    fn match_normal() -> MatchSpec {
+        MatchSpec {
+            r#type: Some(StringOrVec::One("normal".to_string())),
+            button: None,
+            guard: None,
+            tags: None,
+            input: None,
+            name: None,
+            animation: None,
+        }
+    }
+
+    fn normal_move() -> crate::schema::State {
+        crate::schema::State {
+            input: "5L".to_string(),
+            move_type: Some("normal".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_character_apply_rule_extend_keeps_project_rule() {
+        let project = rules_with_apply(vec![ApplyRule {
+            match_spec: match_normal(),
+            set: serde_json::json!({ "hitstop": 8 }),
+            merge: ApplyMerge::Replace,
+        }]);
+        let character = rules_with_apply(vec![ApplyRule {
+            match_spec: match_normal(),
+            set: serde_json::json!({ "blockstun": 12 }),
+            merge: ApplyMerge::Extend,
+        }]);
+
+        let resolved =
+            apply_rules_to_move(Some(&project), Some(&character), &normal_move()).unwrap();
+        assert_eq!(resolved.hitstop, 8);
+        assert_eq!(resolved.blockstun, 12);
+    }
+
+    #[test]
+    fn test_character_apply_rule_replace_drops_project_rule() {
+        let project = rules_with_apply(vec![ApplyRule {
+            match_spec: match_normal(),
+            set: serde_json::json!({ "hitstop": 8 }),
+            merge: ApplyMerge::Replace,
+        }]);
+        let character = rules_with_apply(vec![ApplyRule {
+            match_spec: match_normal(),
+            set: serde_json::json!({ "blockstun": 12 }),
+            merge: ApplyMerge::Replace,
+        }]);
+
+        let resolved =
+            apply_rules_to_move(Some(&project), Some(&character), &normal_move()).unwrap();
+        assert_eq!(resolved.hitstop, 0);
+        assert_eq!(resolved.blockstun, 12);
+    }
+
+    #[test]
+    fn test_apply_merge_defaults_to_replace() {
+        let rules: RulesFile = serde_json::from_str(
+            r#"{
+  "version": 1,
+  "apply": [
+    { "match": {}, "set": { "hitstop": 3 } },
+    { "match": {}, "set": { "hitstop": 4 }, "merge": "extend" }
+  ]
+}"#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.apply[0].merge, ApplyMerge::Replace);
+        assert_eq!(rules.apply[1].merge, ApplyMerge::Extend);
+    }
+
+    #[test]
+    fn test_apply_rejects_values_outside_field_type() {
+        let project = rules_with_apply(vec![ApplyRule {
+            match_spec: match_normal(),
+            set: serde_json::json!({ "startup": 500 }),
+            merge: ApplyMerge::Replace,
+        }]);
+
+        let err = apply_rules_to_move(Some(&project), None, &normal_move()).unwrap_err();
+        assert!(matches!(err, RulesError::FieldOutOfRange { ref field, .. } if field == "startup"));
+        assert_eq!(err.to_string(), "startup must be a u8 in 0..=255, got 500");
+
+        let nested = rules_with_apply(vec![ApplyRule {
+            match_spec: match_normal(),
+            set: serde_json::json!({ "meter_gain": { "hit": -1 } }),
+            merge: ApplyMerge::Replace,
+        }]);
+        let err = apply_rules_to_move(Some(&nested), None, &normal_move()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "meter_gain.hit must be a u16 in 0..=65535, got -1"
+        );
+    }
 }
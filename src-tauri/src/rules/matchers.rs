@@ -16,6 +16,10 @@ pub struct MatchSpec {
     pub tags: Option<Vec<String>>,
     /// Input notation with glob pattern support (* matches any, ? matches one char).
     pub input: Option<StringOrVec>,
+    /// Move name with glob pattern support.
+    pub name: Option<StringOrVec>,
+    /// Animation name with glob pattern support (e.g., "throw_*").
+    pub animation: Option<StringOrVec>,
 }
 
 /// A value that can be either a single string or an array of strings.
@@ -111,6 +115,18 @@ pub fn matches_move(spec: &MatchSpec, mv: &crate::schema::State) -> bool {
         }
     }
 
+    if let Some(name) = &spec.name {
+        if !match_any(name, &mv.name, glob_match) {
+            return false;
+        }
+    }
+
+    if let Some(animation) = &spec.animation {
+        if !match_any(animation, &mv.animation, glob_match) {
+            return false;
+        }
+    }
+
     if let Some(button) = &spec.button {
         let mv_button = match button_from_input(&mv.input) {
             Some(b) => b,
@@ -168,6 +184,8 @@ mod tests {
             guard: None,
             tags: None,
             input: Some(StringOrVec::Many(vec!["5L".to_string(), "2L".to_string()])),
+            name: None,
+            animation: None,
         };
         assert!(matches_move(&spec, &mv));
 
@@ -178,6 +196,8 @@ mod tests {
             guard: Some(StringOrVec::One("unblockable".to_string())),
             tags: None,
             input: Some(StringOrVec::One("2L".to_string())),
+            name: None,
+            animation: None,
         };
         assert!(matches_move(&spec, &mv));
 
@@ -200,6 +220,8 @@ mod tests {
             guard: None,
             tags: None,
             input: None,
+            name: None,
+            animation: None,
         };
 
         assert!(matches_move(&spec, &mv));
@@ -211,6 +233,8 @@ mod tests {
             guard: None,
             tags: None,
             input: None,
+            name: None,
+            animation: None,
         };
 
         assert!(matches_move(&spec, &mv));
@@ -231,6 +255,8 @@ mod tests {
             guard: None,
             tags: Some(vec!["starter".to_string(), "reversal".to_string()]),
             input: None,
+            name: None,
+            animation: None,
         };
         assert!(matches_move(&spec, &mv));
 
@@ -240,7 +266,49 @@ mod tests {
             guard: None,
             tags: Some(vec!["starter".to_string(), "missing".to_string()]),
             input: None,
+            name: None,
+            animation: None,
         };
         assert!(!matches_move(&spec, &mv));
     }
+
+    #[test]
+    fn test_matches_move_name_and_animation_globs() {
+        let throw = crate::schema::State {
+            input: "6H".to_string(),
+            name: "Forward Throw".to_string(),
+            animation: "throw_forward".to_string(),
+            ..Default::default()
+        };
+
+        let jab = crate::schema::State {
+            input: "5L".to_string(),
+            name: "Light Punch".to_string(),
+            animation: "5L".to_string(),
+            ..Default::default()
+        };
+
+        let spec = MatchSpec {
+            r#type: None,
+            button: None,
+            guard: None,
+            tags: None,
+            input: None,
+            name: None,
+            animation: Some(StringOrVec::One("throw_*".to_string())),
+        };
+        assert!(matches_move(&spec, &throw));
+        assert!(!matches_move(&spec, &jab));
+
+        let spec = MatchSpec {
+            name: Some(StringOrVec::Many(vec![
+                "Light *".to_string(),
+                "Heavy *".to_string(),
+            ])),
+            animation: None,
+            ..spec
+        };
+        assert!(matches_move(&spec, &jab));
+        assert!(!matches_move(&spec, &throw));
+    }
 }
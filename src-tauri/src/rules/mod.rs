@@ -12,11 +12,14 @@ pub use validate::*;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 const RULES_VERSION: u32 = 1;
 
 /// Root structure for a Framesmith rules file.
 /// Rules files define:
+/// - other rules files to merge in (`includes`)
 /// - a registry of known resources/events (`registry`)
 /// - default values (`apply`)
 /// - validation constraints (`validate`)
@@ -26,6 +29,10 @@ const RULES_VERSION: u32 = 1;
 pub struct RulesFile {
     /// Schema version. Must be 1.
     pub version: u32,
+    /// Other rules files (paths relative to this file) merged in, in order,
+    /// before this file's own rules.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub includes: Vec<String>,
     /// Optional registry of resources and events used by this project/character.
     pub registry: Option<RulesRegistry>,
     /// Rules that set default values on matching moves.
@@ -52,15 +59,44 @@ pub enum Severity {
     Error,
     /// Warnings indicate potential issues but don't block saving.
     Warning,
+    /// Informational notes; never block saving or export.
+    Info,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ValidationIssue {
     pub field: String,
     pub message: String,
     pub severity: Severity,
 }
 
+/// Validation issues grouped by severity, as returned to the frontend.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IssuesBySeverity {
+    pub errors: Vec<ValidationIssue>,
+    pub warnings: Vec<ValidationIssue>,
+    pub info: Vec<ValidationIssue>,
+}
+
+impl IssuesBySeverity {
+    pub fn from_issues(issues: impl IntoIterator<Item = ValidationIssue>) -> Self {
+        let mut grouped = Self::default();
+        for issue in issues {
+            match issue.severity {
+                Severity::Error => grouped.errors.push(issue),
+                Severity::Warning => grouped.warnings.push(issue),
+                Severity::Info => grouped.info.push(issue),
+            }
+        }
+        grouped
+    }
+
+    /// Only errors block saving and export.
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
 /// Generates the JSON Schema for RulesFile.
 pub fn generate_rules_schema() -> schemars::Schema {
     schemars::schema_for!(RulesFile)
@@ -71,6 +107,18 @@ pub enum RulesError {
     Io(std::io::Error),
     Json(serde_json::Error),
     UnsupportedVersion(u32),
+    /// An included rules file does not exist.
+    MissingInclude(std::path::PathBuf),
+    /// A rules file includes itself, directly or through other includes.
+    IncludeCycle(std::path::PathBuf),
+    /// An apply rule sets an integer field to a value its type can't hold.
+    FieldOutOfRange {
+        field: String,
+        value: String,
+        ty: &'static str,
+        min: i64,
+        max: i64,
+    },
 }
 
 impl std::fmt::Display for RulesError {
@@ -81,6 +129,21 @@ impl std::fmt::Display for RulesError {
             Self::UnsupportedVersion(version) => {
                 write!(f, "unsupported rules version: {version}")
             }
+            Self::MissingInclude(path) => {
+                write!(f, "included rules file not found: {}", path.display())
+            }
+            Self::IncludeCycle(path) => {
+                write!(f, "rules include cycle at {}", path.display())
+            }
+            Self::FieldOutOfRange {
+                field,
+                value,
+                ty,
+                min,
+                max,
+            } => {
+                write!(f, "{field} must be a {ty} in {min}..={max}, got {value}")
+            }
         }
     }
 }
@@ -90,7 +153,10 @@ impl std::error::Error for RulesError {
         match self {
             Self::Io(err) => Some(err),
             Self::Json(err) => Some(err),
-            Self::UnsupportedVersion(_) => None,
+            Self::UnsupportedVersion(_)
+            | Self::MissingInclude(_)
+            | Self::IncludeCycle(_)
+            | Self::FieldOutOfRange { .. } => None,
         }
     }
 }
@@ -122,6 +188,104 @@ pub fn load_rules_file(path: &std::path::Path) -> Result<Option<RulesFile>, Rule
     Ok(Some(rules))
 }
 
+/// Load a rules file and merge in everything it `includes`, recursively.
+///
+/// Includes merge in listed order before the including file, so later files
+/// win: registry resources and schemas are unioned, events and move types are
+/// overridden, and `apply`/`validate` rules are concatenated. A file reached
+/// twice through different includes is merged once. Returns `Ok(None)` if the
+/// root file doesn't exist; a missing include or an include cycle is an error.
+pub fn load_rules_file_resolved(path: &Path) -> Result<Option<RulesFile>, RulesError> {
+    let Some(root) = load_rules_file(path)? else {
+        return Ok(None);
+    };
+    let key = path.canonicalize()?;
+    let mut stack = vec![key.clone()];
+    let mut merged = HashSet::from([key]);
+    resolve_includes(path, root, &mut stack, &mut merged).map(Some)
+}
+
+fn resolve_includes(
+    path: &Path,
+    mut rules: RulesFile,
+    stack: &mut Vec<PathBuf>,
+    merged: &mut HashSet<PathBuf>,
+) -> Result<RulesFile, RulesError> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut base: Option<RulesFile> = None;
+
+    for include in std::mem::take(&mut rules.includes) {
+        let include_path = dir.join(&include);
+        let key = include_path
+            .canonicalize()
+            .map_err(|_| RulesError::MissingInclude(include_path.clone()))?;
+        if stack.contains(&key) {
+            return Err(RulesError::IncludeCycle(include_path));
+        }
+        if !merged.insert(key.clone()) {
+            continue;
+        }
+
+        let included = load_rules_file(&include_path)?
+            .ok_or_else(|| RulesError::MissingInclude(include_path.clone()))?;
+        stack.push(key);
+        let included = resolve_includes(&include_path, included, stack, merged)?;
+        stack.pop();
+
+        base = Some(match base {
+            Some(base) => merge_rules_files(base, included),
+            None => included,
+        });
+    }
+
+    Ok(match base {
+        Some(base) => merge_rules_files(base, rules),
+        None => rules,
+    })
+}
+
+/// Merge two rules files, with `over` taking precedence over `base`.
+fn merge_rules_files(base: RulesFile, over: RulesFile) -> RulesFile {
+    let registry = (base.registry.is_some() || over.registry.is_some())
+        .then(|| merged_registry(Some(&base), Some(&over)));
+    let properties = merged_property_schema(Some(&base), Some(&over));
+    let tags = merged_tag_schema(Some(&base), Some(&over));
+
+    RulesFile {
+        version: over.version,
+        includes: Vec::new(),
+        registry,
+        apply: base.apply.into_iter().chain(over.apply).collect(),
+        validate: base.validate.into_iter().chain(over.validate).collect(),
+        properties,
+        tags,
+    }
+}
+
+/// Every file a rules file includes, directly or transitively.
+///
+/// Best effort: unreadable or missing files are skipped. Used to track the
+/// files a resolved rules file depends on.
+pub fn rules_include_paths(path: &Path) -> Vec<PathBuf> {
+    let mut seen = HashSet::new();
+    let mut pending = vec![path.to_path_buf()];
+    let mut paths = Vec::new();
+    while let Some(current) = pending.pop() {
+        let Ok(Some(rules)) = load_rules_file(&current) else {
+            continue;
+        };
+        let dir = current.parent().unwrap_or(Path::new("."));
+        for include in &rules.includes {
+            let include_path = dir.join(include);
+            if seen.insert(include_path.clone()) {
+                paths.push(include_path.clone());
+                pending.push(include_path);
+            }
+        }
+    }
+    paths
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +370,114 @@ mod tests {
         let _ = std::fs::remove_file(&path);
     }
 
+    fn write_rules(dir: &Path, name: &str, json: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, json).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_rules_file_resolved_merges_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        write_rules(
+            dir.path(),
+            "frame-data.json",
+            r#"{
+  "version": 1,
+  "registry": { "resources": ["meter"] },
+  "apply": [{ "match": { "type": "normal" }, "set": { "hitstop": 3 } }]
+}"#,
+        );
+        write_rules(
+            dir.path(),
+            "vfx.json",
+            r#"{
+  "version": 1,
+  "registry": { "resources": ["heat"] },
+  "validate": [{
+    "match": {},
+    "require": { "animation": { "exists": true } },
+    "severity": "warning"
+  }]
+}"#,
+        );
+        let root = write_rules(
+            dir.path(),
+            "framesmith.rules.json",
+            r#"{
+  "version": 1,
+  "includes": ["frame-data.json", "vfx.json"],
+  "registry": { "resources": ["meter", "ammo"] },
+  "apply": [{ "match": { "type": "special" }, "set": { "hitstop": 5 } }]
+}"#,
+        );
+
+        let rules = load_rules_file_resolved(&root).unwrap().unwrap();
+        assert!(rules.includes.is_empty());
+        assert_eq!(
+            rules.registry.unwrap().resources,
+            vec!["meter".to_string(), "heat".to_string(), "ammo".to_string()]
+        );
+        // Included rules come first, so the root file's rules apply last.
+        assert_eq!(rules.apply.len(), 2);
+        assert_eq!(rules.apply[0].set["hitstop"], 3);
+        assert_eq!(rules.apply[1].set["hitstop"], 5);
+        assert_eq!(rules.validate.len(), 1);
+
+        let mut includes = rules_include_paths(&root);
+        includes.sort();
+        assert_eq!(
+            includes,
+            vec![
+                dir.path().join("frame-data.json"),
+                dir.path().join("vfx.json")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_rules_file_resolved_rejects_cycles_and_missing_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let own = write_rules(
+            dir.path(),
+            "self.json",
+            r#"{ "version": 1, "includes": ["self.json"] }"#,
+        );
+        assert!(matches!(
+            load_rules_file_resolved(&own),
+            Err(RulesError::IncludeCycle(_))
+        ));
+
+        write_rules(
+            dir.path(),
+            "a.json",
+            r#"{ "version": 1, "includes": ["b.json"] }"#,
+        );
+        let b = write_rules(
+            dir.path(),
+            "b.json",
+            r#"{ "version": 1, "includes": ["a.json"] }"#,
+        );
+        assert!(matches!(
+            load_rules_file_resolved(&b),
+            Err(RulesError::IncludeCycle(_))
+        ));
+
+        let missing = write_rules(
+            dir.path(),
+            "missing.json",
+            r#"{ "version": 1, "includes": ["nope.json"] }"#,
+        );
+        assert!(matches!(
+            load_rules_file_resolved(&missing),
+            Err(RulesError::MissingInclude(_))
+        ));
+
+        assert!(load_rules_file_resolved(&dir.path().join("absent.json"))
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_generate_rules_schema() {
         let schema = generate_rules_schema();
@@ -217,4 +489,34 @@ mod tests {
         assert!(json.contains("MatchSpec"));
         assert!(json.contains("Severity"));
     }
+
+    #[test]
+    fn test_generate_rules_schema_carries_field_descriptions() {
+        let schema = serde_json::to_value(generate_rules_schema()).unwrap();
+        let defs = &schema["$defs"];
+
+        let description = |def: &str, field: &str| {
+            defs[def]["properties"][field]["description"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string()
+        };
+        assert!(!description("ValidateRule", "severity").is_empty());
+        assert!(!description("ValidateRule", "match").is_empty());
+        assert!(!description("ApplyRule", "match").is_empty());
+        assert!(!description("MatchSpec", "type").is_empty());
+        assert!(!schema["properties"]["validate"]["description"]
+            .as_str()
+            .unwrap_or_default()
+            .is_empty());
+
+        for variant in defs["EventArgSpec"]["oneOf"].as_array().unwrap() {
+            assert!(variant["description"]
+                .as_str()
+                .is_some_and(|d| !d.is_empty()));
+            assert!(variant["properties"]["required"]["description"]
+                .as_str()
+                .is_some_and(|d| !d.is_empty()));
+        }
+    }
 }
@@ -104,6 +104,7 @@ mod tests {
     fn rules_with_property_schema(props: PropertySchema) -> RulesFile {
         RulesFile {
             version: 1,
+            includes: vec![],
             registry: None,
             apply: Vec::new(),
             validate: Vec::new(),
@@ -115,6 +116,7 @@ mod tests {
     fn rules_with_tags(tags: Vec<String>) -> RulesFile {
         RulesFile {
             version: 1,
+            includes: vec![],
             registry: None,
             apply: Vec::new(),
             validate: Vec::new(),
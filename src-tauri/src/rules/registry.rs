@@ -64,19 +64,65 @@ impl EventContext {
 }
 
 /// Schema for a single event argument.
+///
+/// `required` args must be present on every emit of the event.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type")]
 pub enum EventArgSpec {
+    /// Boolean argument.
     #[serde(rename = "bool")]
-    Bool,
+    Bool {
+        /// Whether every emit must provide this argument.
+        #[serde(default)]
+        required: bool,
+    },
+    /// 64-bit integer argument.
     #[serde(rename = "i64")]
-    I64,
+    I64 {
+        /// Whether every emit must provide this argument.
+        #[serde(default)]
+        required: bool,
+    },
+    /// Floating-point argument with optional bounds.
     #[serde(rename = "f32")]
-    F32 { min: Option<f32>, max: Option<f32> },
+    F32 {
+        /// Inclusive lower bound.
+        min: Option<f32>,
+        /// Inclusive upper bound.
+        max: Option<f32>,
+        /// Whether every emit must provide this argument.
+        #[serde(default)]
+        required: bool,
+    },
+    /// Free-form string argument.
     #[serde(rename = "string")]
-    String,
+    String {
+        /// Whether every emit must provide this argument.
+        #[serde(default)]
+        required: bool,
+    },
+    /// String argument restricted to a fixed set of values.
     #[serde(rename = "enum")]
-    Enum { values: Vec<String> },
+    Enum {
+        /// Allowed values.
+        values: Vec<String>,
+        /// Whether every emit must provide this argument.
+        #[serde(default)]
+        required: bool,
+    },
+}
+
+impl EventArgSpec {
+    /// Whether emits must provide this arg.
+    pub fn is_required(&self) -> bool {
+        match self {
+            Self::Bool { required }
+            | Self::I64 { required }
+            | Self::F32 { required, .. }
+            | Self::String { required }
+            | Self::Enum { required, .. } => *required,
+        }
+    }
 }
 
 /// Merge project + character registries.
@@ -214,6 +260,57 @@ pub fn validate_character_resources_with_registry(
     issues
 }
 
+/// Flag move type filter groups that list a type missing from `types`.
+///
+/// Such a group would silently match nothing in the editor's type filter.
+pub fn validate_registry_move_types(registry: &RulesRegistry) -> Vec<ValidationIssue> {
+    let Some(move_types) = &registry.move_types else {
+        return Vec::new();
+    };
+    let declared = move_types
+        .types
+        .iter()
+        .map(|s| s.as_str())
+        .collect::<std::collections::HashSet<_>>();
+
+    let mut issues = Vec::new();
+    for (group, types) in &move_types.filter_groups {
+        for (i, ty) in types.iter().enumerate() {
+            if !declared.contains(ty.as_str()) {
+                issues.push(ValidationIssue {
+                    field: format!("registry.move_types.filter_groups.{group}[{i}]"),
+                    message: format!(
+                        "Filter group '{group}' references undeclared move type '{ty}'"
+                    ),
+                    severity: Severity::Error,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Flag a move whose `animation` has no clip in the character's assets manifest.
+///
+/// Only runs when the caller has loaded `assets.json`; a move without an
+/// animation is left alone. Missing clips are warnings so a renamed clip
+/// doesn't block saving.
+pub fn validate_move_animation_with_assets(
+    mv: &crate::schema::State,
+    assets: &crate::schema::CharacterAssets,
+) -> Vec<ValidationIssue> {
+    if mv.animation.is_empty() || assets.animations.contains_key(&mv.animation) {
+        return Vec::new();
+    }
+
+    vec![ValidationIssue {
+        field: "animation".to_string(),
+        message: format!("Animation '{}' not found in assets manifest", mv.animation),
+        severity: Severity::Warning,
+    }]
+}
+
 pub(super) fn validate_move_registry(
     mv: &crate::schema::State,
     registry: &RulesRegistry,
@@ -316,6 +413,16 @@ pub(super) fn validate_move_registry(
             });
         }
 
+        for (k, spec) in &def.args {
+            if spec.is_required() && !emit.args.contains_key(k) {
+                issues.push(ValidationIssue {
+                    field: format!("{base}.args.{k}"),
+                    message: format!("Missing required arg '{k}' for event '{}'", emit.id),
+                    severity: Severity::Error,
+                });
+            }
+        }
+
         for (k, v) in &emit.args {
             let Some(spec) = def.args.get(k) else {
                 issues.push(ValidationIssue {
@@ -338,10 +445,10 @@ pub(super) fn validate_move_registry(
             };
 
             match (spec, v) {
-                (EventArgSpec::Bool, crate::schema::EventArgValue::Bool(_)) => {}
-                (EventArgSpec::I64, crate::schema::EventArgValue::I64(_)) => {}
-                (EventArgSpec::String, crate::schema::EventArgValue::String(_)) => {}
-                (EventArgSpec::F32 { min, max }, crate::schema::EventArgValue::F32(x)) => {
+                (EventArgSpec::Bool { .. }, crate::schema::EventArgValue::Bool(_)) => {}
+                (EventArgSpec::I64 { .. }, crate::schema::EventArgValue::I64(_)) => {}
+                (EventArgSpec::String { .. }, crate::schema::EventArgValue::String(_)) => {}
+                (EventArgSpec::F32 { min, max, .. }, crate::schema::EventArgValue::F32(x)) => {
                     if let Some(min) = min {
                         if *x < *min {
                             issues.push(ValidationIssue {
@@ -361,7 +468,7 @@ pub(super) fn validate_move_registry(
                         }
                     }
                 }
-                (EventArgSpec::F32 { min, max }, crate::schema::EventArgValue::I64(x)) => {
+                (EventArgSpec::F32 { min, max, .. }, crate::schema::EventArgValue::I64(x)) => {
                     let x = *x as f32;
                     if let Some(min) = min {
                         if x < *min {
@@ -382,7 +489,7 @@ pub(super) fn validate_move_registry(
                         }
                     }
                 }
-                (EventArgSpec::Enum { values }, crate::schema::EventArgValue::String(s)) => {
+                (EventArgSpec::Enum { values, .. }, crate::schema::EventArgValue::String(s)) => {
                     if !values.iter().any(|v| v == s) {
                         issues.push(ValidationIssue {
                             field: format!("{base}.args.{k}"),
@@ -394,10 +501,10 @@ pub(super) fn validate_move_registry(
                         });
                     }
                 }
-                (EventArgSpec::Bool, _) => mismatch("bool"),
-                (EventArgSpec::I64, _) => mismatch("i64"),
+                (EventArgSpec::Bool { .. }, _) => mismatch("bool"),
+                (EventArgSpec::I64 { .. }, _) => mismatch("i64"),
                 (EventArgSpec::F32 { .. }, _) => mismatch("f32"),
-                (EventArgSpec::String, _) => mismatch("string"),
+                (EventArgSpec::String { .. }, _) => mismatch("string"),
                 (EventArgSpec::Enum { .. }, _) => mismatch("enum (string)"),
             }
         }
@@ -459,6 +566,7 @@ mod tests {
     fn rules_with_registry(registry: RulesRegistry) -> RulesFile {
         RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: Some(registry),
             apply: Vec::new(),
             validate: Vec::new(),
@@ -506,7 +614,7 @@ mod tests {
         assert_eq!(ev.contexts, vec![EventContext::OnHit, EventContext::Notify]);
         assert_eq!(
             ev.args.get("amount"),
-            Some(&EventArgSpec::I64),
+            Some(&EventArgSpec::I64 { required: false }),
             "amount arg should deserialize as i64 spec"
         );
 
@@ -543,6 +651,7 @@ mod tests {
 
         let project = RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: Some(RulesRegistry {
                 resources: vec!["heat".to_string(), "ammo".to_string()],
                 events: project_events,
@@ -566,6 +675,7 @@ mod tests {
 
         let character = RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: Some(RulesRegistry {
                 resources: vec!["heat".to_string(), "stamina".to_string()],
                 events: character_events,
@@ -591,6 +701,35 @@ mod tests {
         assert_eq!(swing.contexts, vec![EventContext::Notify]);
     }
 
+    #[test]
+    fn test_filter_group_with_undeclared_type_is_error() {
+        let mut filter_groups = std::collections::BTreeMap::new();
+        filter_groups.insert(
+            "specials".to_string(),
+            vec!["special".to_string(), "ex".to_string()],
+        );
+        filter_groups.insert("normals".to_string(), vec!["normal".to_string()]);
+        let registry = RulesRegistry {
+            move_types: Some(MoveTypesConfig {
+                types: vec!["normal".to_string(), "special".to_string()],
+                filter_groups,
+            }),
+            ..Default::default()
+        };
+
+        let issues = validate_registry_move_types(&registry);
+        assert_eq!(
+            issues,
+            vec![ValidationIssue {
+                field: "registry.move_types.filter_groups.specials[1]".to_string(),
+                message: "Filter group 'specials' references undeclared move type 'ex'".to_string(),
+                severity: Severity::Error,
+            }]
+        );
+
+        assert!(validate_registry_move_types(&RulesRegistry::default()).is_empty());
+    }
+
     #[test]
     fn test_validate_move_events_unknown_id_is_error() {
         let rules = rules_with_registry(RulesRegistry {
@@ -622,6 +761,7 @@ mod tests {
             EventArgSpec::F32 {
                 min: None,
                 max: None,
+                required: false,
             },
         );
 
@@ -668,6 +808,7 @@ mod tests {
             EventArgSpec::F32 {
                 min: Some(0.0),
                 max: Some(10.0),
+                required: false,
             },
         );
 
@@ -713,6 +854,7 @@ mod tests {
             "strength".to_string(),
             EventArgSpec::Enum {
                 values: vec!["light".to_string(), "med".to_string(), "heavy".to_string()],
+                required: false,
             },
         );
 
@@ -751,6 +893,64 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_validate_move_events_missing_required_arg_is_error() {
+        let mut args = std::collections::BTreeMap::new();
+        args.insert("amount".to_string(), EventArgSpec::I64 { required: true });
+        args.insert(
+            "scale".to_string(),
+            EventArgSpec::F32 {
+                min: None,
+                max: None,
+                required: false,
+            },
+        );
+
+        let mut events = std::collections::BTreeMap::new();
+        events.insert(
+            "gain_heat".to_string(),
+            EventDefinition {
+                contexts: vec![EventContext::OnHit],
+                args,
+            },
+        );
+
+        let rules = rules_with_registry(RulesRegistry {
+            resources: vec![],
+            events,
+            ..Default::default()
+        });
+
+        let mut mv = make_valid_move();
+        mv.on_hit = Some(crate::schema::OnHit {
+            events: vec![crate::schema::EventEmit {
+                id: "gain_heat".to_string(),
+                args: std::collections::BTreeMap::new(),
+            }],
+            ..Default::default()
+        });
+
+        let issues = super::super::validate_move_with_rules(Some(&rules), None, &mv).unwrap();
+        let missing: Vec<_> = issues
+            .iter()
+            .filter(|i| i.message.contains("Missing required arg"))
+            .collect();
+        assert_eq!(missing.len(), 1, "only 'amount' is required: {:?}", missing);
+        assert_eq!(missing[0].severity, Severity::Error);
+        assert_eq!(missing[0].field, "on_hit.events[0].args.amount");
+    }
+
+    #[test]
+    fn test_event_arg_required_defaults_to_false() {
+        let spec: EventArgSpec = serde_json::from_str(r#"{ "type": "i64" }"#).unwrap();
+        assert!(!spec.is_required());
+
+        let spec: EventArgSpec =
+            serde_json::from_str(r#"{ "type": "enum", "values": ["a"], "required": true }"#)
+                .unwrap();
+        assert!(spec.is_required());
+    }
+
     #[test]
     fn test_validate_move_notify_frame_out_of_bounds_is_error() {
         let rules = rules_with_registry(RulesRegistry {
@@ -793,10 +993,47 @@ mod tests {
             .any(|i| { i.severity == Severity::Error && i.field == "preconditions[0].name" }));
     }
 
+    fn assets_with_animations(names: &[&str]) -> crate::schema::CharacterAssets {
+        let clips = names
+            .iter()
+            .map(|name| {
+                format!(r#""{name}": {{ "mode": "sprite", "texture": "atlas", "frame_size": {{ "w": 64, "h": 64 }}, "frames": 4 }}"#)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        serde_json::from_str(&format!(
+            r#"{{ "version": 1, "animations": {{ {clips} }} }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate_move_animation_present_in_manifest_passes() {
+        let mut mv = make_valid_move();
+        mv.animation = "5L".to_string();
+
+        let assets = assets_with_animations(&["5L", "idle"]);
+        assert!(validate_move_animation_with_assets(&mv, &assets).is_empty());
+    }
+
+    #[test]
+    fn test_validate_move_animation_missing_from_manifest_warns() {
+        let mut mv = make_valid_move();
+        mv.animation = "5L".to_string();
+
+        let assets = assets_with_animations(&["idle"]);
+        let issues = validate_move_animation_with_assets(&mv, &assets);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "animation");
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert!(issues[0].message.contains("'5L'"));
+    }
+
     #[test]
     fn test_merged_rules_struct() {
         let project = RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: Some(RulesRegistry {
                 resources: vec!["heat".to_string()],
                 ..Default::default()
@@ -812,6 +1049,7 @@ mod tests {
 
         let character = RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: Some(RulesRegistry {
                 resources: vec!["ammo".to_string()],
                 ..Default::default()
@@ -8,7 +8,7 @@ use super::{
     RulesError, RulesFile, Severity, ValidationIssue,
 };
 
-/// A rule that enforces constraints on moves, producing errors or warnings.
+/// A rule that enforces constraints on moves, producing errors, warnings, or info notes.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ValidateRule {
     /// Criteria for which moves this rule applies to.
@@ -70,20 +70,54 @@ fn is_unset_value(value: &serde_json::Value) -> bool {
     }
 }
 
-fn get_value_at_path<'a>(
+/// Resolve `path` against `root`, expanding each `name[*]` segment to every
+/// element of the `name` array.
+///
+/// Returns each concrete field path (e.g. `hits[1].damage`) with the value
+/// found there, or `None` if it is missing. A missing or non-array wildcard
+/// target yields no entries.
+fn resolve_path<'a>(
     root: &'a serde_json::Value,
     path: &[String],
-) -> Option<&'a serde_json::Value> {
-    let mut cur = root;
-    for key in path {
-        match cur {
-            serde_json::Value::Object(map) => {
-                cur = map.get(key)?;
-            }
-            _ => return None,
+) -> Vec<(String, Option<&'a serde_json::Value>)> {
+    let mut out = Vec::new();
+    resolve_path_into(Some(root), path, String::new(), &mut out);
+    out
+}
+
+fn resolve_path_into<'a>(
+    cur: Option<&'a serde_json::Value>,
+    path: &[String],
+    field: String,
+    out: &mut Vec<(String, Option<&'a serde_json::Value>)>,
+) {
+    let Some((segment, rest)) = path.split_first() else {
+        out.push((field, cur));
+        return;
+    };
+    let child_field = |name: &str| {
+        if field.is_empty() {
+            name.to_string()
+        } else {
+            format!("{field}.{name}")
         }
+    };
+
+    if let Some(name) = segment.strip_suffix("[*]") {
+        let items = cur
+            .and_then(|v| v.as_object())
+            .and_then(|map| map.get(name))
+            .and_then(|v| v.as_array());
+        for (i, item) in items.into_iter().flatten().enumerate() {
+            resolve_path_into(Some(item), rest, child_field(&format!("{name}[{i}]")), out);
+        }
+        return;
     }
-    Some(cur)
+
+    let next = cur
+        .and_then(|v| v.as_object())
+        .and_then(|map| map.get(segment));
+    resolve_path_into(next, rest, child_field(segment), out);
 }
 
 fn is_constraint_object(map: &serde_json::Map<String, serde_json::Value>) -> bool {
@@ -177,13 +211,16 @@ fn validate_require_object(
     };
 
     for (key, val) in map {
-        path.push(key.clone());
+        // Dotted keys ("hits[*].damage") are shorthand for nesting.
+        let segments = key.split('.').count();
+        path.extend(key.split('.').map(str::to_string));
 
         match val {
             serde_json::Value::Object(obj) if is_constraint_object(obj) => {
-                let target = get_value_at_path(resolved_json, path);
-                if !constraints_pass(obj, target) {
-                    let field = path.join(".");
+                for (field, target) in resolve_path(resolved_json, path) {
+                    if constraints_pass(obj, target) {
+                        continue;
+                    }
                     let message = rule
                         .message
                         .clone()
@@ -203,7 +240,7 @@ fn validate_require_object(
             }
         }
 
-        path.pop();
+        path.truncate(path.len() - segments);
     }
 }
 
@@ -282,6 +319,12 @@ pub fn get_builtin_validations() -> Vec<BuiltinValidation> {
             constraint: "end <= total frames".to_string(),
             error_message: "end frame exceeds total frames".to_string(),
         },
+        // Kara Cancel
+        BuiltinValidation {
+            field: "kara".to_string(),
+            constraint: "start <= end".to_string(),
+            error_message: "start frame cannot be after end frame".to_string(),
+        },
         // Hits (v2 Schema)
         BuiltinValidation {
             field: "hits[i].frames".to_string(),
@@ -384,14 +427,16 @@ pub fn get_builtin_validations() -> Vec<BuiltinValidation> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use super::super::matchers::StringOrVec;
+    use super::super::IssuesBySeverity;
+    use super::*;
 
     const RULES_VERSION: u32 = 1;
 
     fn rules_with_validate(validate: Vec<ValidateRule>) -> RulesFile {
         RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: None,
             apply: Vec::new(),
             validate,
@@ -432,6 +477,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "animation": { "exists": true } }),
             severity: Severity::Warning,
@@ -446,6 +493,38 @@ mod tests {
             .any(|i| i.field == "animation" && i.severity == Severity::Warning));
     }
 
+    #[test]
+    fn test_validate_rule_info_severity_is_grouped_separately() {
+        let rules: RulesFile = serde_json::from_str(
+            r#"{
+  "version": 1,
+  "validate": [
+    {
+      "match": {},
+      "require": { "animation": { "exists": true } },
+      "severity": "info",
+      "message": "no animation yet"
+    }
+  ]
+}"#,
+        )
+        .unwrap();
+
+        let mv = make_valid_move();
+        let issues = validate_move_with_rules(Some(&rules), None, &mv).unwrap();
+
+        assert!(issues
+            .iter()
+            .any(|i| i.field == "animation" && i.severity == Severity::Info));
+
+        let grouped = IssuesBySeverity::from_issues(issues);
+        assert!(!grouped.has_errors());
+        assert!(grouped.errors.is_empty());
+        assert!(grouped.warnings.is_empty());
+        assert_eq!(grouped.info.len(), 1);
+        assert_eq!(grouped.info[0].message, "no animation yet");
+    }
+
     #[test]
     fn test_validate_rule_min_error_on_startup() {
         let rules = rules_with_validate(vec![ValidateRule {
@@ -455,6 +534,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "startup": { "min": 3 } }),
             severity: Severity::Error,
@@ -470,12 +551,67 @@ mod tests {
             .any(|i| i.field == "startup" && i.severity == Severity::Error));
     }
 
+    #[test]
+    fn test_validate_rule_wildcard_checks_every_array_element() {
+        let rules = rules_with_validate(vec![ValidateRule {
+            match_spec: MatchSpec {
+                r#type: None,
+                button: None,
+                guard: None,
+                tags: None,
+                input: None,
+                name: None,
+                animation: None,
+            },
+            require: serde_json::json!({ "hits[*].damage": { "min": 1 } }),
+            severity: Severity::Error,
+            message: Some("every hit must deal damage".to_string()),
+        }]);
+
+        let hit = |damage: u16| -> crate::schema::Hit {
+            serde_json::from_value(serde_json::json!({
+                "frames": [1, 1],
+                "damage": damage,
+                "chip_damage": null,
+                "hitstun": 10,
+                "blockstun": 5,
+                "hitstop": 4,
+                "guard": "mid",
+                "hitboxes": [],
+                "cancels": []
+            }))
+            .unwrap()
+        };
+        let mut mv = make_valid_move();
+        mv.hits = Some(vec![hit(20), hit(0), hit(30)]);
+        let issues = validate_move_with_rules(Some(&rules), None, &mv).unwrap();
+
+        let violations: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.message == "every hit must deal damage")
+            .map(|i| i.field.as_str())
+            .collect();
+        assert_eq!(violations, ["hits[1].damage"]);
+
+        // The nested form is equivalent, and a move without hits passes.
+        let mut nested = rules.clone();
+        nested.validate[0].require = serde_json::json!({ "hits[*]": { "damage": { "min": 1 } } });
+        let issues = validate_move_with_rules(Some(&nested), None, &mv).unwrap();
+        assert!(issues.iter().any(|i| i.field == "hits[1].damage"));
+
+        let issues = validate_move_with_rules(Some(&rules), None, &make_valid_move()).unwrap();
+        assert!(!issues
+            .iter()
+            .any(|i| i.message == "every hit must deal damage"));
+    }
+
     #[test]
     fn test_validate_rules_run_on_resolved_values() {
-        use super::super::apply::ApplyRule;
+        use super::super::apply::{ApplyMerge, ApplyRule};
 
         let project = RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: None,
             apply: vec![ApplyRule {
                 match_spec: MatchSpec {
@@ -484,8 +620,11 @@ mod tests {
                     guard: None,
                     tags: None,
                     input: None,
+                    name: None,
+                    animation: None,
                 },
                 set: serde_json::json!({ "hitstop": 8 }),
+                merge: ApplyMerge::Replace,
             }],
             validate: vec![ValidateRule {
                 match_spec: MatchSpec {
@@ -494,6 +633,8 @@ mod tests {
                     guard: None,
                     tags: None,
                     input: None,
+                    name: None,
+                    animation: None,
                 },
                 require: serde_json::json!({ "hitstop": { "min": 1 } }),
                 severity: Severity::Error,
@@ -519,6 +660,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "startup": { "max": 5 } }),
             severity: Severity::Error,
@@ -543,6 +686,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "guard": { "equals": "low" } }),
             severity: Severity::Error,
@@ -567,6 +712,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "guard": { "in": ["mid", "low"] } }),
             severity: Severity::Error,
@@ -591,6 +738,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "startup": { "min": 3 } }),
             severity: Severity::Warning,
@@ -604,6 +753,8 @@ mod tests {
                 guard: None,
                 tags: None,
                 input: None,
+                name: None,
+                animation: None,
             },
             require: serde_json::json!({ "startup": { "min": 4 } }),
             severity: Severity::Error,
@@ -635,10 +786,11 @@ mod tests {
 
     #[test]
     fn test_validate_rule_matching_uses_resolved_move() {
-        use super::super::apply::ApplyRule;
+        use super::super::apply::{ApplyMerge, ApplyRule};
 
         let project = RulesFile {
             version: RULES_VERSION,
+            includes: vec![],
             registry: None,
             apply: vec![ApplyRule {
                 match_spec: MatchSpec {
@@ -647,8 +799,11 @@ mod tests {
                     guard: None,
                     tags: None,
                     input: Some(StringOrVec::One("236*".to_string())),
+                    name: None,
+                    animation: None,
                 },
                 set: serde_json::json!({ "type": "special" }),
+                merge: ApplyMerge::Replace,
             }],
             validate: vec![ValidateRule {
                 match_spec: MatchSpec {
@@ -657,6 +812,8 @@ mod tests {
                     guard: None,
                     tags: None,
                     input: None,
+                    name: None,
+                    animation: None,
                 },
                 require: serde_json::json!({ "hitstop": { "min": 1 } }),
                 severity: Severity::Error,
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Custom schema for Option<(u8, u8)>
-fn optional_frame_range_schema(gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+pub(super) fn optional_frame_range_schema(gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
     gen.subschema_for::<Option<[u8; 2]>>()
 }
 
@@ -17,6 +17,9 @@ pub struct CharacterResource {
     pub name: String,
     pub start: u16,
     pub max: u16,
+    /// Amount added every frame; negative values decay the resource.
+    #[serde(default)]
+    pub regen: i16,
 }
 
 /// Resource delta applied by a trigger.
@@ -273,6 +273,16 @@ pub struct State {
     pub costs: Option<Vec<Cost>>,
     pub movement: Option<Movement>,
     pub super_freeze: Option<SuperFreeze>,
+    /// Frames `[start, end]` (inclusive) in which the state can cancel back to
+    /// idle (kara cancel).
+    #[schemars(schema_with = "effects::optional_frame_range_schema")]
+    pub kara: Option<(u8, u8)>,
+    /// The state is authored as airborne. Pushbox collision follows the
+    /// runtime's grounded state, not this flag.
+    pub airborne: Option<bool>,
+    /// The state can be entered during hitstun or blockstun (bursts, alpha
+    /// counters).
+    pub allow_from_stun: Option<bool>,
     pub on_use: Option<OnUse>,
     pub on_hit: Option<OnHit>,
     pub on_block: Option<OnBlock>,
@@ -322,6 +332,9 @@ impl Default for State {
             costs: None,
             movement: None,
             super_freeze: None,
+            kara: None,
+            airborne: None,
+            allow_from_stun: None,
             on_use: None,
             on_hit: None,
             on_block: None,
@@ -159,7 +159,21 @@ pub fn validate_variants_no_chain(
 }
 
 /// Flatten all variants into fully resolved states.
+///
+/// Fails with every variant error joined by `"; "` if any variant is broken.
 pub fn flatten_variants(states: Vec<(String, State)>) -> Result<Vec<State>, String> {
+    let (result, errors) = flatten_variants_lenient(states);
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(result)
+}
+
+/// Flatten variants, leaving out the ones whose base can't be resolved.
+///
+/// Returns the resolved states alongside every variant error, so the editor
+/// can still load a character with a broken variant and report it.
+pub fn flatten_variants_lenient(states: Vec<(String, State)>) -> (Vec<State>, Vec<String>) {
     let mut base_map: HashMap<String, State> = HashMap::new();
     let mut variants: Vec<(String, State)> = Vec::new();
 
@@ -174,9 +188,6 @@ pub fn flatten_variants(states: Vec<(String, State)>) -> Result<Vec<State>, Stri
     let base_names: HashSet<String> = base_map.keys().cloned().collect();
     let variant_names: HashSet<String> = variants.iter().map(|(n, _)| n.clone()).collect();
     let errors = validate_variants_no_chain(&variants, &base_names, &variant_names);
-    if !errors.is_empty() {
-        return Err(errors.join("; "));
-    }
 
     let mut result: Vec<State> = Vec::new();
     for (name, mut state) in base_map.clone() {
@@ -185,15 +196,14 @@ pub fn flatten_variants(states: Vec<(String, State)>) -> Result<Vec<State>, Stri
     }
 
     for (name, overlay) in variants {
-        let base_name = overlay.base.as_ref().unwrap();
-        let base = base_map.get(base_name).ok_or_else(|| {
-            format!("Base state '{}' not found for variant '{}'", base_name, name)
-        })?;
-        let resolved = resolve_variant(base, &overlay, &name);
-        result.push(resolved);
+        // Missing and chained bases were reported by validation above.
+        let Some(base) = overlay.base.as_ref().and_then(|b| base_map.get(b)) else {
+            continue;
+        };
+        result.push(resolve_variant(base, &overlay, &name));
     }
 
-    Ok(result)
+    (result, errors)
 }
 
 #[cfg(test)]
@@ -445,6 +455,28 @@ mod tests {
         assert!(result.unwrap_err().contains("Base state '5H' not found"));
     }
 
+    #[test]
+    fn flatten_lenient_skips_broken_variants_and_reports_them() {
+        let base = State {
+            input: "5L".to_string(),
+            ..Default::default()
+        };
+        let orphan = State {
+            base: Some("5H".to_string()),
+            ..Default::default()
+        };
+
+        let states = vec![("5L".to_string(), base), ("5H~level1".to_string(), orphan)];
+
+        let (flattened, errors) = flatten_variants_lenient(states);
+        assert_eq!(flattened.len(), 1);
+        assert_eq!(flattened[0].id.as_deref(), Some("5L"));
+        assert_eq!(
+            errors,
+            vec!["Variant '5H~level1': Base state '5H' not found"]
+        );
+    }
+
     #[test]
     fn integration_load_and_flatten() {
         let base_json = r#"{
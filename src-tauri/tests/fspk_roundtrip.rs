@@ -38,6 +38,8 @@ fn fspk_export_roundtrips_through_reader() {
             .is_some(),
         "pack should contain a string table"
     );
+    pack.validate_strings()
+        .expect("every string reference should resolve");
 
     let moves = pack.states().expect("pack should contain a moves section");
     assert_eq!(moves.len(), char_data.moves.len());
@@ -105,6 +107,9 @@ fn fspk_move_record_fields_match_reader_layout() {
             costs: None,
             movement: None,
             super_freeze: None,
+            kara: None,
+            airborne: None,
+            allow_from_stun: None,
             on_use: None,
             on_hit: None,
             on_block: None,
@@ -116,6 +121,7 @@ fn fspk_move_record_fields_match_reader_layout() {
             id: None,
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export zx-fspack bytes");
@@ -243,6 +249,9 @@ fn fspk_pushbox_chain_roundtrip() {
             costs: None,
             movement: None,
             super_freeze: None,
+            kara: None,
+            airborne: None,
+            allow_from_stun: None,
             on_use: None,
             on_hit: None,
             on_block: None,
@@ -253,6 +262,7 @@ fn fspk_pushbox_chain_roundtrip() {
             id: None,
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -289,6 +299,1320 @@ fn fspk_pushbox_chain_roundtrip() {
     assert_eq!(shape.d_raw(), 1280, "pushbox h (Q12.4)");
 }
 
+/// Every known move type string exports to a byte that decodes to the matching `StateType`.
+#[test]
+fn fspk_state_type_roundtrips_through_enum() {
+    use framesmith_fspack::StateType;
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, State};
+
+    let cases: [(Option<&str>, StateType); 11] = [
+        (Some("normal"), StateType::Normal),
+        (Some("command_normal"), StateType::CommandNormal),
+        (Some("special"), StateType::Special),
+        (Some("super"), StateType::Super),
+        (Some("movement"), StateType::Movement),
+        (Some("throw"), StateType::Throw),
+        (Some("ex"), StateType::Ex),
+        (Some("rekka"), StateType::Rekka),
+        (Some("system"), StateType::System),
+        (Some("custom_type"), StateType::Unknown(255)),
+        (None, StateType::Normal),
+    ];
+
+    for (move_type, expected) in cases {
+        let char_data = CharacterData {
+            character: make_test_character("t"),
+            moves: vec![State {
+                input: "5L".to_string(),
+                move_type: move_type.map(str::to_string),
+                ..Default::default()
+            }],
+            cancel_table: CancelTable::default(),
+            variant_errors: Vec::new(),
+        };
+
+        let bytes = codegen::export_fspk(&char_data, None).expect("export");
+        let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+        let mv = pack.states().expect("states").get(0).expect("state 0");
+
+        assert_eq!(mv.state_type_enum(), expected, "move type {:?}", move_type);
+        assert_eq!(expected.to_u8(), mv.state_type());
+    }
+}
+
+/// State movement exports as a parallel record the runtime can integrate.
+#[test]
+fn fspk_state_movement_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, Movement, State, Vec2};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                startup: 3,
+                active: 2,
+                recovery: 5,
+                ..Default::default()
+            },
+            State {
+                input: "66".to_string(),
+                startup: 3,
+                active: 2,
+                recovery: 5,
+                movement: Some(Movement {
+                    velocity: Some(Vec2 { x: 4.0, y: 0.0 }),
+                    frames: Some((3, 6)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let movements = pack.state_movements().expect("movement section");
+    assert_eq!(movements.len(), 2, "one record per state");
+
+    // Sorted by input: "5L" (index 0) then "66" (index 1)
+    assert!(!movements.get(0).unwrap().is_moving());
+    let dash = movements.get(1).unwrap();
+    assert_eq!((dash.start_frame(), dash.end_frame()), (3, 6));
+    assert_eq!(dash.velocity_x(), 4 * 256);
+    assert_eq!(dash.velocity_y(), 0);
+}
+
+/// A kara window exports into the state record and reads back as inclusive frames.
+#[test]
+fn fspk_kara_window_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                startup: 3,
+                active: 2,
+                recovery: 5,
+                ..Default::default()
+            },
+            State {
+                input: "5M".to_string(),
+                startup: 6,
+                active: 3,
+                recovery: 10,
+                kara: Some((1, 3)),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let states = pack.states().expect("states");
+
+    assert_eq!(states.get(0).unwrap().kara_window(), None);
+    assert_eq!(states.get(1).unwrap().kara_window(), Some((1, 3)));
+}
+
+/// Meter gain exports into STATE_METER_GAIN and feeds the runtime's meter award.
+#[test]
+fn fspk_meter_gain_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, CharacterResource, MeterGain, State};
+
+    let mut character = make_test_character("t");
+    character.resources = vec![CharacterResource {
+        name: "meter".to_string(),
+        start: 0,
+        max: 1000,
+        regen: 0,
+    }];
+    let char_data = CharacterData {
+        character,
+        moves: vec![State {
+            input: "5L".to_string(),
+            startup: 3,
+            active: 2,
+            recovery: 5,
+            meter_gain: MeterGain {
+                hit: 100,
+                whiff: 15,
+            },
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let gain = pack
+        .state_meter_gains()
+        .expect("meter gains")
+        .get(0)
+        .unwrap();
+    assert_eq!((gain.meter_gain_hit(), gain.meter_gain_whiff()), (100, 15));
+
+    let mut rt = framesmith_runtime::CharacterState::default();
+    framesmith_runtime::init_resources(&mut rt, &pack).expect("resources fit");
+    framesmith_runtime::report_hit(&mut rt);
+    framesmith_runtime::grant_meter_on_hit(&mut rt, &pack);
+    let meter = framesmith_runtime::resource_index(&pack, "meter").unwrap();
+    assert_eq!(framesmith_runtime::resource(&rt, meter as u8), 100);
+}
+
+/// On-use meter and sub-state export into STATE_ON_USE and apply once at move start.
+#[test]
+fn fspk_on_use_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, CharacterResource, EntersState, OnUse, State};
+
+    let mut character = make_test_character("t");
+    character.resources = vec![CharacterResource {
+        name: "meter".to_string(),
+        start: 0,
+        max: 1000,
+        regen: 0,
+    }];
+    let char_data = CharacterData {
+        character,
+        moves: vec![
+            State {
+                input: "stance".to_string(),
+                ..Default::default()
+            },
+            State {
+                input: "236K".to_string(),
+                on_use: Some(OnUse {
+                    enters_state: Some(EntersState {
+                        name: "rekka".to_string(),
+                        duration: Some(25),
+                        persistent: None,
+                        exit_input: None,
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            State {
+                input: "22P".to_string(),
+                on_use: Some(OnUse {
+                    enters_state: Some(EntersState {
+                        name: "stance".to_string(),
+                        duration: None,
+                        persistent: None,
+                        exit_input: None,
+                    }),
+                    gain_meter: Some(50),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let on_use = pack.state_on_uses().expect("on-use section");
+    assert_eq!(on_use.len(), 3, "one record per state");
+
+    // Sorted by input: "22P", "236K", "stance"
+    let entry = on_use.get(0).unwrap();
+    assert_eq!((entry.enters_state(), entry.gain_meter()), (Some(2), 50));
+    // "rekka" is a mode, not a state, so there is nothing to enter
+    let rekka = on_use.get(1).unwrap();
+    assert_eq!((rekka.enters_state(), rekka.gain_meter()), (None, 0));
+    let stance = on_use.get(2).unwrap();
+    assert_eq!((stance.enters_state(), stance.gain_meter()), (None, 0));
+
+    // What couldn't be encoded is reported rather than dropped silently
+    assert_eq!(
+        codegen::fspk_export_warnings(&char_data),
+        vec![
+            "236K: on_use.enters_state 'rekka' is not a state; exported as none".to_string(),
+            "236K: on_use.enters_state.duration is not exported".to_string(),
+        ]
+    );
+
+    let mut rt = framesmith_runtime::CharacterState::default();
+    framesmith_runtime::init_resources(&mut rt, &pack).expect("resources fit");
+    framesmith_runtime::apply_on_use(&mut rt, &pack, 0);
+    let meter = framesmith_runtime::resource_index(&pack, "meter").unwrap();
+    assert_eq!(framesmith_runtime::resource(&rt, meter as u8), 50);
+}
+
+/// An on-hit resource delta decodes back to its name, amount and trigger.
+#[test]
+fn fspk_on_hit_resource_delta_roundtrip() {
+    use framesmith_fspack::ResourceTrigger;
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, CharacterResource, OnHit, ResourceDelta, State};
+
+    let mut character = make_test_character("t");
+    character.resources = vec![CharacterResource {
+        name: "heat".to_string(),
+        start: 0,
+        max: 100,
+        regen: 0,
+    }];
+    let char_data = CharacterData {
+        character,
+        moves: vec![State {
+            input: "5H".to_string(),
+            on_hit: Some(OnHit {
+                resource_deltas: vec![ResourceDelta {
+                    name: "heat".to_string(),
+                    delta: 30,
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let deltas = pack
+        .move_resource_deltas()
+        .expect("resource deltas section");
+    let (off, len) = pack
+        .state_extras()
+        .unwrap()
+        .get(0)
+        .unwrap()
+        .resource_deltas();
+    assert_eq!(len, 1);
+
+    let delta = deltas.get_at(off, 0).expect("delta 0");
+    assert_eq!(
+        pack.string(delta.name_off(), delta.name_len()),
+        Some("heat")
+    );
+    assert_eq!(delta.delta(), 30);
+    assert_eq!(
+        delta.trigger(),
+        framesmith_fspack::RESOURCE_DELTA_TRIGGER_ON_HIT
+    );
+    assert_eq!(delta.trigger_enum(), ResourceTrigger::OnHit);
+}
+
+/// A super's freeze exports into STATE_SUPER_FREEZE and freezes both characters at its start.
+#[test]
+fn fspk_super_freeze_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{
+        CancelCondition, CancelTable, CancelTagRule, State, SuperFreeze, Tag,
+    };
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                total: Some(20),
+                ..Default::default()
+            },
+            State {
+                input: "236236P".to_string(),
+                total: Some(40),
+                tags: vec![Tag::new("super").unwrap()],
+                super_freeze: Some(SuperFreeze {
+                    frames: 30,
+                    zoom: Some(1.5),
+                    darken: Some(0.5),
+                    flash: Some(true),
+                }),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable {
+            tag_rules: vec![CancelTagRule {
+                from: "any".to_string(),
+                to: "super".to_string(),
+                on: CancelCondition::WHIFF,
+                after_frame: 0,
+                before_frame: 255,
+            }],
+            ..Default::default()
+        },
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let freezes = pack.state_super_freezes().expect("super freeze section");
+    assert_eq!(freezes.len(), 2, "one record per state");
+
+    // Sorted by input: "236236P", "5L"
+    assert_eq!(
+        pack.super_freeze(0),
+        Some(framesmith_fspack::SuperFreezeData {
+            frames: 30,
+            zoom: framesmith_fspack::Q8_8::from_raw(384),
+            darken: 128,
+            flash: true,
+        })
+    );
+    assert_eq!(pack.super_freeze(1), None);
+
+    // P1 cancels 5L into the super; both characters freeze for 30 frames
+    let p1 = framesmith_runtime::CharacterState {
+        current_state: 1,
+        ..Default::default()
+    };
+    let p2 = framesmith_runtime::CharacterState {
+        current_state: 1,
+        frame: 3,
+        ..Default::default()
+    };
+    let request = framesmith_runtime::FrameInput {
+        requested_state: Some(0),
+        ..Default::default()
+    };
+    let result = framesmith_runtime::step_exchange(
+        &p1,
+        &p2,
+        (&pack, &pack),
+        (&request, &framesmith_runtime::FrameInput::default()),
+        ((0, 0), (200, 0)),
+        framesmith_runtime::TradePolicy::Trade,
+    );
+    assert_eq!(result.p1.current_state, 0);
+    assert_eq!(result.super_freeze, 30);
+    assert_eq!((result.p1.hitstop, result.p2.hitstop), (30, 30));
+}
+
+/// On-hit bounces and launch knockback export onto every hit window of the move.
+#[test]
+fn fspk_launcher_hit_window_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, FrameHitbox, Knockback, OnHit, Rect, State};
+
+    let hitbox = FrameHitbox {
+        frames: (5, 7),
+        r#box: Rect {
+            x: 0,
+            y: -60,
+            w: 40,
+            h: 30,
+        },
+    };
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                startup: 3,
+                active: 2,
+                recovery: 5,
+                hitboxes: vec![hitbox.clone()],
+                ..Default::default()
+            },
+            State {
+                input: "2H".to_string(),
+                startup: 5,
+                active: 3,
+                recovery: 20,
+                hitboxes: vec![hitbox],
+                on_hit: Some(OnHit {
+                    knockback: Some(Knockback {
+                        knockback_type: "launch".to_string(),
+                        x: Some(10),
+                        y: Some(-45),
+                    }),
+                    wall_bounce: Some(true),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let hit_window = |input: &str| {
+        let (idx, _) = pack.find_state_by_input(input).expect("state");
+        pack.state_windows(idx)
+            .unwrap()
+            .hit_windows()
+            .next()
+            .expect("hit window")
+    };
+
+    let jab = hit_window("5L");
+    assert_eq!(
+        (jab.launch(), jab.wall_bounce(), jab.ground_bounce()),
+        (0, false, false)
+    );
+
+    let launcher = hit_window("2H");
+    assert_eq!(launcher.launch(), -45);
+    assert!(launcher.wall_bounce());
+    assert!(!launcher.ground_bounce());
+    assert!(!launcher.is_throw());
+
+    // Both knockback components export to the parallel knockback section.
+    let knockbacks = pack.state_knockbacks().expect("knockback section");
+    let knockback = |input: &str| {
+        let (idx, _) = pack.find_state_by_input(input).expect("state");
+        let k = knockbacks.get(idx).expect("knockback record");
+        (k.x(), k.y())
+    };
+    assert_eq!(knockback("5L"), (0, 0));
+    assert_eq!(knockback("2H"), (10, -45));
+}
+
+/// Lock the HitWindow24 layout: every field the exporter writes reads back
+/// through its `HitWindowView` accessor, for both flat hitboxes and `hits`.
+#[test]
+fn fspk_hit_window_fields_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{
+        CancelTable, FrameHitbox, GuardType, Hit, HitboxShape, Knockback, OnHit, Pushback, Rect,
+        State,
+    };
+
+    let on_hit = OnHit {
+        knockback: Some(Knockback {
+            knockback_type: "launch".to_string(),
+            x: Some(4),
+            y: Some(-30),
+        }),
+        ground_bounce: Some(true),
+        ..Default::default()
+    };
+    let base = State {
+        input: "5H".to_string(),
+        startup: 6,
+        active: 6,
+        recovery: 14,
+        damage: 700,
+        hitstun: 21,
+        blockstun: 17,
+        hitstop: 11,
+        guard: GuardType::Low,
+        pushback: Pushback { hit: 3, block: 5 },
+        on_hit: Some(on_hit),
+        ..Default::default()
+    };
+    let rect = Rect {
+        x: 5,
+        y: -40,
+        w: 30,
+        h: 20,
+    };
+    let flat = State {
+        hitboxes: vec![
+            FrameHitbox {
+                frames: (6, 7),
+                r#box: rect.clone(),
+            },
+            FrameHitbox {
+                frames: (8, 11),
+                r#box: rect,
+            },
+        ],
+        ..base.clone()
+    };
+    let hit = |frames, damage, chip| Hit {
+        frames,
+        damage,
+        chip_damage: Some(chip),
+        hitstun: 19,
+        blockstun: 13,
+        hitstop: 7,
+        guard: GuardType::High,
+        hitboxes: vec![
+            HitboxShape::Aabb {
+                x: 0,
+                y: -30,
+                w: 20,
+                h: 10,
+            },
+            HitboxShape::Aabb {
+                x: 10,
+                y: -30,
+                w: 20,
+                h: 10,
+            },
+        ],
+        cancels: vec![],
+    };
+    let multi = State {
+        hits: Some(vec![hit((6, 7), 300, 15), hit((9, 11), 450, 23)]),
+        ..base
+    };
+
+    // (state, start, end, guard, damage, chip, hitstun, blockstun, hitstop, shapes_off, shapes_len)
+    let cases = [
+        (flat, 8, 11, 2, 700, 0, 21, 17, 11, 12, 1),
+        (multi, 9, 11, 0, 450, 23, 19, 13, 7, 24, 2),
+    ];
+    for (state, start, end, guard, damage, chip, hitstun, blockstun, hitstop, off, len) in cases {
+        let char_data = CharacterData {
+            character: make_test_character("t"),
+            moves: vec![state],
+            cancel_table: CancelTable::default(),
+            variant_errors: Vec::new(),
+        };
+        let bytes = codegen::export_fspk(&char_data, None).expect("export");
+        let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+        let hw = pack
+            .state_windows(0)
+            .unwrap()
+            .hit_windows()
+            .nth(1)
+            .expect("second window");
+
+        assert_eq!((hw.start_frame(), hw.end_frame()), (start, end));
+        assert_eq!(hw.guard(), guard);
+        assert_eq!(hw.flags(), framesmith_fspack::HIT_FLAG_GROUND_BOUNCE);
+        assert_eq!(hw.damage(), damage);
+        assert_eq!(hw.chip_damage(), chip);
+        assert_eq!(
+            (hw.hitstun(), hw.blockstun(), hw.hitstop()),
+            (hitstun, blockstun, hitstop)
+        );
+        assert_eq!(hw.launch(), -30);
+        assert_eq!((hw.shapes_off(), hw.shapes_len()), (off, len));
+        // Per-hit cancels live in STATE_HIT_CANCELS; the window's own range is unused.
+        assert_eq!((hw.cancels_off(), hw.cancels_len()), (0, 0));
+        // Pushback is per state, in the parallel STATE_PUSHBACK section.
+        let pushback = pack.state_pushbacks().unwrap().get(0).unwrap();
+        assert_eq!(
+            (pushback.hit_pushback_raw(), pushback.block_pushback_raw()),
+            (48, 80)
+        );
+        assert_eq!(
+            (pushback.hit_pushback_px(), pushback.block_pushback_px()),
+            (3, 5)
+        );
+    }
+}
+
+/// Verify state flags (airborne, allow_from_stun) are written to the state record flags byte.
+#[test]
+fn fspk_state_flags_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                startup: 3,
+                active: 2,
+                recovery: 5,
+                ..Default::default()
+            },
+            State {
+                input: "j.H".to_string(),
+                startup: 8,
+                active: 4,
+                recovery: 10,
+                airborne: Some(true),
+                ..Default::default()
+            },
+            State {
+                input: "burst".to_string(),
+                startup: 10,
+                active: 5,
+                recovery: 20,
+                allow_from_stun: Some(true),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let states = pack.states().expect("states");
+    let flags_of = |input: &str| {
+        let (idx, _) = pack.find_state_by_input(input).expect("state");
+        let state = states.get(idx).unwrap();
+        (state.is_airborne(), state.allows_from_stun())
+    };
+
+    assert_eq!(flags_of("5L"), (false, false));
+    assert_eq!(flags_of("j.H"), (true, false));
+    assert_eq!(flags_of("burst"), (false, true));
+}
+
+/// Verify distance preconditions are exported to STATE_CONDITIONS and read back per state.
+#[test]
+fn fspk_distance_precondition_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, Precondition, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                startup: 3,
+                active: 2,
+                recovery: 5,
+                ..Default::default()
+            },
+            State {
+                input: "6H".to_string(),
+                startup: 10,
+                active: 3,
+                recovery: 15,
+                preconditions: Some(vec![Precondition::Distance {
+                    min: Some(100),
+                    max: None,
+                }]),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    assert!(pack.state_conditions(0).is_none(), "5L has no conditions");
+    let conditions = pack.state_conditions(1).unwrap();
+    assert_eq!(conditions.len(), 1);
+    let cond = conditions.get(0).unwrap();
+    assert_eq!(cond.kind(), framesmith_fspack::CONDITION_KIND_DISTANCE);
+    assert_eq!(cond.min(), Some(100));
+    assert_eq!(cond.max(), None);
+}
+
+/// Verify charge preconditions export as CONDITION_KIND_CHARGE records.
+#[test]
+fn fspk_charge_precondition_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, Precondition, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![State {
+            input: "236P".to_string(),
+            startup: 12,
+            active: 4,
+            recovery: 20,
+            preconditions: Some(vec![Precondition::Charge {
+                direction: "down".to_string(),
+                min_frames: 40,
+            }]),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    let conditions = pack.state_conditions(0).unwrap();
+    assert_eq!(conditions.len(), 1);
+    let cond = conditions.get(0).unwrap();
+    assert_eq!(cond.kind(), framesmith_fspack::CONDITION_KIND_CHARGE);
+    assert_eq!(cond.arg(), framesmith_fspack::CHARGE_DIR_DOWN);
+    assert_eq!(cond.min(), Some(40));
+    assert_eq!(cond.max(), None);
+}
+
+/// Verify grounded/airborne preconditions export as argument-less condition records.
+#[test]
+fn fspk_grounded_airborne_precondition_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, Precondition, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                preconditions: Some(vec![Precondition::Grounded]),
+                ..Default::default()
+            },
+            State {
+                input: "j.L".to_string(),
+                preconditions: Some(vec![Precondition::Airborne]),
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    for (index, kind) in [
+        (0, framesmith_fspack::CONDITION_KIND_GROUNDED),
+        (1, framesmith_fspack::CONDITION_KIND_AIRBORNE),
+    ] {
+        let conditions = pack.state_conditions(index).unwrap();
+        assert_eq!(conditions.len(), 1);
+        let cond = conditions.get(0).unwrap();
+        assert_eq!(cond.kind(), kind);
+        assert_eq!((cond.min(), cond.max()), (None, None));
+    }
+}
+
+fn rekka_hit(frames: (u8, u8), cancels: &[&str]) -> framesmith_lib::schema::Hit {
+    framesmith_lib::schema::Hit {
+        frames,
+        damage: 40,
+        chip_damage: None,
+        hitstun: 15,
+        blockstun: 10,
+        hitstop: 8,
+        guard: framesmith_lib::schema::GuardType::Mid,
+        hitboxes: vec![],
+        cancels: cancels.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+/// Verify each `hits[]` entry exports as its own hit window with its own values and shapes.
+#[test]
+fn fspk_multi_hit_windows_roundtrip() {
+    use framesmith_fspack::DecodedShape;
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, FrameHitbox, GuardType, HitboxShape, Rect, State};
+
+    let first = framesmith_lib::schema::Hit {
+        chip_damage: Some(3),
+        hitboxes: vec![HitboxShape::Aabb {
+            x: 10,
+            y: -40,
+            w: 30,
+            h: 20,
+        }],
+        ..rekka_hit((5, 6), &[])
+    };
+    let second = framesmith_lib::schema::Hit {
+        damage: 90,
+        hitstun: 22,
+        guard: GuardType::Low,
+        hitboxes: vec![
+            HitboxShape::Circle {
+                x: 20,
+                y: -10,
+                r: 12,
+            },
+            HitboxShape::Aabb {
+                x: 0,
+                y: -8,
+                w: 16,
+                h: 8,
+            },
+        ],
+        ..rekka_hit((12, 14), &[])
+    };
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![State {
+            input: "236P".to_string(),
+            damage: 999,
+            // Ignored: `hits` takes over the move's hit windows.
+            hitboxes: vec![FrameHitbox {
+                frames: (1, 2),
+                r#box: Rect {
+                    x: 0,
+                    y: 0,
+                    w: 1,
+                    h: 1,
+                },
+            }],
+            hits: Some(vec![first, second]),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let windows: Vec<_> = pack
+        .state_windows(0)
+        .expect("state 0")
+        .hit_windows()
+        .collect();
+    assert_eq!(windows.len(), 2);
+
+    let (a, b) = (&windows[0], &windows[1]);
+    assert_eq!((a.start_frame(), a.end_frame()), (5, 6));
+    assert_eq!((a.damage(), a.chip_damage(), a.hitstun()), (40, 3, 15));
+    assert_eq!((b.start_frame(), b.end_frame()), (12, 14));
+    assert_eq!((b.damage(), b.chip_damage(), b.hitstun()), (90, 0, 22));
+    assert_ne!(a.guard(), b.guard());
+
+    let decoded = pack.decode();
+    let shapes: Vec<_> = decoded.states[0]
+        .hit_windows
+        .iter()
+        .map(|w| w.shapes.clone())
+        .collect();
+    assert_eq!(
+        shapes,
+        vec![
+            vec![DecodedShape::Aabb {
+                x: 10,
+                y: -40,
+                w: 30,
+                h: 20
+            }],
+            vec![
+                DecodedShape::Circle {
+                    x: 20,
+                    y: -10,
+                    r: 12
+                },
+                DecodedShape::Aabb {
+                    x: 0,
+                    y: -8,
+                    w: 16,
+                    h: 8
+                },
+            ],
+        ]
+    );
+}
+
+/// Verify per-hit cancel routes are exported in frame order with resolved targets.
+#[test]
+fn fspk_per_hit_cancels_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, State};
+
+    let state = |input: &str, hits| State {
+        input: input.to_string(),
+        hits,
+        ..Default::default()
+    };
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            state("5L", None),
+            // Authored out of order: the exporter sorts hits by start frame.
+            state(
+                "236K",
+                Some(vec![
+                    rekka_hit((15, 17), &["236K~K~K"]),
+                    rekka_hit((5, 7), &["236K~K", "5L"]),
+                ]),
+            ),
+            state("236K~K", None),
+            state("236K~K~K", None),
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let cancels = pack.cancels().expect("CANCELS_U16 section");
+    let index = |input: &str| pack.find_state_by_input(input).expect(input).0 as u16;
+
+    assert!(pack.state_hit_cancels(index("5L") as usize).is_none());
+    let hits = pack
+        .state_hit_cancels(index("236K") as usize)
+        .expect("236K hit cancels");
+    let routes: Vec<((u8, u8), Vec<u16>)> = hits
+        .iter()
+        .map(|hit| {
+            let (off, len) = hit.cancels();
+            let targets = (0..len as usize)
+                .map(|i| cancels.get_at(off, i).unwrap())
+                .collect();
+            ((hit.start_frame(), hit.end_frame()), targets)
+        })
+        .collect();
+    assert_eq!(
+        routes,
+        vec![
+            ((5, 7), vec![index("236K~K"), index("5L")]),
+            ((15, 17), vec![index("236K~K~K")]),
+        ]
+    );
+}
+
+#[test]
+fn fspk_per_hit_cancel_to_unknown_move_fails_export() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![State {
+            input: "236K".to_string(),
+            hits: Some(vec![rekka_hit((5, 7), &["missing"])]),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let err = codegen::export_fspk(&char_data, None).unwrap_err();
+    assert!(err.contains("'missing'"), "{err}");
+}
+
+/// Verify `PackView::decode` recovers a move's frame data and boxes from an export.
+#[test]
+fn fspk_decode_matches_source_state() {
+    use framesmith_fspack::DecodedShape;
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, FrameHitbox, Rect, State};
+
+    let source = State {
+        input: "2M".to_string(),
+        startup: 8,
+        active: 4,
+        recovery: 14,
+        damage: 60,
+        hitstun: 18,
+        blockstun: 12,
+        hitstop: 9,
+        hitboxes: vec![FrameHitbox {
+            frames: (8, 11),
+            r#box: Rect {
+                x: 5,
+                y: -20,
+                w: 40,
+                h: 12,
+            },
+        }],
+        hurtboxes: vec![FrameHitbox {
+            frames: (0, 25),
+            r#box: Rect {
+                x: -15,
+                y: -50,
+                w: 30,
+                h: 50,
+            },
+        }],
+        ..Default::default()
+    };
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![source.clone()],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let decoded = framesmith_fspack::PackView::parse(&bytes)
+        .expect("parse")
+        .decode();
+
+    assert_eq!(decoded.states.len(), 1);
+    let state = &decoded.states[0];
+    assert_eq!(state.input.as_deref(), Some(source.input.as_str()));
+    assert_eq!(
+        (state.startup, state.active, state.recovery),
+        (source.startup, source.active, source.recovery)
+    );
+    assert_eq!(state.damage, source.damage);
+    assert_eq!(state.hitstun, source.hitstun);
+    assert_eq!(state.blockstun, source.blockstun);
+    assert_eq!(state.hitstop, source.hitstop);
+
+    assert_eq!(state.hit_windows.len(), 1);
+    let hit = &state.hit_windows[0];
+    assert_eq!((hit.start_frame, hit.end_frame), source.hitboxes[0].frames);
+    assert_eq!(
+        hit.shapes,
+        vec![DecodedShape::Aabb {
+            x: 5,
+            y: -20,
+            w: 40,
+            h: 12
+        }]
+    );
+
+    assert_eq!(state.hurt_windows.len(), 1);
+    let hurt = &state.hurt_windows[0];
+    assert_eq!(
+        (hurt.start_frame, hurt.end_frame),
+        source.hurtboxes[0].frames
+    );
+    assert_eq!(
+        hurt.shapes,
+        vec![DecodedShape::Aabb {
+            x: -15,
+            y: -50,
+            w: 30,
+            h: 50
+        }]
+    );
+}
+
+/// Verify throw moves export their hit windows with the throw flag set.
+#[test]
+fn fspk_throw_hit_window_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, FrameHitbox, Rect, State};
+
+    let hitbox = FrameHitbox {
+        frames: (5, 6),
+        r#box: Rect {
+            x: 0,
+            y: -40,
+            w: 20,
+            h: 20,
+        },
+    };
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5LP".to_string(),
+                startup: 5,
+                active: 2,
+                recovery: 10,
+                hitboxes: vec![hitbox.clone()],
+                ..Default::default()
+            },
+            State {
+                input: "6HP+HK".to_string(),
+                move_type: Some("throw".to_string()),
+                startup: 5,
+                active: 2,
+                recovery: 20,
+                hitboxes: vec![hitbox],
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    let strike = pack.state_windows(0).unwrap().hit_windows().next().unwrap();
+    assert!(!strike.is_throw());
+    let throw = pack.state_windows(1).unwrap().hit_windows().next().unwrap();
+    assert!(throw.is_throw());
+    assert_eq!(throw.flags(), framesmith_fspack::HIT_FLAG_THROW);
+}
+
+/// Verify advanced hurtbox flags (armor/invuln) survive export and read back via `flags()`.
+#[test]
+fn fspk_advanced_hurtbox_flags_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, FrameHurtbox, HitboxShape, HurtboxFlag, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![State {
+            input: "5H".to_string(),
+            name: "Armored Heavy".to_string(),
+            startup: 12,
+            active: 4,
+            recovery: 20,
+            animation: "heavy".to_string(),
+            advanced_hurtboxes: Some(vec![FrameHurtbox {
+                frames: (0, 15),
+                boxes: vec![HitboxShape::Aabb {
+                    x: -10,
+                    y: -60,
+                    w: 30,
+                    h: 60,
+                }],
+                flags: Some(vec![HurtboxFlag::Armor]),
+            }]),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    let mv = pack
+        .states()
+        .expect("moves section")
+        .get(0)
+        .expect("move 0");
+    assert_eq!(mv.hurt_windows_len(), 1, "hurt_windows_len");
+
+    let hw = pack
+        .hurt_windows()
+        .expect("HURT_WINDOWS section")
+        .get_at(mv.hurt_windows_off(), 0)
+        .expect("hurt window 0");
+    assert_eq!(hw.start_frame(), 0);
+    assert_eq!(hw.end_frame(), 15);
+    assert_eq!(hw.flags(), framesmith_fspack::HURT_FLAG_ARMOR, "armor bit");
+    assert_eq!(
+        hw.flags() & framesmith_fspack::HURT_FLAG_STRIKE_INVULN,
+        0,
+        "armor is not invuln"
+    );
+
+    let shape = pack
+        .shapes()
+        .expect("SHAPES section")
+        .get_at(hw.shapes_off(), 0)
+        .expect("hurt shape");
+    assert_eq!(shape.kind(), framesmith_fspack::SHAPE_KIND_AABB);
+    assert_eq!(shape.a_raw(), -160, "hurt shape x (Q12.4)");
+}
+
+#[test]
+fn fspk_advanced_hurtboxes_replace_legacy_hurtboxes() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{
+        CancelTable, FrameHitbox, FrameHurtbox, HitboxShape, HurtboxFlag, Rect, State,
+    };
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![State {
+            input: "6H".to_string(),
+            startup: 14,
+            active: 3,
+            recovery: 18,
+            hurtboxes: vec![FrameHitbox {
+                frames: (0, 34),
+                r#box: Rect {
+                    x: -20,
+                    y: -70,
+                    w: 40,
+                    h: 70,
+                },
+            }],
+            advanced_hurtboxes: Some(vec![
+                FrameHurtbox {
+                    frames: (0, 13),
+                    boxes: vec![HitboxShape::Aabb {
+                        x: -12,
+                        y: -60,
+                        w: 24,
+                        h: 60,
+                    }],
+                    flags: Some(vec![HurtboxFlag::Armor]),
+                },
+                FrameHurtbox {
+                    frames: (14, 34),
+                    boxes: vec![HitboxShape::Aabb {
+                        x: -12,
+                        y: -60,
+                        w: 24,
+                        h: 60,
+                    }],
+                    flags: None,
+                },
+            ]),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let windows = pack.state_windows(0).expect("state 0 windows");
+
+    let hurt: Vec<_> = windows
+        .hurt_windows()
+        .map(|hw| ((hw.start_frame(), hw.end_frame()), hw.flags()))
+        .collect();
+    assert_eq!(
+        hurt,
+        vec![((0, 13), framesmith_fspack::HURT_FLAG_ARMOR), ((14, 34), 0)],
+        "legacy hurtbox should not be exported alongside advanced ones"
+    );
+}
+
+/// Verify each shape kind decodes through its typed accessor after export.
+#[test]
+fn fspk_shape_kinds_roundtrip_through_typed_accessors() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, FrameHurtbox, HitboxShape, State};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![State {
+            input: "5M".to_string(),
+            startup: 5,
+            active: 3,
+            recovery: 10,
+            advanced_hurtboxes: Some(vec![FrameHurtbox {
+                frames: (0, 17),
+                boxes: vec![
+                    HitboxShape::Aabb {
+                        x: -10,
+                        y: -60,
+                        w: 30,
+                        h: 60,
+                    },
+                    HitboxShape::Circle {
+                        x: 20,
+                        y: -40,
+                        r: 12,
+                    },
+                    HitboxShape::Capsule {
+                        x1: 0,
+                        y1: -50,
+                        x2: 40,
+                        y2: -30,
+                        r: 8,
+                    },
+                ],
+                flags: None,
+            }]),
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    let mv = pack
+        .states()
+        .expect("moves section")
+        .get(0)
+        .expect("move 0");
+    let hw = pack
+        .hurt_windows()
+        .expect("HURT_WINDOWS section")
+        .get_at(mv.hurt_windows_off(), 0)
+        .expect("hurt window 0");
+    assert_eq!(hw.shapes_len(), 3);
+    let shapes = pack.shapes().expect("SHAPES section");
+    let shape = |i| shapes.get_at(hw.shapes_off(), i).expect("shape");
+
+    assert_eq!(shape(0).as_aabb(), Some((-10, -60, 30, 60)));
+    assert_eq!(shape(0).as_circle(), None);
+
+    assert_eq!(shape(1).as_circle(), Some((20, -40, 12)));
+    assert_eq!(shape(1).as_aabb(), None);
+
+    assert_eq!(shape(2).as_capsule(), Some((0, -50, 40, -30, 8)));
+    assert_eq!(shape(2).as_aabb(), None);
+}
+
 #[test]
 fn fspk_exports_resources_and_events_sections() {
     use framesmith_lib::commands::CharacterData;
@@ -372,6 +1696,7 @@ fn fspk_exports_resources_and_events_sections() {
         name: "heat".to_string(),
         start: 0,
         max: 10,
+        regen: 0,
     }];
 
     let char_data = CharacterData {
@@ -394,10 +1719,13 @@ fn fspk_exports_resources_and_events_sections() {
             },
         ],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export zx-fspack bytes");
     let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse exported pack");
+    pack.validate_strings()
+        .expect("every string reference should resolve");
 
     // Resources section exists and decodes
     let resources = pack
@@ -466,6 +1794,17 @@ fn fspk_exports_resources_and_events_sections() {
         .expect("notify id");
     assert_eq!(n_id, "vfx.swing_trail");
 
+    // 5M: same notify through the resolved iterator
+    let resolved: Vec<_> = pack.notifies_for_state(idx_5m).collect();
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(resolved[0].frame(), 7);
+    let event = resolved[0].events().next().expect("resolved notify event");
+    assert_eq!(event.id(), "vfx.swing_trail");
+    assert_eq!(
+        event.arg("bone"),
+        Some(framesmith_fspack::EventArgValue::Str("hand_r"))
+    );
+
     // 236P: cost + precondition + on_use delta
     let costs = pack
         .move_resource_costs()
@@ -511,6 +1850,127 @@ fn fspk_exports_resources_and_events_sections() {
     );
 }
 
+/// Verify resource start, max and per-frame regen/decay decode from RESOURCE_DEFS.
+#[test]
+fn fspk_resource_regen_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, CharacterResource, State};
+
+    let mut character = make_test_character("t");
+    character.resources = vec![
+        CharacterResource {
+            name: "meter".to_string(),
+            start: 0,
+            max: 100,
+            regen: 2,
+        },
+        CharacterResource {
+            name: "heat".to_string(),
+            start: 50,
+            max: 50,
+            regen: -1,
+        },
+    ];
+    let char_data = CharacterData {
+        character,
+        moves: vec![State {
+            input: "5L".to_string(),
+            startup: 3,
+            active: 2,
+            recovery: 5,
+            ..Default::default()
+        }],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let resources = pack.resource_defs().expect("RESOURCE_DEFS section");
+    let decoded: Vec<(&str, u16, u16, i16)> = (0..resources.len())
+        .map(|i| {
+            let res = resources.get(i).unwrap();
+            let name = pack.string(res.name_off(), res.name_len()).unwrap();
+            (name, res.start(), res.max(), res.regen_per_frame())
+        })
+        .collect();
+
+    assert_eq!(decoded, vec![("meter", 0, 100, 2), ("heat", 50, 50, -1)]);
+}
+
+#[test]
+fn fspk_metadata_roundtrip() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::CancelTable;
+
+    let char_data = CharacterData {
+        character: make_test_character("glitch"),
+        moves: vec![],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let metadata = pack.metadata().expect("METADATA section");
+
+    assert_eq!(metadata.character_id(), Some("glitch"));
+    assert_eq!(metadata.exporter_version(), Some(env!("CARGO_PKG_VERSION")));
+    assert_eq!(metadata.build_timestamp(), None);
+}
+
+#[test]
+fn fspk_big_endian_export_decodes_like_little_endian() {
+    let char_data = commands::load_character("../characters".to_string(), "test_char".to_string())
+        .expect("load test_char character");
+
+    let le = codegen::export_fspk(&char_data, None).expect("export little-endian");
+    let be = codegen::export_fspk_big_endian(&char_data, None).expect("export big-endian");
+    assert_eq!(le.len(), be.len());
+    assert_ne!(le, be, "big-endian export should differ from little-endian");
+
+    let expected = framesmith_fspack::PackView::parse(&le).expect("parse little-endian");
+    let view = framesmith_fspack::PackView::parse(&be).expect("parse big-endian");
+    assert_eq!(view.endian(), framesmith_fspack::Endian::Big);
+    assert_eq!(view.decode(), expected.decode());
+    assert_eq!(view.validate_strings(), Ok(()));
+    assert_eq!(view.stats(), expected.stats());
+
+    let buf = framesmith_fspack::PackBuf::parse_owned(be.clone()).expect("own big-endian");
+    assert_eq!(buf.view().decode(), expected.decode());
+
+    let mut back = be;
+    framesmith_fspack::to_little_endian(&mut back).expect("convert back");
+    assert_eq!(back, le);
+}
+
+#[test]
+fn fspk_flags_mark_sorted_inputs() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, State};
+
+    let state = |input: &str| State {
+        input: input.to_string(),
+        startup: 3,
+        active: 2,
+        recovery: 5,
+        ..Default::default()
+    };
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![state("5H"), state("2L"), state("5L")],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    assert!(pack.flags().sorted_inputs());
+    let inputs: Vec<_> = pack.state_summaries().map(|s| s.input).collect();
+    assert_eq!(inputs, vec![Some("2L"), Some("5H"), Some("5L")]);
+}
+
 #[test]
 fn fspk_exports_move_input_notation() {
     use framesmith_lib::commands::CharacterData;
@@ -538,6 +1998,7 @@ fn fspk_exports_move_input_notation() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export zx-fspack bytes");
@@ -590,6 +2051,7 @@ fn tags_survive_roundtrip() {
             },
         ],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -632,6 +2094,7 @@ fn empty_tags_roundtrip() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -690,6 +2153,7 @@ fn cancel_tag_rules_roundtrip() {
         character: make_test_character("t"),
         moves: vec![mv0, mv1],
         cancel_table,
+        variant_errors: Vec::new(),
     };
 
     // Export and parse
@@ -726,6 +2190,128 @@ fn cancel_tag_rules_roundtrip() {
     assert_eq!(tags_236p, vec!["special"]);
 }
 
+/// Verify the resolved cancel tag rule iterator yields tag names and conditions.
+#[test]
+fn cancel_tag_rules_resolved_roundtrip() {
+    use framesmith_fspack::CancelCondition as PackCancelCondition;
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelCondition, CancelTable, CancelTagRule, State, Tag};
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                tags: vec![Tag::new("normal").unwrap()],
+                ..Default::default()
+            },
+            State {
+                input: "236P".to_string(),
+                tags: vec![Tag::new("special").unwrap()],
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable {
+            tag_rules: vec![
+                CancelTagRule {
+                    from: "normal".to_string(),
+                    to: "special".to_string(),
+                    on: CancelCondition(
+                        CancelCondition::HIT.to_binary() | CancelCondition::BLOCK.to_binary(),
+                    ),
+                    after_frame: 3,
+                    before_frame: 12,
+                },
+                CancelTagRule {
+                    from: "any".to_string(),
+                    to: "normal".to_string(),
+                    on: CancelCondition::WHIFF,
+                    after_frame: 0,
+                    before_frame: 255,
+                },
+            ],
+            ..Default::default()
+        },
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let rules = pack.cancel_tag_rules().expect("CANCEL_TAG_RULES section");
+    let resolved: Vec<_> = rules.resolved().collect();
+
+    assert_eq!(resolved.len(), 2);
+
+    assert_eq!(resolved[0].from, "normal");
+    assert_eq!(resolved[0].to, "special");
+    assert!(resolved[0].on.on_hit() && resolved[0].on.on_block());
+    assert!(!resolved[0].on.on_whiff());
+    assert_eq!((resolved[0].after_frame, resolved[0].before_frame), (3, 12));
+
+    assert_eq!(resolved[1].from, "any");
+    assert_eq!(resolved[1].to, "normal");
+    assert_eq!(resolved[1].on, PackCancelCondition::WHIFF);
+    assert_eq!(
+        (resolved[1].after_frame, resolved[1].before_frame),
+        (0, 255)
+    );
+}
+
+/// A character with both a tag rule and a deny pair exports both cancel sections.
+#[test]
+fn cancel_tag_rules_and_denies_export_together() {
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelCondition, CancelTable, CancelTagRule, State, Tag};
+
+    let mut deny = std::collections::HashMap::new();
+    deny.insert("236P".to_string(), vec!["5L".to_string()]);
+
+    let char_data = CharacterData {
+        character: make_test_character("t"),
+        moves: vec![
+            State {
+                input: "5L".to_string(),
+                tags: vec![Tag::new("normal").unwrap()],
+                ..Default::default()
+            },
+            State {
+                input: "236P".to_string(),
+                tags: vec![Tag::new("special").unwrap()],
+                ..Default::default()
+            },
+        ],
+        cancel_table: CancelTable {
+            tag_rules: vec![CancelTagRule {
+                from: "normal".to_string(),
+                to: "special".to_string(),
+                on: CancelCondition::ALWAYS,
+                after_frame: 0,
+                before_frame: 255,
+            }],
+            deny,
+        },
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+
+    let rules = pack.cancel_tag_rules().expect("CANCEL_TAG_RULES section");
+    assert_eq!(rules.len(), 1);
+    let rule = rules.get(0).unwrap();
+    assert_eq!(rule.from_tag(), Some("normal"));
+    assert_eq!(rule.to_tag(), Some("special"));
+
+    let idx_5l = pack.find_state_by_input("5L").expect("5L").0 as u16;
+    let idx_236p = pack.find_state_by_input("236P").expect("236P").0 as u16;
+    assert_eq!(
+        pack.cancel_denies().expect("CANCEL_DENIES section").len(),
+        4
+    );
+    assert!(pack.has_cancel_deny(idx_236p, idx_5l));
+    assert!(!pack.has_cancel_deny(idx_5l, idx_236p));
+}
+
 #[test]
 fn cancel_denies_roundtrip() {
     use framesmith_lib::commands::CharacterData;
@@ -764,6 +2350,7 @@ fn cancel_denies_roundtrip() {
         character: make_test_character("t"),
         moves: vec![mv0, mv1],
         cancel_table,
+        variant_errors: Vec::new(),
     };
 
     // Export and parse
@@ -912,6 +2499,7 @@ fn state_properties_scalar_survive_roundtrip() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -976,6 +2564,7 @@ fn state_properties_nested_flattened_on_export() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -1022,6 +2611,7 @@ fn state_without_properties_has_no_props_raw() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -1077,6 +2667,7 @@ fn mixed_states_with_and_without_properties() {
         character: make_test_character("t"),
         moves: vec![mv_no_props, mv_with_props],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     let bytes = codegen::export_fspk(&char_data, None).expect("export");
@@ -1109,6 +2700,61 @@ fn mixed_states_with_and_without_properties() {
 // Schema-Based Property Export Tests
 // =============================================================================
 
+#[test]
+fn character_properties_roundtrip_with_typed_values() {
+    use framesmith_fspack::{PROP_TYPE_BOOL, PROP_TYPE_Q24_8, PROP_TYPE_STR};
+    use framesmith_lib::commands::CharacterData;
+    use framesmith_lib::schema::{CancelTable, PropertyValue};
+
+    let mut character = make_test_character("t");
+    character
+        .properties
+        .insert("health".to_string(), PropertyValue::Number(10000.0));
+    character
+        .properties
+        .insert("walk_speed".to_string(), PropertyValue::Number(4.0));
+    character
+        .properties
+        .insert("can_air_dash".to_string(), PropertyValue::Bool(true));
+
+    let char_data = CharacterData {
+        character,
+        moves: vec![],
+        cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
+    };
+
+    let bytes = codegen::export_fspk(&char_data, None).expect("export");
+    let pack = framesmith_fspack::PackView::parse(&bytes).expect("parse");
+    let props = pack.character_props().expect("CHARACTER_PROPS section");
+    let find = |name: &str| {
+        props
+            .iter()
+            .find(|p| {
+                let (off, len) = p.name();
+                pack.string(off, len) == Some(name)
+            })
+            .unwrap_or_else(|| panic!("property '{}' should be exported", name))
+    };
+
+    let health = find("health");
+    assert_eq!(health.value_type(), PROP_TYPE_Q24_8);
+    assert_eq!(health.as_q24_8(), 10000 << 8);
+
+    let walk_speed = find("walk_speed");
+    assert_eq!(walk_speed.value_type(), PROP_TYPE_Q24_8);
+    assert_eq!(walk_speed.as_q24_8(), 4 << 8);
+
+    let air_dash = find("can_air_dash");
+    assert_eq!(air_dash.value_type(), PROP_TYPE_BOOL);
+    assert!(air_dash.as_bool());
+
+    let archetype = find("archetype");
+    assert_eq!(archetype.value_type(), PROP_TYPE_STR);
+    let (off, len) = archetype.as_str_ref();
+    assert_eq!(pack.string(off as u32, len), Some("test"));
+}
+
 #[test]
 fn schema_section_present_when_rules_have_property_schema() {
     use framesmith_lib::commands::CharacterData;
@@ -1136,12 +2782,14 @@ fn schema_section_present_when_rules_have_property_schema() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     // Create rules with property schema
     // Must include all character props from make_test_character()
     let rules = RulesFile {
         version: 1,
+        includes: vec![],
         registry: None,
         apply: vec![],
         validate: vec![],
@@ -1204,6 +2852,7 @@ fn schema_section_absent_when_no_property_schema() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     // Export without rules (no schema)
@@ -1247,11 +2896,13 @@ fn schema_based_property_records_are_8_bytes() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     // Create rules with property schema
     let rules = RulesFile {
         version: 1,
+        includes: vec![],
         registry: None,
         apply: vec![],
         validate: vec![],
@@ -1317,11 +2968,13 @@ fn export_with_schema_rejects_unknown_property() {
             ..Default::default()
         }],
         cancel_table: CancelTable::default(),
+        variant_errors: Vec::new(),
     };
 
     // Create rules with property schema that doesn't include "unknownProp"
     let rules = RulesFile {
         version: 1,
+        includes: vec![],
         registry: None,
         apply: vec![],
         validate: vec![],
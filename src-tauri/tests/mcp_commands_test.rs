@@ -175,6 +175,31 @@ fn get_character_nonexistent_returns_error() {
     assert!(result.unwrap_err().contains("not found"));
 }
 
+#[test]
+fn load_character_reports_variant_with_missing_base() {
+    let temp_dir = TempDir::new().unwrap();
+    let characters_dir = setup_test_project(&temp_dir);
+
+    create_test_character(&characters_dir, "test-char");
+    create_test_move(&characters_dir, "test-char", "5L", "Light Punch");
+    let states_dir = Path::new(&characters_dir).join("test-char").join("states");
+    fs::write(
+        states_dir.join("5H~level1.json"),
+        r#"{"base": "5H", "damage": 80}"#,
+    )
+    .unwrap();
+
+    let result = load_character(characters_dir, "test-char".to_string()).unwrap();
+
+    // The broken variant is left out, but the rest of the character loads.
+    let inputs: Vec<&str> = result.moves.iter().map(|m| m.input.as_str()).collect();
+    assert_eq!(inputs, vec!["5L"]);
+    assert_eq!(
+        result.variant_errors,
+        vec!["Variant '5H~level1': Base state '5H' not found"]
+    );
+}
+
 #[test]
 fn list_moves_returns_summaries() {
     let temp_dir = TempDir::new().unwrap();
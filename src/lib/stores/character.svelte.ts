@@ -1,7 +1,15 @@
 import { invoke } from "@tauri-apps/api/core";
-import type { CharacterData, CharacterSummary, State, MergedRegistry } from "$lib/types";
+import type {
+  CharacterData,
+  CharacterSummary,
+  DeleteCharacterResult,
+  SaveMoveResult,
+  State,
+  MergedRegistry,
+} from "$lib/types";
 import { loadAssets, resetAssetsState } from "./assets.svelte";
 import { getProjectPath } from "./project.svelte";
+import { showError } from "./toast.svelte";
 import { TrainingSync, createMainWindowSync } from "$lib/training";
 
 // Training sync instance (created lazily)
@@ -171,6 +179,10 @@ export async function selectCharacter(characterId: string): Promise<void> {
     rulesRegistry = registry;
     void loadAssets(characterId);
 
+    if (nextCharacter.variant_errors?.length) {
+      showError(`Broken variants: ${nextCharacter.variant_errors.join("; ")}`);
+    }
+
     // Notify training windows of character change
     notifyCharacterChange();
   } catch (e) {
@@ -208,7 +220,11 @@ export function resetCharacterState(): void {
   resetAssetsState();
 }
 
-export async function saveMove(mv: State): Promise<void> {
+/**
+ * Validate and save a move. With `blockOnError` (the default) a move with
+ * validation errors is not written; check `saved` on the result.
+ */
+export async function saveMove(mv: State, blockOnError = true): Promise<SaveMoveResult> {
   const charactersDir = getCharactersDir();
   if (!charactersDir) {
     throw new Error("No project open");
@@ -220,11 +236,15 @@ export async function saveMove(mv: State): Promise<void> {
   loading = true;
   error = null;
   try {
-    await invoke("save_move", {
+    const result = await invoke<SaveMoveResult>("save_move", {
       charactersDir,
       characterId: currentCharacter.character.id,
       mv,
+      blockOnError,
     });
+    if (!result.saved) {
+      return result;
+    }
 
     // Update local state
     const index = currentCharacter.moves.findIndex((m) => m.input === mv.input);
@@ -234,6 +254,8 @@ export async function saveMove(mv: State): Promise<void> {
 
     // Notify training windows of save
     notifyCharacterSave();
+
+    return result;
   } catch (e) {
     error = String(e);
     throw e;
@@ -242,6 +264,23 @@ export async function saveMove(mv: State): Promise<void> {
   }
 }
 
+/** Resolve a stored move through the apply rules without saving it. */
+export async function previewResolvedMove(moveInput: string): Promise<State> {
+  const charactersDir = getCharactersDir();
+  if (!charactersDir) {
+    throw new Error("No project open");
+  }
+  if (!currentCharacter) {
+    throw new Error("No character selected");
+  }
+
+  return invoke<State>("preview_resolved_move", {
+    charactersDir,
+    characterId: currentCharacter.character.id,
+    moveInput,
+  });
+}
+
 export async function exportCharacter(
   adapter: string,
   outputPath: string,
@@ -306,13 +345,13 @@ export async function cloneCharacter(
   await loadCharacterList();
 }
 
-export async function deleteCharacter(characterId: string): Promise<void> {
+export async function deleteCharacter(characterId: string): Promise<DeleteCharacterResult> {
   const charactersDir = getCharactersDir();
   if (!charactersDir) {
     throw new Error("No project open");
   }
 
-  await invoke("delete_character", {
+  const result = await invoke<DeleteCharacterResult>("delete_character", {
     charactersDir,
     characterId,
   });
@@ -326,6 +365,8 @@ export async function deleteCharacter(characterId: string): Promise<void> {
 
   // Reload character list
   await loadCharacterList();
+
+  return result;
 }
 
 export async function createMove(input: string, name: string): Promise<State> {
@@ -128,6 +128,11 @@ describe('DummyController', () => {
       controller.setState('block_auto');
       expect(controller.getWasmState()).toBe(WasmDummyState.BlockAuto);
     });
+
+    it('should convert block_after_first_hit to WASM DummyState.BlockAfterFirstHit', () => {
+      controller.setState('block_after_first_hit');
+      expect(controller.getWasmState()).toBe(WasmDummyState.BlockAfterFirstHit);
+    });
   });
 
   describe('full config update', () => {
@@ -16,7 +16,8 @@ export type DummyState =
   | 'jump'
   | 'block_stand'
   | 'block_crouch'
-  | 'block_auto';
+  | 'block_auto'
+  | 'block_after_first_hit';
 
 /**
  * Dummy recovery behavior after getting hit.
@@ -131,6 +132,8 @@ export class DummyController {
         return WasmDummyState.BlockCrouch;
       case 'block_auto':
         return WasmDummyState.BlockAuto;
+      case 'block_after_first_hit':
+        return WasmDummyState.BlockAfterFirstHit;
     }
   }
 }
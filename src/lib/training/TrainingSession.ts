@@ -20,6 +20,10 @@ export enum DummyState {
   BlockStand = 3,
   BlockCrouch = 4,
   BlockAuto = 5,
+  /** Pick one of the behaviors above with the seeded PRNG, held until the dummy recovers from its next hit or block. */
+  Random = 6,
+  /** Stand open until a hit lands, then block until the hitstun or blockstun runs out. */
+  BlockAfterFirstHit = 7,
 }
 
 /**
@@ -44,6 +48,19 @@ export interface CharacterState {
   hit_confirmed: boolean;
   block_confirmed: boolean;
   resources: number[];
+  grounded: boolean;
+  /** Remaining hitstop frames; the character holds its frame until 0. */
+  hitstop: number;
+}
+
+/**
+ * Resource definition from a character's pack.
+ *
+ * Listed in the same order as `CharacterState.resources`.
+ */
+export interface ResourceInfo {
+  name: string;
+  max: number;
 }
 
 /**
@@ -60,6 +77,27 @@ export interface HitResult {
   guard: number;
   hit_pushback: number;
   block_pushback: number;
+  /** Throw hits ignore blocking; block fields are zero. */
+  is_throw: boolean;
+  wall_bounce: boolean;
+  ground_bounce: boolean;
+  /** Vertical launch in pixels from knockback `y` (negative is up, 0 = no launch). */
+  launch: number;
+  /** The defender was in the startup, active or recovery of a move; `hitstun` includes the bonus. */
+  counter_hit: boolean;
+  /** The dummy blocked the hit; always false for hits on the player. */
+  blocked: boolean;
+}
+
+/**
+ * Stats of one finished combo on the dummy.
+ */
+export interface ComboSummary {
+  hits: number;
+  /** Damage after combo scaling (the player pack's `combo_scaling_step`/`combo_scaling_min`, 10% each by default). */
+  total_damage: number;
+  /** Most hits in any combo since the session was created or reset. */
+  max_combo: number;
 }
 
 /**
@@ -70,6 +108,25 @@ export interface PushSeparation {
   dummy_dx: number;
 }
 
+/**
+ * A collision shape in world pixels.
+ *
+ * Rects keep their raw Q8.8 rotation in `angle_raw`.
+ */
+export type BoxShape =
+  | { kind: 'aabb'; x: number; y: number; w: number; h: number }
+  | { kind: 'rect'; x: number; y: number; w: number; h: number; angle_raw: number }
+  | { kind: 'circle'; x: number; y: number; r: number }
+  | { kind: 'capsule'; x1: number; y1: number; x2: number; y2: number; r: number };
+
+/**
+ * Hit and hurt shapes active on a character's current frame.
+ */
+export interface ActiveBoxes {
+  hit: BoxShape[];
+  hurt: BoxShape[];
+}
+
 /**
  * Result of a single frame tick.
  */
@@ -78,6 +135,12 @@ export interface FrameResult {
   dummy: CharacterState;
   hits: HitResult[];
   push_separation?: PushSeparation;
+  /**
+   * Either character's health is at 0. Stays set on later ticks until
+   * `reset()`, `setInfiniteHealth(true)` or a `stepBack()` to before the KO
+   * restores health.
+   */
+  ko: boolean;
 }
 
 /**
@@ -186,6 +249,65 @@ export class TrainingSession {
     return this.session.available_cancels();
   }
 
+  /**
+   * Get cancel targets the player can actually perform right now.
+   *
+   * Unlike `availableCancels`, this also drops targets whose preconditions
+   * fail at the current distance or whose resource costs can't be paid.
+   *
+   * @returns Array of move indices that can be cancelled into
+   */
+  availableCancelsUsable(): number[] {
+    return this.session.available_cancels_usable();
+  }
+
+  /**
+   * Get resource names and maxima, in the order of `CharacterState.resources`.
+   *
+   * @param which - 0 for the player, 1 for the dummy
+   */
+  resourceNames(which: 0 | 1): ResourceInfo[] {
+    return this.session.resource_names(which);
+  }
+
+  /**
+   * Get the hit and hurt shapes active this frame, offset by position and
+   * mirrored for a character facing -x.
+   *
+   * @param which - 0 for the player, 1 for the dummy
+   */
+  activeBoxes(which: 0 | 1): ActiveBoxes {
+    return this.session.active_boxes(which);
+  }
+
+  /**
+   * Get the player's current value of a resource by name.
+   *
+   * @param name - Resource name (e.g., "meter", "heat")
+   * @returns The resource value, or undefined if the pack doesn't define it
+   */
+  resourceByName(name: string): number | undefined {
+    return this.session.resource_by_name(name);
+  }
+
+  /**
+   * Get a character's remaining health.
+   *
+   * Starts at the character's `health` property, or 1000 without one.
+   *
+   * @param which - 0 for the player, 1 for the dummy
+   */
+  health(which: 0 | 1): number {
+    return this.session.health(which);
+  }
+
+  /**
+   * Stop hits from dealing health damage. Turning it on refills both characters.
+   */
+  setInfiniteHealth(enabled: boolean): void {
+    this.session.set_infinite_health(enabled);
+  }
+
   /**
    * Get the hit results from the last tick.
    */
@@ -193,6 +315,15 @@ export class TrainingSession {
     return this.session.hit_results();
   }
 
+  /**
+   * Get the stats of the last finished combo on the dummy.
+   *
+   * A combo finishes when the dummy's hitstun runs out or it blocks.
+   */
+  lastComboSummary(): ComboSummary | undefined {
+    return this.session.last_combo_summary() ?? undefined;
+  }
+
   /**
    * Reset the session to initial state.
    */
@@ -200,6 +331,45 @@ export class TrainingSession {
     this.session.reset();
   }
 
+  /**
+   * Seed the PRNG used by `DummyState.Random`.
+   *
+   * The same seed replays the same sequence of dummy decisions.
+   *
+   * @param seed - 32-bit seed value
+   */
+  setSeed(seed: number): void {
+    this.session.set_seed(seed);
+  }
+
+  /**
+   * Undo the most recent tick.
+   *
+   * Restores both characters, positions, hits and the dummy PRNG to their
+   * state before that tick.
+   *
+   * @returns false if there is no history left to step back through
+   */
+  stepBack(): boolean {
+    return this.session.step_back();
+  }
+
+  /**
+   * Set how many ticks `stepBack` can undo (0 disables history).
+   *
+   * @param depth - Maximum number of ticks kept (default 120)
+   */
+  setHistoryDepth(depth: number): void {
+    this.session.set_history_depth(depth);
+  }
+
+  /**
+   * Number of ticks `stepBack` can currently undo.
+   */
+  historyLen(): number {
+    return this.session.history_len();
+  }
+
   /**
    * Set character positions (for collision checking).
    *
@@ -400,6 +400,15 @@ export interface State {
   // v2: Super freeze effect
   super_freeze?: SuperFreeze;
 
+  // Kara cancel: [start, end] frames (inclusive) that can cancel back to idle
+  kara?: [number, number];
+
+  // Airborne: authored as an air state (pushboxes follow the runtime's grounded state)
+  airborne?: boolean;
+
+  // Can be entered during hitstun/blockstun (bursts, alpha counters)
+  allow_from_stun?: boolean;
+
   // v2: Event callbacks
   on_use?: OnUse;
   on_hit?: OnHit;
@@ -421,6 +430,16 @@ export interface CharacterData {
   character: Character;
   moves: State[];  // JSON field name preserved for backward compatibility
   cancel_table: CancelTable;
+  /** Variants that couldn't be resolved on load; they are left out of `moves`. */
+  variant_errors?: string[];
+}
+
+/** Exported FSPK pack returned by `get_character_fspk`. */
+export interface CharacterFspk {
+  /** Base64-encoded FSPK bytes. */
+  data: string;
+  /** Hash of the pack bytes; unchanged packs keep the same hash. */
+  hash: string;
 }
 
 export interface CharacterSummary {
@@ -472,6 +491,45 @@ export interface MergedRegistry {
   chain_order?: string[];
 }
 
+// =============================================================================
+// Validation Types
+// =============================================================================
+
+export type Severity = "error" | "warning" | "info";
+
+export interface ValidationIssue {
+  field: string;
+  message: string;
+  severity: Severity;
+}
+
+/** Validation issues grouped by severity. Only errors block saving. */
+export interface IssuesBySeverity {
+  errors: ValidationIssue[];
+  warnings: ValidationIssue[];
+  info: ValidationIssue[];
+}
+
+/** Result of `save_move`. `saved` is false when errors blocked the save. */
+export interface SaveMoveResult {
+  saved: boolean;
+  issues: IssuesBySeverity;
+}
+
+/** A value in another character's data that names a deleted character. */
+export interface DanglingReference {
+  character: string;
+  /** File within that character's directory, e.g. `globals.json`. */
+  file: string;
+  /** JSON pointer to the referencing value. */
+  path: string;
+}
+
+/** Result of `delete_character`. */
+export interface DeleteCharacterResult {
+  dangling_references: DanglingReference[];
+}
+
 // =============================================================================
 // Global States Types
 // =============================================================================